@@ -0,0 +1,119 @@
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use scylla::errors::TranslationError;
+use scylla::policies::address_translator::{AddressTranslator, UntranslatedPeer};
+
+use crate::error_conversion::FFIMaybeException;
+use crate::ffi::{CSharpStr, FFIMaybeGCHandle, FFIStr, GCHandlePtr, SharedGCHandle};
+
+/// Opaque C# representation of a custom address translator.
+pub enum CustomAddressTranslator {}
+
+/// Receives the address translated by C#.
+#[derive(Debug, Default)]
+pub struct TranslatedAddress(Option<String>);
+
+/// Callback asking C# to translate the address advertised by a node.
+///
+/// C# reports the translated address with `translated_address_set`, or returns an exception
+/// if the address cannot be translated. The callback is called on a Tokio worker thread,
+/// so it must be thread-safe and must not block.
+type TranslateAddress = unsafe extern "C" fn(
+    translator: GCHandlePtr<'_, CustomAddressTranslator>,
+    untranslated_address: FFIStr<'_>,
+    out_translated: &mut TranslatedAddress,
+) -> FFIMaybeException;
+
+/// Address translator implemented in C#, passed as part of the session config.
+///
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct BridgedAddressTranslator {
+    translator: FFIMaybeGCHandle<CustomAddressTranslator>,
+    translate: Option<TranslateAddress>,
+}
+
+impl BridgedAddressTranslator {
+    /// Returns the translator calling into C#, or `None` if no translator was given.
+    pub(crate) fn into_translator(self) -> Option<Arc<dyn AddressTranslator>> {
+        let translate = self.translate?;
+        let translator = self.translator.try_into_ffi_gc_handle()?;
+
+        Some(Arc::new(CallbackAddressTranslator {
+            translator: SharedGCHandle::new(translator),
+            translate,
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct CallbackAddressTranslator {
+    translator: SharedGCHandle<CustomAddressTranslator>,
+    translate: TranslateAddress,
+}
+
+impl CallbackAddressTranslator {
+    fn translate_blocking(&self, untranslated: SocketAddr) -> Result<SocketAddr, TranslationError> {
+        let untranslated_str = untranslated.to_string();
+        let mut translated = TranslatedAddress::default();
+
+        let exception = unsafe {
+            // SAFETY: the callback and the translator handle are provided by C#,
+            // and the handle is kept alive by `self`.
+            (self.translate)(
+                self.translator.borrow(),
+                FFIStr::new(&untranslated_str),
+                &mut translated,
+            )
+        };
+
+        // The C# exception cannot be carried by `TranslationError`, so only its presence is reported.
+        if exception.has_exception() {
+            return Err(translation_failure(format!(
+                "C# address translator failed for {untranslated}"
+            )));
+        }
+
+        let Some(translated) = translated.0 else {
+            return Err(TranslationError::NoRuleForAddress(untranslated));
+        };
+
+        translated.parse().map_err(|e| {
+            translation_failure(format!(
+                "C# address translator returned invalid address {translated:?} for {untranslated}: {e}"
+            ))
+        })
+    }
+}
+
+fn translation_failure(message: String) -> TranslationError {
+    TranslationError::IoError(Arc::new(io::Error::new(
+        io::ErrorKind::InvalidData,
+        message,
+    )))
+}
+
+#[async_trait]
+impl AddressTranslator for CallbackAddressTranslator {
+    async fn translate_address(
+        &self,
+        untranslated_peer: &UntranslatedPeer,
+    ) -> Result<SocketAddr, TranslationError> {
+        self.translate_blocking(untranslated_peer.untranslated_address())
+    }
+}
+
+/// Sets the result of a [`TranslateAddress`] call, e.g. `"10.0.0.1:9042"`.
+#[unsafe(no_mangle)]
+pub extern "C" fn translated_address_set(
+    out_translated: &mut TranslatedAddress,
+    address: CSharpStr<'_>,
+) {
+    out_translated.0 = address
+        .as_cstr()
+        .map(|cstr| cstr.to_str().unwrap().to_owned());
+}
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use scylla::authentication::{AuthError, AuthenticatorProvider, AuthenticatorSession};
+
+use crate::error_conversion::FFIMaybeException;
+use crate::ffi::{FFIBool, FFIMaybeGCHandle, FFISlice, FFIStr, GCHandlePtr, SharedGCHandle};
+
+/// Opaque C# representation of a custom auth provider, shared by all connections.
+pub enum CustomAuthProvider {}
+
+/// Opaque C# representation of the authenticator of a single connection,
+/// holding the state of its SASL exchange.
+pub enum CustomAuthenticator {}
+
+/// Receives a SASL token produced by C#. A token that is never set is null,
+/// which the server tells apart from an empty one.
+#[derive(Debug, Default)]
+pub struct AuthResponse(Option<Vec<u8>>);
+
+/// Callback asking C# to create the authenticator of a new connection.
+///
+/// C# stores the handle of the new authenticator in `out_authenticator`, and Rust takes
+/// ownership of it. The name of the authenticator class of the server is passed along,
+/// as providers may depend on it.
+type NewAuthenticator = unsafe extern "C" fn(
+    provider: GCHandlePtr<'_, CustomAuthProvider>,
+    authenticator_name: FFIStr<'_>,
+    out_authenticator: &mut FFIMaybeGCHandle<CustomAuthenticator>,
+) -> FFIMaybeException;
+
+/// Callback asking C# for the first token of the SASL exchange,
+/// reported with `auth_response_set`.
+type InitialResponse = unsafe extern "C" fn(
+    authenticator: GCHandlePtr<'_, CustomAuthenticator>,
+    authenticator_name: FFIStr<'_>,
+    out_response: &mut AuthResponse,
+) -> FFIMaybeException;
+
+/// Callback asking C# to answer a challenge of the server, reported with `auth_response_set`.
+/// `challenge_is_null` tells a null challenge apart from an empty one.
+type EvaluateChallenge = unsafe extern "C" fn(
+    authenticator: GCHandlePtr<'_, CustomAuthenticator>,
+    challenge: FFISlice<'_, u8>,
+    challenge_is_null: FFIBool,
+    out_response: &mut AuthResponse,
+) -> FFIMaybeException;
+
+/// Callback passing the final token of a successful SASL exchange to C#.
+type OnSuccess = unsafe extern "C" fn(
+    authenticator: GCHandlePtr<'_, CustomAuthenticator>,
+    token: FFISlice<'_, u8>,
+    token_is_null: FFIBool,
+) -> FFIMaybeException;
+
+/// Authenticator implemented in C#, passed as part of the session config.
+///
+/// All callbacks are called on Tokio worker threads, so they must not block.
+/// Callbacks of a single connection are called one at a time, but different
+/// connections authenticate concurrently, so the provider must be thread-safe.
+/// `on_success` may be null, in which case the final token is ignored.
+///
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct BridgedAuthenticator {
+    provider: FFIMaybeGCHandle<CustomAuthProvider>,
+    new_authenticator: Option<NewAuthenticator>,
+    initial_response: Option<InitialResponse>,
+    evaluate_challenge: Option<EvaluateChallenge>,
+    on_success: Option<OnSuccess>,
+}
+
+impl BridgedAuthenticator {
+    /// Returns the provider calling into C#, or `None` if no custom authenticator was given.
+    pub(crate) fn into_provider(self) -> Option<Arc<dyn AuthenticatorProvider>> {
+        let new_authenticator = self.new_authenticator?;
+        let initial_response = self.initial_response?;
+        let evaluate_challenge = self.evaluate_challenge?;
+        let provider = self.provider.try_into_ffi_gc_handle()?;
+
+        Some(Arc::new(CallbackAuthenticatorProvider {
+            provider: SharedGCHandle::new(provider),
+            new_authenticator,
+            initial_response,
+            evaluate_challenge,
+            on_success: self.on_success,
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct CallbackAuthenticatorProvider {
+    provider: SharedGCHandle<CustomAuthProvider>,
+    new_authenticator: NewAuthenticator,
+    initial_response: InitialResponse,
+    evaluate_challenge: EvaluateChallenge,
+    on_success: Option<OnSuccess>,
+}
+
+#[async_trait]
+impl AuthenticatorProvider for CallbackAuthenticatorProvider {
+    async fn start_authentication_session(
+        &self,
+        authenticator_name: &str,
+    ) -> Result<(Option<Vec<u8>>, Box<dyn AuthenticatorSession>), AuthError> {
+        let mut authenticator = FFIMaybeGCHandle::empty();
+        let exception = unsafe {
+            // SAFETY: the callback and the provider handle are provided by C#,
+            // and the handle is kept alive by `self`.
+            (self.new_authenticator)(
+                self.provider.borrow(),
+                FFIStr::new(authenticator_name),
+                &mut authenticator,
+            )
+        };
+        // The C# exception cannot be carried by `AuthError`, so only its presence is reported.
+        // C# logs the exception itself.
+        if exception.has_exception() {
+            return Err(format!(
+                "C# auth provider failed to create an authenticator for {authenticator_name}"
+            ));
+        }
+        let Some(authenticator) = authenticator.try_into_ffi_gc_handle() else {
+            return Err(format!(
+                "C# auth provider returned no authenticator for {authenticator_name}"
+            ));
+        };
+        let authenticator = SharedGCHandle::new(authenticator);
+
+        let mut response = AuthResponse::default();
+        let exception = unsafe {
+            // SAFETY: the authenticator handle is owned by `authenticator`.
+            (self.initial_response)(
+                authenticator.borrow(),
+                FFIStr::new(authenticator_name),
+                &mut response,
+            )
+        };
+        if exception.has_exception() {
+            return Err(format!(
+                "C# authenticator failed to produce the initial response for {authenticator_name}"
+            ));
+        }
+
+        let session = CallbackAuthenticatorSession {
+            authenticator,
+            evaluate_challenge: self.evaluate_challenge,
+            on_success: self.on_success,
+        };
+        Ok((response.0, Box::new(session)))
+    }
+}
+
+/// SASL exchange of a single connection, freeing the C# authenticator once it is over.
+#[derive(Debug)]
+struct CallbackAuthenticatorSession {
+    authenticator: SharedGCHandle<CustomAuthenticator>,
+    evaluate_challenge: EvaluateChallenge,
+    on_success: Option<OnSuccess>,
+}
+
+#[async_trait]
+impl AuthenticatorSession for CallbackAuthenticatorSession {
+    async fn evaluate_challenge(
+        &mut self,
+        token: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>, AuthError> {
+        let mut response = AuthResponse::default();
+        let exception = unsafe {
+            // SAFETY: the callback and the authenticator handle are provided by C#,
+            // and the handle is kept alive by `self`.
+            (self.evaluate_challenge)(
+                self.authenticator.borrow(),
+                FFISlice::new(token.unwrap_or_default()),
+                token.is_none().into(),
+                &mut response,
+            )
+        };
+        if exception.has_exception() {
+            return Err(
+                "C# authenticator failed to evaluate the challenge of the server".to_owned(),
+            );
+        }
+        Ok(response.0)
+    }
+
+    async fn success(&mut self, token: Option<&[u8]>) -> Result<(), AuthError> {
+        let Some(on_success) = self.on_success else {
+            return Ok(());
+        };
+        let exception = unsafe {
+            // SAFETY: as above.
+            on_success(
+                self.authenticator.borrow(),
+                FFISlice::new(token.unwrap_or_default()),
+                token.is_none().into(),
+            )
+        };
+        if exception.has_exception() {
+            return Err("C# authenticator rejected the final token of the server".to_owned());
+        }
+        Ok(())
+    }
+}
+
+/// Sets the token of an [`InitialResponse`] or [`EvaluateChallenge`] call.
+/// The bytes are copied, so they only need to stay valid for this call.
+/// Not calling it leaves a null token.
+#[unsafe(no_mangle)]
+pub extern "C" fn auth_response_set(out_response: &mut AuthResponse, token: FFISlice<'_, u8>) {
+    out_response.0 = Some(token.as_slice().to_vec());
+}
@@ -0,0 +1,293 @@
+use std::sync::{Arc, Mutex};
+
+use scylla::cluster::ClusterState;
+use scylla::frame::response::result::{ColumnType, NativeType};
+use scylla::statement::Statement;
+use scylla::statement::batch::{Batch, BatchStatement, BatchType};
+
+use crate::error_conversion::FFIMaybeException;
+use crate::execution_profile::{BridgedExecutionProfile, profile_handle};
+use crate::ffi::{
+    ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, CSharpStr, FFI, FFIBool, FromArc,
+};
+use crate::pre_serialized_values::{PopulateValues, PopulateValuesContext, PreSerializedValues};
+use crate::prepared_statement::BridgedPreparedStatement;
+use crate::task::{ExceptionConstructors, ManuallyDestructible};
+
+/// A batch being built by C#, together with the values of each of its statements.
+pub struct BridgedBatch {
+    pub(crate) inner: Mutex<BatchWithValues>,
+}
+
+impl FFI for BridgedBatch {
+    type Origin = FromArc;
+}
+
+#[derive(Clone)]
+pub(crate) struct BatchWithValues {
+    pub(crate) batch: Batch,
+    pub(crate) values: Vec<PreSerializedValues>,
+}
+
+impl BatchWithValues {
+    /// Returns the index of the first prepared statement whose table does not match the batch
+    /// type, i.e. a non-counter statement in a counter batch or a counter one in another batch.
+    ///
+    /// Statements are classified by whether their table has counter columns, looked up in
+    /// `cluster_state`. Unprepared statements, whose table is only known to the server, and
+    /// statements whose table is unknown (e.g. with schema metadata disabled), are left for the
+    /// server to validate.
+    pub(crate) fn find_mismatched_counter_statement(
+        &self,
+        cluster_state: &ClusterState,
+    ) -> Option<usize> {
+        let is_counter_batch = matches!(self.batch.get_type(), BatchType::Counter);
+
+        self.batch
+            .statements
+            .iter()
+            .position(|statement| match statement {
+                BatchStatement::PreparedStatement(prepared) => prepared
+                    .get_table_spec()
+                    .and_then(|spec| {
+                        cluster_state
+                            .get_keyspace(spec.ks_name())?
+                            .tables
+                            .get(spec.table_name())
+                    })
+                    .is_some_and(|table| {
+                        let is_counter_table = table.columns.values().any(|column| {
+                            matches!(column.typ, ColumnType::Native(NativeType::Counter))
+                        });
+                        is_counter_table != is_counter_batch
+                    }),
+                BatchStatement::Query(_) => false,
+            })
+    }
+}
+
+/// Creates an empty batch of the given type: `0` for LOGGED, `1` for UNLOGGED, `2` for COUNTER.
+/// C# becomes the owner of the batch and must release it with `batch_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_new(
+    batch_type: u8,
+    out_batch: Option<&mut ManuallyDestructible>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(out_batch) = out_batch else {
+            return FFIMaybeException::null_argument("out_batch", constructors);
+        };
+
+        let batch_type = match batch_type {
+            0 => BatchType::Logged,
+            1 => BatchType::Unlogged,
+            2 => BatchType::Counter,
+            _ => {
+                let ex = constructors
+                    .invalid_argument_exception_constructor
+                    .construct_from_rust(
+                        format!("Invalid batch type value {0} passed from C#.", batch_type)
+                            .as_str(),
+                    );
+                return FFIMaybeException::from_exception(ex);
+            }
+        };
+
+        let batch = Arc::new(BridgedBatch {
+            inner: Mutex::new(BatchWithValues {
+                batch: Batch::new(batch_type),
+                values: Vec::new(),
+            }),
+        });
+        *out_batch = ManuallyDestructible::from_destructible(batch);
+        FFIMaybeException::ok()
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_free(batch_ptr: BridgedOwnedSharedPtr<BridgedBatch>) {
+    ArcFFI::free(batch_ptr);
+    tracing::trace!("[FFI] BridgedBatch freed");
+}
+
+/// Appends an unprepared statement to the batch, with values populated by C# like for
+/// `session_execute_simple`.
+///
+/// Fails with `ArgumentNullException` if `statement` is null, or with `ArgumentException`
+/// if it is not valid UTF-8.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_add_simple(
+    batch_ptr: BridgedBorrowedSharedPtr<'_, BridgedBatch>,
+    statement: CSharpStr<'_>,
+    populate_values_context: PopulateValuesContext<'_>,
+    populate_values: PopulateValues,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let batch = ArcFFI::as_ref(batch_ptr).expect("valid and non-null BridgedBatch pointer");
+        let Some(statement) = statement.as_cstr() else {
+            return FFIMaybeException::null_argument("statement", constructors);
+        };
+        let Ok(statement) = statement.to_str() else {
+            let ex = constructors
+                .argument_exception_constructor
+                .construct_from_rust("Batch statement is not valid UTF-8");
+            return FFIMaybeException::from_exception(ex);
+        };
+
+        let psv = match PreSerializedValues::from_populate_callback(
+            populate_values_context,
+            populate_values,
+        ) {
+            Ok(v) => v,
+            Err(exception) => return FFIMaybeException::from_exception(exception),
+        };
+
+        let mut guard = batch
+            .inner
+            .lock()
+            .expect("lock not poisoned by an earlier panic");
+        guard.batch.append_statement(Statement::new(statement));
+        guard.values.push(psv);
+
+        FFIMaybeException::ok()
+    })
+}
+
+/// Appends a prepared statement to the batch, with values populated by C# like for
+/// `session_execute_prepared`.
+///
+/// Fails with `RequestInvalidException` if the number of values does not match the number
+/// of bind markers of the statement.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_add_prepared(
+    batch_ptr: BridgedBorrowedSharedPtr<'_, BridgedBatch>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    populate_values_context: PopulateValuesContext<'_>,
+    populate_values: PopulateValues,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let batch = ArcFFI::as_ref(batch_ptr).expect("valid and non-null BridgedBatch pointer");
+        let bridged_prepared = ArcFFI::as_ref(prepared_statement_ptr)
+            .expect("valid and non-null BridgedPreparedStatement pointer");
+
+        let psv = match PreSerializedValues::from_populate_callback(
+            populate_values_context,
+            populate_values,
+        ) {
+            Ok(v) => v,
+            Err(exception) => return FFIMaybeException::from_exception(exception),
+        };
+
+        let prepared_statement = bridged_prepared
+            .inner
+            .read()
+            .expect("lock not poisoned by an earlier panic")
+            .clone();
+
+        let expected = prepared_statement.get_variable_col_specs().len();
+        if psv.len() != expected {
+            let ex = constructors
+                .request_invalid_exception_constructor
+                .construct_from_rust(
+                    format!(
+                        "Statement expects {expected} bound values, but {0} were given",
+                        psv.len()
+                    )
+                    .as_str(),
+                );
+            return FFIMaybeException::from_exception(ex);
+        }
+
+        let mut guard = batch
+            .inner
+            .lock()
+            .expect("lock not poisoned by an earlier panic");
+        guard.batch.append_statement(prepared_statement);
+        guard.values.push(psv);
+
+        FFIMaybeException::ok()
+    })
+}
+
+/// Sets the consistency level of the whole batch.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_set_consistency_level(
+    batch_ptr: BridgedBorrowedSharedPtr<'_, BridgedBatch>,
+    consistency_level: u16,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let batch = ArcFFI::as_ref(batch_ptr).expect("valid and non-null BridgedBatch pointer");
+
+        let Ok(cl) = consistency_level.try_into() else {
+            let ex = constructors
+                .invalid_argument_exception_constructor
+                .construct_from_rust(
+                    format!(
+                        "Invalid consistency level value {0} passed from C#.",
+                        consistency_level
+                    )
+                    .as_str(),
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
+
+        batch
+            .inner
+            .lock()
+            .expect("lock not poisoned by an earlier panic")
+            .batch
+            .set_consistency(cl);
+
+        FFIMaybeException::ok()
+    })
+}
+
+/// Sets whether the whole batch is idempotent. Counter batches are never idempotent.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_set_is_idempotent(
+    batch_ptr: BridgedBorrowedSharedPtr<'_, BridgedBatch>,
+    is_idempotent: FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let batch = ArcFFI::as_ref(batch_ptr).expect("valid and non-null BridgedBatch pointer");
+
+        let mut guard = batch
+            .inner
+            .lock()
+            .expect("lock not poisoned by an earlier panic");
+        let is_counter_batch = matches!(guard.batch.get_type(), BatchType::Counter);
+        guard
+            .batch
+            .set_is_idempotent(bool::from(is_idempotent) && !is_counter_batch);
+
+        FFIMaybeException::ok()
+    })
+}
+
+/// Attaches an execution profile to the whole batch, overriding the default profile of the
+/// session. A null `profile_ptr` detaches the profile. Profiles of the prepared statements in the
+/// batch are ignored, like their other execution options.
+#[unsafe(no_mangle)]
+pub extern "C" fn batch_set_execution_profile(
+    batch_ptr: BridgedBorrowedSharedPtr<'_, BridgedBatch>,
+    profile_ptr: BridgedBorrowedSharedPtr<'_, BridgedExecutionProfile>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let batch = ArcFFI::as_ref(batch_ptr).expect("valid and non-null BridgedBatch pointer");
+
+        batch
+            .inner
+            .lock()
+            .expect("lock not poisoned by an earlier panic")
+            .batch
+            .set_execution_profile_handle(profile_handle(profile_ptr));
+
+        FFIMaybeException::ok()
+    })
+}
@@ -0,0 +1,551 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use scylla::cluster::metadata::CollectionType;
+use scylla::deserialize::value::DeserializeValue;
+use scylla::frame::response::result::ColumnType;
+use uuid::Uuid;
+
+use crate::error_conversion::{FFIMaybeException, RowAccessError};
+use crate::ffi::{
+    ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIBool, FFISlice, FFIStr,
+    FromArc,
+};
+use crate::query_result::BridgedQueryResult;
+use crate::row::{BridgedRow, deserialize_cell, write_cell};
+use crate::row_set::column_type_to_code;
+use crate::task::ExceptionConstructors;
+use crate::udt::{BridgedTupleView, BridgedUdtView};
+
+/// A collection, UDT or tuple value, split into its serialized items.
+///
+/// Items share the response frame and are deserialized only when read, just like row cells.
+#[derive(Debug)]
+pub(crate) struct CompositeValue {
+    /// Name of the value in error messages, e.g. `tags` or `tags[2]`.
+    name: String,
+    /// Names of the items, for UDT fields only.
+    item_names: Box<[String]>,
+    /// Types of the items. Items repeat these types cyclically, which lets a single
+    /// element type (or a key and a value type) describe all items of a collection.
+    item_types: Box<[ColumnType<'static>]>,
+    items: Box<[Option<Bytes>]>,
+}
+
+impl CompositeValue {
+    pub(crate) fn new(
+        name: String,
+        item_names: Box<[String]>,
+        item_types: Box<[ColumnType<'static>]>,
+        items: Box<[Option<Bytes>]>,
+    ) -> Self {
+        Self {
+            name,
+            item_names,
+            item_types,
+            items,
+        }
+    }
+
+    pub(crate) fn items_count(&self) -> usize {
+        self.items.len()
+    }
+
+    pub(crate) fn item_type(&self, index: usize) -> &ColumnType<'static> {
+        &self.item_types[index % self.item_types.len()]
+    }
+
+    /// Type of the item at `index`, failing if there is no such item.
+    pub(crate) fn checked_item_type(
+        &self,
+        index: usize,
+    ) -> Result<&ColumnType<'static>, RowAccessError> {
+        self.item(index)?;
+        Ok(self.item_type(index))
+    }
+
+    /// Name of the item at `index`, if the items are named (UDT fields).
+    pub(crate) fn item_name(&self, index: usize) -> Result<Option<&str>, RowAccessError> {
+        self.item(index)?;
+        Ok(self.item_names.get(index).map(String::as_str))
+    }
+
+    fn item(&self, index: usize) -> Result<Option<&Bytes>, RowAccessError> {
+        self.items
+            .get(index)
+            .map(Option::as_ref)
+            .ok_or(RowAccessError::ItemIndexOutOfRange {
+                index,
+                count: self.items.len(),
+            })
+    }
+
+    /// Name of the item at `index` in error messages, e.g. `tags[2]` or `address.city`.
+    fn item_display_name(&self, index: usize) -> String {
+        match self.item_names.get(index) {
+            Some(item_name) => format!("{}.{}", self.name, item_name),
+            None => format!("{}[{}]", self.name, index),
+        }
+    }
+
+    pub(crate) fn get<'val, T>(&'val self, index: usize) -> Result<Option<T>, RowAccessError>
+    where
+        T: DeserializeValue<'val, 'val>,
+    {
+        let item = self.item(index)?;
+        deserialize_cell(&self.item_display_name(index), self.item_type(index), item)
+    }
+
+    /// Opens the item at `index` as a nested composite value.
+    pub(crate) fn open_item<V: OpenComposite>(
+        &self,
+        index: usize,
+    ) -> Result<Option<V>, RowAccessError> {
+        let item = self.item(index)?;
+        V::open(self.item_display_name(index), self.item_type(index), item)
+    }
+}
+
+/// A handle over a [`CompositeValue`] that can be opened from a serialized cell or item.
+pub(crate) trait OpenComposite: Sized {
+    /// Splits a serialized value of type `typ` into items, or returns `None` if it is null.
+    fn open(
+        name: String,
+        typ: &ColumnType<'static>,
+        value: Option<&Bytes>,
+    ) -> Result<Option<Self>, RowAccessError>;
+}
+
+/// Reads a single `[int length][bytes]` item starting at `offset`.
+/// The inner `None` denotes a null item (negative length).
+pub(crate) fn read_item(value: &Bytes, offset: &mut usize) -> Option<Option<Bytes>> {
+    let len_bytes = value.get(*offset..*offset + 4)?;
+    *offset += 4;
+
+    // A negative length denotes a null item.
+    let Ok(len) = usize::try_from(i32::from_be_bytes(len_bytes.try_into().ok()?)) else {
+        return Some(None);
+    };
+
+    let end = offset.checked_add(len).filter(|&end| end <= value.len())?;
+    let item = value.slice(*offset..end);
+    *offset = end;
+    Some(Some(item))
+}
+
+/// Splits a serialized collection into `[int length][bytes]` items.
+/// Returns `None` if the value does not hold exactly `count * items_per_entry` items.
+fn split_items(value: &Bytes, items_per_entry: usize) -> Option<Box<[Option<Bytes>]>> {
+    let count_bytes = value.get(..4)?;
+    let count = usize::try_from(i32::from_be_bytes(count_bytes.try_into().ok()?)).ok()?;
+    let items_count = count.checked_mul(items_per_entry)?;
+
+    let mut offset = 4;
+    // Every item takes at least 4 bytes, which bounds the allocation for malformed counts.
+    let mut items = Vec::with_capacity(items_count.min(value.len() / 4));
+    for _ in 0..items_count {
+        items.push(read_item(value, &mut offset)?);
+    }
+
+    (offset == value.len()).then(|| items.into_boxed_slice())
+}
+
+/// A `list`, `set` or `map` value.
+///
+/// Map entries are flattened: the key of entry `i` is item `2 * i`, its value item `2 * i + 1`.
+/// Nested (frozen) collections, UDTs and tuples are opened as handles of their own.
+#[derive(Debug)]
+pub(crate) struct BridgedCollection(CompositeValue);
+
+impl FFI for BridgedCollection {
+    type Origin = FromArc;
+}
+
+impl OpenComposite for BridgedCollection {
+    /// Note that Cassandra stores an empty non-frozen collection as null,
+    /// so only frozen collections can be read back as empty.
+    fn open(
+        name: String,
+        typ: &ColumnType<'static>,
+        value: Option<&Bytes>,
+    ) -> Result<Option<Self>, RowAccessError> {
+        let item_types: Box<[ColumnType<'static>]> = match typ {
+            ColumnType::Collection {
+                typ: CollectionType::List(element) | CollectionType::Set(element),
+                ..
+            } => Box::new([element.as_ref().clone()]),
+            ColumnType::Collection {
+                typ: CollectionType::Map(key, value),
+                ..
+            } => Box::new([key.as_ref().clone(), value.as_ref().clone()]),
+            _ => {
+                return Err(RowAccessError::UnexpectedType {
+                    name,
+                    expected: "list, set or map",
+                });
+            }
+        };
+
+        let Some(value) = value else {
+            return Ok(None);
+        };
+
+        let Some(items) = split_items(value, item_types.len()) else {
+            return Err(RowAccessError::MalformedValue { name });
+        };
+
+        Ok(Some(Self(CompositeValue::new(
+            name,
+            Box::new([]),
+            item_types,
+            items,
+        ))))
+    }
+}
+
+impl BridgedCollection {
+    /// Number of elements, or of entries for a map.
+    fn len(&self) -> usize {
+        self.0.items_count() / self.0.item_types.len()
+    }
+}
+
+/// Writes a freshly opened composite value to `out`, transferring its ownership to C#.
+pub(crate) fn write_composite<V: ArcFFI>(
+    value: Result<Option<V>, RowAccessError>,
+    out: &mut BridgedOwnedSharedPtr<V>,
+    out_is_null: &mut FFIBool,
+    constructors: &ExceptionConstructors,
+) -> FFIMaybeException {
+    let value = value.map(|v| v.map(|v| ArcFFI::into_ptr(Arc::new(v))));
+    write_cell(value, out, out_is_null, constructors)
+}
+
+/// Opens the cell at (`row`, `col`) of a result as a collection, UDT or tuple.
+pub(crate) fn open_result_cell<V: OpenComposite>(
+    result: &BridgedQueryResult,
+    row: usize,
+    col: usize,
+) -> Result<Option<V>, RowAccessError> {
+    result
+        .cell(row, col)
+        .and_then(|(column, cell)| V::open(column.name.clone(), &column.typ, cell))
+}
+
+/// Opens the `list`, `set` or `map` cell at (`row`, `col`) of a result.
+/// C# becomes the owner of the collection and must release it with `collection_free`.
+///
+/// A null collection sets `out_is_null` instead. Note that Cassandra returns
+/// an empty non-frozen collection as null.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_collection(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedCollection>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        write_composite(
+            open_result_cell(result, row, col),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+/// Opens the `list`, `set` or `map` cell at `col_index` of a row.
+/// C# becomes the owner of the collection and must release it with `collection_free`.
+///
+/// Collections are exposed as handles rather than through per-type `row_get_col_list_*`
+/// getters, so that rows, results, UDTs and tuples share the same element getters, and
+/// nested collections can be opened in turn. The handle keeps the row's response frame
+/// alive, so it may outlive the row. Its length is given by `collection_get_length`.
+///
+/// Map entries are read by index too: the key of entry `i` is item `2 * i`, and its value
+/// item `2 * i + 1`, each read with the getter of its type (see `collection_get_item_type`).
+///
+/// A null collection sets `out_is_null` instead. Note that Cassandra returns
+/// an empty non-frozen collection as null.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_collection(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedCollection>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let collection = row.cell(col_index).and_then(|(column, cell)| {
+            BridgedCollection::open(column.name.clone(), &column.typ, cell)
+        });
+        write_composite(collection, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_free(collection_ptr: BridgedOwnedSharedPtr<BridgedCollection>) {
+    ArcFFI::free(collection_ptr);
+    tracing::trace!("[FFI] BridgedCollection freed");
+}
+
+/// Writes the number of elements of a list or set, or the number of entries of a map.
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_length(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    out_length: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        *out_length = collection.len();
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the type code of item `index`, i.e. the element type, or the key type (even `index`)
+/// or value type (odd `index`) of a map.
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_item_type(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out_type_code: &mut u8,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        *out_type_code = column_type_to_code(collection.0.item_type(index));
+        FFIMaybeException::ok()
+    })
+}
+
+/// Opens item `index`, which must itself be a (frozen) collection.
+/// C# becomes the owner of the nested collection and must release it with `collection_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_collection(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedCollection>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        write_composite(
+            collection.0.open_item(index),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+/// Opens item `index`, which must be a UDT.
+/// C# becomes the owner of the view and must release it with `udt_view_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_udt(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedUdtView>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        write_composite(
+            collection.0.open_item(index),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+/// Opens item `index`, which must be a tuple.
+/// C# becomes the owner of the view and must release it with `tuple_view_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_tuple(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedTupleView>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        write_composite(
+            collection.0.open_item(index),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+// Typed item getters, with the same semantics as the typed cell getters of the result.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_int32(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        write_cell(
+            collection.0.get::<i32>(index),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_int64(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        write_cell(
+            collection.0.get::<i64>(index),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+/// Reads a `text` or `ascii` item. The string borrows from the collection.
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_text<'coll>(
+    collection_ptr: BridgedBorrowedSharedPtr<'coll, BridgedCollection>,
+    index: usize,
+    out: &mut FFIStr<'coll>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        let item = collection.0.get::<&str>(index).map(|v| v.map(FFIStr::new));
+        write_cell(item, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_bool(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut FFIBool,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        let item = collection
+            .0
+            .get::<bool>(index)
+            .map(|v| v.map(FFIBool::from));
+        write_cell(item, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_double(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut f64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        write_cell(
+            collection.0.get::<f64>(index),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_float(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut f32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        write_cell(
+            collection.0.get::<f32>(index),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+/// Reads a `blob` item. The bytes borrow from the collection.
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_blob<'coll>(
+    collection_ptr: BridgedBorrowedSharedPtr<'coll, BridgedCollection>,
+    index: usize,
+    out: &mut FFISlice<'coll, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        let item = collection
+            .0
+            .get::<&[u8]>(index)
+            .map(|v| v.map(FFISlice::new));
+        write_cell(item, out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `uuid` or `timeuuid` item as 16 big-endian bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn collection_get_uuid(
+    collection_ptr: BridgedBorrowedSharedPtr<'_, BridgedCollection>,
+    index: usize,
+    out: &mut [u8; 16],
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let collection =
+            ArcFFI::as_ref(collection_ptr).expect("valid and non-null BridgedCollection pointer");
+        let item = collection
+            .0
+            .get::<Uuid>(index)
+            .map(|v| v.map(Uuid::into_bytes));
+        write_cell(item, out, out_is_null, constructors)
+    })
+}
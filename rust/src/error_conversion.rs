@@ -1,17 +1,28 @@
-use crate::ffi::{FFIGCHandle, FFIMaybeGCHandle, FFISlice, FFIStr};
+use crate::ffi::{FFIBool, FFIGCHandle, FFIMaybeGCHandle, FFISlice, FFIStr};
+use crate::pre_serialized_values::MAX_TIME_NANOS;
 use scylla::errors::{
-    BadKeyspaceName, ClusterStateTokenError, ConnectionError, ConnectionPoolError, DbError,
-    DeserializationError, MetadataError, NewSessionError, NextPageError, NextRowError,
-    PagerExecutionError, PrepareError, RequestAttemptError, RequestError, SchemaAgreementError,
-    SerializationError, TypeCheckError, UseKeyspaceError,
+    BadKeyspaceName, ClusterStateTokenError, ConnectionError, ConnectionPoolError,
+    ConnectionSetupRequestErrorKind, DbError, DeserializationError, ExecutionError,
+    IntoRowsResultError, MetadataError, NewSessionError, NextPageError, NextRowError,
+    PagerExecutionError, PrepareError, RequestAttemptError, RequestError, RowsError,
+    SchemaAgreementError, SerializationError, TracingError, TypeCheckError, UseKeyspaceError,
 };
+use scylla::errors::{OperationType, WriteType};
+use scylla::observability::history::{AttemptResult, StructuredHistory};
+use scylla::statement::Consistency;
+use std::any::Any;
 use std::fmt::{Debug, Display};
 use std::mem::size_of;
+use std::net::{IpAddr, SocketAddr};
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::Arc;
 use thiserror::Error;
 
 use crate::task::ExceptionConstructors;
 
+#[cfg(test)]
+pub(crate) mod testing;
+
 // Opaque type representing a C# Exception.
 enum Exception {}
 
@@ -30,38 +41,245 @@ const _: [(); size_of::<FFIException>()] = [(); size_of::<(*const (), *const ())
 /// The pointer inside this package references a GCHandle allocated on the C# side.
 /// Rust must treat this as an opaque handle and must not attempt to free it.
 /// At the managed boundary, C# must either throw (which frees) or explicitly free the handle.
+///
+/// Alongside the exception, the package carries a stable [`FFIErrorCode`] and the address of the
+/// node the error concerns, if known. They let C# branch on the kind of error, e.g. in retry
+/// loops, without inspecting the exception. See `ffi_exception_get_code` and `ffi_exception_get_host`.
 /// All changes to this struct must be mirrored in C# code in the exact same order.
-#[repr(transparent)]
+#[repr(C)]
 #[must_use]
-pub struct FFIMaybeException(FFIMaybeGCHandle<Exception>);
+pub struct FFIMaybeException {
+    exception: FFIMaybeGCHandle<Exception>,
+    code: FFIErrorCode,
+    host: FFIErrorHost,
+}
+
+// Compile-time assertion on the layout of `FFIMaybeException`.
+// Ensures ABI compatibility with C# (opaque FFIMaybeGCHandle, then the code and the host).
+const _: [(); size_of::<FFIMaybeException>()] =
+    [(); size_of::<((*const (), *const ()), i32, (u16, u8, [u8; 16]))>()];
+
+/// Stable category of an error passed to C#, independent of the exception type it maps to.
+/// All changes to this enum must be mirrored in C# code. Values must never be reused.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FFIErrorCode {
+    /// No error occurred.
+    None = 0,
+    /// An error without a more specific category, including exceptions raised by C# callbacks.
+    Other = 1,
+    /// A bug in the driver, such as a caught panic.
+    InternalError = 2,
+    /// An invalid argument was passed from C#.
+    InvalidArgument = 3,
+    Serialization = 4,
+    Deserialization = 5,
+    SyntaxError = 6,
+    /// The server rejected the request as invalid, e.g. for a missing table or a bad configuration.
+    InvalidQuery = 7,
+    Unauthorized = 8,
+    AlreadyExists = 9,
+    Unavailable = 10,
+    Overloaded = 11,
+    /// The server timed out waiting for replicas.
+    ServerTimeout = 12,
+    /// Replicas reported a failure, e.g. of a read, a write or a function.
+    ServerFailure = 13,
+    /// Any other error reported by the server.
+    ServerError = 14,
+    /// The driver timed out waiting for the response.
+    ClientTimeout = 15,
+    /// The connection to the node broke, or could not be established.
+    ConnectionBroken = 16,
+    /// No node could serve the request.
+    NoHostAvailable = 17,
+    /// The driver rejected the request before sending it, or the exchange broke the protocol.
+    RequestInvalid = 18,
+}
+
+/// Address of the node an error concerns, as passed in [`FFIMaybeException`].
+/// `ip_len` is 4 for IPv4, 16 for IPv6, or 0 if the node is unknown.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FFIErrorHost {
+    port: u16,
+    ip_len: u8,
+    ip: [u8; 16],
+}
 
-// Compile-time assertion that `FFIMaybeException` is double-pointer-sized.
-// Ensures ABI compatibility with C# (opaque FFIMaybeGCHandle across FFI).
-const _: [(); size_of::<FFIMaybeException>()] = [(); size_of::<(*const (), *const ())>()];
+impl FFIErrorHost {
+    fn unknown() -> Self {
+        Self {
+            port: 0,
+            ip_len: 0,
+            ip: [0; 16],
+        }
+    }
+
+    fn address(&self) -> Option<SocketAddr> {
+        let ip = match self.ip_len {
+            4 => IpAddr::from(<[u8; 4]>::try_from(&self.ip[..4]).ok()?),
+            16 => IpAddr::from(self.ip),
+            _ => return None,
+        };
+        Some(SocketAddr::new(ip, self.port))
+    }
+}
+
+impl From<Option<SocketAddr>> for FFIErrorHost {
+    fn from(address: Option<SocketAddr>) -> Self {
+        let Some(address) = address else {
+            return Self::unknown();
+        };
+        let mut host = Self {
+            port: address.port(),
+            ..Self::unknown()
+        };
+        match address.ip() {
+            IpAddr::V4(ip) => {
+                host.ip[..4].copy_from_slice(&ip.octets());
+                host.ip_len = 4;
+            }
+            IpAddr::V6(ip) => {
+                host.ip = ip.octets();
+                host.ip_len = 16;
+            }
+        }
+        host
+    }
+}
 
 impl FFIMaybeException {
     pub(crate) fn ok() -> Self {
-        Self(FFIMaybeGCHandle::empty())
+        Self {
+            exception: FFIMaybeGCHandle::empty(),
+            code: FFIErrorCode::None,
+            host: FFIErrorHost::unknown(),
+        }
     }
 
+    /// Wraps an exception that was already constructed, of no more specific category.
     pub(crate) fn from_exception(exception: FFIException) -> Self {
-        Self(exception.0.into_ffi_maybe_gc_handle())
+        Self::from_exception_with_code(exception, FFIErrorCode::Other, None)
+    }
+
+    fn from_exception_with_code(
+        exception: FFIException,
+        code: FFIErrorCode,
+        host: Option<SocketAddr>,
+    ) -> Self {
+        Self {
+            exception: exception.0.into_ffi_maybe_gc_handle(),
+            code,
+            host: host.into(),
+        }
     }
 
     pub(crate) fn from_error<E>(error: E, constructors: &ExceptionConstructors) -> Self
     where
         E: ErrorToException,
     {
+        let code = error.error_code();
+        let host = error.error_host();
         let exception_ptr = error.to_exception(constructors);
-        Self::from_exception(exception_ptr)
+        Self::from_exception_with_code(exception_ptr, code, host)
     }
 
     pub(crate) fn has_exception(&self) -> bool {
-        !self.0.is_empty()
+        !self.exception.is_empty()
     }
 
     pub(crate) fn try_into_ffi_exception(self) -> Option<FFIException> {
-        self.0.try_into_ffi_gc_handle().map(FFIException)
+        self.exception.try_into_ffi_gc_handle().map(FFIException)
+    }
+
+    /// Reports a null pointer passed from C# as `ArgumentNullException` naming `param_name`.
+    ///
+    /// Entry points check every pointer argument, including out-pointers taken as
+    /// `Option<&mut T>`, with this before touching them, instead of panicking on null.
+    pub(crate) fn null_argument(
+        param_name: &'static str,
+        constructors: &ExceptionConstructors,
+    ) -> Self {
+        Self::from_error(NullArgumentError { param_name }, constructors)
+    }
+
+    /// Runs the body of an `extern "C"` function, turning a panic into a C# `DriverInternalError`.
+    ///
+    /// Every entry point that returns `FFIMaybeException` and receives the exception constructors
+    /// must wrap its body in this, so that a panic never unwinds into managed code.
+    /// Entry points without an exception channel abort the process on panic instead,
+    /// as Rust does for any panic escaping an `extern "C"` function.
+    pub(crate) fn catch_panics(
+        constructors: &ExceptionConstructors,
+        body: impl FnOnce() -> FFIMaybeException,
+    ) -> Self {
+        // Shared state touched by a panicking body is either behind a lock, which gets poisoned,
+        // or read-only, so observing it after the panic is sound.
+        match panic::catch_unwind(AssertUnwindSafe(body)) {
+            Ok(result) => result,
+            Err(payload) => Self::from_panic(constructors, payload.as_ref()),
+        }
+    }
+
+    /// Reports a caught panic as a C# `DriverInternalError`.
+    pub(crate) fn from_panic(
+        constructors: &ExceptionConstructors,
+        payload: &(dyn Any + Send),
+    ) -> Self {
+        Self::from_exception_with_code(
+            constructors
+                .driver_internal_error_constructor
+                .construct_from_panic(payload),
+            FFIErrorCode::InternalError,
+            None,
+        )
+    }
+}
+
+/// Returns the category of the error in `exception`, or `None` if it holds no exception.
+#[unsafe(no_mangle)]
+pub extern "C" fn ffi_exception_get_code(exception: &FFIMaybeException) -> FFIErrorCode {
+    exception.code
+}
+
+/// Writes the address of the node the error in `exception` concerns.
+///
+/// Returns false, leaving the outputs unchanged, if the node is unknown or there is no error.
+/// Only the first `out_ip_len` bytes of `out_ip` are written: 4 for IPv4, 16 for IPv6.
+#[unsafe(no_mangle)]
+pub extern "C" fn ffi_exception_get_host(
+    exception: &FFIMaybeException,
+    out_ip: &mut [u8; 16],
+    out_ip_len: &mut usize,
+    out_port: &mut u16,
+) -> FFIBool {
+    let Some(address) = exception.host.address() else {
+        return false.into();
+    };
+    match address.ip() {
+        IpAddr::V4(ip) => {
+            out_ip[..4].copy_from_slice(&ip.octets());
+            *out_ip_len = 4;
+        }
+        IpAddr::V6(ip) => {
+            *out_ip = ip.octets();
+            *out_ip_len = 16;
+        }
+    }
+    *out_port = address.port();
+    true.into()
+}
+
+/// Extracts the message of a caught panic.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    // Panic payloads can be of any type, but `panic!()` macro only uses &str or String.
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.as_str()
+    } else {
+        "Weird panic with non-string payload"
     }
 }
 
@@ -84,6 +302,20 @@ impl RustExceptionConstructor {
 // These constructors allow us to preserve the specific exception type and message on the C# side for
 // well-known error conditions, rather than converting them all to a generic RustException.
 
+/// FFI constructor for C# `DriverInternalError`, raised when Rust code panics.
+#[repr(transparent)]
+pub struct DriverInternalErrorConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> FFIException,
+);
+
+impl DriverInternalErrorConstructor {
+    pub(crate) fn construct_from_panic(&self, payload: &(dyn Any + Send)) -> FFIException {
+        let message = format!("Rust panic: {}", panic_message(payload));
+        let message = FFIStr::new(&message);
+        unsafe { (self.0)(message) }
+    }
+}
+
 /// FFI constructor for C# `FunctionFailureException`.
 #[repr(transparent)]
 pub struct FunctionFailureExceptionConstructor(
@@ -110,16 +342,53 @@ impl InvalidConfigurationInQueryExceptionConstructor {
     }
 }
 
+/// Failure of a single host, as reported to C# `NoHostAvailableException`.
+///
+/// The exception stays owned by Rust and is freed after the constructor returns,
+/// so C# must only read the exception object and not free its handle.
+#[repr(C)]
+pub struct FFIHostError<'a> {
+    address: FFIStr<'a>,
+    exception: FFIException,
+}
+
 /// FFI constructor for C# `NoHostAvailableException`.
+///
+/// `host_errors` holds the failure of each attempted host, and may be empty
+/// when the hosts are unknown.
 #[repr(transparent)]
 pub struct NoHostAvailableExceptionConstructor(
-    unsafe extern "C" fn(message: FFIStr) -> FFIException,
+    unsafe extern "C" fn(
+        message: FFIStr,
+        host_errors: FFISlice<'_, FFIHostError<'_>>,
+    ) -> FFIException,
 );
 
 impl NoHostAvailableExceptionConstructor {
     pub(crate) fn construct_from_rust(&self, message: &str) -> FFIException {
+        self.construct_with_host_errors(message, Vec::new())
+    }
+
+    /// Builds the exception from the address of each attempted host and the exception
+    /// its failure was converted to.
+    pub(crate) fn construct_with_host_errors(
+        &self,
+        message: &str,
+        host_errors: Vec<(String, FFIException)>,
+    ) -> FFIException {
         let message = FFIStr::new(message);
-        unsafe { (self.0)(message) }
+        let (addresses, exceptions): (Vec<String>, Vec<FFIException>) =
+            host_errors.into_iter().unzip();
+        // Dropping the host errors after the call frees the exception handles.
+        let host_errors: Vec<FFIHostError<'_>> = addresses
+            .iter()
+            .zip(exceptions)
+            .map(|(address, exception)| FFIHostError {
+                address: FFIStr::new(address),
+                exception,
+            })
+            .collect();
+        unsafe { (self.0)(message, FFISlice::new(&host_errors)) }
     }
 }
 
@@ -152,15 +421,14 @@ impl PreparedQueryNotFoundExceptionConstructor {
     }
 }
 
-// TODO: Use this constructor for a specific error type.
-/// FFI constructor for C# `RequestInvalidException` (currently unused).
+/// FFI constructor for C# `RequestInvalidException`, raised for requests rejected
+/// on the client side, before (or instead of) being processed by the server.
 #[repr(transparent)]
 pub struct RequestInvalidExceptionConstructor(
     unsafe extern "C" fn(message: FFIStr<'_>) -> FFIException,
 );
 
 impl RequestInvalidExceptionConstructor {
-    #[expect(dead_code)] // Currently unused
     pub(crate) fn construct_from_rust(&self, message: &str) -> FFIException {
         let message = FFIStr::new(message);
         unsafe { (self.0)(message) }
@@ -191,6 +459,35 @@ impl ArgumentExceptionConstructor {
     }
 }
 
+/// FFI constructor for C# `AuthenticationException`.
+#[repr(transparent)]
+pub struct AuthenticationExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>, host: FFIStr<'_>) -> FFIException,
+);
+
+impl AuthenticationExceptionConstructor {
+    /// Builds an `AuthenticationException` for a failed login to `host`, given as `ip:port`.
+    pub(crate) fn construct_from_rust(&self, message: &str, host: &str) -> FFIException {
+        let message = FFIStr::new(message);
+        let host = FFIStr::new(host);
+        unsafe { (self.0)(message, host) }
+    }
+}
+
+/// FFI constructor for C# `System.ArgumentNullException`.
+#[repr(transparent)]
+pub struct ArgumentNullExceptionConstructor(
+    unsafe extern "C" fn(param_name: FFIStr<'_>, message: FFIStr<'_>) -> FFIException,
+);
+
+impl ArgumentNullExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, param_name: &str, message: &str) -> FFIException {
+        let param_name = FFIStr::new(param_name);
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(param_name, message) }
+    }
+}
+
 /// FFI constructor for C# `SchemaAgreementRequiredHostAbsentException`.
 #[repr(transparent)]
 pub struct SchemaAgreementRequiredHostAbsentExceptionConstructor(
@@ -256,15 +553,13 @@ impl SyntaxErrorExceptionConstructor {
     }
 }
 
-// TODO: Use this constructor for a specific error type.
-/// FFI constructor for C# `TraceRetrievalException` (currently unused).
+/// FFI constructor for C# `TraceRetrievalException`.
 #[repr(transparent)]
 pub struct TraceRetrievalExceptionConstructor(
     unsafe extern "C" fn(message: FFIStr<'_>) -> FFIException,
 );
 
 impl TraceRetrievalExceptionConstructor {
-    #[expect(dead_code)] // Currently unused
     pub(crate) fn construct_from_rust(&self, message: &str) -> FFIException {
         let message = FFIStr::new(message);
         unsafe { (self.0)(message) }
@@ -298,15 +593,23 @@ impl UnauthorizedExceptionConstructor {
 /// FFI constructor for C# `AlreadyExistsException`.
 #[repr(transparent)]
 pub struct AlreadyExistsConstructor(
-    unsafe extern "C" fn(keyspace: FFIStr<'_>, table: FFIStr<'_>) -> FFIException,
+    unsafe extern "C" fn(
+        keyspace: FFIStr<'_>,
+        table: FFIStr<'_>,
+        was_table_creation: FFIBool,
+    ) -> FFIException,
 );
 
 impl AlreadyExistsConstructor {
-    /// Builds an `AlreadyExistsException` from keyspace and table names.
+    /// Builds an `AlreadyExistsException` from the keyspace and table names of the server error.
+    ///
+    /// When a keyspace creation conflicts, the server sends an empty table name, which is
+    /// passed on as is, and `was_table_creation` is false.
     pub(crate) fn construct_from_rust(&self, keyspace: &str, table: &str) -> FFIException {
+        let was_table_creation = !table.is_empty();
         let ks = FFIStr::new(keyspace);
         let tb = FFIStr::new(table);
-        unsafe { (self.0)(ks, tb) }
+        unsafe { (self.0)(ks, tb, was_table_creation.into()) }
     }
 }
 
@@ -370,6 +673,184 @@ impl DeserializationExceptionConstructor {
     }
 }
 
+/// FFI constructor for C# `OverloadedException`.
+#[repr(transparent)]
+pub struct OverloadedExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> FFIException,
+);
+
+impl OverloadedExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> FFIException {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `IsBootstrappingException`.
+#[repr(transparent)]
+pub struct IsBootstrappingExceptionConstructor(
+    unsafe extern "C" fn(message: FFIStr<'_>) -> FFIException,
+);
+
+impl IsBootstrappingExceptionConstructor {
+    pub(crate) fn construct_from_rust(&self, message: &str) -> FFIException {
+        let message = FFIStr::new(message);
+        unsafe { (self.0)(message) }
+    }
+}
+
+/// FFI constructor for C# `RateLimitReachedException`, raised by Scylla's per-partition rate limiting.
+///
+/// The operation type is passed as its protocol code: `0` for reads and `1` for writes.
+#[repr(transparent)]
+pub struct RateLimitReachedExceptionConstructor(
+    unsafe extern "C" fn(op_type: u8, rejected_by_coordinator: FFIBool) -> FFIException,
+);
+
+impl RateLimitReachedExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        op_type: &OperationType,
+        rejected_by_coordinator: bool,
+    ) -> FFIException {
+        let op_type = match op_type {
+            OperationType::Read => 0,
+            OperationType::Write => 1,
+            OperationType::Other(code) => *code,
+        };
+        unsafe { (self.0)(op_type, rejected_by_coordinator.into()) }
+    }
+}
+
+/// FFI constructor for C# `ReadFailureException`.
+#[repr(transparent)]
+pub struct ReadFailureExceptionConstructor(
+    unsafe extern "C" fn(
+        consistency: u16,
+        received: i32,
+        required: i32,
+        failures: i32,
+        data_present: FFIBool,
+    ) -> FFIException,
+);
+
+impl ReadFailureExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        received: i32,
+        required: i32,
+        failures: i32,
+        data_present: bool,
+    ) -> FFIException {
+        unsafe {
+            (self.0)(
+                consistency as u16,
+                received,
+                required,
+                failures,
+                data_present.into(),
+            )
+        }
+    }
+}
+
+/// FFI constructor for C# `ReadTimeoutException`.
+///
+/// `data_present` must be passed on, as the C# retry policies decide on it.
+#[repr(transparent)]
+pub struct ReadTimeoutExceptionConstructor(
+    unsafe extern "C" fn(
+        consistency: u16,
+        received: i32,
+        required: i32,
+        data_present: FFIBool,
+    ) -> FFIException,
+);
+
+impl ReadTimeoutExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        received: i32,
+        required: i32,
+        data_present: bool,
+    ) -> FFIException {
+        unsafe { (self.0)(consistency as u16, received, required, data_present.into()) }
+    }
+}
+
+/// FFI constructor for C# `UnavailableException`.
+#[repr(transparent)]
+pub struct UnavailableExceptionConstructor(
+    unsafe extern "C" fn(consistency: u16, required: i32, alive: i32) -> FFIException,
+);
+
+impl UnavailableExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        required: i32,
+        alive: i32,
+    ) -> FFIException {
+        unsafe { (self.0)(consistency as u16, required, alive) }
+    }
+}
+
+/// FFI constructor for C# `WriteFailureException`.
+///
+/// Like for `WriteTimeoutException`, the write type is passed as its protocol name.
+#[repr(transparent)]
+pub struct WriteFailureExceptionConstructor(
+    unsafe extern "C" fn(
+        consistency: u16,
+        received: i32,
+        required: i32,
+        failures: i32,
+        write_type: FFIStr<'_>,
+    ) -> FFIException,
+);
+
+impl WriteFailureExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        received: i32,
+        required: i32,
+        failures: i32,
+        write_type: &WriteType,
+    ) -> FFIException {
+        let write_type = FFIStr::new(write_type.as_str());
+        unsafe { (self.0)(consistency as u16, received, required, failures, write_type) }
+    }
+}
+
+/// FFI constructor for C# `WriteTimeoutException`.
+///
+/// The write type is passed as its protocol name (e.g. `SIMPLE`, `BATCH_LOG`), which C# expects.
+#[repr(transparent)]
+pub struct WriteTimeoutExceptionConstructor(
+    unsafe extern "C" fn(
+        consistency: u16,
+        received: i32,
+        required: i32,
+        write_type: FFIStr<'_>,
+    ) -> FFIException,
+);
+
+impl WriteTimeoutExceptionConstructor {
+    pub(crate) fn construct_from_rust(
+        &self,
+        consistency: Consistency,
+        received: i32,
+        required: i32,
+        write_type: &WriteType,
+    ) -> FFIException {
+        let write_type = FFIStr::new(write_type.as_str());
+        unsafe { (self.0)(consistency as u16, received, required, write_type) }
+    }
+}
+
 // Special errors for C# wrapper.
 
 /// Wrapper enum to represent errors that may occur during session operations:
@@ -414,6 +895,313 @@ pub(crate) enum MetadataBridgeError {
     TokenComputationFailed(#[from] ClusterStateTokenError),
 }
 
+/// Errors detected while translating the C# session configuration into a `SessionBuilder`.
+#[derive(Debug, Error)]
+pub(crate) enum SessionConfigError {
+    #[error("Local datacenter name must not be empty when cross-datacenter failover is disabled")]
+    EmptyLocalDatacenter,
+
+    #[error("Local rack cannot be set without a local datacenter")]
+    RackWithoutDatacenter,
+
+    #[error("Unknown retry policy kind: {0}")]
+    UnknownRetryPolicy(u8),
+
+    #[error("Unknown compression kind: {0}")]
+    UnknownCompression(u8),
+
+    #[error("Invalid IP address in host filter: {0:?}")]
+    InvalidHostFilterAddress(String),
+
+    #[error("Custom retry policy selected, but no policy was provided")]
+    MissingCustomRetryPolicy,
+
+    #[error("Unknown speculative execution policy kind: {0}")]
+    UnknownSpeculativeExecutionPolicy(u8),
+
+    #[error("Speculative execution delay must be positive, got {0} ms")]
+    InvalidSpeculativeExecutionDelay(i64),
+
+    #[error("Speculative execution percentile must be between 0 and 100 exclusive, got {0}")]
+    InvalidSpeculativeExecutionPercentile(f64),
+
+    #[error("Invalid consistency level value {0} in execution profile")]
+    InvalidConsistency(u16),
+
+    #[error("Invalid serial consistency level value {0} in execution profile")]
+    InvalidSerialConsistency(u16),
+
+    #[error("Username must not be empty")]
+    EmptyUsername,
+
+    #[error("Invalid CA certificate: {0}")]
+    InvalidCaCertificate(String),
+
+    #[error("Invalid client certificate: {0}")]
+    InvalidClientCertificate(String),
+
+    #[error("Invalid client private key: {0}")]
+    InvalidClientKey(String),
+
+    #[error("A client certificate and its private key must be set together")]
+    IncompleteClientIdentity,
+
+    #[error("Unknown TLS protocols: {0:#04x}")]
+    UnknownTlsProtocols(u8),
+
+    #[error("The {0} must be given either inline or as a file path, not both")]
+    ConflictingTlsSources(&'static str),
+
+    #[error("The path of the {0} file is not valid UTF-8")]
+    InvalidTlsFilePath(&'static str),
+
+    #[error("Failed to read the {what} file {}: {source}", path.display())]
+    UnreadableTlsFile {
+        what: &'static str,
+        path: std::path::PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid TLS configuration: {0}")]
+    Tls(String),
+}
+
+/// Errors raised when reading cells of a result row.
+#[derive(Debug, Error)]
+pub(crate) enum RowAccessError {
+    #[error("Column index {index} is out of range for a row with {count} columns")]
+    ColumnIndexOutOfRange { index: usize, count: usize },
+
+    #[error("Row index {index} is out of range for a result with {count} rows")]
+    RowIndexOutOfRange { index: usize, count: usize },
+
+    #[error("Item index {index} is out of range for {count} items")]
+    ItemIndexOutOfRange { index: usize, count: usize },
+
+    #[error("Column {name} is not a {expected}")]
+    UnexpectedType {
+        name: String,
+        expected: &'static str,
+    },
+
+    #[error("Column {name} holds a malformed value")]
+    MalformedValue { name: String },
+
+    #[error("Column {name} cannot be read as the requested type: {source}")]
+    TypeMismatch {
+        name: String,
+        #[source]
+        source: TypeCheckError,
+    },
+
+    #[error("Failed to deserialize the cell: {0}")]
+    Deserialization(#[source] DeserializationError),
+
+    #[error("Result has no [applied] column, as it is not the result of a conditional statement")]
+    NotConditional,
+}
+
+/// Errors raised when taking the next rows of a row cursor.
+#[derive(Debug, Error)]
+pub(crate) enum RowCursorError {
+    #[error(transparent)]
+    NextRow(#[from] NextRowError),
+
+    /// The background task fetching the rows panicked, so the stream ended early.
+    #[error("Row cursor producer panicked: {}", panic_message(.0.as_ref()))]
+    ProducerPanicked(Box<dyn Any + Send>),
+
+    #[error("Row cursor producer was cancelled before the end of the stream")]
+    ProducerCancelled,
+}
+
+impl From<tokio::task::JoinError> for RowCursorError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        match error.try_into_panic() {
+            Ok(payload) => RowCursorError::ProducerPanicked(payload),
+            Err(_) => RowCursorError::ProducerCancelled,
+        }
+    }
+}
+
+/// A null pointer was passed from C# where a valid one is required.
+#[derive(Debug, Error)]
+#[error("Value cannot be null (parameter '{param_name}')")]
+pub(crate) struct NullArgumentError {
+    pub(crate) param_name: &'static str,
+}
+
+/// Errors raised while binding a value that Rust validates before sending it.
+/// Reported to C# wrapped in a `SerializationError`.
+#[derive(Debug, Error)]
+pub(crate) enum BindValueError {
+    #[error("A uuid must be 16 bytes long, got {0} bytes")]
+    InvalidUuidLength(usize),
+
+    #[error("A timeuuid must be a version 1 UUID, got version {0}")]
+    NotTimeuuid(usize),
+
+    #[error("An inet address must be 4 or 16 bytes long, got {0} bytes")]
+    InvalidInetLength(usize),
+
+    #[error("Collection type code {0:#04x} is neither a list nor a set")]
+    NotAListOrSet(u8),
+
+    #[error("The serialized elements do not hold exactly {0} elements")]
+    MalformedCollection(i32),
+
+    #[error("Element {0} of the collection is null, which collections cannot hold")]
+    NullCollectionElement(usize),
+
+    #[error("Element {index} of the collection is not a valid value of type code {type_code:#04x}")]
+    InvalidCollectionElement { index: usize, type_code: u8 },
+
+    #[error("The serialized fields do not hold exactly {0} tuple fields")]
+    MalformedTuple(usize),
+
+    #[error("Field {index} of the tuple is not a valid value of type code {type_code:#04x}")]
+    InvalidTupleField { index: usize, type_code: u8 },
+
+    #[error("The serialized fields are not at most {0} user defined type fields")]
+    MalformedUdt(usize),
+
+    #[error(
+        "Field {index} of the user defined type is not a valid value of type code {type_code:#04x}"
+    )]
+    InvalidUdtField { index: usize, type_code: u8 },
+
+    #[error("The serialized entries do not hold exactly {0} key/value pairs")]
+    MalformedMap(i32),
+
+    #[error("The {part} of entry {entry} of the map is null, which maps cannot hold")]
+    NullMapItem { entry: usize, part: &'static str },
+
+    #[error(
+        "The {part} of entry {entry} of the map is not a valid value of type code {type_code:#04x}"
+    )]
+    InvalidMapItem {
+        entry: usize,
+        part: &'static str,
+        type_code: u8,
+    },
+
+    #[error("The unscaled value of a decimal must be at least one byte long")]
+    EmptyUnscaledDecimal,
+
+    #[error("A varint must be at least one byte long")]
+    EmptyVarint,
+
+    #[error(
+        "A date must be within {} and {} days since the epoch, got {0}",
+        i32::MIN,
+        i32::MAX
+    )]
+    DateOutOfRange(i64),
+
+    #[error(
+        "A time must be within 0 and {} nanoseconds since midnight, got {0}",
+        MAX_TIME_NANOS
+    )]
+    TimeOutOfRange(i64),
+
+    #[error(
+        "The months, days and nanoseconds of a duration must not have different signs, \
+         got {months}, {days} and {nanoseconds}"
+    )]
+    MixedSignDuration {
+        months: i32,
+        days: i32,
+        nanoseconds: i64,
+    },
+
+    #[error("A vector must have a positive dimension, got {0}")]
+    InvalidVectorDimension(i32),
+
+    #[error("A vector of dimension {expected} must hold {expected} elements, got {actual}")]
+    VectorDimensionMismatch { expected: usize, actual: usize },
+}
+
+/// Errors raised while reading a response into a query result handed over to C#.
+#[derive(Debug, Error)]
+pub(crate) enum QueryResultError {
+    #[error(transparent)]
+    IntoRowsResult(#[from] IntoRowsResultError),
+
+    #[error(transparent)]
+    Rows(#[from] RowsError),
+
+    #[error(transparent)]
+    Deserialization(#[from] DeserializationError),
+}
+
+/// Errors raised by executions returning a whole result, or a single page of it,
+/// as a `BridgedQueryResult`.
+#[derive(Debug, Error)]
+pub(crate) enum SinglePageExecutionError {
+    #[error("Statement is empty")]
+    EmptyStatement,
+
+    #[error("Statement expects {expected} bound values, but {actual} were given")]
+    BindCountMismatch { expected: usize, actual: usize },
+
+    #[error(
+        "Statement {index} of the batch cannot be executed in it: counter and non-counter \
+         statements must be sent in a COUNTER and a LOGGED or UNLOGGED batch respectively"
+    )]
+    CounterBatchMismatch { index: usize },
+
+    #[error(transparent)]
+    Execution(#[from] ExecutionError),
+
+    /// No host could serve the request. Holds the failure of each attempt made,
+    /// as recorded in the request history.
+    #[error("All hosts tried for query failed: {error}")]
+    NoHostAvailable {
+        error: ExecutionError,
+        host_errors: Vec<(SocketAddr, RequestAttemptError)>,
+    },
+
+    #[error(transparent)]
+    Result(#[from] QueryResultError),
+}
+
+impl SinglePageExecutionError {
+    /// Wraps a failed execution, turning host unavailability into [`Self::NoHostAvailable`]
+    /// with the attempts found in `history`, which should come from a `HistoryCollector`
+    /// attached to the executed statement.
+    pub(crate) fn from_execution(error: ExecutionError, history: &StructuredHistory) -> Self {
+        let is_no_host_available = matches!(
+            error,
+            ExecutionError::EmptyPlan
+                | ExecutionError::ConnectionPoolError(_)
+                | ExecutionError::LastAttemptError(
+                    RequestAttemptError::BrokenConnectionError(_)
+                        | RequestAttemptError::UnableToAllocStreamId
+                )
+        );
+        if !is_no_host_available {
+            return Self::Execution(error);
+        }
+
+        let host_errors = history
+            .requests
+            .last()
+            .into_iter()
+            .flat_map(|request| {
+                std::iter::once(&request.non_speculative_fiber).chain(&request.speculative_fibers)
+            })
+            .flat_map(|fiber| &fiber.attempts)
+            .filter_map(|attempt| match &attempt.result {
+                Some(AttemptResult::Error(_, error, _)) => Some((attempt.node_addr, error.clone())),
+                Some(AttemptResult::Success(_)) | None => None,
+            })
+            .collect();
+
+        Self::NoHostAvailable { error, host_errors }
+    }
+}
+
 /// Trait for converting Rust error types into pointers to C# exceptions using constructors from the TCB.
 ///
 /// # Purpose
@@ -431,6 +1219,16 @@ pub(crate) enum MetadataBridgeError {
 /// The handle must be freed on the C# side when no longer needed.
 pub trait ErrorToException {
     fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException;
+
+    /// Stable category of the error, passed to C# alongside the exception.
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::Other
+    }
+
+    /// Address of the node the error concerns, if known.
+    fn error_host(&self) -> Option<SocketAddr> {
+        None
+    }
 }
 
 // This allows returning Infallible as an error type in functions that cannot fail.
@@ -462,6 +1260,10 @@ impl ErrorToException for Arc<std::io::Error> {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::ConnectionBroken
+    }
 }
 
 // Specific mapping for PagerExecutionError.
@@ -484,6 +1286,58 @@ impl ErrorToException for PagerExecutionError {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            PagerExecutionError::PrepareError(e) => e.error_code(),
+            PagerExecutionError::SerializationError(_) => FFIErrorCode::Serialization,
+            PagerExecutionError::NextPageError(e) => e.error_code(),
+            PagerExecutionError::UseKeyspaceError(e) => e.error_code(),
+            PagerExecutionError::SchemaAgreementError(e) => e.error_code(),
+            PagerExecutionError::MetadataError(e) => e.error_code(),
+            _ => FFIErrorCode::Other,
+        }
+    }
+}
+
+// Specific mapping for ExecutionError.
+#[deny(clippy::wildcard_enum_match_arm)]
+impl ErrorToException for ExecutionError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        match self {
+            ExecutionError::PrepareError(e) => e.to_exception(ctors),
+
+            ExecutionError::ConnectionPoolError(e) => e.to_exception(ctors),
+
+            ExecutionError::LastAttemptError(e) => e.to_exception(ctors),
+
+            ExecutionError::RequestTimeout(duration) => ctors
+                .operation_timed_out_exception_constructor
+                .construct_from_rust(duration.as_millis().clamp(0, i32::MAX as u128) as i32),
+
+            ExecutionError::UseKeyspaceError(e) => e.to_exception(ctors),
+
+            ExecutionError::SchemaAgreementError(e) => e.to_exception(ctors),
+
+            ExecutionError::MetadataError(e) => e.to_exception(ctors),
+
+            _ => ctors.rust_exception_constructor.construct_from_rust(&self),
+        }
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            ExecutionError::PrepareError(e) => e.error_code(),
+            ExecutionError::ConnectionPoolError(e) => e.error_code(),
+            ExecutionError::LastAttemptError(e) => e.error_code(),
+            ExecutionError::RequestTimeout(_) => FFIErrorCode::ClientTimeout,
+            ExecutionError::EmptyPlan => FFIErrorCode::NoHostAvailable,
+            ExecutionError::UseKeyspaceError(e) => e.error_code(),
+            ExecutionError::SchemaAgreementError(e) => e.error_code(),
+            ExecutionError::MetadataError(e) => e.error_code(),
+            _ => FFIErrorCode::Other,
+        }
+    }
 }
 
 // Specific mapping for NextPageError.
@@ -502,6 +1356,17 @@ impl ErrorToException for NextPageError {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            NextPageError::RequestFailure(e) => e.error_code(),
+            NextPageError::TypeCheckError(e) => e.error_code(),
+            NextPageError::PartitionKeyError(_) | NextPageError::ResultMetadataParseError(_) => {
+                FFIErrorCode::Other
+            }
+            _ => FFIErrorCode::Other,
+        }
+    }
 }
 
 // Specific mapping for RequestError.
@@ -522,6 +1387,16 @@ impl ErrorToException for RequestError {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            RequestError::ConnectionPoolError(e) => e.error_code(),
+            RequestError::RequestTimeout(_) => FFIErrorCode::ClientTimeout,
+            RequestError::LastAttemptError(e) => e.error_code(),
+            RequestError::EmptyPlan => FFIErrorCode::NoHostAvailable,
+            _ => FFIErrorCode::Other,
+        }
+    }
 }
 
 // Specific mapping for RequestAttemptError.
@@ -535,22 +1410,44 @@ impl ErrorToException for RequestAttemptError {
                 (&db_error, message.as_str()).to_exception(ctors)
             }
 
+            // The request could not be built, or the exchange broke the protocol.
             RequestAttemptError::CqlRequestSerialization(_)
-            | RequestAttemptError::UnableToAllocStreamId
-            | RequestAttemptError::BrokenConnectionError(_)
             | RequestAttemptError::BodyExtensionsParseError(_)
             | RequestAttemptError::CqlResultParseError(_)
             | RequestAttemptError::CqlErrorParseError(_)
             | RequestAttemptError::UnexpectedResponse(_)
             | RequestAttemptError::RepreparedIdChanged { .. }
             | RequestAttemptError::RepreparedIdMissingInBatch
-            | RequestAttemptError::NonfinishedPagingState => {
+            | RequestAttemptError::NonfinishedPagingState => ctors
+                .request_invalid_exception_constructor
+                .construct_from_rust(&self.to_string()),
+
+            RequestAttemptError::UnableToAllocStreamId
+            | RequestAttemptError::BrokenConnectionError(_) => {
                 ctors.rust_exception_constructor.construct_from_rust(&self)
             }
 
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            RequestAttemptError::SerializationError(_) => FFIErrorCode::Serialization,
+            RequestAttemptError::DbError(db_error, _) => db_error_code(db_error),
+            RequestAttemptError::CqlRequestSerialization(_)
+            | RequestAttemptError::BodyExtensionsParseError(_)
+            | RequestAttemptError::CqlResultParseError(_)
+            | RequestAttemptError::CqlErrorParseError(_)
+            | RequestAttemptError::UnexpectedResponse(_)
+            | RequestAttemptError::RepreparedIdChanged { .. }
+            | RequestAttemptError::RepreparedIdMissingInBatch
+            | RequestAttemptError::NonfinishedPagingState => FFIErrorCode::RequestInvalid,
+            RequestAttemptError::UnableToAllocStreamId
+            | RequestAttemptError::BrokenConnectionError(_) => FFIErrorCode::ConnectionBroken,
+            _ => FFIErrorCode::Other,
+        }
+    }
 }
 
 // Specific mapping for PrepareError
@@ -569,6 +1466,15 @@ impl ErrorToException for PrepareError {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            PrepareError::ConnectionPoolError(e) => e.error_code(),
+            PrepareError::AllAttemptsFailed { first_attempt } => first_attempt.error_code(),
+            PrepareError::PreparedStatementIdsMismatch => FFIErrorCode::Other,
+            _ => FFIErrorCode::Other,
+        }
+    }
 }
 
 // Specific mapping for NewSessionError
@@ -594,6 +1500,82 @@ impl ErrorToException for NewSessionError {
     }
 }
 
+/// Error of `session_create`, together with the contact points the session was created from.
+///
+/// Login failures happen while connecting, and the Rust driver does not report which node
+/// rejected the credentials, so the contact points are reported instead.
+#[derive(Debug, Error)]
+#[error("{source}")]
+pub(crate) struct SessionCreateError {
+    #[source]
+    source: NewSessionError,
+    contact_points: String,
+}
+
+impl SessionCreateError {
+    pub(crate) fn new(source: NewSessionError, contact_points: &str) -> Self {
+        Self {
+            source,
+            contact_points: contact_points.to_owned(),
+        }
+    }
+
+    /// Returns the server or authenticator message if the session could not be created
+    /// because logging in failed.
+    fn authentication_failure(&self) -> Option<String> {
+        let NewSessionError::MetadataError(MetadataError::ConnectionPoolError(
+            ConnectionPoolError::Broken {
+                last_connection_error: ConnectionError::ConnectionSetupRequestError(e),
+            },
+        )) = &self.source
+        else {
+            return None;
+        };
+        match e.get_error() {
+            ConnectionSetupRequestErrorKind::DbError(DbError::AuthenticationError, message) => {
+                Some(message.clone())
+            }
+            ConnectionSetupRequestErrorKind::MissingAuthentication => Some(e.to_string()),
+            // Failures of a custom authenticator, reported by the C# callbacks.
+            ConnectionSetupRequestErrorKind::StartAuthSessionError(message)
+            | ConnectionSetupRequestErrorKind::AuthChallengeEvaluationError(message)
+            | ConnectionSetupRequestErrorKind::AuthFinishError(message) => Some(message.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl ErrorToException for SessionCreateError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        match self.authentication_failure() {
+            Some(message) => ctors
+                .authentication_exception_constructor
+                .construct_from_rust(&message, &self.contact_points),
+            None => self.source.to_exception(ctors),
+        }
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self.authentication_failure() {
+            Some(_) => FFIErrorCode::Unauthorized,
+            None => self.source.error_code(),
+        }
+    }
+
+    fn error_host(&self) -> Option<SocketAddr> {
+        self.authentication_failure()?;
+        first_contact_point(&self.contact_points)
+    }
+}
+
+/// Returns the first address in the comma separated `contact_points` that is an IP with a port.
+/// Contact points given as host names cannot be reported as a host.
+fn first_contact_point(contact_points: &str) -> Option<SocketAddr> {
+    contact_points
+        .split(',')
+        .find_map(|contact_point| contact_point.trim().parse().ok())
+}
+
 #[deny(clippy::wildcard_enum_match_arm)]
 impl ErrorToException for MetadataError {
     fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
@@ -633,6 +1615,16 @@ impl ErrorToException for UseKeyspaceError {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            UseKeyspaceError::BadKeyspaceName(e) => e.error_code(),
+            UseKeyspaceError::RequestError(e) => e.error_code(),
+            UseKeyspaceError::KeyspaceNameMismatch { .. } => FFIErrorCode::Other,
+            UseKeyspaceError::RequestTimeout(_) => FFIErrorCode::ClientTimeout,
+            _ => FFIErrorCode::Other,
+        }
+    }
 }
 
 #[deny(clippy::wildcard_enum_match_arm)]
@@ -656,6 +1648,34 @@ impl ErrorToException for BadKeyspaceName {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::InvalidQuery
+    }
+}
+
+/// Category of an error reported by the server.
+#[deny(clippy::wildcard_enum_match_arm)]
+fn db_error_code(db_error: &DbError) -> FFIErrorCode {
+    match db_error {
+        DbError::SyntaxError => FFIErrorCode::SyntaxError,
+        DbError::Invalid | DbError::ConfigError => FFIErrorCode::InvalidQuery,
+        DbError::AlreadyExists { .. } => FFIErrorCode::AlreadyExists,
+        DbError::Unauthorized | DbError::AuthenticationError => FFIErrorCode::Unauthorized,
+        DbError::Unavailable { .. } => FFIErrorCode::Unavailable,
+        DbError::Overloaded | DbError::RateLimitReached { .. } => FFIErrorCode::Overloaded,
+        DbError::ReadTimeout { .. } | DbError::WriteTimeout { .. } => FFIErrorCode::ServerTimeout,
+        DbError::ReadFailure { .. }
+        | DbError::WriteFailure { .. }
+        | DbError::FunctionFailure { .. } => FFIErrorCode::ServerFailure,
+        DbError::TruncateError
+        | DbError::Unprepared { .. }
+        | DbError::IsBootstrapping
+        | DbError::ServerError
+        | DbError::ProtocolError
+        | DbError::Other(_) => FFIErrorCode::ServerError,
+        _ => FFIErrorCode::ServerError,
+    }
 }
 
 // Tuple-based mapping to include the server-provided message alongside DbError
@@ -694,17 +1714,78 @@ impl ErrorToException for (&DbError, &str) {
                 .invalid_configuration_in_query_constructor
                 .construct_from_rust(message),
 
+            DbError::Unavailable {
+                consistency,
+                required,
+                alive,
+            } => ctors.unavailable_exception_constructor.construct_from_rust(
+                *consistency,
+                *required,
+                *alive,
+            ),
+
+            DbError::ReadTimeout {
+                consistency,
+                received,
+                required,
+                data_present,
+            } => ctors
+                .read_timeout_exception_constructor
+                .construct_from_rust(*consistency, *received, *required, *data_present),
+
+            DbError::WriteTimeout {
+                consistency,
+                received,
+                required,
+                write_type,
+            } => ctors
+                .write_timeout_exception_constructor
+                .construct_from_rust(*consistency, *received, *required, write_type),
+
+            DbError::ReadFailure {
+                consistency,
+                received,
+                required,
+                numfailures,
+                data_present,
+            } => ctors
+                .read_failure_exception_constructor
+                .construct_from_rust(
+                    *consistency,
+                    *received,
+                    *required,
+                    *numfailures,
+                    *data_present,
+                ),
+
+            DbError::WriteFailure {
+                consistency,
+                received,
+                required,
+                numfailures,
+                write_type,
+            } => ctors
+                .write_failure_exception_constructor
+                .construct_from_rust(*consistency, *received, *required, *numfailures, write_type),
+
+            DbError::Overloaded => ctors
+                .overloaded_exception_constructor
+                .construct_from_rust(message),
+
+            DbError::IsBootstrapping => ctors
+                .is_bootstrapping_exception_constructor
+                .construct_from_rust(message),
+
+            DbError::RateLimitReached {
+                op_type,
+                rejected_by_coordinator,
+            } => ctors
+                .rate_limit_reached_exception_constructor
+                .construct_from_rust(op_type, *rejected_by_coordinator),
+
             DbError::AuthenticationError
-            | DbError::Unavailable { .. }
-            | DbError::Overloaded
-            | DbError::IsBootstrapping
-            | DbError::ReadTimeout { .. }
-            | DbError::WriteTimeout { .. }
-            | DbError::ReadFailure { .. }
-            | DbError::WriteFailure { .. }
             | DbError::ServerError
             | DbError::ProtocolError
-            | DbError::RateLimitReached { .. }
             | DbError::Other(_) => ctors
                 .rust_exception_constructor
                 .construct_from_rust(db_error),
@@ -714,6 +1795,10 @@ impl ErrorToException for (&DbError, &str) {
                 .construct_from_rust(db_error),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        db_error_code(self.0)
+    }
 }
 
 #[deny(clippy::wildcard_enum_match_arm)]
@@ -739,6 +1824,16 @@ impl ErrorToException for ConnectionPoolError {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            ConnectionPoolError::Broken { .. } | ConnectionPoolError::Initializing => {
+                FFIErrorCode::ConnectionBroken
+            }
+            ConnectionPoolError::NodeDisabledByHostFilter => FFIErrorCode::NoHostAvailable,
+            _ => FFIErrorCode::Other,
+        }
+    }
 }
 
 #[deny(clippy::wildcard_enum_match_arm)]
@@ -752,6 +1847,37 @@ impl ErrorToException for NextRowError {
             _ => ctors.rust_exception_constructor.construct_from_rust(&self),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            NextRowError::NextPageError(e) => e.error_code(),
+            NextRowError::RowDeserializationError(_) => FFIErrorCode::Deserialization,
+            _ => FFIErrorCode::Other,
+        }
+    }
+}
+
+#[deny(clippy::wildcard_enum_match_arm)]
+impl ErrorToException for RowCursorError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        match self {
+            RowCursorError::NextRow(e) => e.to_exception(ctors),
+            RowCursorError::ProducerPanicked(payload) => ctors
+                .driver_internal_error_constructor
+                .construct_from_panic(payload.as_ref()),
+            RowCursorError::ProducerCancelled => {
+                ctors.rust_exception_constructor.construct_from_rust(&self)
+            }
+        }
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            RowCursorError::NextRow(e) => e.error_code(),
+            RowCursorError::ProducerPanicked(_) => FFIErrorCode::InternalError,
+            RowCursorError::ProducerCancelled => FFIErrorCode::Other,
+        }
+    }
 }
 
 impl ErrorToException for DeserializationError {
@@ -760,6 +1886,10 @@ impl ErrorToException for DeserializationError {
             .deserialization_exception_constructor
             .construct_from_rust(&self.to_string())
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::Deserialization
+    }
 }
 
 impl ErrorToException for SerializationError {
@@ -768,6 +1898,10 @@ impl ErrorToException for SerializationError {
             .serialization_exception_constructor
             .construct_from_rust(&self.to_string())
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::Serialization
+    }
 }
 
 impl ErrorToException for TypeCheckError {
@@ -776,6 +1910,10 @@ impl ErrorToException for TypeCheckError {
             .invalid_type_exception_constructor
             .construct_from_rust(&self.to_string())
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::Deserialization
+    }
 }
 
 #[deny(clippy::wildcard_enum_match_arm)]
@@ -821,6 +1959,160 @@ where
     }
 }
 
+#[deny(clippy::wildcard_enum_match_arm)]
+impl ErrorToException for SessionConfigError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        match self {
+            SessionConfigError::RackWithoutDatacenter
+            | SessionConfigError::EmptyLocalDatacenter
+            | SessionConfigError::UnknownRetryPolicy(_)
+            | SessionConfigError::UnknownCompression(_)
+            | SessionConfigError::InvalidHostFilterAddress(_)
+            | SessionConfigError::MissingCustomRetryPolicy
+            | SessionConfigError::UnknownSpeculativeExecutionPolicy(_)
+            | SessionConfigError::InvalidSpeculativeExecutionDelay(_)
+            | SessionConfigError::InvalidSpeculativeExecutionPercentile(_)
+            | SessionConfigError::InvalidConsistency(_)
+            | SessionConfigError::InvalidSerialConsistency(_)
+            | SessionConfigError::EmptyUsername
+            | SessionConfigError::InvalidCaCertificate(_)
+            | SessionConfigError::InvalidClientCertificate(_)
+            | SessionConfigError::InvalidClientKey(_)
+            | SessionConfigError::IncompleteClientIdentity
+            | SessionConfigError::UnknownTlsProtocols(_)
+            | SessionConfigError::ConflictingTlsSources(_)
+            | SessionConfigError::InvalidTlsFilePath(_)
+            | SessionConfigError::UnreadableTlsFile { .. }
+            | SessionConfigError::Tls(_) => ctors
+                .argument_exception_constructor
+                .construct_from_rust(&self.to_string()),
+        }
+    }
+}
+
+impl ErrorToException for NullArgumentError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        ctors
+            .argument_null_exception_constructor
+            .construct_from_rust(self.param_name, &self.to_string())
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::InvalidArgument
+    }
+}
+
+#[deny(clippy::wildcard_enum_match_arm)]
+impl ErrorToException for RowAccessError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        match self {
+            RowAccessError::ColumnIndexOutOfRange { .. }
+            | RowAccessError::RowIndexOutOfRange { .. }
+            | RowAccessError::ItemIndexOutOfRange { .. }
+            | RowAccessError::NotConditional => ctors
+                .invalid_argument_exception_constructor
+                .construct_from_rust(&self.to_string()),
+
+            RowAccessError::TypeMismatch { .. } | RowAccessError::UnexpectedType { .. } => ctors
+                .invalid_type_exception_constructor
+                .construct_from_rust(&self.to_string()),
+
+            RowAccessError::Deserialization(_) | RowAccessError::MalformedValue { .. } => ctors
+                .deserialization_exception_constructor
+                .construct_from_rust(&self.to_string()),
+        }
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            RowAccessError::ColumnIndexOutOfRange { .. }
+            | RowAccessError::RowIndexOutOfRange { .. }
+            | RowAccessError::ItemIndexOutOfRange { .. }
+            | RowAccessError::NotConditional => FFIErrorCode::InvalidArgument,
+
+            RowAccessError::TypeMismatch { .. }
+            | RowAccessError::UnexpectedType { .. }
+            | RowAccessError::Deserialization(_)
+            | RowAccessError::MalformedValue { .. } => FFIErrorCode::Deserialization,
+        }
+    }
+}
+
+#[deny(clippy::wildcard_enum_match_arm)]
+impl ErrorToException for QueryResultError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        match self {
+            QueryResultError::Deserialization(e) => e.to_exception(ctors),
+
+            QueryResultError::IntoRowsResult(_) | QueryResultError::Rows(_) => ctors
+                .deserialization_exception_constructor
+                .construct_from_rust(&self.to_string()),
+        }
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::Deserialization
+    }
+}
+
+#[deny(clippy::wildcard_enum_match_arm)]
+impl ErrorToException for SinglePageExecutionError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        match self {
+            // Caught on the client side, so the request is never sent.
+            SinglePageExecutionError::EmptyStatement
+            | SinglePageExecutionError::BindCountMismatch { .. } => ctors
+                .request_invalid_exception_constructor
+                .construct_from_rust(&self.to_string()),
+
+            // The server rejects such batches with an invalid request error.
+            SinglePageExecutionError::CounterBatchMismatch { .. } => ctors
+                .invalid_query_constructor
+                .construct_from_rust(&self.to_string()),
+
+            SinglePageExecutionError::Execution(e) => e.to_exception(ctors),
+
+            SinglePageExecutionError::NoHostAvailable { error, host_errors } => {
+                let host_errors = host_errors
+                    .into_iter()
+                    .map(|(address, e)| (address.to_string(), e.to_exception(ctors)))
+                    .collect();
+                let message = error.to_string();
+                ctors
+                    .no_host_available_exception_constructor
+                    .construct_with_host_errors(&message, host_errors)
+            }
+
+            SinglePageExecutionError::Result(e) => e.to_exception(ctors),
+        }
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self {
+            SinglePageExecutionError::EmptyStatement
+            | SinglePageExecutionError::BindCountMismatch { .. } => FFIErrorCode::RequestInvalid,
+            SinglePageExecutionError::CounterBatchMismatch { .. } => FFIErrorCode::InvalidQuery,
+            SinglePageExecutionError::Execution(e) => e.error_code(),
+            SinglePageExecutionError::NoHostAvailable { .. } => FFIErrorCode::NoHostAvailable,
+            SinglePageExecutionError::Result(e) => e.error_code(),
+        }
+    }
+
+    /// For [`Self::NoHostAvailable`], the node of the last failed attempt.
+    fn error_host(&self) -> Option<SocketAddr> {
+        match self {
+            SinglePageExecutionError::NoHostAvailable { host_errors, .. } => {
+                host_errors.last().map(|(address, _)| *address)
+            }
+            SinglePageExecutionError::EmptyStatement
+            | SinglePageExecutionError::BindCountMismatch { .. }
+            | SinglePageExecutionError::CounterBatchMismatch { .. }
+            | SinglePageExecutionError::Execution(_)
+            | SinglePageExecutionError::Result(_) => None,
+        }
+    }
+}
+
 #[derive(Error, Debug, Clone)]
 pub(crate) enum HostIdError {
     #[error("invalid host id: not a valid uuid: {0}")]
@@ -835,6 +2127,10 @@ impl ErrorToException for HostIdError {
                 .construct_from_rust(&self.to_string()),
         }
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::InvalidArgument
+    }
 }
 
 impl ErrorToException for SchemaAgreementError {
@@ -860,6 +2156,16 @@ impl ErrorToException for SchemaAgreementError {
     }
 }
 
+// Whatever prevented reading the trace, including the trace not being complete in
+// `system_traces` after the configured number of attempts, C# reports it the same way.
+impl ErrorToException for TracingError {
+    fn to_exception(self, ctors: &ExceptionConstructors) -> FFIException {
+        ctors
+            .trace_retrieval_exception_constructor
+            .construct_from_rust(&format!("Failed to retrieve the query trace: {self}"))
+    }
+}
+
 pub(crate) struct InvalidArgumentError<'a>(pub(crate) &'a str);
 
 impl ErrorToException for InvalidArgumentError<'_> {
@@ -868,4 +2174,106 @@ impl ErrorToException for InvalidArgumentError<'_> {
             .invalid_argument_exception_constructor
             .construct_from_rust(self.0)
     }
+
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::InvalidArgument
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use scylla_cql_core::frame::response::CqlResponseKind;
+
+    use super::testing::{RECORDING_CONSTRUCTORS, RecordedException, take_recorded};
+    use super::*;
+
+    /// Converts `error` the way entry points do and returns the exception C# is asked for.
+    fn convert(error: impl ErrorToException) -> RecordedException {
+        let _exception = FFIMaybeException::from_error(error, &RECORDING_CONSTRUCTORS);
+        take_recorded()
+    }
+
+    #[test]
+    fn client_side_validation_errors_raise_request_invalid() {
+        let bind_count_mismatch = convert(SinglePageExecutionError::BindCountMismatch {
+            expected: 2,
+            actual: 1,
+        });
+        assert_eq!(bind_count_mismatch.kind, "RequestInvalidException");
+        assert_eq!(
+            bind_count_mismatch.message,
+            "Statement expects 2 bound values, but 1 were given"
+        );
+
+        let empty_statement = convert(SinglePageExecutionError::EmptyStatement);
+        assert_eq!(empty_statement.kind, "RequestInvalidException");
+
+        let bind_count_mismatch = SinglePageExecutionError::BindCountMismatch {
+            expected: 2,
+            actual: 1,
+        };
+        assert_eq!(
+            bind_count_mismatch.error_code(),
+            FFIErrorCode::RequestInvalid
+        );
+        assert_eq!(
+            SinglePageExecutionError::EmptyStatement.error_code(),
+            FFIErrorCode::RequestInvalid
+        );
+    }
+
+    #[test]
+    fn protocol_errors_raise_request_invalid() {
+        let unexpected_response = convert(RequestAttemptError::UnexpectedResponse(
+            CqlResponseKind::Ready,
+        ));
+        assert_eq!(unexpected_response.kind, "RequestInvalidException");
+        assert_eq!(
+            RequestAttemptError::UnexpectedResponse(CqlResponseKind::Ready).error_code(),
+            FFIErrorCode::RequestInvalid
+        );
+    }
+
+    #[test]
+    fn first_contact_point_is_reported_from_several() {
+        assert_eq!(
+            first_contact_point("127.0.0.1:9042, 127.0.0.2:9042"),
+            Some("127.0.0.1:9042".parse().unwrap())
+        );
+        assert_eq!(
+            first_contact_point("node1.example.com:9042,127.0.0.2:9042"),
+            Some("127.0.0.2:9042".parse().unwrap())
+        );
+        assert_eq!(first_contact_point("node1.example.com:9042"), None);
+    }
+
+    #[test]
+    fn session_create_errors_report_no_host_unless_authentication_failed() {
+        let error = SessionCreateError::new(
+            NewSessionError::EmptyKnownNodesList,
+            "127.0.0.1:9042,127.0.0.2:9042",
+        );
+        assert_eq!(error.error_host(), None);
+    }
+
+    #[test]
+    fn rack_without_datacenter_raises_argument_exception() {
+        let recorded = convert(SessionConfigError::RackWithoutDatacenter);
+        assert_eq!(recorded.kind, "ArgumentException");
+        assert_eq!(
+            recorded.message,
+            "Local rack cannot be set without a local datacenter"
+        );
+    }
+
+    #[test]
+    fn tracing_errors_raise_trace_retrieval() {
+        let recorded = convert(TracingError::EmptyResults);
+        assert_eq!(recorded.kind, "TraceRetrievalException");
+        assert!(
+            recorded
+                .message
+                .starts_with("Failed to retrieve the query trace: ")
+        );
+    }
 }
@@ -0,0 +1,216 @@
+//! Exception constructors for unit tests, standing in for the ones C# passes to Rust.
+//!
+//! Each constructor records the exception C# would have built and returns a dangling handle,
+//! so that tests can check which exception an entry point or an error mapping asks for.
+
+use std::cell::RefCell;
+
+use super::*;
+use crate::ffi::FFIGCHandle;
+
+/// An exception built by [`RECORDING_CONSTRUCTORS`]: the name of its C# type and its message.
+///
+/// For `ArgumentNullException`, the message is the name of the null parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RecordedException {
+    pub(crate) kind: &'static str,
+    pub(crate) message: String,
+}
+
+thread_local! {
+    static RECORDED: RefCell<Vec<RecordedException>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(kind: &'static str, message: impl Into<String>) -> FFIException {
+    RECORDED.with_borrow_mut(|recorded| {
+        recorded.push(RecordedException {
+            kind,
+            message: message.into(),
+        })
+    });
+    FFIException(FFIGCHandle::dangling())
+}
+
+/// Returns the only exception built on this thread since the last call.
+pub(crate) fn take_recorded() -> RecordedException {
+    let mut recorded = RECORDED.take();
+    assert_eq!(
+        recorded.len(),
+        1,
+        "expected exactly one exception, got {recorded:?}"
+    );
+    recorded.remove(0)
+}
+
+macro_rules! message_constructors {
+    ($($name:ident => $kind:literal),* $(,)?) => {
+        $(
+            unsafe extern "C" fn $name(message: FFIStr<'_>) -> FFIException {
+                record($kind, message.as_str())
+            }
+        )*
+    };
+}
+
+message_constructors!(
+    already_shutdown => "AlreadyShutdownException",
+    argument => "ArgumentException",
+    deserialization => "DeserializationException",
+    driver_internal_error => "DriverInternalError",
+    function_failure => "FunctionFailureException",
+    invalid_argument => "InvalidArgumentException",
+    invalid_configuration_in_query => "InvalidConfigurationInQueryException",
+    invalid_query => "InvalidQueryException",
+    invalid_type => "InvalidTypeException",
+    is_bootstrapping => "IsBootstrappingException",
+    overloaded => "OverloadedException",
+    request_invalid => "RequestInvalidException",
+    rust_exception => "RustException",
+    schema_agreement_required_host_absent => "SchemaAgreementRequiredHostAbsentException",
+    schema_agreement_rows_result => "SchemaAgreementRowsResultException",
+    schema_agreement_single_row => "SchemaAgreementSingleRowException",
+    schema_agreement_timeout => "SchemaAgreementTimeoutException",
+    serialization => "SerializationException",
+    syntax_error => "SyntaxErrorException",
+    trace_retrieval => "TraceRetrievalException",
+    truncate => "TruncateException",
+    unauthorized => "UnauthorizedException",
+);
+
+unsafe extern "C" fn already_exists(
+    keyspace: FFIStr<'_>,
+    table: FFIStr<'_>,
+    _was_table_creation: FFIBool,
+) -> FFIException {
+    record(
+        "AlreadyExistsException",
+        format!("{}.{}", keyspace.as_str(), table.as_str()),
+    )
+}
+
+unsafe extern "C" fn argument_null(param_name: FFIStr<'_>, _message: FFIStr<'_>) -> FFIException {
+    record("ArgumentNullException", param_name.as_str())
+}
+
+unsafe extern "C" fn authentication(message: FFIStr<'_>, _host: FFIStr<'_>) -> FFIException {
+    record("AuthenticationException", message.as_str())
+}
+
+unsafe extern "C" fn no_host_available(
+    message: FFIStr<'_>,
+    _host_errors: FFISlice<'_, FFIHostError<'_>>,
+) -> FFIException {
+    record("NoHostAvailableException", message.as_str())
+}
+
+unsafe extern "C" fn operation_timed_out(timeout_ms: i32) -> FFIException {
+    record("OperationTimedOutException", format!("{timeout_ms} ms"))
+}
+
+unsafe extern "C" fn prepared_query_not_found(
+    message: FFIStr<'_>,
+    _unknown_id: FFISlice<'_, u8>,
+) -> FFIException {
+    record("PreparedQueryNotFoundException", message.as_str())
+}
+
+unsafe extern "C" fn rate_limit_reached(
+    _op_type: u8,
+    _rejected_by_coordinator: FFIBool,
+) -> FFIException {
+    record("RateLimitReachedException", "")
+}
+
+unsafe extern "C" fn read_failure(
+    _consistency: u16,
+    _received: i32,
+    _required: i32,
+    _failures: i32,
+    _data_present: FFIBool,
+) -> FFIException {
+    record("ReadFailureException", "")
+}
+
+unsafe extern "C" fn read_timeout(
+    _consistency: u16,
+    _received: i32,
+    _required: i32,
+    _data_present: FFIBool,
+) -> FFIException {
+    record("ReadTimeoutException", "")
+}
+
+unsafe extern "C" fn unavailable(_consistency: u16, _required: i32, _alive: i32) -> FFIException {
+    record("UnavailableException", "")
+}
+
+unsafe extern "C" fn write_failure(
+    _consistency: u16,
+    _received: i32,
+    _required: i32,
+    _failures: i32,
+    write_type: FFIStr<'_>,
+) -> FFIException {
+    record("WriteFailureException", write_type.as_str())
+}
+
+unsafe extern "C" fn write_timeout(
+    _consistency: u16,
+    _received: i32,
+    _required: i32,
+    write_type: FFIStr<'_>,
+) -> FFIException {
+    record("WriteTimeoutException", write_type.as_str())
+}
+
+/// Constructors recording every exception they are asked for, see [`take_recorded`].
+pub(crate) static RECORDING_CONSTRUCTORS: ExceptionConstructors = ExceptionConstructors {
+    already_exists_constructor: AlreadyExistsConstructor(already_exists),
+    already_shutdown_exception_constructor: AlreadyShutdownExceptionConstructor(already_shutdown),
+    argument_exception_constructor: ArgumentExceptionConstructor(argument),
+    argument_null_exception_constructor: ArgumentNullExceptionConstructor(argument_null),
+    authentication_exception_constructor: AuthenticationExceptionConstructor(authentication),
+    deserialization_exception_constructor: DeserializationExceptionConstructor(deserialization),
+    driver_internal_error_constructor: DriverInternalErrorConstructor(driver_internal_error),
+    function_failure_exception_constructor: FunctionFailureExceptionConstructor(function_failure),
+    invalid_argument_exception_constructor: InvalidArgumentExceptionConstructor(invalid_argument),
+    invalid_configuration_in_query_constructor: InvalidConfigurationInQueryExceptionConstructor(
+        invalid_configuration_in_query,
+    ),
+    invalid_query_constructor: InvalidQueryConstructor(invalid_query),
+    invalid_type_exception_constructor: InvalidTypeExceptionConstructor(invalid_type),
+    is_bootstrapping_exception_constructor: IsBootstrappingExceptionConstructor(is_bootstrapping),
+    no_host_available_exception_constructor: NoHostAvailableExceptionConstructor(no_host_available),
+    operation_timed_out_exception_constructor: OperationTimedOutExceptionConstructor(
+        operation_timed_out,
+    ),
+    overloaded_exception_constructor: OverloadedExceptionConstructor(overloaded),
+    prepared_query_not_found_exception_constructor: PreparedQueryNotFoundExceptionConstructor(
+        prepared_query_not_found,
+    ),
+    rate_limit_reached_exception_constructor: RateLimitReachedExceptionConstructor(
+        rate_limit_reached,
+    ),
+    read_failure_exception_constructor: ReadFailureExceptionConstructor(read_failure),
+    read_timeout_exception_constructor: ReadTimeoutExceptionConstructor(read_timeout),
+    request_invalid_exception_constructor: RequestInvalidExceptionConstructor(request_invalid),
+    rust_exception_constructor: RustExceptionConstructor(rust_exception),
+    schema_agreement_required_host_absent_exception_constructor:
+        SchemaAgreementRequiredHostAbsentExceptionConstructor(schema_agreement_required_host_absent),
+    schema_agreement_rows_result_exception_constructor:
+        SchemaAgreementRowsResultExceptionConstructor(schema_agreement_rows_result),
+    schema_agreement_single_row_exception_constructor: SchemaAgreementSingleRowExceptionConstructor(
+        schema_agreement_single_row,
+    ),
+    schema_agreement_timeout_exception_constructor: SchemaAgreementTimeoutExceptionConstructor(
+        schema_agreement_timeout,
+    ),
+    serialization_exception_constructor: SerializationExceptionConstructor(serialization),
+    syntax_error_exception_constructor: SyntaxErrorExceptionConstructor(syntax_error),
+    trace_retrieval_exception_constructor: TraceRetrievalExceptionConstructor(trace_retrieval),
+    truncate_exception_constructor: TruncateExceptionConstructor(truncate),
+    unauthorized_exception_constructor: UnauthorizedExceptionConstructor(unauthorized),
+    unavailable_exception_constructor: UnavailableExceptionConstructor(unavailable),
+    write_failure_exception_constructor: WriteFailureExceptionConstructor(write_failure),
+    write_timeout_exception_constructor: WriteTimeoutExceptionConstructor(write_timeout),
+};
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use scylla::client::execution_profile::{ExecutionProfile, ExecutionProfileHandle};
+use scylla::statement::{Consistency, SerialConsistency};
+
+use crate::error_conversion::{FFIMaybeException, SessionConfigError};
+use crate::ffi::{ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIBool, FromArc};
+use crate::retry_policy::BridgedCustomRetryPolicy;
+use crate::session_config::{
+    BridgedLoadBalancingPolicy, BridgedSpeculativeExecutionConfig, RetryPolicyKind,
+};
+use crate::task::{ExceptionConstructors, ManuallyDestructible};
+
+/// Execution profile options passed from C#.
+///
+/// Used both for the default profile of a session and for the profiles built with
+/// `execution_profile_build`, which C# attaches to statements and batches.
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct BridgedExecutionProfileConfig<'a> {
+    /// Consistency of requests not setting their own, if `has_consistency` is set.
+    consistency: u16,
+    has_consistency: FFIBool,
+
+    /// Serial consistency of conditional requests not setting their own,
+    /// if `has_serial_consistency` is set.
+    serial_consistency: u16,
+    has_serial_consistency: FFIBool,
+
+    /// Timeout of requests not setting their own, in milliseconds.
+    /// Zero disables the timeout and negative values keep the driver default.
+    request_timeout_millis: i64,
+
+    load_balancing_policy: BridgedLoadBalancingPolicy<'a>,
+
+    /// Retry policy, a [`RetryPolicyKind`] discriminant.
+    retry_policy: u8,
+
+    /// Retry policy implemented in C#, used if `retry_policy` is [`RetryPolicyKind::Custom`].
+    custom_retry_policy: BridgedCustomRetryPolicy,
+
+    /// Speculative execution options.
+    speculative_execution: BridgedSpeculativeExecutionConfig,
+}
+
+impl BridgedExecutionProfileConfig<'_> {
+    /// Consume this config and build the profile.
+    ///
+    /// Fails if a consistency is invalid or if one of the policies cannot be applied.
+    pub(crate) fn into_profile(self) -> Result<ExecutionProfile, SessionConfigError> {
+        let mut profile = ExecutionProfile::builder();
+
+        if self.has_consistency.into() {
+            let consistency = Consistency::try_from(self.consistency)
+                .map_err(|_| SessionConfigError::InvalidConsistency(self.consistency))?;
+            profile = profile.consistency(consistency);
+        }
+
+        if self.has_serial_consistency.into() {
+            // The discriminants of SERIAL and LOCAL_SERIAL in the protocol, like in C#.
+            let serial_consistency = match self.serial_consistency {
+                0x0008 => SerialConsistency::Serial,
+                0x0009 => SerialConsistency::LocalSerial,
+                other => return Err(SessionConfigError::InvalidSerialConsistency(other)),
+            };
+            profile = profile.serial_consistency(Some(serial_consistency));
+        }
+
+        if self.request_timeout_millis >= 0 {
+            let timeout = (self.request_timeout_millis > 0)
+                .then(|| Duration::from_millis(self.request_timeout_millis as u64));
+            profile = profile.request_timeout(timeout);
+        }
+
+        profile = self.load_balancing_policy.apply_to_profile(profile)?;
+        let retry_policy = RetryPolicyKind::try_from(self.retry_policy)?;
+        profile = profile.retry_policy(retry_policy.into_policy(self.custom_retry_policy)?);
+        profile = self.speculative_execution.apply_to_profile(profile)?;
+
+        Ok(profile.build())
+    }
+}
+
+/// An immutable execution profile, shared by every statement and batch it is attached to.
+#[derive(Debug)]
+pub struct BridgedExecutionProfile {
+    pub(crate) handle: ExecutionProfileHandle,
+}
+
+impl FFI for BridgedExecutionProfile {
+    type Origin = FromArc;
+}
+
+/// Builds an execution profile from `config`.
+/// C# becomes the owner of the profile and must release it with `execution_profile_free`.
+/// Statements and batches the profile is attached to keep it alive on their own.
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_profile_build(
+    config: BridgedExecutionProfileConfig<'_>,
+    out_profile: Option<&mut ManuallyDestructible>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(out_profile) = out_profile else {
+            return FFIMaybeException::null_argument("out_profile", constructors);
+        };
+
+        let profile = match config.into_profile() {
+            Ok(profile) => profile,
+            Err(e) => return FFIMaybeException::from_error(e, constructors),
+        };
+
+        let profile = Arc::new(BridgedExecutionProfile {
+            handle: profile.into_handle(),
+        });
+        *out_profile = ManuallyDestructible::from_destructible(profile);
+        FFIMaybeException::ok()
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn execution_profile_free(
+    profile_ptr: BridgedOwnedSharedPtr<BridgedExecutionProfile>,
+) {
+    ArcFFI::free(profile_ptr);
+    tracing::trace!("[FFI] BridgedExecutionProfile freed");
+}
+
+/// Returns the handle of the profile behind `profile_ptr`, or `None` for a null pointer.
+pub(crate) fn profile_handle(
+    profile_ptr: BridgedBorrowedSharedPtr<'_, BridgedExecutionProfile>,
+) -> Option<ExecutionProfileHandle> {
+    ArcFFI::as_ref(profile_ptr).map(|profile| profile.handle.clone())
+}
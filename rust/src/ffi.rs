@@ -538,7 +538,7 @@ pub mod blittable {
     ///
     /// Types that can be blittable include:
     /// - Primitive types: integers, floats
-    /// - Our FFI types: `FFIStr`, `FFIBool`
+    /// - Our FFI types: `FFIStr`, `FFIBool`, `FFIHostError`
     pub trait Blittable: blittable_sealed::Sealed + Sized {}
 
     // Implement Blittable for primitive types
@@ -578,6 +578,9 @@ pub mod blittable {
 
     impl blittable_sealed::Sealed for super::FFIBool {}
     impl Blittable for super::FFIBool {}
+
+    impl<'a> blittable_sealed::Sealed for crate::error_conversion::FFIHostError<'a> {}
+    impl<'a> Blittable for crate::error_conversion::FFIHostError<'a> {}
 }
 
 pub use blittable::Blittable;
@@ -758,6 +761,12 @@ impl<'a> FFIStr<'a> {
             },
         }
     }
+
+    /// Reads the string back, as C# would.
+    #[cfg(test)]
+    pub(crate) fn as_str(&self) -> &str {
+        std::str::from_utf8(self.slice.as_slice()).expect("FFIStr holds valid UTF-8")
+    }
 }
 
 // Compile-time assertions for size and alignment of `FFIStr` to ensure it matches the expected layout.
@@ -962,6 +971,23 @@ impl<T> FFIGCHandle<T> {
     }
 }
 
+#[cfg(test)]
+impl<T> FFIGCHandle<T> {
+    /// Builds a handle to no C# object, whose destructor does nothing.
+    /// Unit tests return it from callbacks standing in for C#.
+    pub(crate) fn dangling() -> Self {
+        unsafe extern "C" fn free_nothing<T>(_handle: GCHandlePtr<T>) {}
+
+        Self {
+            gchandle: GCHandlePtr(FFINonNullPtr {
+                ptr: NonNull::dangling(),
+                _phantom: PhantomData,
+            }),
+            free: free_nothing::<T>,
+        }
+    }
+}
+
 impl<T> Debug for FFIGCHandle<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FFIGCHandle")
@@ -982,6 +1008,34 @@ impl<T> Drop for FFIGCHandle<T> {
     }
 }
 
+/// An [`FFIGCHandle`] that can be shared between threads.
+///
+/// Meant for C# objects that Rust calls into from Tokio worker threads, such as custom
+/// policies. Such objects must be thread-safe on the C# side.
+pub(crate) struct SharedGCHandle<T>(FFIGCHandle<T>);
+
+impl<T> SharedGCHandle<T> {
+    pub(crate) fn new(handle: FFIGCHandle<T>) -> Self {
+        Self(handle)
+    }
+
+    /// Borrows the GCHandle, for use by C#.
+    pub(crate) fn borrow<'gc>(&'gc self) -> GCHandlePtr<'gc, T> {
+        self.0.borrow()
+    }
+}
+
+impl<T> Debug for SharedGCHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedGCHandle").field(&self.0).finish()
+    }
+}
+
+// SAFETY: GCHandles can be used from any thread, and the C# objects they point to
+// are required to be thread-safe.
+unsafe impl<T> Send for SharedGCHandle<T> {}
+unsafe impl<T> Sync for SharedGCHandle<T> {}
+
 /// An **optional** pointer to GCHandle owned by Rust, together with the destructor.
 /// This is useful to ensure that GCHandle is freed when Rust-side
 /// object is dropped. Mainly employable in async scenarios.
@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use scylla::cluster::metadata::Peer;
+use scylla::policies::host_filter::HostFilter;
+
+use crate::error_conversion::SessionConfigError;
+use crate::ffi::{CSharpStr, FFIBool, FFIMaybeGCHandle, FFIStr, GCHandlePtr, SharedGCHandle};
+
+/// Opaque C# representation of a custom host filter.
+pub enum CustomHostFilter {}
+
+/// Callback asking C# whether the driver may connect to a node.
+///
+/// The datacenter and rack are null if the node does not advertise them. C# must not throw:
+/// a filter that fails is expected to reject the node. The callback is called on a Tokio
+/// worker thread whenever the topology is refreshed, so it must be thread-safe and must not block.
+type AcceptHost = unsafe extern "C" fn(
+    filter: GCHandlePtr<'_, CustomHostFilter>,
+    address: FFIStr<'_>,
+    datacenter: FFIStr<'_>,
+    rack: FFIStr<'_>,
+) -> FFIBool;
+
+/// Host filter passed from C#, deciding which nodes the driver opens connection pools to.
+///
+/// A node is accepted if its IP address is in `allowed` (when given), is not in `denied`,
+/// and is accepted by the C# callback (when given). Rejected nodes still appear in the cluster
+/// metadata, but are never connected to. Addresses are matched regardless of the port.
+///
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct BridgedHostFilter<'a> {
+    /// Comma-separated IP addresses of the only nodes to connect to, or null for no allow-list.
+    allowed: CSharpStr<'a>,
+
+    /// Comma-separated IP addresses of nodes never to connect to, or null for no deny-list.
+    denied: CSharpStr<'a>,
+
+    filter: FFIMaybeGCHandle<CustomHostFilter>,
+    accept: Option<AcceptHost>,
+}
+
+impl BridgedHostFilter<'_> {
+    /// Returns the filter, or `None` if no list nor callback was given.
+    ///
+    /// Fails if a listed address is not a valid IP address.
+    pub(crate) fn into_host_filter(
+        self,
+    ) -> Result<Option<Arc<dyn HostFilter>>, SessionConfigError> {
+        let allowed = parse_addresses(self.allowed)?;
+        let denied = parse_addresses(self.denied)?.unwrap_or_default();
+        let callback = match (self.accept, self.filter.try_into_ffi_gc_handle()) {
+            (Some(accept), Some(filter)) => Some(CallbackHostFilter {
+                filter: SharedGCHandle::new(filter),
+                accept,
+            }),
+            _ => None,
+        };
+
+        if allowed.is_none() && denied.is_empty() && callback.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(Arc::new(BridgedHostFilterImpl {
+            allowed,
+            denied,
+            callback,
+        })))
+    }
+}
+
+fn parse_addresses(list: CSharpStr<'_>) -> Result<Option<HashSet<IpAddr>>, SessionConfigError> {
+    let Some(list) = list.as_cstr() else {
+        return Ok(None);
+    };
+    list.to_str()
+        .unwrap()
+        .split(',')
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .map(|address| {
+            address
+                .parse()
+                .map_err(|_| SessionConfigError::InvalidHostFilterAddress(address.to_owned()))
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+#[derive(Debug)]
+struct BridgedHostFilterImpl {
+    allowed: Option<HashSet<IpAddr>>,
+    denied: HashSet<IpAddr>,
+    callback: Option<CallbackHostFilter>,
+}
+
+impl HostFilter for BridgedHostFilterImpl {
+    fn accept(&self, peer: &Peer) -> bool {
+        let ip = peer.address.into_inner().ip();
+        if self
+            .allowed
+            .as_ref()
+            .is_some_and(|allowed| !allowed.contains(&ip))
+        {
+            return false;
+        }
+        if self.denied.contains(&ip) {
+            return false;
+        }
+        self.callback
+            .as_ref()
+            .is_none_or(|callback| callback.accept(peer))
+    }
+}
+
+#[derive(Debug)]
+struct CallbackHostFilter {
+    filter: SharedGCHandle<CustomHostFilter>,
+    accept: AcceptHost,
+}
+
+impl CallbackHostFilter {
+    fn accept(&self, peer: &Peer) -> bool {
+        let address = peer.address.into_inner().to_string();
+
+        let accepted = unsafe {
+            // SAFETY: the callback and the filter handle are provided by C#,
+            // and the handle is kept alive by `self`.
+            (self.accept)(
+                self.filter.borrow(),
+                FFIStr::new(&address),
+                optional_str(&peer.datacenter),
+                optional_str(&peer.rack),
+            )
+        };
+        accepted.into()
+    }
+}
+
+/// Borrows an optional string, passing null when it is missing.
+fn optional_str(value: &Option<String>) -> FFIStr<'_> {
+    match value.as_deref() {
+        Some(value) => FFIStr::new(value),
+        None => FFIStr::null(),
+    }
+}
@@ -1,10 +1,23 @@
+mod address_translator;
+mod authenticator;
+mod batch;
+mod collection;
 mod error_conversion;
+mod execution_profile;
 pub mod ffi;
+mod host_filter;
 pub mod logging;
 mod metadata;
 mod pre_serialized_values;
 mod prepared_statement;
+mod query_result;
+mod query_trace;
+mod retry_policy;
+mod row;
+mod row_cursor;
 mod row_set;
 mod session;
 mod session_config;
+mod ssl_options;
 mod task;
+mod udt;
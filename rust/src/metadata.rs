@@ -49,6 +49,9 @@ pub struct CSharpHostData<'a> {
     port: u16,
     datacenter: FFIStr<'a>,
     rack: FFIStr<'a>,
+    /// Whether the driver has open connections to the node. Nodes rejected by the
+    /// host filter are never connected, but are still reported.
+    is_connected: FFIBool,
 }
 
 enum ReplicaList {}
@@ -90,51 +93,55 @@ pub extern "C" fn cluster_state_fill_nodes(
     cluster_state_ptr: BridgedBorrowedSharedPtr<'_, ClusterState>,
     refresh_context_ptr: RefreshContextPtr,
     callback: ConstructCSharpHost,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let cluster_state =
-        ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
-
-    for node in cluster_state.get_nodes_info() {
-        // UUID as bytes
-        let uuid_bytes = FFISlice::new(node.host_id.as_bytes());
-
-        let port = node.address.port();
-        let ip_octets = IpOctets::new(node.address.ip());
-        let ip_bytes = FFISlice::new(ip_octets.as_slice());
-
-        // Get datacenter (Option<String>) - pass null when missing
-        let dc_str = match node.datacenter.as_deref() {
-            Some(s) => FFIStr::new(s),
-            None => FFIStr::null(),
-        };
-
-        // Get rack (Option<String>) - pass null when missing
-        let rack_str = match node.rack.as_deref() {
-            Some(s) => FFIStr::new(s),
-            None => FFIStr::null(),
-        };
-
-        // Invoke the callback to construct and add the Host to the C# list object.
-        // All pointers passed to the callback are only valid during this invocation.
-        // The callback must copy all data immediately.
-        unsafe {
-            let ffi_exception = callback(
-                refresh_context_ptr,
-                CSharpHostData {
-                    id_bytes: uuid_bytes,
-                    ip_bytes,
-                    port,
-                    datacenter: dc_str,
-                    rack: rack_str,
-                },
-            );
-            if ffi_exception.has_exception() {
-                return ffi_exception;
+    FFIMaybeException::catch_panics(constructors, || {
+        let cluster_state =
+            ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
+
+        for node in cluster_state.get_nodes_info() {
+            // UUID as bytes
+            let uuid_bytes = FFISlice::new(node.host_id.as_bytes());
+
+            let port = node.address.port();
+            let ip_octets = IpOctets::new(node.address.ip());
+            let ip_bytes = FFISlice::new(ip_octets.as_slice());
+
+            // Get datacenter (Option<String>) - pass null when missing
+            let dc_str = match node.datacenter.as_deref() {
+                Some(s) => FFIStr::new(s),
+                None => FFIStr::null(),
+            };
+
+            // Get rack (Option<String>) - pass null when missing
+            let rack_str = match node.rack.as_deref() {
+                Some(s) => FFIStr::new(s),
+                None => FFIStr::null(),
+            };
+
+            // Invoke the callback to construct and add the Host to the C# list object.
+            // All pointers passed to the callback are only valid during this invocation.
+            // The callback must copy all data immediately.
+            unsafe {
+                let ffi_exception = callback(
+                    refresh_context_ptr,
+                    CSharpHostData {
+                        id_bytes: uuid_bytes,
+                        ip_bytes,
+                        port,
+                        datacenter: dc_str,
+                        rack: rack_str,
+                        is_connected: node.is_connected().into(),
+                    },
+                );
+                if ffi_exception.has_exception() {
+                    return ffi_exception;
+                }
             }
         }
-    }
 
-    FFIMaybeException::ok()
+        FFIMaybeException::ok()
+    })
 }
 
 /// Ephemeral per-call helper: separates validation, serialization, and token computation
@@ -345,26 +352,29 @@ pub extern "C" fn cluster_state_get_keyspace_names(
     cluster_state_ptr: BridgedBorrowedSharedPtr<'_, ClusterState>,
     keyspace_name_list_ptr: KeyspaceNameListPtr,
     add_keyspace_name_callback: AddKeyspaceName,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    tracing::trace!("[FFI] cluster_state_get_keyspace_names called");
-
-    let cluster_state =
-        ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
-
-    unsafe {
-        let ffi_exception = ffi_callback_for_each(
-            keyspace_name_list_ptr,
-            add_keyspace_name_callback,
-            cluster_state
-                .keyspaces_iter()
-                .map(|(ks_name, _)| FFIStr::new(ks_name)),
-        );
-        if ffi_exception.has_exception() {
-            return ffi_exception;
+    FFIMaybeException::catch_panics(constructors, || {
+        tracing::trace!("[FFI] cluster_state_get_keyspace_names called");
+
+        let cluster_state =
+            ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
+
+        unsafe {
+            let ffi_exception = ffi_callback_for_each(
+                keyspace_name_list_ptr,
+                add_keyspace_name_callback,
+                cluster_state
+                    .keyspaces_iter()
+                    .map(|(ks_name, _)| FFIStr::new(ks_name)),
+            );
+            if ffi_exception.has_exception() {
+                return ffi_exception;
+            }
         }
-    }
 
-    FFIMaybeException::ok()
+        FFIMaybeException::ok()
+    })
 }
 
 /// Opaque type representing the C# KeyspaceContext.
@@ -446,101 +456,103 @@ pub extern "C" fn cluster_state_get_keyspace_metadata(
     construct_keyspace_callback: ConstructCSharpKeyspaceMetadata,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    tracing::trace!("[FFI] cluster_state_get_keyspace_metadata");
-
-    let cluster_state =
-        ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
-
-    let keyspace_name = keyspace_name
-        .as_cstr()
-        .expect("valid C string for keyspace_name")
-        .to_str()
-        .expect("valid UTF-8 keyspace name");
+    FFIMaybeException::catch_panics(constructors, || {
+        tracing::trace!("[FFI] cluster_state_get_keyspace_metadata");
 
-    let Some(keyspace) = cluster_state.get_keyspace(keyspace_name) else {
-        // If the keyspace is not found, return invalid argument exception to indicate the caller provided an invalid keyspace name.
-        let ex = constructors
-            .invalid_argument_exception_constructor
-            .construct_from_rust("Keyspace not found in cluster metadata");
-        return FFIMaybeException::from_exception(ex);
-    };
+        let cluster_state =
+            ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
 
-    tracing::trace!("[FFI] Found keyspace: '{}'", keyspace_name);
+        let keyspace_name = keyspace_name
+            .as_cstr()
+            .expect("valid C string for keyspace_name")
+            .to_str()
+            .expect("valid UTF-8 keyspace name");
+
+        let Some(keyspace) = cluster_state.get_keyspace(keyspace_name) else {
+            // If the keyspace is not found, return invalid argument exception to indicate the caller provided an invalid keyspace name.
+            let ex = constructors
+                .invalid_argument_exception_constructor
+                .construct_from_rust("Keyspace not found in cluster metadata");
+            return FFIMaybeException::from_exception(ex);
+        };
 
-    let durable_writes = keyspace.durable_writes;
+        tracing::trace!("[FFI] Found keyspace: '{}'", keyspace_name);
 
-    #[deny(clippy::wildcard_enum_match_arm)]
-    let strategy_class = match &keyspace.strategy {
-        scylla::cluster::metadata::Strategy::SimpleStrategy { replication_factor } => {
-            unsafe {
-                let res = (add_rep_factor_callback.simple_strategy)(
-                    replication_options_ptr,
-                    *replication_factor,
-                );
-                if res.has_exception() {
-                    return res;
-                }
-            }
-            FFIStr::new("SimpleStrategy")
-        }
+        let durable_writes = keyspace.durable_writes;
 
-        scylla::cluster::metadata::Strategy::NetworkTopologyStrategy {
-            datacenter_repfactors,
-        } => {
-            tracing::trace!(
-                "[FFI] NetworkTopologyStrategy with datacenter rep_factors: {:?}",
-                datacenter_repfactors
-            );
-            for (dc, rf) in datacenter_repfactors {
+        #[deny(clippy::wildcard_enum_match_arm)]
+        let strategy_class = match &keyspace.strategy {
+            scylla::cluster::metadata::Strategy::SimpleStrategy { replication_factor } => {
                 unsafe {
-                    let res = (add_rep_factor_callback.network_topology_strategy)(
+                    let res = (add_rep_factor_callback.simple_strategy)(
                         replication_options_ptr,
-                        FFIStr::new(dc),
-                        *rf,
+                        *replication_factor,
                     );
                     if res.has_exception() {
                         return res;
                     }
                 }
+                FFIStr::new("SimpleStrategy")
             }
-            FFIStr::new("NetworkTopologyStrategy")
-        }
 
-        scylla::cluster::metadata::Strategy::LocalStrategy => FFIStr::new("LocalStrategy"),
+            scylla::cluster::metadata::Strategy::NetworkTopologyStrategy {
+                datacenter_repfactors,
+            } => {
+                tracing::trace!(
+                    "[FFI] NetworkTopologyStrategy with datacenter rep_factors: {:?}",
+                    datacenter_repfactors
+                );
+                for (dc, rf) in datacenter_repfactors {
+                    unsafe {
+                        let res = (add_rep_factor_callback.network_topology_strategy)(
+                            replication_options_ptr,
+                            FFIStr::new(dc),
+                            *rf,
+                        );
+                        if res.has_exception() {
+                            return res;
+                        }
+                    }
+                }
+                FFIStr::new("NetworkTopologyStrategy")
+            }
 
-        scylla::cluster::metadata::Strategy::Other { name, data } => {
-            tracing::trace!("[FFI] Other strategy '{}' with options: {:?}", name, data);
-            for (k, v) in data {
-                unsafe {
-                    let res = (add_rep_factor_callback.other_strategy)(
-                        replication_options_ptr,
-                        FFIStr::new(k),
-                        FFIStr::new(v),
-                    );
-                    if res.has_exception() {
-                        return res;
+            scylla::cluster::metadata::Strategy::LocalStrategy => FFIStr::new("LocalStrategy"),
+
+            scylla::cluster::metadata::Strategy::Other { name, data } => {
+                tracing::trace!("[FFI] Other strategy '{}' with options: {:?}", name, data);
+                for (k, v) in data {
+                    unsafe {
+                        let res = (add_rep_factor_callback.other_strategy)(
+                            replication_options_ptr,
+                            FFIStr::new(k),
+                            FFIStr::new(v),
+                        );
+                        if res.has_exception() {
+                            return res;
+                        }
                     }
                 }
+                FFIStr::new(name.as_str())
             }
-            FFIStr::new(name.as_str())
-        }
 
-        // The match is exhaustive, but we add a wildcard arm to satisfy the compiler since the Strategy enum is non-exhaustive.
-        // If we ever encounter a new strategy variant that we don't know how to handle, we need to update this code to support it.
-        _ => unreachable!("All strategy variants should be covered"),
-    };
-
-    // Invoke the construct_keyspace_callback to construct and fill the C# KeyspaceMetadata object.
-    // All pointers passed to the callback are only valid during this invocation.
-    // The callback must copy all data immediately.
-    unsafe {
-        construct_keyspace_callback(
-            keyspace_context_ptr,
-            durable_writes,
-            strategy_class,
-            replication_options_ptr,
-        )
-    }
+            // The match is exhaustive, but we add a wildcard arm to satisfy the compiler since the Strategy enum is non-exhaustive.
+            // If we ever encounter a new strategy variant that we don't know how to handle, we need to update this code to support it.
+            _ => unreachable!("All strategy variants should be covered"),
+        };
+
+        // Invoke the construct_keyspace_callback to construct and fill the C# KeyspaceMetadata object.
+        // All pointers passed to the callback are only valid during this invocation.
+        // The callback must copy all data immediately.
+        unsafe {
+            construct_keyspace_callback(
+                keyspace_context_ptr,
+                durable_writes,
+                strategy_class,
+                replication_options_ptr,
+            )
+        }
+    })
 }
 
 /// Opaque type representing the C# TableNameList.
@@ -580,30 +592,32 @@ pub extern "C" fn cluster_state_get_table_names(
     callback: AddTableName,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let cluster_state =
-        ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
-
-    let keyspace_name = keyspace_name
-        .as_cstr()
-        .expect("valid C string for keyspace_name")
-        .to_str()
-        .expect("valid UTF-8 keyspace name");
-
-    let Some(keyspace) = cluster_state.get_keyspace(keyspace_name) else {
-        // If the keyspace is not found, return invalid argument exception to indicate the caller provided an invalid keyspace name.
-        let ex = constructors
-            .invalid_argument_exception_constructor
-            .construct_from_rust("Keyspace not found in cluster metadata");
-        return FFIMaybeException::from_exception(ex);
-    };
-
-    unsafe {
-        ffi_callback_for_each(
-            table_name_list_ptr,
-            callback,
-            keyspace.tables.keys().map(|k| FFIStr::new(k.as_str())),
-        )
-    }
+    FFIMaybeException::catch_panics(constructors, || {
+        let cluster_state =
+            ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
+
+        let keyspace_name = keyspace_name
+            .as_cstr()
+            .expect("valid C string for keyspace_name")
+            .to_str()
+            .expect("valid UTF-8 keyspace name");
+
+        let Some(keyspace) = cluster_state.get_keyspace(keyspace_name) else {
+            // If the keyspace is not found, return invalid argument exception to indicate the caller provided an invalid keyspace name.
+            let ex = constructors
+                .invalid_argument_exception_constructor
+                .construct_from_rust("Keyspace not found in cluster metadata");
+            return FFIMaybeException::from_exception(ex);
+        };
+
+        unsafe {
+            ffi_callback_for_each(
+                table_name_list_ptr,
+                callback,
+                keyspace.tables.keys().map(|k| FFIStr::new(k.as_str())),
+            )
+        }
+    })
 }
 
 /// Opaque type representing the C# UdtContext.
@@ -639,60 +653,62 @@ pub extern "C" fn cluster_state_get_udt_metadata(
     construct_udt_metadata: ConstructCSharpUdtMetadata,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    tracing::trace!("[FFI] cluster_state_get_udt_metadata called");
-
-    let cluster_state =
-        ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
-
-    let keyspace_name = keyspace_name
-        .as_cstr()
-        .expect("valid C string for keyspace_name")
-        .to_str()
-        .expect("valid UTF-8 keyspace name");
-
-    let udt_name = udt_name
-        .as_cstr()
-        .expect("valid C string for udt_name")
-        .to_str()
-        .expect("valid UTF-8 UDT name");
-
-    let Some(keyspace) = cluster_state.get_keyspace(keyspace_name) else {
-        let ex = constructors
-            .invalid_argument_exception_constructor
-            .construct_from_rust("Keyspace not found in cluster metadata");
-        return FFIMaybeException::from_exception(ex);
-    };
-
-    let Some(udt) = keyspace.user_defined_types.get(udt_name) else {
-        let ex = constructors
-            .invalid_argument_exception_constructor
-            .construct_from_rust("UDT not found in keyspace metadata");
-        return FFIMaybeException::from_exception(ex);
-    };
-
-    for (field_name, field_type) in udt.field_types.iter() {
-        let type_code = column_type_to_code(field_type);
-        let type_info_handle: BridgedBorrowedSharedPtr<ColumnType> = if type_code >= 0x20 {
-            RefFFI::as_ptr(field_type)
-        } else {
-            RefFFI::null()
+    FFIMaybeException::catch_panics(constructors, || {
+        tracing::trace!("[FFI] cluster_state_get_udt_metadata called");
+
+        let cluster_state =
+            ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
+
+        let keyspace_name = keyspace_name
+            .as_cstr()
+            .expect("valid C string for keyspace_name")
+            .to_str()
+            .expect("valid UTF-8 keyspace name");
+
+        let udt_name = udt_name
+            .as_cstr()
+            .expect("valid C string for udt_name")
+            .to_str()
+            .expect("valid UTF-8 UDT name");
+
+        let Some(keyspace) = cluster_state.get_keyspace(keyspace_name) else {
+            let ex = constructors
+                .invalid_argument_exception_constructor
+                .construct_from_rust("Keyspace not found in cluster metadata");
+            return FFIMaybeException::from_exception(ex);
         };
 
-        let maybe_ffi_exception = unsafe {
-            construct_udt_field(
-                udt_context_ptr,
-                FFIStr::new(field_name.as_ref()),
-                type_code,
-                type_info_handle,
-            )
+        let Some(udt) = keyspace.user_defined_types.get(udt_name) else {
+            let ex = constructors
+                .invalid_argument_exception_constructor
+                .construct_from_rust("UDT not found in keyspace metadata");
+            return FFIMaybeException::from_exception(ex);
         };
 
-        if maybe_ffi_exception.has_exception() {
-            return maybe_ffi_exception;
+        for (field_name, field_type) in udt.field_types.iter() {
+            let type_code = column_type_to_code(field_type);
+            let type_info_handle: BridgedBorrowedSharedPtr<ColumnType> = if type_code >= 0x20 {
+                RefFFI::as_ptr(field_type)
+            } else {
+                RefFFI::null()
+            };
+
+            let maybe_ffi_exception = unsafe {
+                construct_udt_field(
+                    udt_context_ptr,
+                    FFIStr::new(field_name.as_ref()),
+                    type_code,
+                    type_info_handle,
+                )
+            };
+
+            if maybe_ffi_exception.has_exception() {
+                return maybe_ffi_exception;
+            }
         }
-    }
 
-    unsafe { construct_udt_metadata(udt_context_ptr, FFIStr::new(udt.name.as_ref())) }
+        unsafe { construct_udt_metadata(udt_context_ptr, FFIStr::new(udt.name.as_ref())) }
+    })
 }
 
 /// Opaque type representing the C# TableColumnsContext.
@@ -783,132 +799,133 @@ pub extern "C" fn cluster_state_get_table_metadata(
     construct_table_metadata: ConstructCSharpTableMetadata,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    tracing::trace!("[FFI] cluster_state_get_table_metadata called");
-
-    let cluster_state =
-        ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
-
-    let keyspace_name = keyspace_name
-        .as_cstr()
-        .expect("valid C string for keyspace_name")
-        .to_str()
-        .expect("valid UTF-8 keyspace name");
-
-    let Some(keyspace) = cluster_state.get_keyspace(keyspace_name) else {
-        // If the keyspace is not found, return invalid argument exception to indicate the caller provided an invalid keyspace name.
-        let ex = constructors
-            .invalid_argument_exception_constructor
-            .construct_from_rust("Keyspace not found in cluster metadata");
-        return FFIMaybeException::from_exception(ex);
-    };
-
-    let table_name = table_name
-        .as_cstr()
-        .expect("valid C string for table_name")
-        .to_str()
-        .expect("valid UTF-8 table name");
-
-    let Some(table) = keyspace.tables.get(table_name) else {
-        // If the table is not found, return invalid argument exception to indicate the caller provided an invalid table name.
-        let ex = constructors
-            .invalid_argument_exception_constructor
-            .construct_from_rust("Table not found in keyspace metadata");
-        return FFIMaybeException::from_exception(ex);
-    };
-
-    for (column_name, column) in table.columns.iter() {
-        tracing::trace!(
-            "[FFI] Passing definition of column '{}' in table '{}.{}'",
-            column_name,
-            keyspace_name,
-            table_name
-        );
-
-        let type_code = column_type_to_code(&column.typ);
-        let type_info_handle: BridgedBorrowedSharedPtr<ColumnType> = if type_code >= 0x20 {
-            RefFFI::as_ptr(&column.typ)
-        } else {
-            RefFFI::null()
+    FFIMaybeException::catch_panics(constructors, || {
+        tracing::trace!("[FFI] cluster_state_get_table_metadata called");
+
+        let cluster_state =
+            ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
+
+        let keyspace_name = keyspace_name
+            .as_cstr()
+            .expect("valid C string for keyspace_name")
+            .to_str()
+            .expect("valid UTF-8 keyspace name");
+
+        let Some(keyspace) = cluster_state.get_keyspace(keyspace_name) else {
+            // If the keyspace is not found, return invalid argument exception to indicate the caller provided an invalid keyspace name.
+            let ex = constructors
+                .invalid_argument_exception_constructor
+                .construct_from_rust("Keyspace not found in cluster metadata");
+            return FFIMaybeException::from_exception(ex);
         };
 
-        let is_static = matches!(column.kind, scylla::cluster::metadata::ColumnKind::Static);
-        let is_frozen = match &column.typ {
-            ColumnType::Collection { frozen, .. } | ColumnType::UserDefinedType { frozen, .. } => {
-                *frozen
-            }
-            _ => false,
+        let table_name = table_name
+            .as_cstr()
+            .expect("valid C string for table_name")
+            .to_str()
+            .expect("valid UTF-8 table name");
+
+        let Some(table) = keyspace.tables.get(table_name) else {
+            // If the table is not found, return invalid argument exception to indicate the caller provided an invalid table name.
+            let ex = constructors
+                .invalid_argument_exception_constructor
+                .construct_from_rust("Table not found in keyspace metadata");
+            return FFIMaybeException::from_exception(ex);
         };
 
+        for (column_name, column) in table.columns.iter() {
+            tracing::trace!(
+                "[FFI] Passing definition of column '{}' in table '{}.{}'",
+                column_name,
+                keyspace_name,
+                table_name
+            );
+
+            let type_code = column_type_to_code(&column.typ);
+            let type_info_handle: BridgedBorrowedSharedPtr<ColumnType> = if type_code >= 0x20 {
+                RefFFI::as_ptr(&column.typ)
+            } else {
+                RefFFI::null()
+            };
+
+            let is_static = matches!(column.kind, scylla::cluster::metadata::ColumnKind::Static);
+            let is_frozen = match &column.typ {
+                ColumnType::Collection { frozen, .. }
+                | ColumnType::UserDefinedType { frozen, .. } => *frozen,
+                _ => false,
+            };
+
+            unsafe {
+                let ffi_exception = construct_table_column(
+                    table_columns_context_ptr,
+                    FFIStr::new(column_name),
+                    type_code,
+                    type_info_handle,
+                    is_static.into(),
+                    is_frozen.into(),
+                );
+                if ffi_exception.has_exception() {
+                    return ffi_exception;
+                }
+            }
+        }
+
         unsafe {
-            let ffi_exception = construct_table_column(
-                table_columns_context_ptr,
-                FFIStr::new(column_name),
-                type_code,
-                type_info_handle,
-                is_static.into(),
-                is_frozen.into(),
+            // Add partition keys to the C# PartitionKeys list via the callback
+            tracing::trace!(
+                "[FFI] Adding partition keys for table '{}.{}'",
+                keyspace_name,
+                table_name
+            );
+            let ffi_exception = ffi_callback_for_each(
+                partition_keys_ptr,
+                add_primary_key_callback,
+                table
+                    .partition_key
+                    .iter()
+                    .map(|pk| FFIStr::new(pk.as_str())),
             );
             if ffi_exception.has_exception() {
                 return ffi_exception;
             }
-        }
-    }
 
-    unsafe {
-        // Add partition keys to the C# PartitionKeys list via the callback
-        tracing::trace!(
-            "[FFI] Adding partition keys for table '{}.{}'",
-            keyspace_name,
-            table_name
-        );
-        let ffi_exception = ffi_callback_for_each(
-            partition_keys_ptr,
-            add_primary_key_callback,
-            table
-                .partition_key
-                .iter()
-                .map(|pk| FFIStr::new(pk.as_str())),
-        );
-        if ffi_exception.has_exception() {
-            return ffi_exception;
-        }
-
-        // Add clustering keys to the C# ClusteringKeys list via the callback
-        tracing::trace!(
-            "[FFI] Adding clustering keys for table '{}.{}'",
-            keyspace_name,
-            table_name
-        );
-        let ffi_exception = ffi_callback_for_each(
-            clustering_keys_ptr,
-            add_primary_key_callback,
-            table
-                .clustering_key
-                .iter()
-                .map(|ck| FFIStr::new(ck.as_str())),
-        );
-        if ffi_exception.has_exception() {
-            return ffi_exception;
-        }
+            // Add clustering keys to the C# ClusteringKeys list via the callback
+            tracing::trace!(
+                "[FFI] Adding clustering keys for table '{}.{}'",
+                keyspace_name,
+                table_name
+            );
+            let ffi_exception = ffi_callback_for_each(
+                clustering_keys_ptr,
+                add_primary_key_callback,
+                table
+                    .clustering_key
+                    .iter()
+                    .map(|ck| FFIStr::new(ck.as_str())),
+            );
+            if ffi_exception.has_exception() {
+                return ffi_exception;
+            }
 
-        // Finally, construct the C# TableMetadata object by invoking the callback with pointers to the table metadata.
-        tracing::trace!(
-            "[FFI] Constructing C# TableMetadata for table '{}.{}'",
-            keyspace_name,
-            table_name
-        );
-        let ffi_exception = construct_table_metadata(
-            table_context_ptr,
-            table_columns_context_ptr,
-            partition_keys_ptr,
-            clustering_keys_ptr,
-        );
-        if ffi_exception.has_exception() {
-            return ffi_exception;
+            // Finally, construct the C# TableMetadata object by invoking the callback with pointers to the table metadata.
+            tracing::trace!(
+                "[FFI] Constructing C# TableMetadata for table '{}.{}'",
+                keyspace_name,
+                table_name
+            );
+            let ffi_exception = construct_table_metadata(
+                table_context_ptr,
+                table_columns_context_ptr,
+                partition_keys_ptr,
+                clustering_keys_ptr,
+            );
+            if ffi_exception.has_exception() {
+                return ffi_exception;
+            }
         }
-    }
 
-    FFIMaybeException::ok()
+        FFIMaybeException::ok()
+    })
 }
 
 #[unsafe(no_mangle)]
@@ -920,27 +937,29 @@ pub extern "C" fn cluster_state_get_replicas_legacy_murmur3<'ctx>(
     callback: OnReplicaPair<'ctx>,
     exception_constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let cluster_state =
-        ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
-
-    // The partition key supplied by the C# caller, already serialized to wire bytes.
-    let pre_serialized_partition_key =
-        match RustReplicaBridge::pre_serialized_values_from(partition_key) {
-            Ok(value) => value,
+    FFIMaybeException::catch_panics(exception_constructors, || {
+        let cluster_state =
+            ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
+
+        // The partition key supplied by the C# caller, already serialized to wire bytes.
+        let pre_serialized_partition_key =
+            match RustReplicaBridge::pre_serialized_values_from(partition_key) {
+                Ok(value) => value,
+                Err(e) => return FFIMaybeException::from_error(e, exception_constructors),
+            };
+
+        let bridge = match RustReplicaBridge::new_token_ring_based(
+            cluster_state,
+            keyspace,
+            PartitionerName::Murmur3,
+            pre_serialized_partition_key,
+        ) {
+            Ok(b) => b,
             Err(e) => return FFIMaybeException::from_error(e, exception_constructors),
         };
 
-    let bridge = match RustReplicaBridge::new_token_ring_based(
-        cluster_state,
-        keyspace,
-        PartitionerName::Murmur3,
-        pre_serialized_partition_key,
-    ) {
-        Ok(b) => b,
-        Err(e) => return FFIMaybeException::from_error(e, exception_constructors),
-    };
-
-    bridge.get_replicas(callback_context, callback)
+        bridge.get_replicas(callback_context, callback)
+    })
 }
 
 #[unsafe(no_mangle)]
@@ -954,27 +973,30 @@ pub extern "C" fn cluster_state_get_replicas<'ctx>(
     callback: OnReplicaPair<'ctx>,
     exception_constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let cluster_state =
-        ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
-
-    // The partition key supplied by the C# caller: one serialized value per key column
-    // (composite keys have several), assembled here via the populate callback.
-    let pre_serialized_partition_key =
-        match PreSerializedValues::from_populate_callback(populate_values_context, populate_values)
-        {
+    FFIMaybeException::catch_panics(exception_constructors, || {
+        let cluster_state =
+            ArcFFI::as_ref(cluster_state_ptr).expect("valid and non-null ClusterState pointer");
+
+        // The partition key supplied by the C# caller: one serialized value per key column
+        // (composite keys have several), assembled here via the populate callback.
+        let pre_serialized_partition_key = match PreSerializedValues::from_populate_callback(
+            populate_values_context,
+            populate_values,
+        ) {
             Ok(v) => v,
             Err(exception) => return FFIMaybeException::from_exception(exception),
         };
 
-    let bridge = match RustReplicaBridge::new_tablet_based(
-        cluster_state,
-        keyspace,
-        table,
-        pre_serialized_partition_key,
-    ) {
-        Ok(b) => b,
-        Err(e) => return FFIMaybeException::from_error(e, exception_constructors),
-    };
-
-    bridge.get_replicas(callback_context, callback)
+        let bridge = match RustReplicaBridge::new_tablet_based(
+            cluster_state,
+            keyspace,
+            table,
+            pre_serialized_partition_key,
+        ) {
+            Ok(b) => b,
+            Err(e) => return FFIMaybeException::from_error(e, exception_constructors),
+        };
+
+        bridge.get_replicas(callback_context, callback)
+    })
 }
@@ -1,11 +1,160 @@
-use crate::error_conversion::{FFIException, FFIMaybeException};
-use crate::ffi::{BridgedBorrowedExclusivePtr, FFI, FFIPtr, FFISlice, FromBox};
+use crate::collection::read_item;
+use crate::error_conversion::{BindValueError, FFIException, FFIMaybeException};
+use crate::ffi::{BridgedBorrowedExclusivePtr, FFI, FFIBool, FFIPtr, FFISlice, FromBox};
 use crate::task::ExceptionConstructors;
+use bytes::Bytes;
 use scylla::frame::response::result::{ColumnType, NativeType};
 use scylla::serialize::SerializationError;
+use scylla::serialize::row::{RowSerializationContext, SerializeRow};
 use scylla::serialize::value::SerializeValue;
-use scylla::serialize::writers::CellWriter;
+use scylla::serialize::writers::{CellWriter, RowWriter};
+use scylla::value::CqlDuration;
+use scylla_cql_core::frame::types::RawValue;
 use scylla_cql_core::serialize::row::SerializedValues;
+use uuid::Uuid;
+
+/// Strips leading bytes of a non-empty two's-complement integer that only repeat the sign,
+/// i.e. a `0x00` followed by a byte with a clear high bit, or a `0xFF` followed by one with a set high bit.
+fn minimal_varint(mut bytes: &[u8]) -> &[u8] {
+    while let [first, second, ..] = bytes {
+        let redundant =
+            (*first == 0x00 && second & 0x80 == 0) || (*first == 0xFF && second & 0x80 != 0);
+        if !redundant {
+            break;
+        }
+        bytes = &bytes[1..];
+    }
+    bytes
+}
+
+/// The last nanosecond of a day, the largest valid `time`.
+pub(crate) const MAX_TIME_NANOS: i64 = 86_399_999_999_999;
+
+/// A `date` is sent as an unsigned number of days with the epoch at 2^31.
+const DATE_EPOCH_OFFSET: i64 = 1 << 31;
+
+/// Size of a serialized `float`, the only element type of vectors bound by `add_float_vector`.
+const FLOAT_SIZE: usize = 4;
+
+/// Returns the declared dimension of a `vector<float, N>` type, or `None` for other types.
+pub(crate) fn float_vector_dimension(typ: &ColumnType<'_>) -> Option<usize> {
+    match typ {
+        ColumnType::Vector { typ, dimensions } => match typ.as_ref() {
+            ColumnType::Native(NativeType::Float) => Some(usize::from(*dimensions)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Byte length of a serialized `vector<float, N>` of the given dimension.
+pub(crate) fn float_vector_byte_len(dimension: usize) -> usize {
+    dimension * FLOAT_SIZE
+}
+
+/// Type codes of the collections accepted by `add_collection`, as in `column_type_to_code`.
+const LIST_TYPE_CODE: u8 = 0x20;
+const SET_TYPE_CODE: u8 = 0x22;
+
+/// Why the items of a collection could not be assembled, with the index of the offending item.
+enum CollectionItemError {
+    Malformed,
+    Null(usize),
+    Invalid(usize),
+}
+
+/// Assembles a serialized collection of `count` entries, each made of one item per type code
+/// in `item_types` (one for lists and sets, a key and a value for maps). `items` holds the
+/// serialized items, each prefixed with its `[int]` length, and must hold exactly these items.
+///
+/// Null items are rejected, and items of fixed-size and text types are checked against their type.
+fn assemble_collection(
+    count: i32,
+    item_types: &[u8],
+    items: &[u8],
+) -> Result<Bytes, CollectionItemError> {
+    let items_count = usize::try_from(count)
+        .ok()
+        .and_then(|count| count.checked_mul(item_types.len()))
+        .ok_or(CollectionItemError::Malformed)?;
+
+    let mut value = Vec::with_capacity(4 + items.len());
+    value.extend_from_slice(&count.to_be_bytes());
+    value.extend_from_slice(items);
+    let value = Bytes::from(value);
+
+    let mut offset = 4;
+    for index in 0..items_count {
+        let item = read_item(&value, &mut offset).ok_or(CollectionItemError::Malformed)?;
+        let item = item.ok_or(CollectionItemError::Null(index))?;
+        if !is_valid_element(item_types[index % item_types.len()], &item) {
+            return Err(CollectionItemError::Invalid(index));
+        }
+    }
+    if offset != value.len() {
+        return Err(CollectionItemError::Malformed);
+    }
+    Ok(value)
+}
+
+/// Why the fields of a tuple or UDT are invalid, with the index of the offending field.
+enum FieldError {
+    Malformed,
+    Invalid(usize),
+}
+
+/// Checks serialized tuple or UDT `fields`, each prefixed with its `[int]` length, against the
+/// type codes in `field_types`. Null fields are allowed, and non-null fields of fixed-size and text
+/// types are checked against their type.
+///
+/// With `allow_missing_trailing`, `fields` may stop before the last fields, as UDT values may.
+fn check_fields(
+    field_types: &[u8],
+    fields: &Bytes,
+    allow_missing_trailing: bool,
+) -> Result<(), FieldError> {
+    let mut offset = 0;
+    for (index, &type_code) in field_types.iter().enumerate() {
+        if allow_missing_trailing && offset == fields.len() {
+            break;
+        }
+        let field = read_item(fields, &mut offset).ok_or(FieldError::Malformed)?;
+        let Some(field) = field else {
+            continue;
+        };
+        if !is_valid_element(type_code, &field) {
+            return Err(FieldError::Invalid(index));
+        }
+    }
+    if offset != fields.len() {
+        return Err(FieldError::Malformed);
+    }
+    Ok(())
+}
+
+/// Whether `element` is a valid serialized value of the native type with code `type_code`.
+/// Only fixed-size and text types are checked; other values are left to the server.
+fn is_valid_element(type_code: u8, element: &[u8]) -> bool {
+    match type_code {
+        // ascii
+        0x01 => element.is_ascii(),
+        // bigint, counter, double, timestamp, time
+        0x02 | 0x05 | 0x07 | 0x0B | 0x12 => element.len() == 8,
+        // boolean, tinyint
+        0x04 | 0x14 => element.len() == 1,
+        // float, int, date
+        0x08 | 0x09 | 0x11 => element.len() == 4,
+        // uuid, timeuuid
+        0x0C | 0x0F => element.len() == 16,
+        // text
+        0x0D => std::str::from_utf8(element).is_ok(),
+        // inet
+        0x10 => element.len() == 4 || element.len() == 16,
+        // smallint
+        0x13 => element.len() == 2,
+        _ => true,
+    }
+}
 
 /// A single pre-serialized cell: either a C#-backed value, or a
 /// logical null/unset marker.
@@ -34,6 +183,7 @@ impl SerializeValue for PreSerializedCell<'_> {
 }
 
 /// Holds the final serialized values that can be used with queries.
+#[derive(Clone)]
 pub(crate) struct PreSerializedValues {
     serialized_values: SerializedValues,
 }
@@ -45,6 +195,11 @@ impl PreSerializedValues {
         }
     }
 
+    /// Number of values added so far.
+    pub(crate) fn len(&self) -> usize {
+        self.serialized_values.element_count() as usize
+    }
+
     /// Consume and return the inner SerializedValues.
     pub(crate) fn into_serialized_values(self) -> SerializedValues {
         self.serialized_values
@@ -59,6 +214,281 @@ impl PreSerializedValues {
         self.serialized_values.add_value(&cell, dummy_column_type())
     }
 
+    /// Add a `uuid` or `timeuuid` given as 16 bytes in RFC 4122 (big-endian) order.
+    ///
+    /// A `timeuuid` must additionally be a version 1 UUID, as the server would reject it otherwise.
+    pub(crate) fn add_uuid(
+        &mut self,
+        bytes: &[u8],
+        is_timeuuid: bool,
+    ) -> Result<(), SerializationError> {
+        let uuid = Uuid::from_slice(bytes)
+            .map_err(|_| SerializationError::new(BindValueError::InvalidUuidLength(bytes.len())))?;
+        if is_timeuuid && uuid.get_version_num() != 1 {
+            return Err(SerializationError::new(BindValueError::NotTimeuuid(
+                uuid.get_version_num(),
+            )));
+        }
+        let cell = PreSerializedCell::Value(FFISlice::new(uuid.as_bytes()));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add an `inet` given as the 4 octets of an IPv4 address or the 16 octets of an IPv6 address.
+    pub(crate) fn add_inet(&mut self, bytes: &[u8]) -> Result<(), SerializationError> {
+        if bytes.len() != 4 && bytes.len() != 16 {
+            return Err(SerializationError::new(BindValueError::InvalidInetLength(
+                bytes.len(),
+            )));
+        }
+        let cell = PreSerializedCell::Value(FFISlice::new(bytes));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `list` or `set` of `count` elements of the type with code `element_type`.
+    /// `elements` holds the serialized elements, each prefixed with its `[int]` length.
+    ///
+    /// Elements of fixed-size and text types are checked against `element_type`.
+    /// Set elements are not checked for duplicates: the server stores sets by value,
+    /// so duplicates collapse into a single element, as they would in a CQL literal.
+    pub(crate) fn add_collection(
+        &mut self,
+        collection_type: u8,
+        element_type: u8,
+        count: i32,
+        elements: &[u8],
+    ) -> Result<(), SerializationError> {
+        if collection_type != LIST_TYPE_CODE && collection_type != SET_TYPE_CODE {
+            return Err(SerializationError::new(BindValueError::NotAListOrSet(
+                collection_type,
+            )));
+        }
+        let value = assemble_collection(count, &[element_type], elements).map_err(|e| {
+            SerializationError::new(match e {
+                CollectionItemError::Malformed => BindValueError::MalformedCollection(count),
+                CollectionItemError::Null(index) => BindValueError::NullCollectionElement(index),
+                CollectionItemError::Invalid(index) => BindValueError::InvalidCollectionElement {
+                    index,
+                    type_code: element_type,
+                },
+            })
+        })?;
+
+        let cell = PreSerializedCell::Value(FFISlice::new(&value));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `map` of `count` entries with keys of the type with code `key_type` and values of
+    /// the type with code `value_type`. `entries` holds the serialized keys and values, alternating
+    /// and each prefixed with its `[int]` length.
+    ///
+    /// Keys and values of fixed-size and text types are checked against their type. Values of
+    /// other types, e.g. nested collections, are passed through as serialized by C#.
+    /// Keys are not checked for duplicates: the server keeps the last value of a duplicated key.
+    pub(crate) fn add_map(
+        &mut self,
+        key_type: u8,
+        value_type: u8,
+        count: i32,
+        entries: &[u8],
+    ) -> Result<(), SerializationError> {
+        let part = |index: usize| if index % 2 == 0 { "key" } else { "value" };
+        let value = assemble_collection(count, &[key_type, value_type], entries).map_err(|e| {
+            SerializationError::new(match e {
+                CollectionItemError::Malformed => BindValueError::MalformedMap(count),
+                CollectionItemError::Null(index) => BindValueError::NullMapItem {
+                    entry: index / 2,
+                    part: part(index),
+                },
+                CollectionItemError::Invalid(index) => BindValueError::InvalidMapItem {
+                    entry: index / 2,
+                    part: part(index),
+                    type_code: if index % 2 == 0 { key_type } else { value_type },
+                },
+            })
+        })?;
+
+        let cell = PreSerializedCell::Value(FFISlice::new(&value));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `tuple` whose fields have the types with codes `field_types`, in order.
+    /// `fields` holds the serialized fields, each prefixed with its `[int]` length,
+    /// a negative length denoting a null field.
+    ///
+    /// Unlike collections, tuples have no count prefix, and may hold null fields.
+    /// Non-null fields of fixed-size and text types are checked against their type.
+    pub(crate) fn add_tuple(
+        &mut self,
+        field_types: &[u8],
+        fields: &[u8],
+    ) -> Result<(), SerializationError> {
+        let value = Bytes::copy_from_slice(fields);
+        check_fields(field_types, &value, false).map_err(|e| {
+            SerializationError::new(match e {
+                FieldError::Malformed => BindValueError::MalformedTuple(field_types.len()),
+                FieldError::Invalid(index) => BindValueError::InvalidTupleField {
+                    index,
+                    type_code: field_types[index],
+                },
+            })
+        })?;
+
+        let cell = PreSerializedCell::Value(FFISlice::new(&value));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a user defined type value whose fields have the types with codes `field_types`,
+    /// in the order of the type definition. `fields` holds the serialized fields, each prefixed
+    /// with its `[int]` length, a negative length denoting a null field.
+    ///
+    /// Like tuples, UDT values may hold null fields. They may also omit trailing fields,
+    /// which the server reads as null.
+    pub(crate) fn add_udt(
+        &mut self,
+        field_types: &[u8],
+        fields: &[u8],
+    ) -> Result<(), SerializationError> {
+        let value = Bytes::copy_from_slice(fields);
+        check_fields(field_types, &value, true).map_err(|e| {
+            SerializationError::new(match e {
+                FieldError::Malformed => BindValueError::MalformedUdt(field_types.len()),
+                FieldError::Invalid(index) => BindValueError::InvalidUdtField {
+                    index,
+                    type_code: field_types[index],
+                },
+            })
+        })?;
+
+        let cell = PreSerializedCell::Value(FFISlice::new(&value));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `decimal` equal to `unscaled * 10^(-scale)`, the unscaled value given as
+    /// big-endian two's-complement bytes.
+    ///
+    /// Any non-empty byte sequence is a valid two's-complement integer, so only emptiness
+    /// is rejected. Redundant sign bytes are kept, as the server accepts them.
+    pub(crate) fn add_decimal(
+        &mut self,
+        scale: i32,
+        unscaled: &[u8],
+    ) -> Result<(), SerializationError> {
+        if unscaled.is_empty() {
+            return Err(SerializationError::new(
+                BindValueError::EmptyUnscaledDecimal,
+            ));
+        }
+        let mut bytes = Vec::with_capacity(size_of::<i32>() + unscaled.len());
+        bytes.extend_from_slice(&scale.to_be_bytes());
+        bytes.extend_from_slice(unscaled);
+        let cell = PreSerializedCell::Value(FFISlice::new(&bytes));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `varint` given as big-endian two's-complement bytes.
+    ///
+    /// Fails if `value` is empty. Redundant leading sign bytes are stripped,
+    /// as the protocol requires the shortest encoding.
+    pub(crate) fn add_varint(&mut self, value: &[u8]) -> Result<(), SerializationError> {
+        if value.is_empty() {
+            return Err(SerializationError::new(BindValueError::EmptyVarint));
+        }
+        let cell = PreSerializedCell::Value(FFISlice::new(minimal_varint(value)));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `timestamp` given in milliseconds since the Unix epoch. Every `i64` is valid.
+    pub(crate) fn add_timestamp(&mut self, millis: i64) -> Result<(), SerializationError> {
+        let bytes = millis.to_be_bytes();
+        let cell = PreSerializedCell::Value(FFISlice::new(&bytes));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `date` given in days since the Unix epoch, which may be negative.
+    ///
+    /// Fails if the date does not fit the 32 bits of the protocol, i.e. is outside the range of `i32`.
+    pub(crate) fn add_date(&mut self, days: i64) -> Result<(), SerializationError> {
+        if i32::try_from(days).is_err() {
+            return Err(SerializationError::new(BindValueError::DateOutOfRange(
+                days,
+            )));
+        }
+        let bytes = ((days + DATE_EPOCH_OFFSET) as u32).to_be_bytes();
+        let cell = PreSerializedCell::Value(FFISlice::new(&bytes));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `time` given in nanoseconds since midnight.
+    ///
+    /// Fails if the time is negative or not within a single day.
+    pub(crate) fn add_time(&mut self, nanos: i64) -> Result<(), SerializationError> {
+        if !(0..=MAX_TIME_NANOS).contains(&nanos) {
+            return Err(SerializationError::new(BindValueError::TimeOutOfRange(
+                nanos,
+            )));
+        }
+        let bytes = nanos.to_be_bytes();
+        let cell = PreSerializedCell::Value(FFISlice::new(&bytes));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
+    /// Add a `duration`. The driver encodes each component as a zig-zag vint.
+    ///
+    /// Fails if some components are negative and others positive, as the server would reject them.
+    pub(crate) fn add_duration(
+        &mut self,
+        months: i32,
+        days: i32,
+        nanoseconds: i64,
+    ) -> Result<(), SerializationError> {
+        let signs = [months.signum(), days.signum(), nanoseconds.signum() as i32];
+        if signs.contains(&1) && signs.contains(&-1) {
+            return Err(SerializationError::new(BindValueError::MixedSignDuration {
+                months,
+                days,
+                nanoseconds,
+            }));
+        }
+        let duration = CqlDuration {
+            months,
+            days,
+            nanoseconds,
+        };
+        self.serialized_values
+            .add_value(&duration, &ColumnType::Native(NativeType::Duration))
+    }
+
+    /// Add a `vector<float, N>` with `dimension` elements, each sent as a big-endian float
+    /// without a length prefix.
+    ///
+    /// Fails if `dimension` is not positive or `elements` does not hold exactly `dimension` elements.
+    /// The dimension declared by the bound column is checked once the statement is executed.
+    pub(crate) fn add_float_vector(
+        &mut self,
+        dimension: i32,
+        elements: &[f32],
+    ) -> Result<(), SerializationError> {
+        let Some(dimension) = usize::try_from(dimension).ok().filter(|&d| d > 0) else {
+            return Err(SerializationError::new(
+                BindValueError::InvalidVectorDimension(dimension),
+            ));
+        };
+        if elements.len() != dimension {
+            return Err(SerializationError::new(
+                BindValueError::VectorDimensionMismatch {
+                    expected: dimension,
+                    actual: elements.len(),
+                },
+            ));
+        }
+        let bytes = elements
+            .iter()
+            .flat_map(|element| element.to_be_bytes())
+            .collect::<Vec<_>>();
+        let cell = PreSerializedCell::Value(FFISlice::new(&bytes));
+        self.serialized_values.add_value(&cell, dummy_column_type())
+    }
+
     pub(crate) fn add_null(&mut self) -> Result<(), SerializationError> {
         let cell = PreSerializedCell::Null;
         self.serialized_values.add_value(&cell, dummy_column_type())
@@ -91,6 +521,57 @@ impl PreSerializedValues {
     }
 }
 
+/// Lets already serialized values be passed to APIs taking [`SerializeRow`],
+/// e.g. to execute unprepared statements, whose bind markers have no known types.
+///
+/// When the bind markers have known types, i.e. for prepared statements, `vector<float, N>`
+/// values are checked against the dimension declared by their column.
+impl SerializeRow for PreSerializedValues {
+    fn serialize(
+        &self,
+        ctx: &RowSerializationContext<'_>,
+        writer: &mut RowWriter,
+    ) -> Result<(), SerializationError> {
+        let columns = ctx.columns();
+        for (index, value) in self.serialized_values.iter().enumerate() {
+            let cell = writer.make_cell_writer();
+            match value {
+                RawValue::Value(bytes) => {
+                    if let Some(column) = columns.get(index) {
+                        check_float_vector(column.typ(), bytes)?;
+                    }
+                    cell.set_value(bytes).map_err(SerializationError::new)?;
+                }
+                RawValue::Null => {
+                    cell.set_null();
+                }
+                RawValue::Unset => {
+                    cell.set_unset();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.serialized_values.is_empty()
+    }
+}
+
+/// Checks that a value bound to a `vector<float, N>` column holds exactly `N` floats.
+/// Values of other types are left to the server.
+fn check_float_vector(typ: &ColumnType<'_>, bytes: &[u8]) -> Result<(), SerializationError> {
+    match float_vector_dimension(typ) {
+        Some(dimension) if bytes.len() != float_vector_byte_len(dimension) => Err(
+            SerializationError::new(BindValueError::VectorDimensionMismatch {
+                expected: dimension,
+                actual: bytes.len() / FLOAT_SIZE,
+            }),
+        ),
+        _ => Ok(()),
+    }
+}
+
 impl FFI for PreSerializedValues {
     type Origin = FromBox;
 }
@@ -115,13 +596,389 @@ pub extern "C" fn psv_add_value(
     value: FFISlice<'_, u8>,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let psv = psv
-        .into_mut_ref()
-        .expect("valid and non-null PreSerializedValues pointer");
-    match psv.add_value(value) {
-        Ok(()) => FFIMaybeException::ok(),
-        Err(e) => FFIMaybeException::from_error(e, constructors),
-    }
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_value(value) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `blob` value given as its raw bytes.
+///
+/// Blobs need no serialization, so C# passes the bound `byte[]` pinned in place instead of
+/// running it through its serializer. The bytes are copied once, into the request buffer.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `value` must point to pinned memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_blob(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    value: FFISlice<'_, u8>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_value(value) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `uuid`, or a `timeuuid` if `is_timeuuid` is set, given as 16 bytes in RFC 4122
+/// (big-endian) order.
+///
+/// Fails if `value` is not exactly 16 bytes long, or if a `timeuuid` is not a version 1 UUID.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `value` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_uuid(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    value: FFISlice<'_, u8>,
+    is_timeuuid: FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_uuid(value.as_slice(), is_timeuuid.into()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add an `inet` given as the octets of the address in network order.
+///
+/// Fails if `value` is neither 4 bytes (IPv4) nor 16 bytes (IPv6) long.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `value` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_inet(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    value: FFISlice<'_, u8>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_inet(value.as_slice()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `list` or `set`, as given by the `collection_type` code (`0x20` or `0x22`), of `count`
+/// elements of the type with code `element_type`. `elements` holds the serialized elements,
+/// each prefixed with its big-endian `[int]` length.
+///
+/// Fails if the type code is not a list or set one, if `elements` does not hold exactly `count`
+/// elements, if an element is null, or if an element of a fixed-size or text type is invalid.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `elements` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_collection(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    collection_type: u8,
+    element_type: u8,
+    count: i32,
+    elements: FFISlice<'_, u8>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_collection(collection_type, element_type, count, elements.as_slice()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `map` of `count` entries with keys of the type with code `key_type` and values of the
+/// type with code `value_type`. `entries` holds the serialized keys and values, alternating,
+/// each prefixed with its big-endian `[int]` length.
+///
+/// Fails if `entries` does not hold exactly `count` key/value pairs, if a key or value is null,
+/// or if a key or value of a fixed-size or text type is invalid.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `entries` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_map(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    key_type: u8,
+    value_type: u8,
+    count: i32,
+    entries: FFISlice<'_, u8>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_map(key_type, value_type, count, entries.as_slice()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `tuple` whose fields have the types with codes `field_types`, in order. `fields` holds
+/// the serialized fields, each prefixed with its big-endian `[int]` length, or with -1 for a null field.
+///
+/// Fails if `fields` does not hold exactly one field per type code, or if a non-null field of
+/// a fixed-size or text type is invalid.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `field_types` and `fields` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_tuple(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    field_types: FFISlice<'_, u8>,
+    fields: FFISlice<'_, u8>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_tuple(field_types.as_slice(), fields.as_slice()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a user defined type value whose fields have the types with codes `field_types`, in the
+/// order of the type definition, as read from the cluster metadata. `fields` holds the serialized
+/// fields, each prefixed with its big-endian `[int]` length, or with -1 for a null field.
+///
+/// Trailing fields may be omitted. Fails if `fields` is truncated or holds more fields than
+/// type codes, or if a non-null field of a fixed-size or text type is invalid.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `field_types` and `fields` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_udt(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    field_types: FFISlice<'_, u8>,
+    fields: FFISlice<'_, u8>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_udt(field_types.as_slice(), fields.as_slice()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `decimal` equal to `unscaled * 10^(-scale)`, the unscaled value given as big-endian
+/// two's-complement bytes, as produced by `BigInteger.ToByteArray(isUnsigned: false, isBigEndian: true)`.
+///
+/// Fails if `unscaled` is empty.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `unscaled` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_decimal(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    scale: i32,
+    unscaled: FFISlice<'_, u8>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_decimal(scale, unscaled.as_slice()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `varint` given as big-endian two's-complement bytes, as produced by
+/// `BigInteger.ToByteArray(isUnsigned: false, isBigEndian: true)`.
+/// Redundant leading sign bytes are stripped before sending.
+///
+/// Fails if `value` is empty.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `value` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_varint(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    value: FFISlice<'_, u8>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_varint(value.as_slice()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `timestamp` given in milliseconds since the Unix epoch.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_timestamp(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    millis: i64,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_timestamp(millis) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `date` given in signed days since the Unix epoch.
+/// The offset of the protocol encoding, which centers the epoch at 2^31, is applied here.
+///
+/// Fails if `days` is outside the range of `i32`.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_date(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    days: i64,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_date(days) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `time` given in nanoseconds since midnight.
+///
+/// Fails if `nanos` is not within 0 and 86399999999999.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_time(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    nanos: i64,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_time(nanos) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `duration` from its three components.
+///
+/// Fails if some components are negative and others positive.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_duration(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    months: i32,
+    days: i32,
+    nanoseconds: i64,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_duration(months, days, nanoseconds) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Add a `vector<float, N>` from its dimension and elements.
+///
+/// Fails if `dimension` is not positive or `elements` does not hold exactly `dimension` elements.
+///
+/// # Safety
+/// - `psv` must be a valid pointer to a `PreSerializedValues`.
+/// - `elements` must point to memory that remains valid for this call (Rust copies immediately).
+/// - `constructors` must point to a valid `ExceptionConstructors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn psv_add_float_vector(
+    psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
+    dimension: i32,
+    elements: FFISlice<'_, f32>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_float_vector(dimension, elements.as_slice()) {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
 }
 
 /// Add a NULL cell to the builder.
@@ -134,13 +991,15 @@ pub extern "C" fn psv_add_null(
     psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let psv = psv
-        .into_mut_ref()
-        .expect("valid and non-null PreSerializedValues pointer");
-    match psv.add_null() {
-        Ok(()) => FFIMaybeException::ok(),
-        Err(e) => FFIMaybeException::from_error(e, constructors),
-    }
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_null() {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
 }
 
 /// Add an UNSET cell to the builder.
@@ -153,13 +1012,15 @@ pub extern "C" fn psv_add_unset(
     psv: BridgedBorrowedExclusivePtr<'_, PreSerializedValues>,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let psv = psv
-        .into_mut_ref()
-        .expect("valid and non-null PreSerializedValues pointer");
-    match psv.add_unset() {
-        Ok(()) => FFIMaybeException::ok(),
-        Err(e) => FFIMaybeException::from_error(e, constructors),
-    }
+    FFIMaybeException::catch_panics(constructors, || {
+        let psv = psv
+            .into_mut_ref()
+            .expect("valid and non-null PreSerializedValues pointer");
+        match psv.add_unset() {
+            Ok(()) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
 }
 
 /// Opaque type for the C# populate-values callback context.
@@ -1,9 +1,12 @@
 use crate::error_conversion::FFIMaybeException;
+use crate::execution_profile::{BridgedExecutionProfile, profile_handle};
 use crate::ffi::{
     ArcFFI, BridgedBorrowedSharedPtr, FFI, FFIBool, FFIPtr, FFIStr, FromArc, RefFFI,
     ffi_callback_for_each,
 };
 use crate::row_set::column_type_to_code;
+use crate::session::timestamp_from_micros;
+use crate::session_config::RetryPolicyKind;
 use crate::task::ExceptionConstructors;
 use scylla::frame::response::result::ColumnType;
 use scylla::statement::prepared::PreparedStatement;
@@ -22,21 +25,26 @@ impl FFI for BridgedPreparedStatement {
 #[unsafe(no_mangle)]
 pub extern "C" fn prepared_statement_get_variables_column_specs_count(
     prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
-    out_num_fields: *mut usize,
+    out_num_fields: Option<&mut usize>,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let prepared_statement = ArcFFI::as_ref(prepared_statement_ptr)
-        .expect("valid and non-null BridgedPreparedStatement pointer");
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(out_num_fields) = out_num_fields else {
+            return FFIMaybeException::null_argument("out_num_fields", constructors);
+        };
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
 
-    let guard = prepared_statement
-        .inner
-        .read()
-        .expect("poisoning impossible due to process-aborting panics");
+        let guard = prepared_statement
+            .inner
+            .read()
+            .expect("lock not poisoned by an earlier panic");
 
-    unsafe {
         *out_num_fields = guard.get_variable_col_specs().len();
-    }
 
-    FFIMaybeException::ok()
+        FFIMaybeException::ok()
+    })
 }
 
 #[derive(Clone, Copy)]
@@ -81,85 +89,95 @@ pub extern "C" fn prepared_statement_fill_column_specs_metadata(
     set_prepared_statement_variables_metadata: SetPreparedStatementVariablesMetadata,
     pk_indexes_list_ptr: PartitionKeyIndexesListPtr<'_>,
     add_pk_index: AddPartitionKeyIndex,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let prepared_statement = ArcFFI::as_ref(prepared_statement_ptr)
-        .expect("valid and non-null BridgedPreparedStatement pointer");
-
-    let guard = prepared_statement
-        .inner
-        .read()
-        .expect("poisoning impossible due to process-aborting panics");
-
-    // Iterate column specs and call the metadata setter
-    for (i, spec) in guard.get_variable_col_specs().iter().enumerate() {
-        let name = FFIStr::new(spec.name());
-        let keyspace = FFIStr::new(spec.table_spec().ks_name());
-        let table = FFIStr::new(spec.table_spec().table_name());
-
-        let type_code = column_type_to_code(spec.typ());
-
-        let type_info_handle: BridgedBorrowedSharedPtr<ColumnType> = if type_code >= 0x20 {
-            RefFFI::as_ptr(spec.typ())
-        } else {
-            RefFFI::null()
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
         };
 
-        let is_frozen = match spec.typ() {
-            ColumnType::Collection { frozen, .. } | ColumnType::UserDefinedType { frozen, .. } => {
-                *frozen
+        let guard = prepared_statement
+            .inner
+            .read()
+            .expect("lock not poisoned by an earlier panic");
+
+        // Iterate column specs and call the metadata setter
+        for (i, spec) in guard.get_variable_col_specs().iter().enumerate() {
+            let name = FFIStr::new(spec.name());
+            let keyspace = FFIStr::new(spec.table_spec().ks_name());
+            let table = FFIStr::new(spec.table_spec().table_name());
+
+            let type_code = column_type_to_code(spec.typ());
+
+            let type_info_handle: BridgedBorrowedSharedPtr<ColumnType> = if type_code >= 0x20 {
+                RefFFI::as_ptr(spec.typ())
+            } else {
+                RefFFI::null()
+            };
+
+            let is_frozen = match spec.typ() {
+                ColumnType::Collection { frozen, .. }
+                | ColumnType::UserDefinedType { frozen, .. } => *frozen,
+                _ => false,
+            };
+
+            unsafe {
+                let ffi_exception = set_prepared_statement_variables_metadata(
+                    columns_ptr,
+                    i,
+                    name,
+                    keyspace,
+                    table,
+                    type_code,
+                    type_info_handle,
+                    is_frozen as u8,
+                );
+
+                // If there is an exception returned from callback, throw it as soon as possible
+                if ffi_exception.has_exception() {
+                    return ffi_exception;
+                }
             }
-            _ => false,
-        };
+        }
 
         unsafe {
-            let ffi_exception = set_prepared_statement_variables_metadata(
-                columns_ptr,
-                i,
-                name,
-                keyspace,
-                table,
-                type_code,
-                type_info_handle,
-                is_frozen as u8,
-            );
-
-            // If there is an exception returned from callback, throw it as soon as possible
-            if ffi_exception.has_exception() {
-                return ffi_exception;
-            }
+            ffi_callback_for_each(
+                pk_indexes_list_ptr,
+                add_pk_index,
+                guard
+                    .get_variable_pk_indexes()
+                    .iter()
+                    .map(|pk_indexes| pk_indexes.index),
+            )
         }
-    }
-
-    unsafe {
-        ffi_callback_for_each(
-            pk_indexes_list_ptr,
-            add_pk_index,
-            guard
-                .get_variable_pk_indexes()
-                .iter()
-                .map(|pk_indexes| pk_indexes.index),
-        )
-    }
+    })
 }
 
 #[unsafe(no_mangle)]
 pub extern "C" fn prepared_statement_is_lwt(
     prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
-    is_lwt: &mut FFIBool,
+    is_lwt: Option<&mut FFIBool>,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let prepared_statement = ArcFFI::as_ref(prepared_statement_ptr)
-        .expect("valid and non-null BridgedPreparedStatement pointer");
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(is_lwt) = is_lwt else {
+            return FFIMaybeException::null_argument("is_lwt", constructors);
+        };
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
 
-    let guard = prepared_statement
-        .inner
-        .read()
-        .expect("poisoning impossible due to process-aborting panics");
+        let guard = prepared_statement
+            .inner
+            .read()
+            .expect("lock not poisoned by an earlier panic");
 
-    let is_lwt_value = guard.is_confirmed_lwt();
+        let is_lwt_value = guard.is_confirmed_lwt();
 
-    *is_lwt = is_lwt_value.into();
+        *is_lwt = is_lwt_value.into();
 
-    FFIMaybeException::ok()
+        FFIMaybeException::ok()
+    })
 }
 
 /// Gets consistency level of the prepared statement.
@@ -167,21 +185,28 @@ pub extern "C" fn prepared_statement_is_lwt(
 pub extern "C" fn prepared_statement_get_consistency_level(
     prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
     consistency_level: Option<&mut i32>,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let prepared_statement = ArcFFI::as_ref(prepared_statement_ptr)
-        .expect("valid and non-null BridgedPreparedStatement pointer");
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(consistency_level) = consistency_level else {
+            return FFIMaybeException::null_argument("consistency_level", constructors);
+        };
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
 
-    let guard = prepared_statement
-        .inner
-        .read()
-        .expect("poisoning impossible due to process-aborting panics");
+        let guard = prepared_statement
+            .inner
+            .read()
+            .expect("lock not poisoned by an earlier panic");
 
-    let maybe_consistency = guard.get_consistency();
+        let maybe_consistency = guard.get_consistency();
 
-    if let (Some(cl), Some(consistency_level)) = (maybe_consistency, consistency_level) {
-        *consistency_level = cl as i32;
-    }
-    FFIMaybeException::ok()
+        if let Some(cl) = maybe_consistency {
+            *consistency_level = cl as i32;
+        }
+        FFIMaybeException::ok()
+    })
 }
 
 /// Sets consistency level of the prepared statement.
@@ -191,50 +216,60 @@ pub extern "C" fn prepared_statement_set_consistency_level(
     consistency_level: u16,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let prepared_statement = ArcFFI::as_ref(prepared_statement_ptr)
-        .expect("valid and non-null BridgedPreparedStatement pointer");
-
-    let mut guard = prepared_statement
-        .inner
-        .write()
-        .expect("poisoning impossible due to process-aborting panics");
-
-    let Ok(cl) = consistency_level.try_into() else {
-        let ex = constructors
-            .invalid_argument_exception_constructor
-            .construct_from_rust(
-                format!(
-                    "Invalid consistency level value {0} passed from C#.",
-                    consistency_level
-                )
-                .as_str(),
-            );
-        return FFIMaybeException::from_exception(ex);
-    };
-
-    guard.set_consistency(cl);
-
-    FFIMaybeException::ok()
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
+
+        let mut guard = prepared_statement
+            .inner
+            .write()
+            .expect("lock not poisoned by an earlier panic");
+
+        let Ok(cl) = consistency_level.try_into() else {
+            let ex = constructors
+                .invalid_argument_exception_constructor
+                .construct_from_rust(
+                    format!(
+                        "Invalid consistency level value {0} passed from C#.",
+                        consistency_level
+                    )
+                    .as_str(),
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
+
+        guard.set_consistency(cl);
+
+        FFIMaybeException::ok()
+    })
 }
 
 /// Gets whether the prepared statement is idempotent.
 #[unsafe(no_mangle)]
 pub extern "C" fn prepared_statement_get_is_idempotent(
     prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
-    is_idempotent: &mut FFIBool,
+    is_idempotent: Option<&mut FFIBool>,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let prepared_statement = ArcFFI::as_ref(prepared_statement_ptr)
-        .expect("valid and non-null BridgedPreparedStatement pointer");
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(is_idempotent) = is_idempotent else {
+            return FFIMaybeException::null_argument("is_idempotent", constructors);
+        };
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
 
-    let guard = prepared_statement
-        .inner
-        .read()
-        .expect("poisoning impossible due to process-aborting panics");
-    let is_idempotent_value = guard.get_is_idempotent();
+        let guard = prepared_statement
+            .inner
+            .read()
+            .expect("lock not poisoned by an earlier panic");
+        let is_idempotent_value = guard.get_is_idempotent();
 
-    *is_idempotent = is_idempotent_value.into();
+        *is_idempotent = is_idempotent_value.into();
 
-    FFIMaybeException::ok()
+        FFIMaybeException::ok()
+    })
 }
 
 /// Sets whether the prepared statement is idempotent.
@@ -242,16 +277,227 @@ pub extern "C" fn prepared_statement_get_is_idempotent(
 pub extern "C" fn prepared_statement_set_is_idempotent(
     prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
     is_idempotent: FFIBool,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let prepared_statement = ArcFFI::as_ref(prepared_statement_ptr)
-        .expect("valid and non-null BridgedPreparedStatement pointer");
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
+
+        let mut guard = prepared_statement
+            .inner
+            .write()
+            .expect("lock not poisoned by an earlier panic");
+
+        guard.set_is_idempotent(is_idempotent.into());
+
+        FFIMaybeException::ok()
+    })
+}
 
-    let mut guard = prepared_statement
-        .inner
-        .write()
-        .expect("poisoning impossible due to process-aborting panics");
+/// Sets whether requests executing the prepared statement are traced by the server.
+/// The trace of such a request can be fetched with `session_get_query_trace`.
+#[unsafe(no_mangle)]
+pub extern "C" fn prepared_statement_set_tracing(
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    tracing: FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
 
-    guard.set_is_idempotent(is_idempotent.into());
+        let mut guard = prepared_statement
+            .inner
+            .write()
+            .expect("lock not poisoned by an earlier panic");
 
-    FFIMaybeException::ok()
+        guard.set_tracing(tracing.into());
+
+        FFIMaybeException::ok()
+    })
+}
+
+/// Sets the client-side write timestamp of the prepared statement, in microseconds since
+/// the Unix epoch. A negative value unsets it, letting the driver or server assign one.
+#[unsafe(no_mangle)]
+pub extern "C" fn prepared_statement_set_timestamp(
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    timestamp_micros: i64,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
+
+        let mut guard = prepared_statement
+            .inner
+            .write()
+            .expect("lock not poisoned by an earlier panic");
+
+        guard.set_timestamp(timestamp_from_micros(timestamp_micros));
+
+        FFIMaybeException::ok()
+    })
+}
+
+/// Value of `policy` in `prepared_statement_set_retry_policy` making the prepared statement
+/// use the retry policy of the session again.
+const SESSION_RETRY_POLICY: u8 = u8::MAX;
+
+/// Sets the retry policy of the prepared statement, overriding the one of the session.
+///
+/// `policy` is a raw `RetryPolicyKind`, or `SESSION_RETRY_POLICY` to use the policy of the
+/// session again. Custom policies implemented in C# can only be set on the session.
+#[unsafe(no_mangle)]
+pub extern "C" fn prepared_statement_set_retry_policy(
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    policy: u8,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
+
+        let retry_policy = if policy == SESSION_RETRY_POLICY {
+            None
+        } else {
+            let kind = match RetryPolicyKind::try_from(policy) {
+                Ok(kind) => kind,
+                Err(e) => return FFIMaybeException::from_error(e, constructors),
+            };
+            let Some(retry_policy) = kind.into_builtin_policy() else {
+                let ex = constructors
+                    .invalid_argument_exception_constructor
+                    .construct_from_rust(
+                        "Custom retry policies can only be set on the session, not on a statement.",
+                    );
+                return FFIMaybeException::from_exception(ex);
+            };
+            Some(retry_policy)
+        };
+
+        let mut guard = prepared_statement
+            .inner
+            .write()
+            .expect("lock not poisoned by an earlier panic");
+
+        guard.set_retry_policy(retry_policy);
+
+        FFIMaybeException::ok()
+    })
+}
+
+/// Attaches an execution profile to the prepared statement, overriding the default profile of
+/// the session. A null `profile_ptr` detaches the profile, so that the session default is used again.
+///
+/// Options set on the statement itself, e.g. its consistency, still take precedence over the profile.
+#[unsafe(no_mangle)]
+pub extern "C" fn prepared_statement_set_execution_profile(
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    profile_ptr: BridgedBorrowedSharedPtr<'_, BridgedExecutionProfile>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let Some(prepared_statement) = ArcFFI::as_ref(prepared_statement_ptr) else {
+            return FFIMaybeException::null_argument("prepared_statement_ptr", constructors);
+        };
+
+        let mut guard = prepared_statement
+            .inner
+            .write()
+            .expect("lock not poisoned by an earlier panic");
+
+        guard.set_execution_profile_handle(profile_handle(profile_ptr));
+
+        FFIMaybeException::ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_conversion::testing::{RECORDING_CONSTRUCTORS, take_recorded};
+    use crate::error_conversion::{FFIErrorCode, ffi_exception_get_code};
+
+    /// Checks that `result` is an `ArgumentNullException` naming `param_name`.
+    fn assert_null_argument(result: FFIMaybeException, param_name: &str) {
+        assert_eq!(
+            ffi_exception_get_code(&result),
+            FFIErrorCode::InvalidArgument
+        );
+        let recorded = take_recorded();
+        assert_eq!(recorded.kind, "ArgumentNullException");
+        assert_eq!(recorded.message, param_name);
+    }
+
+    #[test]
+    fn null_out_pointers_are_reported_by_name() {
+        assert_null_argument(
+            prepared_statement_get_variables_column_specs_count(
+                BridgedBorrowedSharedPtr::null(),
+                None,
+                &RECORDING_CONSTRUCTORS,
+            ),
+            "out_num_fields",
+        );
+        assert_null_argument(
+            prepared_statement_is_lwt(
+                BridgedBorrowedSharedPtr::null(),
+                None,
+                &RECORDING_CONSTRUCTORS,
+            ),
+            "is_lwt",
+        );
+        assert_null_argument(
+            prepared_statement_get_consistency_level(
+                BridgedBorrowedSharedPtr::null(),
+                None,
+                &RECORDING_CONSTRUCTORS,
+            ),
+            "consistency_level",
+        );
+        assert_null_argument(
+            prepared_statement_get_is_idempotent(
+                BridgedBorrowedSharedPtr::null(),
+                None,
+                &RECORDING_CONSTRUCTORS,
+            ),
+            "is_idempotent",
+        );
+    }
+
+    #[test]
+    fn null_statement_is_reported_by_name() {
+        let mut count = 0;
+        assert_null_argument(
+            prepared_statement_get_variables_column_specs_count(
+                BridgedBorrowedSharedPtr::null(),
+                Some(&mut count),
+                &RECORDING_CONSTRUCTORS,
+            ),
+            "prepared_statement_ptr",
+        );
+
+        let mut flag = FFIBool::from(false);
+        assert_null_argument(
+            prepared_statement_is_lwt(
+                BridgedBorrowedSharedPtr::null(),
+                Some(&mut flag),
+                &RECORDING_CONSTRUCTORS,
+            ),
+            "prepared_statement_ptr",
+        );
+        assert_null_argument(
+            prepared_statement_set_consistency_level(
+                BridgedBorrowedSharedPtr::null(),
+                1,
+                &RECORDING_CONSTRUCTORS,
+            ),
+            "prepared_statement_ptr",
+        );
+    }
 }
@@ -0,0 +1,1000 @@
+use std::net::{IpAddr, SocketAddr};
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use scylla::deserialize::row::ColumnIterator;
+use scylla::deserialize::value::DeserializeValue;
+use scylla::observability::history::{AttemptResult, FiberHistory, StructuredHistory};
+use scylla::policies::retry::RetryDecision;
+use scylla::response::PagingStateResponse;
+use scylla::response::query_result::QueryResult;
+use scylla::statement::Consistency;
+use scylla::value::{
+    Counter, CqlDate, CqlDecimalBorrowed, CqlDuration, CqlTime, CqlTimestamp, CqlVarintBorrowed,
+};
+use uuid::Uuid;
+
+use crate::error_conversion::{FFIMaybeException, QueryResultError, RowAccessError};
+use crate::ffi::{
+    ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIBool, FFINonNullPtr, FFISlice,
+    FFIStr, FromArc, IpOctets,
+};
+use crate::pre_serialized_values::{float_vector_byte_len, float_vector_dimension};
+use crate::row::{BridgedRow, Cacheable, ResultColumn, write_cell};
+use crate::row_set::{Columns, SetMetadata, column_type_to_code, set_column_metadata};
+use crate::task::ExceptionConstructors;
+
+/// Name of the column holding the outcome of a lightweight transaction.
+const LWT_APPLIED_COLUMN: &str = "[applied]";
+
+/// Fully fetched result of a single (unpaged, or single-page) request.
+///
+/// Rows share the response frame and deserialize their cells lazily, see [`BridgedRow`].
+/// Results of requests that do not return rows (e.g. `INSERT`) have no columns and no rows.
+#[derive(Debug)]
+pub(crate) struct BridgedQueryResult {
+    columns: Arc<[ResultColumn]>,
+    rows: Vec<BridgedRow>,
+    /// Warnings sent by the server along with the result. Usually empty, which does not allocate.
+    warnings: Vec<String>,
+    execution_info: ExecutionInfo,
+    /// Paging state of the next page, if this is a page of a result with more pages.
+    paging_state: Option<Arc<[u8]>>,
+    /// Id of the server-side trace of the request, if it was traced.
+    tracing_id: Option<Uuid>,
+}
+
+/// How the request producing a result was executed, backing `RowSet.Info` in C#.
+#[derive(Debug)]
+pub(crate) struct ExecutionInfo {
+    /// Address of the node that served the request, formatted as `ip:port`.
+    coordinator: String,
+    /// Consistency the request succeeded with, which differs from the requested one
+    /// if the retry policy downgraded it.
+    achieved_consistency: Consistency,
+    /// Number of attempts made, including retries and speculative executions.
+    attempt_count: u32,
+    /// Number of speculative executions started, in addition to the original execution.
+    speculative_execution_count: u32,
+}
+
+impl ExecutionInfo {
+    /// Builds the execution info of `result` from the history of the request that produced it.
+    /// `history` should come from a `HistoryCollector` attached to the executed statement;
+    /// the last request recorded in it is assumed to be the one that produced `result`.
+    pub(crate) fn new(
+        result: &QueryResult,
+        requested_consistency: Consistency,
+        history: &StructuredHistory,
+    ) -> Self {
+        let coordinator = result.request_coordinator().connection_address();
+
+        let Some(request) = history.requests.last() else {
+            return Self::without_history(coordinator, requested_consistency);
+        };
+
+        let fibers =
+            || std::iter::once(&request.non_speculative_fiber).chain(&request.speculative_fibers);
+        let attempt_count = fibers().map(|fiber| fiber.attempts.len() as u32).sum();
+        let speculative_execution_count = request.speculative_fibers.len() as u32;
+
+        // Only the fiber that succeeded tells which consistency the result was produced with.
+        let achieved_consistency = fibers()
+            .find(|fiber| fiber_succeeded(fiber))
+            .map_or(requested_consistency, |fiber| {
+                achieved_consistency(fiber, requested_consistency)
+            });
+
+        Self {
+            coordinator: coordinator.to_string(),
+            achieved_consistency,
+            attempt_count: attempt_count.max(1),
+            speculative_execution_count,
+        }
+    }
+
+    /// Execution info of a request whose history was not collected.
+    fn without_history(coordinator: SocketAddr, consistency: Consistency) -> Self {
+        Self {
+            coordinator: coordinator.to_string(),
+            achieved_consistency: consistency,
+            attempt_count: 1,
+            speculative_execution_count: 0,
+        }
+    }
+}
+
+fn fiber_succeeded(fiber: &FiberHistory) -> bool {
+    matches!(
+        fiber
+            .attempts
+            .last()
+            .and_then(|attempt| attempt.result.as_ref()),
+        Some(AttemptResult::Success(_))
+    )
+}
+
+/// Applies the consistency changes requested by the retry policy along the fiber.
+fn achieved_consistency(fiber: &FiberHistory, requested: Consistency) -> Consistency {
+    fiber
+        .attempts
+        .iter()
+        .filter_map(|attempt| match &attempt.result {
+            Some(AttemptResult::Error(_, _, RetryDecision::RetrySameTarget(consistency)))
+            | Some(AttemptResult::Error(_, _, RetryDecision::RetryNextTarget(consistency))) => {
+                *consistency
+            }
+            _ => None,
+        })
+        .last()
+        .unwrap_or(requested)
+}
+
+impl FFI for BridgedQueryResult {
+    type Origin = FromArc;
+}
+
+impl BridgedQueryResult {
+    pub(crate) fn from_query_result(
+        result: QueryResult,
+        execution_info: ExecutionInfo,
+    ) -> Result<Self, QueryResultError> {
+        let warnings = result.warnings().map(str::to_owned).collect();
+        let tracing_id = result.tracing_id();
+
+        if !result.is_rows() {
+            return Ok(Self {
+                columns: Arc::new([]),
+                rows: Vec::new(),
+                warnings,
+                execution_info,
+                paging_state: None,
+                tracing_id,
+            });
+        }
+
+        let rows_result = result.into_rows_result()?;
+        let mut columns = ResultColumn::from_specs(rows_result.column_specs().iter());
+        let rows = rows_result
+            .rows::<ColumnIterator>()?
+            .map(|row| BridgedRow::from_column_iterator(&mut columns, row?, false))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            columns,
+            rows,
+            warnings,
+            execution_info,
+            paging_state: None,
+            tracing_id,
+        })
+    }
+
+    /// Records where the page following this one starts, if there is one.
+    pub(crate) fn set_paging_state(&mut self, response: PagingStateResponse) {
+        self.paging_state = match response.into_paging_control_flow() {
+            ControlFlow::Continue(paging_state) => paging_state.as_bytes_slice().cloned(),
+            ControlFlow::Break(()) => None,
+        };
+    }
+
+    fn column(&self, index: usize) -> Result<&ResultColumn, RowAccessError> {
+        self.columns
+            .get(index)
+            .ok_or(RowAccessError::ColumnIndexOutOfRange {
+                index,
+                count: self.columns.len(),
+            })
+    }
+
+    fn row(&self, index: usize) -> Result<&BridgedRow, RowAccessError> {
+        self.rows
+            .get(index)
+            .ok_or(RowAccessError::RowIndexOutOfRange {
+                index,
+                count: self.rows.len(),
+            })
+    }
+
+    /// Returns the column at `col` together with the serialized cell in row `row`.
+    pub(crate) fn cell(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Result<(&ResultColumn, Option<&Bytes>), RowAccessError> {
+        self.row(row)?.cell(col)
+    }
+
+    fn get<'res, T>(&'res self, row: usize, col: usize) -> Result<Option<T>, RowAccessError>
+    where
+        T: DeserializeValue<'res, 'res>,
+    {
+        self.row(row)?.get(col)
+    }
+
+    fn get_cached<T: Cacheable>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Result<Option<T>, RowAccessError> {
+        self.row(row)?.get_cached(col)
+    }
+
+    /// Reads the `[applied]` flag from the first row, or `None` if the result has no such
+    /// column or no rows, i.e. it is not the result of a conditional statement.
+    fn lwt_applied(&self) -> Result<Option<bool>, RowAccessError> {
+        let Some(col) = self
+            .columns
+            .iter()
+            .position(|column| column.name == LWT_APPLIED_COLUMN)
+        else {
+            return Ok(None);
+        };
+        if self.rows.is_empty() {
+            return Ok(None);
+        }
+        self.get::<bool>(0, col)
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_free(result_ptr: BridgedOwnedSharedPtr<BridgedQueryResult>) {
+    ArcFFI::free(result_ptr);
+    tracing::trace!("[FFI] BridgedQueryResult freed");
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_rows_count(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_count: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        *out_count = result.rows.len();
+        FFIMaybeException::ok()
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_columns_count(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_count: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        *out_count = result.columns.len();
+        FFIMaybeException::ok()
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_warnings_count(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_count: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        *out_count = result.warnings.len();
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the server warning at `index`, borrowed from the result.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_warning<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    index: usize,
+    out: &mut FFIStr<'res>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        match result.warnings.get(index) {
+            Some(warning) => {
+                *out = FFIStr::new(warning);
+                FFIMaybeException::ok()
+            }
+            None => FFIMaybeException::from_error(
+                RowAccessError::ItemIndexOutOfRange {
+                    index,
+                    count: result.warnings.len(),
+                },
+                constructors,
+            ),
+        }
+    })
+}
+
+/// Writes the address of the coordinator that served the request as `ip:port`, borrowed from the result.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_coordinator_address<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    out_address: &mut FFIStr<'res>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        *out_address = FFIStr::new(&result.execution_info.coordinator);
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the consistency level the request succeeded with, as its protocol code.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_achieved_consistency(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_consistency: &mut u16,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        *out_consistency = result.execution_info.achieved_consistency as u16;
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the number of attempts made to execute the request, including speculative ones.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_attempt_count(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_count: &mut u32,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        *out_count = result.execution_info.attempt_count;
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the number of speculative executions started for the request, in addition to the
+/// original execution. Only idempotent statements are executed speculatively.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_speculative_execution_count(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_count: &mut u32,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        *out_count = result.execution_info.speculative_execution_count;
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the `[applied]` flag of a lightweight transaction (conditional statement) result.
+///
+/// The flag is read from the first row. On conflict the other columns of that row hold
+/// the existing values, and can be read with the regular getters. `out_has_value` is set
+/// to false for results without an `[applied]` column, i.e. results of non-conditional statements.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_lwt_applied(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_applied: &mut FFIBool,
+    out_has_value: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        *out_has_value = false.into();
+
+        match result.lwt_applied() {
+            Ok(Some(applied)) => {
+                *out_applied = applied.into();
+                *out_has_value = true.into();
+                FFIMaybeException::ok()
+            }
+            Ok(None) => FFIMaybeException::ok(),
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Writes whether a lightweight transaction (conditional statement) was applied.
+///
+/// Unlike `result_get_lwt_applied`, this is meant for results known to be conditional:
+/// it fails if the result has no `[applied]` column. A null flag is reported as not applied.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_was_applied(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_applied: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let has_applied_column = result
+            .columns
+            .iter()
+            .any(|column| column.name == LWT_APPLIED_COLUMN);
+        if !has_applied_column {
+            return FFIMaybeException::from_error(RowAccessError::NotConditional, constructors);
+        }
+
+        match result.lwt_applied() {
+            Ok(applied) => {
+                *out_applied = applied.unwrap_or(false).into();
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Writes the paging state of the page following this one, borrowed from the result.
+///
+/// `out_has_more_pages` is false, and `out_state` is left unchanged, if this is the last page
+/// or the result was not paged.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_paging_state<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    out_state: &mut FFISlice<'res, u8>,
+    out_has_more_pages: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        match &result.paging_state {
+            Some(paging_state) => {
+                *out_state = FFISlice::new(paging_state);
+                *out_has_more_pages = true.into();
+            }
+            None => *out_has_more_pages = false.into(),
+        }
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the 16 big-endian bytes of the id of the server-side trace of the request,
+/// to be passed to `session_get_query_trace`.
+///
+/// `out_has_tracing_id` is false, and `out_bytes` is left unchanged, if the request was not traced.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_tracing_id(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    out_bytes: &mut [u8; 16],
+    out_has_tracing_id: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        match result.tracing_id {
+            Some(tracing_id) => {
+                *out_bytes = tracing_id.into_bytes();
+                *out_has_tracing_id = true.into();
+            }
+            None => *out_has_tracing_id = false.into(),
+        }
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the size of the serialized cells of the row at `row` in bytes.
+/// Meant for diagnostics, e.g. to find unexpectedly wide rows.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_row_byte_size(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    out_size: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        match result.row(row) {
+            Ok(row) => {
+                *out_size = row.byte_size();
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Writes whether the cell at `row` and `col` is null.
+///
+/// CQL tells a null cell apart from an empty one, e.g. an empty `text` or a zero-length `blob`:
+/// only the former is reported as null. Works for columns of any type.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_row_is_null(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        match result.cell(row, col) {
+            Ok((_, bytes)) => {
+                *out_is_null = bytes.is_none().into();
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Writes the name of the column at `index`, borrowed from the result.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_column_name<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    index: usize,
+    out_name: &mut FFIStr<'res>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        match result.column(index) {
+            Ok(column) => {
+                *out_name = FFIStr::new(&column.name);
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Writes the type code of the column at `index`.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_column_type(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    index: usize,
+    out_type_code: &mut u8,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        match result.column(index) {
+            Ok(column) => {
+                *out_type_code = column_type_to_code(&column.typ);
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Calls back into C# for each column of the result to provide its metadata, like
+/// `row_set_fill_columns_metadata`, so that C# can deserialize cells read with `result_get_raw_bytes`.
+/// Type handles passed to `set_metadata` stay valid until the result is freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_fill_columns_metadata(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    columns_ptr: FFINonNullPtr<'_, Columns>,
+    set_metadata: SetMetadata,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        for (index, column) in result.columns.iter().enumerate() {
+            let ffi_exception = set_column_metadata(
+                set_metadata,
+                columns_ptr,
+                index,
+                &column.name,
+                &column.keyspace,
+                &column.table,
+                &column.typ,
+            );
+            if ffi_exception.has_exception() {
+                return ffi_exception;
+            }
+        }
+        FFIMaybeException::ok()
+    })
+}
+
+// Typed cell getters.
+// All of them leave `out` unchanged and set `out_is_null` for null cells,
+// and fail if either index is out of range or the column is of a different type.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_int32(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        write_cell(result.get::<i32>(row, col), out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_int64(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        write_cell(result.get::<i64>(row, col), out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `counter` cell.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_counter(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result.get::<Counter>(row, col).map(|v| v.map(|c| c.0));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `text` or `ascii` cell. The string borrows from the result.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_text<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut FFIStr<'res>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result.get::<&str>(row, col).map(|v| v.map(FFIStr::new));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_bool(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut FFIBool,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result.get::<bool>(row, col).map(|v| v.map(FFIBool::from));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_double(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut f64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        write_cell(result.get::<f64>(row, col), out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_float(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut f32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        write_cell(result.get::<f32>(row, col), out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `blob` cell. The bytes borrow from the result.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_blob<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut FFISlice<'res, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result.get::<&[u8]>(row, col).map(|v| v.map(FFISlice::new));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `vector<float, N>` cell as its `N` big-endian floats. The bytes borrow from the result.
+///
+/// `out_dimension` is set to the dimension `N` declared by the column, also for null cells.
+/// Fails if the column is not a vector of floats.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_float_vector<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut FFISlice<'res, u8>,
+    out_dimension: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result.cell(row, col).and_then(|(column, bytes)| {
+            let Some(dimension) = float_vector_dimension(&column.typ) else {
+                return Err(RowAccessError::UnexpectedType {
+                    name: column.name.clone(),
+                    expected: "vector<float>",
+                });
+            };
+            // Declared dimensions are at most `u16::MAX`.
+            *out_dimension = dimension as i32;
+            match bytes {
+                Some(bytes) if bytes.len() != float_vector_byte_len(dimension) => {
+                    Err(RowAccessError::MalformedValue {
+                        name: column.name.clone(),
+                    })
+                }
+                bytes => Ok(bytes.map(|bytes| FFISlice::new(bytes))),
+            }
+        });
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `decimal` cell as `unscaled * 10^(-scale)`, the unscaled value as big-endian
+/// two's-complement bytes. The bytes borrow from the result.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_decimal<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out_scale: &mut i32,
+    out_unscaled_bytes: &mut FFISlice<'res, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result.get::<CqlDecimalBorrowed>(row, col).map(|v| {
+            v.map(|decimal| {
+                let (unscaled, scale) = decimal.as_signed_be_bytes_slice_and_exponent();
+                *out_scale = scale;
+                FFISlice::new(unscaled)
+            })
+        });
+        write_cell(cell, out_unscaled_bytes, out_is_null, constructors)
+    })
+}
+
+/// Reads a `varint` cell as big-endian two's-complement bytes. The bytes borrow from the result.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_varint<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut FFISlice<'res, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result
+            .get::<CqlVarintBorrowed>(row, col)
+            .map(|v| v.map(|varint| FFISlice::new(varint.as_signed_bytes_be_slice())));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `timestamp` cell in milliseconds since the Unix epoch.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_timestamp(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out_epoch_millis: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result
+            .get::<CqlTimestamp>(row, col)
+            .map(|v| v.map(|timestamp| timestamp.0));
+        write_cell(cell, out_epoch_millis, out_is_null, constructors)
+    })
+}
+
+/// Reads a `date` cell in signed days since the Unix epoch,
+/// with the 2^31 offset of the protocol encoding removed.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_date(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out_epoch_days: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result
+            .get::<CqlDate>(row, col)
+            .map(|v| v.map(|date| date.0.wrapping_sub(1 << 31) as i32));
+        write_cell(cell, out_epoch_days, out_is_null, constructors)
+    })
+}
+
+/// Reads a `time` cell in nanoseconds since midnight.
+/// Values outside of a single day are rejected when the cell is deserialized.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_time(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out_nanos: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result
+            .get::<CqlTime>(row, col)
+            .map(|v| v.map(|time| time.0));
+        write_cell(cell, out_nanos, out_is_null, constructors)
+    })
+}
+
+/// Components of a `duration`, as written by `result_get_duration`.
+#[repr(C)]
+pub struct FFIDuration {
+    months: i32,
+    days: i32,
+    nanoseconds: i64,
+}
+
+/// Reads a `duration` cell, decoding its vint-encoded components.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_duration(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut FFIDuration,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result.get_cached::<CqlDuration>(row, col).map(|v| {
+            v.map(|duration| FFIDuration {
+                months: duration.months,
+                days: duration.days,
+                nanoseconds: duration.nanoseconds,
+            })
+        });
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `uuid` or `timeuuid` cell as 16 big-endian bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_uuid(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut [u8; 16],
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result
+            .get_cached::<Uuid>(row, col)
+            .map(|v| v.map(Uuid::into_bytes));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Octets of an `inet` address, as written by `result_get_inet`.
+/// Only the first `length` octets are set: 4 for IPv4 or 16 for IPv6.
+#[repr(C)]
+pub struct FFIInet {
+    octets: [u8; 16],
+    length: u8,
+}
+
+/// Reads an `inet` cell.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_inet(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut FFIInet,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result.get_cached::<IpAddr>(row, col).map(|v| {
+            v.map(|ip| {
+                let ip = IpOctets::new(ip);
+                let mut octets = [0; 16];
+                octets[..ip.as_slice().len()].copy_from_slice(ip.as_slice());
+                FFIInet {
+                    octets,
+                    length: ip.as_slice().len() as u8,
+                }
+            })
+        });
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Reads the serialized bytes of a cell of any type, including custom types, without deserializing it.
+/// The bytes borrow from the response frame and stay valid until the result is freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_raw_bytes<'res>(
+    result_ptr: BridgedBorrowedSharedPtr<'res, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut FFISlice<'res, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        let cell = result
+            .cell(row, col)
+            .map(|(_, bytes)| bytes.map(|bytes| FFISlice::new(bytes)));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
@@ -0,0 +1,261 @@
+use scylla::observability::tracing::{TracingEvent, TracingInfo};
+
+use crate::error_conversion::{FFIMaybeException, RowAccessError};
+use crate::ffi::{
+    ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIBool, FFIStr, FromArc,
+};
+use crate::task::ExceptionConstructors;
+
+/// Server-side trace of a single request, read from the `system_traces` tables.
+///
+/// Addresses are formatted upfront, so that C# can borrow them as strings
+/// like any other text field of the trace.
+#[derive(Debug)]
+pub(crate) struct BridgedQueryTrace {
+    request: Option<String>,
+    coordinator: Option<String>,
+    client: Option<String>,
+    /// Server-side duration of the request in microseconds.
+    duration_micros: Option<i32>,
+    /// Start of the request in milliseconds since the Unix epoch.
+    started_at_millis: Option<i64>,
+    events: Vec<TraceEvent>,
+}
+
+#[derive(Debug)]
+struct TraceEvent {
+    activity: Option<String>,
+    source: Option<String>,
+    /// Time elapsed on the source node since it began handling the request, in microseconds.
+    source_elapsed_micros: Option<i32>,
+    thread: Option<String>,
+    /// Time of the event in milliseconds since the Unix epoch, taken from its timeuuid.
+    timestamp_millis: Option<i64>,
+}
+
+impl FFI for BridgedQueryTrace {
+    type Origin = FromArc;
+}
+
+impl From<TracingInfo> for BridgedQueryTrace {
+    fn from(info: TracingInfo) -> Self {
+        Self {
+            request: info.request,
+            coordinator: info.coordinator.map(|address| address.to_string()),
+            client: info.client.map(|address| address.to_string()),
+            duration_micros: info.duration,
+            started_at_millis: info.started_at.map(|ts| ts.0),
+            events: info.events.into_iter().map(TraceEvent::from).collect(),
+        }
+    }
+}
+
+impl From<TracingEvent> for TraceEvent {
+    fn from(event: TracingEvent) -> Self {
+        let timestamp_millis = event.event_id.get_timestamp().map(|ts| {
+            let (secs, nanos) = ts.to_unix();
+            secs as i64 * 1000 + (nanos / 1_000_000) as i64
+        });
+        Self {
+            activity: event.activity,
+            source: event.source.map(|address| address.to_string()),
+            source_elapsed_micros: event.source_elapsed,
+            thread: event.thread,
+            timestamp_millis,
+        }
+    }
+}
+
+/// Writes an optional text field of the trace, borrowed from it.
+fn write_optional_str<'a>(
+    value: Option<&'a String>,
+    out: &mut FFIStr<'a>,
+    out_is_null: &mut FFIBool,
+) {
+    match value {
+        Some(value) => {
+            *out = FFIStr::new(value);
+            *out_is_null = false.into();
+        }
+        None => *out_is_null = true.into(),
+    }
+}
+
+/// Borrows an optional text field of an event, together with whether it is present.
+fn optional_str(value: &Option<String>) -> (FFIStr<'_>, FFIBool) {
+    (
+        FFIStr::new(value.as_deref().unwrap_or_default()),
+        value.is_some().into(),
+    )
+}
+
+/// Writes an optional numeric field of the trace. For a missing value only `out_is_null` is set.
+fn write_optional<T: Copy>(value: Option<T>, out: &mut T, out_is_null: &mut FFIBool) {
+    match value {
+        Some(value) => {
+            *out = value;
+            *out_is_null = false.into();
+        }
+        None => *out_is_null = true.into(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn query_trace_free(trace_ptr: BridgedOwnedSharedPtr<BridgedQueryTrace>) {
+    ArcFFI::free(trace_ptr);
+    tracing::trace!("[FFI] BridgedQueryTrace freed");
+}
+
+/// Writes the request type (e.g. `Execute CQL3 prepared query`), borrowed from the trace.
+#[unsafe(no_mangle)]
+pub extern "C" fn query_trace_get_request<'trace>(
+    trace_ptr: BridgedBorrowedSharedPtr<'trace, BridgedQueryTrace>,
+    out: &mut FFIStr<'trace>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let trace =
+            ArcFFI::as_ref(trace_ptr).expect("valid and non-null BridgedQueryTrace pointer");
+        write_optional_str(trace.request.as_ref(), out, out_is_null);
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the IP address of the coordinator, borrowed from the trace.
+#[unsafe(no_mangle)]
+pub extern "C" fn query_trace_get_coordinator<'trace>(
+    trace_ptr: BridgedBorrowedSharedPtr<'trace, BridgedQueryTrace>,
+    out: &mut FFIStr<'trace>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let trace =
+            ArcFFI::as_ref(trace_ptr).expect("valid and non-null BridgedQueryTrace pointer");
+        write_optional_str(trace.coordinator.as_ref(), out, out_is_null);
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the IP address of the client that sent the request, borrowed from the trace.
+#[unsafe(no_mangle)]
+pub extern "C" fn query_trace_get_client<'trace>(
+    trace_ptr: BridgedBorrowedSharedPtr<'trace, BridgedQueryTrace>,
+    out: &mut FFIStr<'trace>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let trace =
+            ArcFFI::as_ref(trace_ptr).expect("valid and non-null BridgedQueryTrace pointer");
+        write_optional_str(trace.client.as_ref(), out, out_is_null);
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the server-side duration of the request in microseconds.
+#[unsafe(no_mangle)]
+pub extern "C" fn query_trace_get_duration_micros(
+    trace_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryTrace>,
+    out: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let trace =
+            ArcFFI::as_ref(trace_ptr).expect("valid and non-null BridgedQueryTrace pointer");
+        write_optional(trace.duration_micros, out, out_is_null);
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the start of the request in milliseconds since the Unix epoch.
+#[unsafe(no_mangle)]
+pub extern "C" fn query_trace_get_started_at(
+    trace_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryTrace>,
+    out_epoch_millis: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let trace =
+            ArcFFI::as_ref(trace_ptr).expect("valid and non-null BridgedQueryTrace pointer");
+        write_optional(trace.started_at_millis, out_epoch_millis, out_is_null);
+        FFIMaybeException::ok()
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn query_trace_get_events_count(
+    trace_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryTrace>,
+    out_count: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let trace =
+            ArcFFI::as_ref(trace_ptr).expect("valid and non-null BridgedQueryTrace pointer");
+        *out_count = trace.events.len();
+        FFIMaybeException::ok()
+    })
+}
+
+/// Fields of a single trace event, as written by `query_trace_get_event`.
+/// Strings borrow from the trace. A field whose `has_` flag is false is missing
+/// from the trace, and is then empty or zero.
+#[repr(C)]
+pub struct FFITraceEvent<'trace> {
+    activity: FFIStr<'trace>,
+    has_activity: FFIBool,
+    source: FFIStr<'trace>,
+    has_source: FFIBool,
+    thread: FFIStr<'trace>,
+    has_thread: FFIBool,
+    source_elapsed_micros: i32,
+    has_source_elapsed_micros: FFIBool,
+    timestamp_millis: i64,
+    has_timestamp_millis: FFIBool,
+}
+
+/// Writes the trace event at `index`. Events are ordered by the time they occurred.
+///
+/// Fails if the index is out of range.
+#[unsafe(no_mangle)]
+pub extern "C" fn query_trace_get_event<'trace>(
+    trace_ptr: BridgedBorrowedSharedPtr<'trace, BridgedQueryTrace>,
+    index: usize,
+    out: &mut FFITraceEvent<'trace>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let trace =
+            ArcFFI::as_ref(trace_ptr).expect("valid and non-null BridgedQueryTrace pointer");
+        let Some(event) = trace.events.get(index) else {
+            return FFIMaybeException::from_error(
+                RowAccessError::ItemIndexOutOfRange {
+                    index,
+                    count: trace.events.len(),
+                },
+                constructors,
+            );
+        };
+
+        let (activity, has_activity) = optional_str(&event.activity);
+        let (source, has_source) = optional_str(&event.source);
+        let (thread, has_thread) = optional_str(&event.thread);
+        *out = FFITraceEvent {
+            activity,
+            has_activity,
+            source,
+            has_source,
+            thread,
+            has_thread,
+            source_elapsed_micros: event.source_elapsed_micros.unwrap_or_default(),
+            has_source_elapsed_micros: event.source_elapsed_micros.is_some().into(),
+            timestamp_millis: event.timestamp_millis.unwrap_or_default(),
+            has_timestamp_millis: event.timestamp_millis.is_some().into(),
+        };
+
+        FFIMaybeException::ok()
+    })
+}
@@ -0,0 +1,292 @@
+use std::sync::Arc;
+
+use scylla::errors::{DbError, RequestAttemptError};
+use scylla::policies::retry::{RequestInfo, RetryDecision, RetryPolicy, RetrySession};
+use scylla::statement::Consistency;
+
+use crate::ffi::{FFIBool, FFIMaybeGCHandle, FFIStr, GCHandlePtr, SharedGCHandle};
+
+/// Opaque C# representation of a custom retry policy.
+pub enum CustomRetryPolicy {}
+
+/// Kind of error a request attempt failed with, as passed to [`DecideRetry`].
+///
+/// C# implementations of the callback must use the same values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum RetryErrorKind {
+    ReadTimeout = 0,
+    WriteTimeout = 1,
+    Unavailable = 2,
+    Overloaded = 3,
+    ServerError = 4,
+    TruncateError = 5,
+    IsBootstrapping = 6,
+    ConnectionBroken = 7,
+    Other = 8,
+}
+
+impl RetryErrorKind {
+    fn of(error: &RequestAttemptError) -> Self {
+        match error {
+            RequestAttemptError::DbError(db_error, _) => match db_error {
+                DbError::ReadTimeout { .. } => Self::ReadTimeout,
+                DbError::WriteTimeout { .. } => Self::WriteTimeout,
+                DbError::Unavailable { .. } => Self::Unavailable,
+                DbError::Overloaded => Self::Overloaded,
+                DbError::ServerError => Self::ServerError,
+                DbError::TruncateError => Self::TruncateError,
+                DbError::IsBootstrapping => Self::IsBootstrapping,
+                _ => Self::Other,
+            },
+            RequestAttemptError::BrokenConnectionError(_) => Self::ConnectionBroken,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Description of a failed request attempt, passed to [`DecideRetry`].
+///
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct RetryDecisionInput<'a> {
+    error_kind: RetryErrorKind,
+
+    /// Consistency of the failed attempt, as reported by the coordinator for timeouts
+    /// and unavailable errors.
+    consistency: u16,
+
+    /// Number of replicas that responded (or were alive, for unavailable errors),
+    /// or -1 if the error does not carry it.
+    received: i32,
+
+    /// Number of replicas required by the consistency, or -1 if the error does not carry it.
+    required: i32,
+
+    /// Whether the replica asked for data responded, for read timeouts.
+    data_present: FFIBool,
+
+    /// Kind of the write that timed out, or null for other errors.
+    write_type: FFIStr<'a>,
+
+    /// Number of retries already made for the request.
+    retry_count: u32,
+    is_idempotent: FFIBool,
+}
+
+impl<'a> RetryDecisionInput<'a> {
+    fn new(request_info: &'a RequestInfo<'_>, retry_count: u32) -> Self {
+        let mut input = Self {
+            error_kind: RetryErrorKind::of(request_info.error),
+            consistency: request_info.consistency as u16,
+            received: -1,
+            required: -1,
+            data_present: false.into(),
+            write_type: FFIStr::null(),
+            retry_count,
+            is_idempotent: request_info.is_idempotent.into(),
+        };
+
+        if let RequestAttemptError::DbError(db_error, _) = request_info.error {
+            match db_error {
+                DbError::ReadTimeout {
+                    consistency,
+                    received,
+                    required,
+                    data_present,
+                } => {
+                    input.consistency = *consistency as u16;
+                    input.received = *received;
+                    input.required = *required;
+                    input.data_present = (*data_present).into();
+                }
+                DbError::WriteTimeout {
+                    consistency,
+                    received,
+                    required,
+                    write_type,
+                } => {
+                    input.consistency = *consistency as u16;
+                    input.received = *received;
+                    input.required = *required;
+                    input.write_type = FFIStr::new(write_type.as_str());
+                }
+                DbError::Unavailable {
+                    consistency,
+                    required,
+                    alive,
+                } => {
+                    input.consistency = *consistency as u16;
+                    input.received = *alive;
+                    input.required = *required;
+                }
+                _ => {}
+            }
+        }
+        input
+    }
+}
+
+/// Decision returned by [`DecideRetry`].
+///
+/// It is initialized to rethrow before the callback is called, so a callback that fails
+/// without writing it rethrows the error.
+///
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct BridgedRetryDecision {
+    /// One of [`RetryDecisionCode`]; unknown values are treated as a rethrow.
+    decision: u8,
+
+    /// Consistency to retry with, used only if `has_consistency` is set.
+    consistency: u16,
+    has_consistency: FFIBool,
+}
+
+impl BridgedRetryDecision {
+    fn rethrow() -> Self {
+        Self {
+            decision: RetryDecisionCode::Rethrow as u8,
+            consistency: 0,
+            has_consistency: false.into(),
+        }
+    }
+
+    /// Converts the decision, rethrowing if it holds an invalid consistency.
+    fn into_retry_decision(self) -> (RetryDecisionCode, Option<Consistency>) {
+        let code = RetryDecisionCode::from(self.decision);
+        if !bool::from(self.has_consistency) {
+            return (code, None);
+        }
+        match Consistency::try_from(self.consistency) {
+            Ok(consistency) => (code, Some(consistency)),
+            Err(_) => {
+                tracing::warn!(
+                    "Custom retry policy returned invalid consistency {}, rethrowing",
+                    self.consistency
+                );
+                (RetryDecisionCode::Rethrow, None)
+            }
+        }
+    }
+}
+
+/// Decision of a custom retry policy.
+///
+/// Passed over FFI as a raw `u8`; unknown values are treated as [`RetryDecisionCode::Rethrow`].
+/// C# implementations of [`DecideRetry`] must use the same values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum RetryDecisionCode {
+    RetrySameHost = 0,
+    RetryNextHost = 1,
+    Rethrow = 2,
+    Ignore = 3,
+}
+
+impl From<u8> for RetryDecisionCode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::RetrySameHost,
+            1 => Self::RetryNextHost,
+            2 => Self::Rethrow,
+            3 => Self::Ignore,
+            _ => {
+                tracing::warn!("Custom retry policy returned unknown decision {value}, rethrowing");
+                Self::Rethrow
+            }
+        }
+    }
+}
+
+/// Callback asking C# whether a failed request attempt should be retried,
+/// writing the decision to `decision`.
+///
+/// It is called on a Tokio worker thread, possibly concurrently for different requests,
+/// so it must be thread-safe and must not block: the worker cannot drive other requests
+/// until the callback returns. It must not throw either; a policy that fails is expected
+/// to leave the decision untouched, which rethrows the error.
+type DecideRetry = unsafe extern "C" fn(
+    policy: GCHandlePtr<'_, CustomRetryPolicy>,
+    input: &RetryDecisionInput<'_>,
+    decision: &mut BridgedRetryDecision,
+);
+
+/// Custom retry policy implemented in C#, passed as part of the session config.
+///
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct BridgedCustomRetryPolicy {
+    policy: FFIMaybeGCHandle<CustomRetryPolicy>,
+    decide_retry: Option<DecideRetry>,
+}
+
+impl BridgedCustomRetryPolicy {
+    /// Returns the policy calling into C#, or `None` if no custom policy was given.
+    pub(crate) fn into_policy(self) -> Option<Arc<dyn RetryPolicy>> {
+        let decide_retry = self.decide_retry?;
+        let policy = self.policy.try_into_ffi_gc_handle()?;
+
+        Some(Arc::new(CallbackRetryPolicy {
+            policy: Arc::new(SharedGCHandle::new(policy)),
+            decide_retry,
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct CallbackRetryPolicy {
+    /// The C# policy object, freed once the last retry session using it is dropped.
+    policy: Arc<SharedGCHandle<CustomRetryPolicy>>,
+    decide_retry: DecideRetry,
+}
+
+impl RetryPolicy for CallbackRetryPolicy {
+    fn new_session(&self) -> Box<dyn RetrySession> {
+        Box::new(CallbackRetrySession {
+            policy: Arc::clone(&self.policy),
+            decide_retry: self.decide_retry,
+            retry_count: 0,
+        })
+    }
+}
+
+struct CallbackRetrySession {
+    policy: Arc<SharedGCHandle<CustomRetryPolicy>>,
+    decide_retry: DecideRetry,
+    retry_count: u32,
+}
+
+impl RetrySession for CallbackRetrySession {
+    fn decide_should_retry(&mut self, request_info: RequestInfo) -> RetryDecision {
+        let input = RetryDecisionInput::new(&request_info, self.retry_count);
+        let mut decision = BridgedRetryDecision::rethrow();
+        // The callback cannot unwind into Rust: C# catches exceptions thrown by the policy
+        // and leaves the rethrow decision in place.
+        unsafe {
+            // SAFETY: the callback and the policy handle are provided by C#,
+            // and the handle is kept alive by `self.policy`.
+            (self.decide_retry)(self.policy.borrow(), &input, &mut decision)
+        };
+
+        match decision.into_retry_decision() {
+            (RetryDecisionCode::RetrySameHost, consistency) => {
+                self.retry_count += 1;
+                RetryDecision::RetrySameTarget(consistency)
+            }
+            (RetryDecisionCode::RetryNextHost, consistency) => {
+                self.retry_count += 1;
+                RetryDecision::RetryNextTarget(consistency)
+            }
+            (RetryDecisionCode::Rethrow, _) => RetryDecision::DontRetry,
+            (RetryDecisionCode::Ignore, _) => RetryDecision::IgnoreWriteError,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.retry_count = 0;
+    }
+}
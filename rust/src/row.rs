@@ -0,0 +1,706 @@
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use bytes::Bytes;
+use scylla::deserialize::FrameSlice;
+use scylla::deserialize::row::ColumnIterator;
+use scylla::deserialize::value::DeserializeValue;
+use scylla::errors::DeserializationError;
+use scylla::frame::response::result::{ColumnSpec, ColumnType};
+use scylla::value::{
+    Counter, CqlDate, CqlDecimalBorrowed, CqlDuration, CqlTime, CqlTimestamp, CqlTimeuuid,
+    CqlVarintBorrowed,
+};
+use uuid::Uuid;
+
+use crate::error_conversion::{FFIMaybeException, RowAccessError};
+use crate::ffi::{
+    ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIBool, FFISlice, FFIStr,
+    FromArc,
+};
+use crate::row_set::column_type_to_code;
+use crate::task::ExceptionConstructors;
+
+/// Name, table and type of a single result column.
+///
+/// Owned, so that rows can outlive the response frame they were read from.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ResultColumn {
+    pub(crate) name: String,
+    pub(crate) keyspace: String,
+    pub(crate) table: String,
+    pub(crate) typ: ColumnType<'static>,
+}
+
+impl ResultColumn {
+    fn from_spec(spec: &ColumnSpec<'_>) -> Self {
+        Self {
+            name: spec.name().to_owned(),
+            keyspace: spec.table_spec().ks_name().to_owned(),
+            table: spec.table_spec().table_name().to_owned(),
+            typ: spec.typ().clone().into_owned(),
+        }
+    }
+
+    /// Builds the column list shared by all rows of a result.
+    pub(crate) fn from_specs<'a, 'b: 'a>(
+        specs: impl IntoIterator<Item = &'a ColumnSpec<'b>>,
+    ) -> Arc<[ResultColumn]> {
+        specs.into_iter().map(Self::from_spec).collect()
+    }
+}
+
+/// A single row of a query result.
+///
+/// Cells are kept in their serialized form as slices of the response frame, which
+/// stays alive (shared between all rows read from it) for as long as any of them does.
+/// A cell is only deserialized when C# asks for it, so columns that are never read
+/// cost nothing but their slice. Values that take some decoding are cached once
+/// deserialized (see [`BridgedRow::get_cached`]), so reading a cell again does not
+/// deserialize it again.
+#[derive(Debug)]
+pub(crate) struct BridgedRow {
+    columns: Arc<[ResultColumn]>,
+    cells: Box<[Option<Bytes>]>,
+    /// Deserialized cells with their column index, holding only the columns actually
+    /// read through the cache. C# usually reads a few columns of a row, so a short list
+    /// searched linearly costs less than a slot for every column.
+    cache: Mutex<Vec<(usize, CachedValue)>>,
+}
+
+/// A deserialized cell kept in the cache of a [`BridgedRow`].
+///
+/// Numeric, boolean and temporal scalars are not cached, as deserializing them only reads
+/// a few fixed bytes, which is no more work than a cache lookup. Neither are borrowed
+/// values (`text`, `blob`, `decimal`, `varint`), as reading them again only validates and
+/// slices the serialized cell.
+#[derive(Debug, Clone)]
+pub(crate) enum CachedValue {
+    Uuid(Uuid),
+    Timeuuid(CqlTimeuuid),
+    Inet(IpAddr),
+    Duration(CqlDuration),
+}
+
+/// Types that [`BridgedRow::get_cached`] can keep in the cache of a row.
+pub(crate) trait Cacheable: Clone + for<'a> DeserializeValue<'a, 'a> {
+    fn into_cached(self) -> CachedValue;
+
+    /// Returns the cached value if it was cached as `Self`.
+    fn from_cached(cached: &CachedValue) -> Option<Self>;
+}
+
+macro_rules! impl_cacheable {
+    ($($typ:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl Cacheable for $typ {
+                fn into_cached(self) -> CachedValue {
+                    CachedValue::$variant(self)
+                }
+
+                fn from_cached(cached: &CachedValue) -> Option<Self> {
+                    match cached {
+                        CachedValue::$variant(value) => Some(value.clone()),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_cacheable!(
+    Uuid => Uuid,
+    CqlTimeuuid => Timeuuid,
+    IpAddr => Inet,
+    CqlDuration => Duration,
+);
+
+impl FFI for BridgedRow {
+    type Origin = FromArc;
+}
+
+impl BridgedRow {
+    /// Reads all cells of a row out of `column_iterator`.
+    ///
+    /// Metadata may (rarely) change between pages, so on the first row of a page
+    /// `columns` is replaced if the page describes its columns differently.
+    pub(crate) fn from_column_iterator(
+        columns: &mut Arc<[ResultColumn]>,
+        column_iterator: ColumnIterator<'_, '_>,
+        new_page_began: bool,
+    ) -> Result<Self, DeserializationError> {
+        let mut page_columns = Vec::new();
+        let mut cells = Vec::with_capacity(columns.len());
+
+        for column in column_iterator {
+            let raw_column = column?;
+            if new_page_began {
+                page_columns.push(ResultColumn::from_spec(raw_column.spec));
+            }
+            cells.push(raw_column.slice.map(|slice| slice.to_bytes()));
+        }
+
+        if new_page_began && page_columns.as_slice() != &columns[..] {
+            *columns = page_columns.into();
+        }
+
+        Ok(Self {
+            columns: Arc::clone(columns),
+            cells: cells.into_boxed_slice(),
+            cache: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Size of the serialized cells of this row in bytes, not counting null cells.
+    pub(crate) fn byte_size(&self) -> usize {
+        self.cells.iter().flatten().map(Bytes::len).sum()
+    }
+
+    fn column(&self, index: usize) -> Result<&ResultColumn, RowAccessError> {
+        self.columns
+            .get(index)
+            .ok_or(RowAccessError::ColumnIndexOutOfRange {
+                index,
+                count: self.columns.len(),
+            })
+    }
+
+    /// Returns the column at `index` together with its serialized cell (`None` if null).
+    pub(crate) fn cell(
+        &self,
+        index: usize,
+    ) -> Result<(&ResultColumn, Option<&Bytes>), RowAccessError> {
+        let column = self.column(index)?;
+        Ok((column, self.cells[index].as_ref()))
+    }
+
+    /// Deserializes the cell at `index` as `T`, or returns `None` if the cell is null.
+    ///
+    /// The column type from the result metadata is checked against `T` first,
+    /// so a mismatch is reported even for null cells.
+    pub(crate) fn get<'row, T>(&'row self, index: usize) -> Result<Option<T>, RowAccessError>
+    where
+        T: DeserializeValue<'row, 'row>,
+    {
+        let (column, cell) = self.cell(index)?;
+        deserialize_cell(&column.name, &column.typ, cell)
+    }
+
+    /// Like [`BridgedRow::get`], but reuses the value if the cell has already been read as `T`.
+    ///
+    /// Only non-null values are cached. A cell read as two types (e.g. a `timeuuid` read
+    /// both as `Uuid` and as `CqlTimeuuid`) is cached once for each of them.
+    pub(crate) fn get_cached<T: Cacheable>(
+        &self,
+        index: usize,
+    ) -> Result<Option<T>, RowAccessError> {
+        let (column, cell) = self.cell(index)?;
+        // The cache holds plain values, so one left behind by a panicking reader is still valid.
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // A value cached as `T` has already passed the type check against this column.
+        if let Some(value) = cache
+            .iter()
+            .filter(|(cached_index, _)| *cached_index == index)
+            .find_map(|(_, cached)| T::from_cached(cached))
+        {
+            return Ok(Some(value));
+        }
+
+        let value = deserialize_cell::<T>(&column.name, &column.typ, cell)?;
+        if let Some(value) = &value {
+            cache.push((index, value.clone().into_cached()));
+        }
+        Ok(value)
+    }
+}
+
+/// Deserializes a serialized value of type `typ` as `T`, or returns `None` if it is null.
+/// `name` identifies the value (column, collection item) in type mismatch errors.
+pub(crate) fn deserialize_cell<'a, T>(
+    name: &str,
+    typ: &'a ColumnType<'a>,
+    cell: Option<&'a Bytes>,
+) -> Result<Option<T>, RowAccessError>
+where
+    T: DeserializeValue<'a, 'a>,
+{
+    T::type_check(typ).map_err(|source| RowAccessError::TypeMismatch {
+        name: name.to_owned(),
+        source,
+    })?;
+
+    let Some(cell) = cell else {
+        return Ok(None);
+    };
+
+    T::deserialize(typ, Some(FrameSlice::new(cell)))
+        .map(Some)
+        .map_err(RowAccessError::Deserialization)
+}
+
+/// Writes the outcome of [`BridgedRow::get`] to the out parameters of a typed getter.
+/// For a null cell only `out_is_null` is set, and `out` is left unchanged.
+pub(crate) fn write_cell<T>(
+    cell: Result<Option<T>, RowAccessError>,
+    out: &mut T,
+    out_is_null: &mut FFIBool,
+    constructors: &ExceptionConstructors,
+) -> FFIMaybeException {
+    match cell {
+        Ok(Some(value)) => {
+            *out = value;
+            *out_is_null = false.into();
+            FFIMaybeException::ok()
+        }
+        Ok(None) => {
+            *out_is_null = true.into();
+            FFIMaybeException::ok()
+        }
+        Err(e) => FFIMaybeException::from_error(e, constructors),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn row_free(row_ptr: BridgedOwnedSharedPtr<BridgedRow>) {
+    ArcFFI::free(row_ptr);
+    tracing::trace!("[FFI] BridgedRow freed");
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_columns_count(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    out_count: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        *out_count = row.cells.len();
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the name and type code of the column at `col_index`.
+/// The name borrows from the row and is valid as long as the row is.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_column_info<'row>(
+    row_ptr: BridgedBorrowedSharedPtr<'row, BridgedRow>,
+    col_index: usize,
+    out_name: &mut FFIStr<'row>,
+    out_type_code: &mut u8,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        match row.column(col_index) {
+            Ok(column) => {
+                *out_name = FFIStr::new(&column.name);
+                *out_type_code = column_type_to_code(&column.typ);
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Reads the CQL `int` cell at `col_index`.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_int32(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        write_cell(row.get::<i32>(col_index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `bigint` cell at `col_index`.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_int64(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        write_cell(row.get::<i64>(col_index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `counter` cell at `col_index`.
+///
+/// Fails if the index is out of range or the column is not a counter, even a `bigint` one.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_counter(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row.get::<Counter>(col_index).map(|v| v.map(|c| c.0));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `smallint` cell at `col_index`.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_smallint(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut i16,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        write_cell(row.get::<i16>(col_index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `tinyint` cell at `col_index`.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_tinyint(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut i8,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        write_cell(row.get::<i8>(col_index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `boolean` cell at `col_index`.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_boolean(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut FFIBool,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row.get::<bool>(col_index).map(|v| v.map(FFIBool::from));
+        write_cell(cell, out, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `float` cell at `col_index`. NaN and infinities are passed through unchanged.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_float(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut f32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        write_cell(row.get::<f32>(col_index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `double` cell at `col_index`. NaN and infinities are passed through unchanged.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_double(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out: &mut f64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        write_cell(row.get::<f64>(col_index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `uuid` or `timeuuid` cell at `col_index` as 16 bytes in RFC 4122
+/// (big-endian) order, the same order as Java's `UUID`. Note that `System.Guid`
+/// stores its first three groups little-endian, so C# must reorder them.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_uuid(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out_bytes: &mut [u8; 16],
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row
+            .get_cached::<Uuid>(col_index)
+            .map(|v| v.map(Uuid::into_bytes));
+        write_cell(cell, out_bytes, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `timeuuid` cell at `col_index` as 16 bytes in RFC 4122 (big-endian) order.
+///
+/// Unlike `row_get_col_as_uuid`, fails for a `uuid` column, so that C# can tell the two apart
+/// where the type matters, e.g. to build a `TimeUuid`.
+/// Also fails if the index is out of range.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_timeuuid(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out_bytes: &mut [u8; 16],
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row
+            .get_cached::<CqlTimeuuid>(col_index)
+            .map(|v| v.map(|timeuuid| Uuid::from(timeuuid).into_bytes()));
+        write_cell(cell, out_bytes, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `blob` cell at `col_index`.
+///
+/// The slice borrows from the row without copying, and stays valid until the row is freed.
+/// A borrowed slice is preferred over copying into a C#-supplied buffer, because C# copies
+/// the bytes into a `byte[]` anyway, and a second copy would be paid on every read.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_blob<'row>(
+    row_ptr: BridgedBorrowedSharedPtr<'row, BridgedRow>,
+    col_index: usize,
+    out_slice: &mut FFISlice<'row, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row.get::<&[u8]>(col_index).map(|v| v.map(FFISlice::new));
+        write_cell(cell, out_slice, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `text` (`varchar`) or `ascii` cell at `col_index`.
+///
+/// The string is valid UTF-8, as the value is validated when deserialized.
+/// It borrows from the row without copying, and stays valid until the row is freed.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_string<'row>(
+    row_ptr: BridgedBorrowedSharedPtr<'row, BridgedRow>,
+    col_index: usize,
+    out_str: &mut FFIStr<'row>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row.get::<&str>(col_index).map(|v| v.map(FFIStr::new));
+        write_cell(cell, out_str, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `inet` cell at `col_index`.
+///
+/// IPv4 addresses are written to `out_v4` and IPv6 addresses to `out_v6`, in network byte order;
+/// `out_is_v6` tells which one was written. The other buffer is left unchanged.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_inet(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out_v4: &mut [u8; 4],
+    out_v6: &mut [u8; 16],
+    out_is_v6: &mut FFIBool,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        match row.get_cached::<IpAddr>(col_index) {
+            Ok(Some(address)) => {
+                match address {
+                    IpAddr::V4(v4) => *out_v4 = v4.octets(),
+                    IpAddr::V6(v6) => *out_v6 = v6.octets(),
+                }
+                *out_is_v6 = address.is_ipv6().into();
+                *out_is_null = false.into();
+                FFIMaybeException::ok()
+            }
+            Ok(None) => {
+                *out_is_null = true.into();
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Reads the CQL `timestamp` cell at `col_index` as milliseconds since the Unix epoch,
+/// the same unit as `DateTimeOffset.ToUnixTimeMilliseconds()`.
+///
+/// Timestamps before 1970 are negative.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_timestamp(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out_epoch_millis: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row.get::<CqlTimestamp>(col_index).map(|v| v.map(|ts| ts.0));
+        write_cell(cell, out_epoch_millis, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `date` cell at `col_index` as days since the Unix epoch (1970-01-01).
+///
+/// CQL stores dates as an unsigned number of days centered on the epoch (2^31 is 1970-01-01),
+/// which is converted here to a signed offset, so dates before 1970 are negative.
+/// The whole `i32` range is valid, about 5.8 million years around the epoch.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_date(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out_days_since_epoch: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row
+            .get::<CqlDate>(col_index)
+            .map(|v| v.map(|date| date.0.wrapping_sub(1 << 31) as i32));
+        write_cell(cell, out_days_since_epoch, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `time` cell at `col_index` as nanoseconds since midnight.
+///
+/// Valid values are in the range `0..86_400_000_000_000`, which the server enforces.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_time(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out_nanos: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row.get::<CqlTime>(col_index).map(|v| v.map(|time| time.0));
+        write_cell(cell, out_nanos, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `decimal` cell at `col_index` as `unscaled * 10^(-scale)`.
+///
+/// The unscaled value is written as big-endian two's-complement bytes, as sent by the server
+/// and as expected by `new BigInteger(bytes, isUnsigned: false, isBigEndian: true)` in C#.
+/// The slice borrows from the row without copying, and stays valid until the row is freed.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_decimal<'row>(
+    row_ptr: BridgedBorrowedSharedPtr<'row, BridgedRow>,
+    col_index: usize,
+    out_scale: &mut i32,
+    out_unscaled_bytes: &mut FFISlice<'row, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row.get::<CqlDecimalBorrowed>(col_index).map(|v| {
+            v.map(|decimal| {
+                let (unscaled, scale) = decimal.as_signed_be_bytes_slice_and_exponent();
+                *out_scale = scale;
+                FFISlice::new(unscaled)
+            })
+        });
+        write_cell(cell, out_unscaled_bytes, out_is_null, constructors)
+    })
+}
+
+/// Reads the CQL `varint` cell at `col_index` as big-endian two's-complement bytes,
+/// the same representation as the unscaled value of [`row_get_col_as_decimal`].
+///
+/// The slice borrows from the row without copying, and stays valid until the row is freed.
+///
+/// Fails if the index is out of range or the column is of a different type.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_as_varint<'row>(
+    row_ptr: BridgedBorrowedSharedPtr<'row, BridgedRow>,
+    col_index: usize,
+    out_bytes: &mut FFISlice<'row, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        let cell = row
+            .get::<CqlVarintBorrowed>(col_index)
+            .map(|v| v.map(|varint| FFISlice::new(varint.as_signed_bytes_be_slice())));
+        write_cell(cell, out_bytes, out_is_null, constructors)
+    })
+}
+
+/// Checks whether the cell at `col_index` is null, without deserializing it.
+///
+/// Fails if the index is out of range.
+#[unsafe(no_mangle)]
+pub extern "C" fn row_get_col_is_null(
+    row_ptr: BridgedBorrowedSharedPtr<'_, BridgedRow>,
+    col_index: usize,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row = ArcFFI::as_ref(row_ptr).expect("valid and non-null BridgedRow pointer");
+        match row.cell(col_index) {
+            Ok((_, cell)) => {
+                *out_is_null = cell.is_none().into();
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
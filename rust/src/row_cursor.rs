@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use scylla::client::pager::QueryPager;
+use scylla::errors::NextRowError;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TryRecvError;
+use tokio::task::JoinHandle;
+
+use crate::error_conversion::{
+    ErrorToException as _, FFIException, FFIMaybeException, RowCursorError,
+};
+use crate::ffi::{
+    ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIGCHandle, FromArc, GCHandlePtr,
+};
+use crate::row::{BridgedRow, ResultColumn};
+use crate::task::{BridgedFuture, ManuallyDestructible, Tcb};
+
+/// Number of rows buffered ahead of C# if the page size is not known.
+const DEFAULT_PREFETCH_ROWS: usize = 5000;
+
+type CursorItem = Result<Arc<BridgedRow>, NextRowError>;
+
+/// Streams the rows of a paged query to C# in batches.
+///
+/// A background task drives the [`QueryPager`] and pushes rows into a bounded channel
+/// (holding up to one page), so fetching the next page overlaps with C# consuming the
+/// current one. Dropping the cursor aborts that task, which drops the pager together
+/// with its connection stream.
+#[derive(Debug)]
+pub(crate) struct BridgedRowCursor {
+    rows: tokio::sync::Mutex<CursorRows>,
+}
+
+#[derive(Debug)]
+struct CursorRows {
+    receiver: mpsc::Receiver<CursorItem>,
+    /// Taken once the channel is drained, to tell a finished producer from a failed one.
+    producer: Option<JoinHandle<()>>,
+}
+
+impl CursorRows {
+    /// Called once the channel is closed and drained. A producer that panicked or was
+    /// cancelled closes the channel just like one that reached the end of the stream,
+    /// so its outcome decides whether the stream really ended.
+    async fn producer_finished(&mut self) -> Result<(), RowCursorError> {
+        match self.producer.take() {
+            Some(producer) => producer.await.map_err(RowCursorError::from),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FFI for BridgedRowCursor {
+    type Origin = FromArc;
+}
+
+impl BridgedRowCursor {
+    /// Starts streaming rows from `pager`. Must be called from within the Tokio runtime.
+    pub(crate) fn spawn(pager: QueryPager, page_size: i32) -> Self {
+        let prefetch_rows = usize::try_from(page_size)
+            .ok()
+            .filter(|&rows| rows > 0)
+            .unwrap_or(DEFAULT_PREFETCH_ROWS);
+
+        let (sender, receiver) = mpsc::channel(prefetch_rows);
+        let producer = tokio::spawn(produce_rows(pager, sender));
+
+        Self {
+            rows: tokio::sync::Mutex::new(CursorRows {
+                receiver,
+                producer: Some(producer),
+            }),
+        }
+    }
+}
+
+impl Drop for BridgedRowCursor {
+    fn drop(&mut self) {
+        if let Some(producer) = &self.rows.get_mut().producer {
+            producer.abort();
+        }
+    }
+}
+
+async fn produce_rows(mut pager: QueryPager, sender: mpsc::Sender<CursorItem>) {
+    let mut columns = ResultColumn::from_specs(pager.column_specs().iter());
+
+    while let Some(next) = pager.next_column_iterator().await {
+        let item = next.and_then(|(column_iterator, new_page_began)| {
+            BridgedRow::from_column_iterator(&mut columns, column_iterator, new_page_began)
+                .map(Arc::new)
+                .map_err(NextRowError::RowDeserializationError)
+        });
+        let failed = item.is_err();
+
+        // Sending fails only if the cursor has been dropped, so nobody needs more rows.
+        if sender.send(item).await.is_err() || failed {
+            break;
+        }
+    }
+
+    tracing::trace!("[FFI] Row cursor producer finished");
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn cursor_free(cursor_ptr: BridgedOwnedSharedPtr<BridgedRowCursor>) {
+    ArcFFI::free(cursor_ptr);
+    tracing::trace!("[FFI] BridgedRowCursor freed");
+}
+
+/// Opaque C# representation of the batch filled by [`cursor_next_rows`].
+pub enum RowBatch {}
+
+/// Callback handing a single row over to C#.
+/// C# becomes the owner of the row and must release it with the destructor passed along.
+type ReceiveRow = unsafe extern "C" fn(
+    batch_ptr: GCHandlePtr<'_, RowBatch>,
+    row: ManuallyDestructible,
+) -> FFIMaybeException;
+
+/// Asynchronously passes up to `max_rows` next rows of the cursor to `receive_row`.
+///
+/// Waits until at least one row is available, then takes only rows that are already
+/// buffered, so a batch never waits for the network more than once.
+/// The task completes with `true` if more rows may follow, or with `false` once the
+/// stream has ended. Note that the final batch may still contain rows (or be empty).
+/// If the background task fetching the rows panicked or was cancelled, the task fails
+/// instead of reporting the end of the stream.
+#[unsafe(no_mangle)]
+pub extern "C" fn cursor_next_rows(
+    tcb: Tcb<bool>,
+    cursor_ptr: BridgedBorrowedSharedPtr<'_, BridgedRowCursor>,
+    max_rows: usize,
+    receive_row: ReceiveRow,
+    batch_handle: FFIGCHandle<RowBatch>,
+) {
+    let cursor =
+        ArcFFI::cloned_from_ptr(cursor_ptr).expect("valid and non-null BridgedRowCursor pointer");
+    let constructors = tcb.constructors;
+
+    BridgedFuture::spawn::<_, _, FFIException, _>(tcb, async move {
+        let mut rows = cursor.rows.lock().await;
+
+        for delivered in 0..max_rows {
+            let next = if delivered == 0 {
+                rows.receiver.recv().await
+            } else {
+                match rows.receiver.try_recv() {
+                    Ok(item) => Some(item),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => None,
+                }
+            };
+
+            // The producer has finished and all of its rows have been consumed.
+            let Some(item) = next else {
+                rows.producer_finished()
+                    .await
+                    .map_err(|e| e.to_exception(constructors))?;
+                return Ok(false);
+            };
+
+            let row = item.map_err(|e| e.to_exception(constructors))?;
+            let ffi_exception = unsafe {
+                receive_row(
+                    batch_handle.borrow(),
+                    ManuallyDestructible::from_destructible(row),
+                )
+            };
+            if let Some(e) = ffi_exception.try_into_ffi_exception() {
+                return Err(e);
+            }
+        }
+
+        Ok(true)
+    });
+}
+
+/// Asynchronously takes the next row of the cursor, for consumers processing rows one by one.
+///
+/// The task completes with the row, which C# must release with `row_free` after reading it
+/// with the `row_get_col_*` accessors, or with a null pointer once the stream has ended.
+/// As with [`cursor_next_rows`], a producer that panicked or was cancelled fails the task.
+/// [`cursor_next_rows`] should be preferred for bulk processing, as it crosses the FFI
+/// boundary once per batch rather than once per row.
+#[unsafe(no_mangle)]
+pub extern "C" fn cursor_next_row(
+    tcb: Tcb<ManuallyDestructible>,
+    cursor_ptr: BridgedBorrowedSharedPtr<'_, BridgedRowCursor>,
+) {
+    let cursor =
+        ArcFFI::cloned_from_ptr(cursor_ptr).expect("valid and non-null BridgedRowCursor pointer");
+
+    BridgedFuture::spawn::<_, _, RowCursorError, _>(tcb, async move {
+        let mut rows = cursor.rows.lock().await;
+        match rows.receiver.recv().await {
+            Some(item) => Ok(Some(item?)),
+            None => {
+                rows.producer_finished().await?;
+                Ok(None)
+            }
+        }
+    });
+}
@@ -38,18 +38,19 @@ impl FFI for ColumnType<'_> {
 #[unsafe(no_mangle)]
 pub extern "C" fn row_set_get_columns_count(
     row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
-    out_num_fields: *mut usize,
+    out_num_fields: &mut usize,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
-    let pager = row_set.pager.blocking_lock();
-    unsafe {
+    FFIMaybeException::catch_panics(constructors, || {
+        let row_set = ArcFFI::as_ref(row_set_ptr).expect("valid and non-null RowSet pointer");
+        let pager = row_set.pager.blocking_lock();
         *out_num_fields = pager.column_specs().len();
-    }
-    FFIMaybeException::ok()
+        FFIMaybeException::ok()
+    })
 }
 
 // Function pointer type for setting column metadata in C#.
-type SetMetadata = unsafe extern "C" fn(
+pub(crate) type SetMetadata = unsafe extern "C" fn(
     columns_ptr: FFINonNullPtr<'_, Columns>,
     value_index: usize,
     name: FFIStr<'_>,
@@ -70,49 +71,71 @@ pub extern "C" fn row_set_fill_columns_metadata(
     row_set_ptr: BridgedBorrowedSharedPtr<'_, RowSet>,
     columns_ptr: FFINonNullPtr<'_, Columns>,
     set_metadata: SetMetadata,
+    constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
-    let pager = row_set.pager.blocking_lock();
-
-    // Iterate column specs and call the metadata setter
-    for (i, spec) in pager.column_specs().iter().enumerate() {
-        let name = FFIStr::new(spec.name());
-        let keyspace = FFIStr::new(spec.table_spec().ks_name());
-        let table = FFIStr::new(spec.table_spec().table_name());
-
-        let type_code = column_type_to_code(spec.typ());
-
-        let type_info_handle: BridgedBorrowedSharedPtr<ColumnType> = if type_code >= 0x20 {
-            RefFFI::as_ptr(spec.typ())
-        } else {
-            RefFFI::null()
-        };
-
-        let is_frozen = match spec.typ() {
-            ColumnType::Collection { frozen, .. } | ColumnType::UserDefinedType { frozen, .. } => {
-                *frozen
-            }
-            _ => false,
-        };
-
-        unsafe {
-            let ffi_exception = set_metadata(
+    FFIMaybeException::catch_panics(constructors, || {
+        let row_set = ArcFFI::as_ref(row_set_ptr).expect("valid and non-null RowSet pointer");
+        let pager = row_set.pager.blocking_lock();
+
+        // Iterate column specs and call the metadata setter
+        for (i, spec) in pager.column_specs().iter().enumerate() {
+            let ffi_exception = set_column_metadata(
+                set_metadata,
                 columns_ptr,
                 i,
-                name,
-                keyspace,
-                table,
-                type_code,
-                type_info_handle,
-                is_frozen as u8,
+                spec.name(),
+                spec.table_spec().ks_name(),
+                spec.table_spec().table_name(),
+                spec.typ(),
             );
             // If there is an exception returned from callback, throw it as soon as possible
             if ffi_exception.has_exception() {
                 return ffi_exception;
             }
         }
+        FFIMaybeException::ok()
+    })
+}
+
+/// Passes the metadata of the column at `index` to C# through `set_metadata`.
+/// Collection, UDT, tuple and vector types also get a handle to their type, which stays
+/// valid for as long as `typ` does.
+pub(crate) fn set_column_metadata(
+    set_metadata: SetMetadata,
+    columns_ptr: FFINonNullPtr<'_, Columns>,
+    index: usize,
+    name: &str,
+    keyspace: &str,
+    table: &str,
+    typ: &ColumnType<'_>,
+) -> FFIMaybeException {
+    let type_code = column_type_to_code(typ);
+
+    let type_info_handle: BridgedBorrowedSharedPtr<ColumnType> = if type_code >= 0x20 {
+        RefFFI::as_ptr(typ)
+    } else {
+        RefFFI::null()
+    };
+
+    let is_frozen = match typ {
+        ColumnType::Collection { frozen, .. } | ColumnType::UserDefinedType { frozen, .. } => {
+            *frozen
+        }
+        _ => false,
+    };
+
+    unsafe {
+        set_metadata(
+            columns_ptr,
+            index,
+            FFIStr::new(name),
+            FFIStr::new(keyspace),
+            FFIStr::new(table),
+            type_code,
+            type_info_handle,
+            is_frozen as u8,
+        )
     }
-    FFIMaybeException::ok()
 }
 
 /// Opaque C# representation of column metadata array.
@@ -250,49 +273,51 @@ pub extern "C" fn row_set_try_next_row_sync(
     constructors: &'static ExceptionConstructors,
     out_result: &mut SyncNextRowResult,
 ) -> FFIMaybeException {
-    let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
+    FFIMaybeException::catch_panics(constructors, || {
+        let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
 
-    let Ok(mut pager) = row_set.pager.try_lock() else {
-        *out_result = SyncNextRowResult::NeedAsync;
-        return FFIMaybeException::ok();
-    };
+        let Ok(mut pager) = row_set.pager.try_lock() else {
+            *out_result = SyncNextRowResult::NeedAsync;
+            return FFIMaybeException::ok();
+        };
 
-    let num_columns = pager.column_specs().len();
-    let mut fut = std::pin::pin!(pager.next_column_iterator());
-    let noop_waker = futures::task::noop_waker();
-    let mut cx = std::task::Context::from_waker(&noop_waker);
+        let num_columns = pager.column_specs().len();
+        let mut fut = std::pin::pin!(pager.next_column_iterator());
+        let noop_waker = futures::task::noop_waker();
+        let mut cx = std::task::Context::from_waker(&noop_waker);
 
-    let Poll::Ready(next) = fut.as_mut().poll(&mut cx) else {
-        *out_result = SyncNextRowResult::NeedAsync;
-        return FFIMaybeException::ok();
-    };
+        let Poll::Ready(next) = fut.as_mut().poll(&mut cx) else {
+            *out_result = SyncNextRowResult::NeedAsync;
+            return FFIMaybeException::ok();
+        };
 
-    let result = deserialize_next_row(
-        next,
-        num_columns,
-        |value_index, slice| unsafe {
-            deserialize_value(
-                columns_ptr,
-                values_ptr,
-                value_index,
-                serializer_ptr,
-                FFISlice::new(slice.as_slice()),
-            )
-        },
-        constructors,
-    );
-
-    match result {
-        Ok(got_row) => {
-            *out_result = if got_row {
-                SyncNextRowResult::GotRow
-            } else {
-                SyncNextRowResult::Exhausted
-            };
-            FFIMaybeException::ok()
+        let result = deserialize_next_row(
+            next,
+            num_columns,
+            |value_index, slice| unsafe {
+                deserialize_value(
+                    columns_ptr,
+                    values_ptr,
+                    value_index,
+                    serializer_ptr,
+                    FFISlice::new(slice.as_slice()),
+                )
+            },
+            constructors,
+        );
+
+        match result {
+            Ok(got_row) => {
+                *out_result = if got_row {
+                    SyncNextRowResult::GotRow
+                } else {
+                    SyncNextRowResult::Exhausted
+                };
+                FFIMaybeException::ok()
+            }
+            Err(exception) => FFIMaybeException::from_exception(exception),
         }
-        Err(exception) => FFIMaybeException::from_exception(exception),
-    }
+    })
 }
 
 /// Async path: spawns a tokio task to read and deserialize the next row.
@@ -590,23 +615,25 @@ pub extern "C" fn row_set_fill_coordinator(
     ip_endpoint_ptr: FFINonNullPtr<'_, IpEndPoint>,
     set_coordinator: SetCoordinator,
 ) -> FFIMaybeException {
-    let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
-    let pager = row_set.pager.blocking_lock();
+    FFIMaybeException::catch_panics(constructors, || {
+        let row_set = ArcFFI::as_ref(row_set_ptr).unwrap();
+        let pager = row_set.pager.blocking_lock();
 
-    let Some(coordinator) = pager.request_coordinators().next() else {
-        return FFIMaybeException::ok();
-    };
+        let Some(coordinator) = pager.request_coordinators().next() else {
+            return FFIMaybeException::ok();
+        };
 
-    let addr = coordinator.connection_address();
-    let octets = IpOctets::new(addr.ip());
+        let addr = coordinator.connection_address();
+        let octets = IpOctets::new(addr.ip());
 
-    unsafe {
-        set_coordinator(
-            ip_endpoint_ptr,
-            FFISlice::new(octets.as_slice()),
-            addr.port(),
-        )
-    }
+        unsafe {
+            set_coordinator(
+                ip_endpoint_ptr,
+                FFISlice::new(octets.as_slice()),
+                addr.port(),
+            )
+        }
+    })
 }
 
 pub(crate) fn column_type_to_code(typ: &ColumnType) -> u8 {
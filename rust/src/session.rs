@@ -1,26 +1,40 @@
 use std::convert::Infallible;
 use std::sync::Arc;
 use std::sync::RwLock as StdRwLock;
+use std::time::Duration;
 
+use scylla::client::PoolSize;
+use scylla::client::execution_profile::ExecutionProfileHandle;
 use scylla::client::session::Session;
-use scylla::cluster::ClusterState;
+use scylla::cluster::{ClusterState, Node};
 use scylla::errors::SchemaAgreementError;
-use scylla::errors::{NewSessionError, PagerExecutionError, PrepareError};
+use scylla::errors::{PagerExecutionError, PrepareError, TracingError, UseKeyspaceError};
+use scylla::frame::response::result::{ColumnType, NativeType};
+use scylla::observability::history::HistoryCollector;
+use scylla::response::PagingState;
+use scylla::statement::Consistency;
 use scylla::statement::Statement;
+use scylla::statement::prepared::PreparedStatement;
 use scylla_cql_core::serialize::row::SerializedValues;
 use tokio::sync::RwLock;
 
+use crate::batch::{BatchWithValues, BridgedBatch};
 use crate::error_conversion::FFIMaybeException;
 use crate::error_conversion::HostIdError;
 use crate::error_conversion::InvalidArgumentError;
+use crate::error_conversion::SessionCreateError;
 use crate::error_conversion::SessionOperationError;
+use crate::error_conversion::SinglePageExecutionError;
 use crate::ffi::FFIPtr;
 use crate::ffi::{
     ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, CSharpManagedStringPtr, CSharpStr,
-    FFI, FFIBool, FFIStr, FromArc, WriteStringCallback,
+    FFI, FFIBool, FFISlice, FFIStr, FromArc, WriteStringCallback,
 };
 use crate::pre_serialized_values::{PopulateValues, PopulateValuesContext, PreSerializedValues};
 use crate::prepared_statement::BridgedPreparedStatement;
+use crate::query_result::{BridgedQueryResult, ExecutionInfo};
+use crate::query_trace::BridgedQueryTrace;
+use crate::row_cursor::BridgedRowCursor;
 use crate::row_set::RowSet;
 use crate::session_config::{BridgedSessionConfig, BridgedSessionConfigResult};
 use crate::task::EmptyAsyncResult;
@@ -63,6 +77,9 @@ impl HostIdPtr<'_> {
 #[derive(Debug)]
 pub(crate) struct BridgedSessionInner {
     session: Option<Session>,
+
+    /// Size of the connection pools, fixed when the session is created.
+    pool_size: PoolSize,
 }
 
 /// Execution options for bound statements mirrored with the managed FFI struct.
@@ -73,6 +90,78 @@ pub struct BoundStatementExecutionOptions {
     pub has_consistency_level: FFIBool,
     pub is_idempotent: FFIBool,
     pub page_size: i32,
+    /// Per-request timeout; non-positive values keep the session default.
+    pub request_timeout_millis: i32,
+    /// Client-side write timestamp in microseconds since the Unix epoch;
+    /// negative values let the driver or server assign one.
+    pub timestamp_micros: i64,
+}
+
+impl BoundStatementExecutionOptions {
+    /// Applies these options to `prepared_statement`.
+    ///
+    /// If no consistency level was provided, it is unset on the statement so that the default
+    /// one is used. Returns a description of the problem if the consistency level is invalid.
+    fn apply_to(self, prepared_statement: &mut PreparedStatement) -> Result<(), String> {
+        if bool::from(self.has_consistency_level) {
+            let consistency = self.consistency_level.try_into().map_err(|err| {
+                format!(
+                    "Invalid consistency level value {0} passed from C# for bound query: {1}",
+                    self.consistency_level, err
+                )
+            })?;
+            prepared_statement.set_consistency(consistency);
+        } else {
+            prepared_statement.unset_consistency();
+        }
+
+        set_is_idempotent(prepared_statement, bool::from(self.is_idempotent));
+        prepared_statement.set_page_size(self.page_size);
+        prepared_statement.set_request_timeout(self.request_timeout());
+        prepared_statement.set_timestamp(self.timestamp());
+
+        Ok(())
+    }
+
+    fn request_timeout(&self) -> Option<Duration> {
+        request_timeout_from_millis(self.request_timeout_millis)
+    }
+
+    fn timestamp(&self) -> Option<i64> {
+        timestamp_from_micros(self.timestamp_micros)
+    }
+}
+
+/// Marks `prepared_statement` as idempotent or not.
+///
+/// Counter updates are never idempotent, as replaying one after an ambiguous failure
+/// (e.g. a write timeout) could apply the delta twice. Statements binding a counter value
+/// are therefore always marked non-idempotent, regardless of what C# requested.
+/// Counter updates with literal deltas cannot be detected and rely on C# defaults.
+fn set_is_idempotent(prepared_statement: &mut PreparedStatement, is_idempotent: bool) {
+    let is_counter_update = prepared_statement
+        .get_variable_col_specs()
+        .iter()
+        .any(|spec| matches!(spec.typ(), ColumnType::Native(NativeType::Counter)));
+
+    prepared_statement.set_is_idempotent(is_idempotent && !is_counter_update);
+}
+
+/// Returns the consistency a request is executed with, as reported back in its execution info.
+///
+/// A consistency set on the request wins; otherwise the one of the request's execution profile
+/// is used, or of the session's default execution profile if the request has none.
+fn effective_consistency(
+    session: &Session,
+    consistency: Option<Consistency>,
+    profile_handle: Option<&ExecutionProfileHandle>,
+) -> Consistency {
+    consistency.unwrap_or_else(|| {
+        profile_handle
+            .unwrap_or_else(|| session.get_default_execution_profile_handle())
+            .to_profile()
+            .get_consistency()
+    })
 }
 
 /// Execution options for simple (unprepared) statements mirrored with
@@ -84,6 +173,33 @@ pub struct SimpleStatementExecutionOptions {
     pub has_consistency_level: FFIBool,
     pub is_idempotent: FFIBool,
     pub page_size: i32,
+    /// Per-request timeout; non-positive values keep the session default.
+    pub request_timeout_millis: i32,
+    /// Client-side write timestamp in microseconds since the Unix epoch;
+    /// negative values let the driver or server assign one.
+    pub timestamp_micros: i64,
+}
+
+impl SimpleStatementExecutionOptions {
+    fn request_timeout(&self) -> Option<Duration> {
+        request_timeout_from_millis(self.request_timeout_millis)
+    }
+
+    fn timestamp(&self) -> Option<i64> {
+        timestamp_from_micros(self.timestamp_micros)
+    }
+}
+
+/// Converts a per-request timeout passed from C# into the one to set on a statement,
+/// where `None` means the session default.
+fn request_timeout_from_millis(request_timeout_millis: i32) -> Option<Duration> {
+    (request_timeout_millis > 0).then(|| Duration::from_millis(request_timeout_millis as u64))
+}
+
+/// Converts a client-side timestamp passed from C# into the one to set on a statement,
+/// where `None` lets the driver (through its timestamp generator) or the server assign one.
+pub(crate) fn timestamp_from_micros(timestamp_micros: i64) -> Option<i64> {
+    (timestamp_micros >= 0).then_some(timestamp_micros)
 }
 
 /// BridgedSession is a thread-safe, asynchronously accessible session wrapper.
@@ -115,15 +231,25 @@ pub extern "C" fn session_create(tcb: Tcb<ManuallyDestructible>, config: Bridged
         uri,
         keyspace,
         builder,
-    } = config.into_session_builder();
+    } = match config.into_session_builder() {
+        Ok(result) => result,
+        Err(e) => {
+            tcb.fail_sync(e);
+            return;
+        }
+    };
     // Own the strings so they can be captured into the 'static creation future.
     let uri = uri.to_owned();
     let keyspace = keyspace.to_owned();
+    let pool_size = builder.config.pool_size;
 
-    BridgedFuture::spawn::<_, _, NewSessionError, _>(tcb, async move {
+    BridgedFuture::spawn::<_, _, SessionCreateError, _>(tcb, async move {
         tracing::debug!("[FFI] Create Session... {}", uri);
 
-        let session = builder.build().await?;
+        let session = builder
+            .build()
+            .await
+            .map_err(|e| SessionCreateError::new(e, &uri))?;
 
         tracing::info!(
             "[FFI] Session created! URI: {}, Keyspace: {}",
@@ -137,74 +263,98 @@ pub extern "C" fn session_create(tcb: Tcb<ManuallyDestructible>, config: Bridged
 
         Ok(Arc::new(RwLock::new(BridgedSessionInner {
             session: Some(session),
+            pool_size,
         })))
     })
 }
 
 /// Validates that `local_dc` matches the datacenter of at least one node in the cluster
-/// reachable through `session`. Raises a `System.ArgumentException` (listing the available
-/// datacenters) when it does not.
+/// reachable through `session`. When it does not, raises a `System.ArgumentException` (listing
+/// the available datacenters) if `fail_if_unknown` is set, and only logs a warning otherwise.
 /// Called from C# right after session creation.
 #[unsafe(no_mangle)]
 pub extern "C" fn session_check_local_dc_existence(
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
     local_dc: CSharpStr<'_>,
+    fail_if_unknown: FFIBool,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let session_arc =
-        ArcFFI::as_ref(session_ptr).expect("valid and non-null BridgedSession pointer");
+    FFIMaybeException::catch_panics(constructors, || {
+        let session_arc =
+            ArcFFI::as_ref(session_ptr).expect("valid and non-null BridgedSession pointer");
+
+        // Try to acquire an owned read lock.
+        // If the operation fails, treat it as session shutting down.
+        let Ok(session_guard) = session_arc.try_read() else {
+            // Session is currently shutting down.
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
 
-    // Try to acquire an owned read lock.
-    // If the operation fails, treat it as session shutting down.
-    let Ok(session_guard) = session_arc.try_read() else {
-        // Session is currently shutting down.
-        let ex = constructors
-            .already_shutdown_exception_constructor
-            .construct_from_rust("Session has been shut down and can no longer execute operations");
-        return FFIMaybeException::from_exception(ex);
-    };
+        // Check if session is connected or if it has been shut down.
+        // If it has been shut down, return appropriate error.
+        let Some(session) = session_guard.session.as_ref() else {
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
 
-    // Check if session is connected or if it has been shut down.
-    // If it has been shut down, return appropriate error.
-    let Some(session) = session_guard.session.as_ref() else {
-        let ex = constructors
-            .already_shutdown_exception_constructor
-            .construct_from_rust("Session has been shut down and can no longer execute operations");
-        return FFIMaybeException::from_exception(ex);
-    };
+        let local_dc = local_dc.as_cstr().unwrap().to_str().unwrap();
 
-    let local_dc = local_dc.as_cstr().unwrap().to_str().unwrap();
+        let cluster_state = session.get_cluster_state();
 
-    let cluster_state = session.get_cluster_state();
+        if cluster_state
+            .get_nodes_info()
+            .iter()
+            .any(|node| node.datacenter.as_deref() == Some(local_dc))
+        {
+            return FFIMaybeException::ok();
+        }
 
-    if cluster_state
-        .get_nodes_info()
-        .iter()
-        .any(|node| node.datacenter.as_deref() == Some(local_dc))
-    {
-        return FFIMaybeException::ok();
-    }
+        // No node matches: build the list of available datacenters and put it in the message.
+        let mut available_dcs: Vec<String> = cluster_state
+            .get_nodes_info()
+            .iter()
+            .filter_map(|node| node.datacenter.clone())
+            .collect();
+        available_dcs.sort_unstable();
+        available_dcs.dedup();
 
-    // No node matches: build the list of available datacenters and put it in the message.
-    let mut available_dcs: Vec<String> = cluster_state
-        .get_nodes_info()
-        .iter()
-        .filter_map(|node| node.datacenter.clone())
-        .collect();
-    available_dcs.sort_unstable();
-    available_dcs.dedup();
-
-    let ex = constructors
-        .argument_exception_constructor
-        .construct_from_rust(&format!(
+        let message = format!(
             "Datacenter {local_dc} does not match any of the nodes, available datacenters: {}.",
             available_dcs.join(", ")
-        ));
-    FFIMaybeException::from_exception(ex)
+        );
+        if !bool::from(fail_if_unknown) {
+            // Nodes of the preferred datacenter may still join later. Until then, requests are
+            // only routed if the policy permits datacenter failover.
+            tracing::warn!("{message}");
+            return FFIMaybeException::ok();
+        }
+
+        let ex = constructors
+            .argument_exception_constructor
+            .construct_from_rust(&message);
+        FFIMaybeException::from_exception(ex)
+    })
 }
 
 /// Shuts down the session by acquiring a write lock and clearing the connected state.
-/// This blocks all future queries. Once shutdown, the session cannot be used for queries anymore.
+/// Once shut down, the session cannot be used for new requests.
+///
+/// Requests awaiting a response hold read locks, so they complete before the underlying session
+/// is dropped. Requests started while the shutdown waits for them cannot take a read lock, and
+/// fail immediately as if the session were already shut down.
+/// Row cursors and pagers do not hold the lock once created: they keep fetching pages on the
+/// connections they reference until C# disposes them.
+/// Shutting down an already shut down session is a no-op, so C# may call this from both
+/// `Dispose` and a finalizer.
 #[unsafe(no_mangle)]
 pub extern "C" fn session_shutdown(
     tcb: Tcb<ManuallyDestructible>,
@@ -223,13 +373,12 @@ pub extern "C" fn session_shutdown(
         // are released and then clear the connected state - no more queries can proceed after this.
         let mut session_guard = session_arc.write().await;
 
-        if session_guard.session.is_none() {
-            panic!("Session is already shut down");
+        if session_guard.session.take().is_none() {
+            tracing::debug!("[FFI] Session is already shut down");
+        } else {
+            tracing::info!("[FFI] Session shutdown complete");
         }
 
-        session_guard.session = None;
-        tracing::info!("[FFI] Session shutdown complete");
-
         // Return None, providing BridgedSession just to satisfy the type constraints.
         // This is temporary and will be replaced with a proper non-allocating empty result type.
         Ok(None::<Arc<BridgedSession>>)
@@ -273,6 +422,8 @@ pub extern "C" fn session_query(
         let mut statement = Statement::new(statement);
         statement.set_is_idempotent(bool::from(execution_options.is_idempotent));
         statement.set_page_size(execution_options.page_size);
+        statement.set_request_timeout(execution_options.request_timeout());
+        statement.set_timestamp(execution_options.timestamp());
 
         if bool::from(execution_options.has_consistency_level) {
             let consistency = execution_options
@@ -320,7 +471,7 @@ pub extern "C" fn session_query_with_values(
         {
             Ok(v) => v,
             Err(exception) => {
-                tcb.fail_task(exception);
+                tcb.fail_sync(exception);
                 return;
             }
         };
@@ -358,8 +509,10 @@ pub extern "C" fn session_query_with_values(
             .await
             .map_err(|e| SessionOperationError::Inner(PagerExecutionError::PrepareError(e)))?;
 
-        prepared.set_is_idempotent(bool::from(execution_options.is_idempotent));
+        set_is_idempotent(&mut prepared, bool::from(execution_options.is_idempotent));
         prepared.set_page_size(execution_options.page_size);
+        prepared.set_request_timeout(execution_options.request_timeout());
+        prepared.set_timestamp(execution_options.timestamp());
 
         if bool::from(execution_options.has_consistency_level) {
             let consistency = execution_options
@@ -394,6 +547,408 @@ pub extern "C" fn session_query_with_values(
     });
 }
 
+/// Executes an unprepared statement with values and returns its whole result as a
+/// [`BridgedQueryResult`], without paging.
+///
+/// Values are sent as serialized by C#, as the types of bind markers of unprepared
+/// statements are not known to the driver. Empty statements fail with `RequestInvalidException`
+/// without reaching the server.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_execute_simple(
+    tcb: Tcb<ManuallyDestructible>,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    statement: CSharpStr<'_>,
+    populate_values_context: PopulateValuesContext<'_>,
+    populate_values: PopulateValues,
+    execution_options: SimpleStatementExecutionOptions,
+) {
+    let psv =
+        match PreSerializedValues::from_populate_callback(populate_values_context, populate_values)
+        {
+            Ok(v) => v,
+            Err(exception) => {
+                tcb.fail_sync(exception);
+                return;
+            }
+        };
+
+    let statement = statement.as_cstr().unwrap().to_str().unwrap().to_owned();
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!(
+        "[FFI] Scheduling unpaged statement for execution: \"{}\"",
+        statement
+    );
+
+    let session_guard_res = session_arc.try_read_owned();
+
+    BridgedFuture::spawn::<_, _, SessionOperationError<SinglePageExecutionError>, _>(
+        tcb,
+        async move {
+            tracing::debug!("[FFI] Executing unpaged statement \"{}\"", statement);
+
+            let Ok(session_guard) = session_guard_res else {
+                return Err(SessionOperationError::AlreadyShutdown);
+            };
+
+            let Some(session) = session_guard.session.as_ref() else {
+                return Err(SessionOperationError::AlreadyShutdown);
+            };
+
+            if statement.trim().is_empty() {
+                return Err(SessionOperationError::Inner(
+                    SinglePageExecutionError::EmptyStatement,
+                ));
+            }
+
+            let mut statement = Statement::new(statement);
+            statement.set_is_idempotent(bool::from(execution_options.is_idempotent));
+            statement.set_request_timeout(execution_options.request_timeout());
+            statement.set_timestamp(execution_options.timestamp());
+
+            if bool::from(execution_options.has_consistency_level) {
+                let consistency = execution_options
+                .consistency_level
+                .try_into()
+                .map_err(|err| {
+                    SessionOperationError::InvalidArgument(format!(
+                        "Invalid consistency level value {0} passed from C# for unpaged query: {1}",
+                        execution_options.consistency_level, err
+                    ))
+                })?;
+                statement.set_consistency(consistency);
+            } else {
+                statement.unset_consistency();
+            }
+
+            // The history tells how many attempts were made and which consistency was achieved.
+            let history = Arc::new(HistoryCollector::new());
+            statement.set_history_listener(history.clone());
+            let requested_consistency = effective_consistency(
+                session,
+                statement.get_consistency(),
+                statement.get_execution_profile_handle(),
+            );
+
+            let result = session.query_unpaged(statement, &psv).await.map_err(|e| {
+                SessionOperationError::Inner(SinglePageExecutionError::from_execution(
+                    e,
+                    &history.clone_structured_history(),
+                ))
+            })?;
+
+            tracing::trace!("[FFI] Unpaged statement executed");
+
+            let execution_info = ExecutionInfo::new(
+                &result,
+                requested_consistency,
+                &history.clone_structured_history(),
+            );
+            let result = BridgedQueryResult::from_query_result(result, execution_info)
+                .map_err(|e| SessionOperationError::Inner(e.into()))?;
+
+            Ok(Arc::new(result))
+        },
+    );
+}
+
+/// Executes a prepared statement with values and returns its whole result as a
+/// [`BridgedQueryResult`], without paging.
+///
+/// The number of values must match the number of bind markers of the statement;
+/// otherwise the call fails with `RequestInvalidException` before anything is sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_execute_prepared(
+    tcb: Tcb<ManuallyDestructible>,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    populate_values_context: PopulateValuesContext<'_>,
+    populate_values: PopulateValues,
+    execution_options: BoundStatementExecutionOptions,
+) {
+    execute_prepared_to_result(
+        tcb,
+        session_ptr,
+        prepared_statement_ptr,
+        populate_values_context,
+        populate_values,
+        execution_options,
+        None,
+    );
+}
+
+/// Executes a prepared statement with values and returns a single page of its result,
+/// of `execution_options.page_size` rows, as a [`BridgedQueryResult`].
+///
+/// The page starts at `paging_state`, as returned by `result_get_paging_state` for the previous
+/// page, or at the beginning of the result if `has_paging_state` is false. Paging states are
+/// opaque bytes which C# may store and replay later, e.g. to page through a result across requests.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_execute_prepared_page(
+    tcb: Tcb<ManuallyDestructible>,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    populate_values_context: PopulateValuesContext<'_>,
+    populate_values: PopulateValues,
+    execution_options: BoundStatementExecutionOptions,
+    paging_state: FFISlice<'_, u8>,
+    has_paging_state: FFIBool,
+) {
+    let paging_state = if bool::from(has_paging_state) {
+        PagingState::new_from_raw_bytes(paging_state.as_slice())
+    } else {
+        PagingState::start()
+    };
+
+    execute_prepared_to_result(
+        tcb,
+        session_ptr,
+        prepared_statement_ptr,
+        populate_values_context,
+        populate_values,
+        execution_options,
+        Some(paging_state),
+    );
+}
+
+/// Executes a prepared statement and completes `tcb` with a [`BridgedQueryResult`].
+/// The whole result is fetched if `paging_state` is `None`, and a single page otherwise.
+fn execute_prepared_to_result(
+    tcb: Tcb<ManuallyDestructible>,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    populate_values_context: PopulateValuesContext<'_>,
+    populate_values: PopulateValues,
+    execution_options: BoundStatementExecutionOptions,
+    paging_state: Option<PagingState>,
+) {
+    let psv =
+        match PreSerializedValues::from_populate_callback(populate_values_context, populate_values)
+        {
+            Ok(v) => v,
+            Err(exception) => {
+                tcb.fail_sync(exception);
+                return;
+            }
+        };
+
+    let bridged_prepared = ArcFFI::as_ref(prepared_statement_ptr).unwrap();
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!("[FFI] Scheduling prepared statement execution into a result");
+
+    let session_guard_res = session_arc.try_read_owned();
+
+    let mut prepared_statement = bridged_prepared
+        .inner
+        .read()
+        .expect("lock not poisoned by an earlier panic")
+        .clone();
+
+    BridgedFuture::spawn::<_, _, SessionOperationError<SinglePageExecutionError>, _>(
+        tcb,
+        async move {
+            tracing::debug!("[FFI] Executing prepared statement into a result");
+
+            let Ok(session_guard) = session_guard_res else {
+                return Err(SessionOperationError::AlreadyShutdown);
+            };
+
+            let Some(session) = session_guard.session.as_ref() else {
+                return Err(SessionOperationError::AlreadyShutdown);
+            };
+
+            let expected = prepared_statement.get_variable_col_specs().len();
+            if psv.len() != expected {
+                return Err(SessionOperationError::Inner(
+                    SinglePageExecutionError::BindCountMismatch {
+                        expected,
+                        actual: psv.len(),
+                    },
+                ));
+            }
+
+            execution_options
+                .apply_to(&mut prepared_statement)
+                .map_err(SessionOperationError::InvalidArgument)?;
+
+            let history = Arc::new(HistoryCollector::new());
+            prepared_statement.set_history_listener(history.clone());
+            let requested_consistency = effective_consistency(
+                session,
+                prepared_statement.get_consistency(),
+                prepared_statement.get_execution_profile_handle(),
+            );
+
+            let (result, paging_state_response) = match paging_state {
+                None => {
+                    let result = session
+                        .execute_unpaged(&prepared_statement, &psv)
+                        .await
+                        .map_err(|e| {
+                            SessionOperationError::Inner(SinglePageExecutionError::from_execution(
+                                e,
+                                &history.clone_structured_history(),
+                            ))
+                        })?;
+                    (result, None)
+                }
+                Some(paging_state) => {
+                    let (result, response) = session
+                        .execute_single_page(&prepared_statement, &psv, paging_state)
+                        .await
+                        .map_err(|e| {
+                            SessionOperationError::Inner(SinglePageExecutionError::from_execution(
+                                e,
+                                &history.clone_structured_history(),
+                            ))
+                        })?;
+                    (result, Some(response))
+                }
+            };
+
+            tracing::trace!("[FFI] Prepared statement executed into a result");
+
+            let execution_info = ExecutionInfo::new(
+                &result,
+                requested_consistency,
+                &history.clone_structured_history(),
+            );
+            let mut result = BridgedQueryResult::from_query_result(result, execution_info)
+                .map_err(|e| SessionOperationError::Inner(e.into()))?;
+            if let Some(response) = paging_state_response {
+                result.set_paging_state(response);
+            }
+
+            Ok(Arc::new(result))
+        },
+    );
+}
+
+/// Executes a batch built with the `batch_*` functions and returns its result as a
+/// [`BridgedQueryResult`], which only has rows for conditional batches.
+///
+/// The batch is snapshotted when this is called, so C# may keep modifying or free it
+/// afterwards. Prepared statements whose table does not match the batch type (e.g. a
+/// non-counter statement in a COUNTER batch) fail with `InvalidQueryException` before
+/// anything is sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_execute_batch(
+    tcb: Tcb<ManuallyDestructible>,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    batch_ptr: BridgedBorrowedSharedPtr<'_, BridgedBatch>,
+) {
+    let mut batch_with_values = ArcFFI::as_ref(batch_ptr)
+        .expect("valid and non-null BridgedBatch pointer")
+        .inner
+        .lock()
+        .expect("lock not poisoned by an earlier panic")
+        .clone();
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!(
+        "[FFI] Scheduling batch of {} statements for execution",
+        batch_with_values.values.len()
+    );
+
+    let session_guard_res = session_arc.try_read_owned();
+
+    BridgedFuture::spawn::<_, _, SessionOperationError<SinglePageExecutionError>, _>(
+        tcb,
+        async move {
+            tracing::debug!("[FFI] Executing batch");
+
+            let Ok(session_guard) = session_guard_res else {
+                return Err(SessionOperationError::AlreadyShutdown);
+            };
+
+            let Some(session) = session_guard.session.as_ref() else {
+                return Err(SessionOperationError::AlreadyShutdown);
+            };
+
+            if let Some(index) =
+                batch_with_values.find_mismatched_counter_statement(&session.get_cluster_state())
+            {
+                return Err(SessionOperationError::Inner(
+                    SinglePageExecutionError::CounterBatchMismatch { index },
+                ));
+            }
+
+            let BatchWithValues { batch, values } = &mut batch_with_values;
+            let history = Arc::new(HistoryCollector::new());
+            batch.set_history_listener(history.clone());
+            let requested_consistency = effective_consistency(
+                session,
+                batch.get_consistency(),
+                batch.get_execution_profile_handle(),
+            );
+
+            let result = session.batch(batch, &*values).await.map_err(|e| {
+                SessionOperationError::Inner(SinglePageExecutionError::from_execution(
+                    e,
+                    &history.clone_structured_history(),
+                ))
+            })?;
+
+            tracing::trace!("[FFI] Batch executed");
+
+            let execution_info = ExecutionInfo::new(
+                &result,
+                requested_consistency,
+                &history.clone_structured_history(),
+            );
+            let result = BridgedQueryResult::from_query_result(result, execution_info)
+                .map_err(|e| SessionOperationError::Inner(e.into()))?;
+
+            Ok(Arc::new(result))
+        },
+    );
+}
+
+/// Fetches the server-side trace of a traced request, identified by the 16 big-endian bytes
+/// of its tracing id (see `result_get_tracing_id`). Resolves to a `BridgedQueryTrace`.
+///
+/// The trace is written asynchronously by the server, so it is polled for until complete,
+/// as configured for the session. Fails with `TraceRetrievalException` if it cannot be read,
+/// including when it is still incomplete after the last attempt.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_get_query_trace(
+    tcb: Tcb<ManuallyDestructible>,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    tracing_id: &[u8; UUID_BYTE_LEN],
+) {
+    let tracing_id = Uuid::from_bytes(*tracing_id);
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!("[FFI] Scheduling retrieval of query trace {}", tracing_id);
+
+    let session_guard_res = session_arc.try_read_owned();
+
+    BridgedFuture::spawn::<_, _, SessionOperationError<TracingError>, _>(tcb, async move {
+        let Ok(session_guard) = session_guard_res else {
+            return Err(SessionOperationError::AlreadyShutdown);
+        };
+
+        let Some(session) = session_guard.session.as_ref() else {
+            return Err(SessionOperationError::AlreadyShutdown);
+        };
+
+        let tracing_info = session
+            .get_tracing_info(&tracing_id)
+            .await
+            .map_err(SessionOperationError::Inner)?;
+
+        tracing::trace!("[FFI] Query trace {} retrieved", tracing_id);
+
+        Ok(Arc::new(BridgedQueryTrace::from(tracing_info)))
+    })
+}
+
+/// Prepares `statement` against the session and completes `tcb` with a new
+/// `BridgedPreparedStatement`, owned by C#.
+///
+/// Preparation is asynchronous, as it requires a round-trip to the cluster. It fails with
+/// an already-shutdown error if the session is being shut down.
 #[unsafe(no_mangle)]
 pub extern "C" fn session_prepare(
     tcb: Tcb<ManuallyDestructible>,
@@ -465,7 +1020,7 @@ pub extern "C" fn session_query_bound(
     let mut prepared_statement = bridged_prepared
         .inner
         .read()
-        .expect("poisoning impossible due to process-aborting panics")
+        .expect("lock not poisoned by an earlier panic")
         .clone();
 
     BridgedFuture::spawn::<_, _, SessionOperationError<PagerExecutionError>, _>(tcb, async move {
@@ -482,27 +1037,11 @@ pub extern "C" fn session_query_bound(
             return Err(SessionOperationError::AlreadyShutdown);
         };
 
-        // If consistency level was provided, apply it to the prepared statement. Otherwise, if consistency level
-        // was not provided, ensure it's unset on the prepared statement so it uses the default (between creating
-        // this bound statement and executing it someone could set a consistency level on the prepared statement).
-        // NOTE: logic used here for applying consistency level is complicated and for now there is no tests covering it.
-        if bool::from(execution_options.has_consistency_level) {
-            let consistency = execution_options
-                .consistency_level
-                .try_into()
-                .map_err(|err| {
-                    SessionOperationError::InvalidArgument(format!(
-                        "Invalid consistency level value {0} passed from C# for bound query: {1}",
-                        execution_options.consistency_level, err
-                    ))
-                })?;
-            prepared_statement.set_consistency(consistency);
-        } else {
-            prepared_statement.unset_consistency();
-        }
-
-        prepared_statement.set_is_idempotent(bool::from(execution_options.is_idempotent));
-        prepared_statement.set_page_size(execution_options.page_size);
+        // If no consistency level was provided, it is unset on the prepared statement so that the
+        // default one is used (someone could have set one on the prepared statement in the meantime).
+        execution_options
+            .apply_to(&mut prepared_statement)
+            .map_err(SessionOperationError::InvalidArgument)?;
 
         // Lock is held for the entire duration of the query operation,
         // preventing shutdown until this future completes
@@ -535,7 +1074,7 @@ pub extern "C" fn session_query_bound_with_values(
         {
             Ok(v) => v,
             Err(exception) => {
-                tcb.fail_task(exception);
+                tcb.fail_sync(exception);
                 return;
             }
         };
@@ -554,7 +1093,7 @@ pub extern "C" fn session_query_bound_with_values(
     let mut prepared_statement = bridged_prepared
         .inner
         .read()
-        .expect("poisoning impossible due to process-aborting panics")
+        .expect("lock not poisoned by an earlier panic")
         .clone();
 
     BridgedFuture::spawn::<_, _, SessionOperationError<PagerExecutionError>, _>(tcb, async move {
@@ -571,28 +1110,11 @@ pub extern "C" fn session_query_bound_with_values(
             return Err(SessionOperationError::AlreadyShutdown);
         };
 
-        // If consistency level was provided, apply it to the prepared statement. Otherwise, if consistency level
-        // was not provided, ensure it's unset on the prepared statement so it uses the default (between creating
-        // this bound statement and executing it someone could set a consistency level on the prepared statement).
-        // NOTE: logic used here for applying consistency level is complicated and for now there is no tests covering it.
-        if bool::from(execution_options.has_consistency_level) {
-            let consistency = execution_options
-                .consistency_level
-                .try_into()
-                .map_err(|err| {
-                    SessionOperationError::InvalidArgument(format!(
-                        "Invalid consistency level value {0} passed from C# for bound query with values: {1}",
-                        execution_options.consistency_level,
-                        err
-                    ))
-                })?;
-            prepared_statement.set_consistency(consistency);
-        } else {
-            prepared_statement.unset_consistency();
-        }
-
-        prepared_statement.set_is_idempotent(bool::from(execution_options.is_idempotent));
-        prepared_statement.set_page_size(execution_options.page_size);
+        // If no consistency level was provided, it is unset on the prepared statement so that the
+        // default one is used (someone could have set one on the prepared statement in the meantime).
+        execution_options
+            .apply_to(&mut prepared_statement)
+            .map_err(SessionOperationError::InvalidArgument)?;
 
         // Convert our FFI wrapper into SerializedValues by consuming it.
         let serialized_values: SerializedValues = psv.into_serialized_values();
@@ -610,6 +1132,76 @@ pub extern "C" fn session_query_bound_with_values(
     });
 }
 
+/// Executes a bound statement and returns a [`BridgedRowCursor`] streaming its rows.
+///
+/// In contrast to [`session_query_bound_with_values`], rows are fetched in the background
+/// and handed over to C# in batches via `cursor_next_rows`.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_execute_iter(
+    tcb: Tcb<ManuallyDestructible>,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    prepared_statement_ptr: BridgedBorrowedSharedPtr<'_, BridgedPreparedStatement>,
+    populate_values_context: PopulateValuesContext<'_>,
+    populate_values: PopulateValues,
+    execution_options: BoundStatementExecutionOptions,
+) {
+    let psv =
+        match PreSerializedValues::from_populate_callback(populate_values_context, populate_values)
+        {
+            Ok(v) => v,
+            Err(exception) => {
+                tcb.fail_sync(exception);
+                return;
+            }
+        };
+
+    let bridged_prepared = ArcFFI::as_ref(prepared_statement_ptr).unwrap();
+    let session_arc = ArcFFI::cloned_from_ptr(session_ptr).unwrap();
+
+    tracing::trace!("[FFI] Scheduling prepared statement execution with a row cursor");
+
+    // Try to acquire an owned read lock.
+    // If the operation fails, treat it as session shutting down.
+    let session_guard_res = session_arc.try_read_owned();
+
+    let mut prepared_statement = bridged_prepared
+        .inner
+        .read()
+        .expect("lock not poisoned by an earlier panic")
+        .clone();
+
+    BridgedFuture::spawn::<_, _, SessionOperationError<PagerExecutionError>, _>(tcb, async move {
+        tracing::debug!("[FFI] Executing prepared statement with a row cursor");
+
+        let Ok(session_guard) = session_guard_res else {
+            // Session is currently shutting down - exit with appropriate error.
+            return Err(SessionOperationError::AlreadyShutdown);
+        };
+
+        // Check if session is connected or if it has been shut down.
+        // If it has been shut down, return appropriate error.
+        let Some(session) = session_guard.session.as_ref() else {
+            return Err(SessionOperationError::AlreadyShutdown);
+        };
+
+        execution_options
+            .apply_to(&mut prepared_statement)
+            .map_err(SessionOperationError::InvalidArgument)?;
+
+        let query_pager = session
+            .execute_iter_preserialized(prepared_statement, psv.into_serialized_values())
+            .await
+            .map_err(SessionOperationError::Inner)?;
+
+        tracing::trace!("[FFI] Prepared statement executed, streaming rows");
+
+        Ok(Arc::new(BridgedRowCursor::spawn(
+            query_pager,
+            execution_options.page_size,
+        )))
+    });
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn session_get_keyspace(
     session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
@@ -617,34 +1209,89 @@ pub extern "C" fn session_get_keyspace(
     cs_string: CSharpManagedStringPtr,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let session_arc =
-        ArcFFI::as_ref(session_ptr).expect("valid and non-null BridgedSession pointer");
+    FFIMaybeException::catch_panics(constructors, || {
+        let session_arc =
+            ArcFFI::as_ref(session_ptr).expect("valid and non-null BridgedSession pointer");
+
+        // Try to acquire a read lock synchronously.
+        let Ok(session_guard) = session_arc.try_read() else {
+            // Session is currently shutting down.
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
 
-    // Try to acquire a read lock synchronously.
-    let Ok(session_guard) = session_arc.try_read() else {
-        // Session is currently shutting down.
-        let ex = constructors
-            .already_shutdown_exception_constructor
-            .construct_from_rust("Session has been shut down and can no longer execute operations");
-        return FFIMaybeException::from_exception(ex);
-    };
+        // Check if session is connected or if it has been shut down.
+        let Some(session) = session_guard.session.as_ref() else {
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
 
-    // Check if session is connected or if it has been shut down.
-    let Some(session) = session_guard.session.as_ref() else {
-        let ex = constructors
-            .already_shutdown_exception_constructor
-            .construct_from_rust("Session has been shut down and can no longer execute operations");
-        return FFIMaybeException::from_exception(ex);
-    };
+        let Some(keyspace) = session.get_keyspace() else {
+            // If no keyspace is set, we don't set FFIStr.
+            // This will be treated as null on the C# side.
+            return FFIMaybeException::ok();
+        };
+
+        let ffi_str = FFIStr::new(keyspace.as_ref());
+        write_cs_str(ffi_str, cs_string)
+    })
+}
 
-    let Some(keyspace) = session.get_keyspace() else {
-        // If no keyspace is set, we don't set FFIStr.
-        // This will be treated as null on the C# side.
-        return FFIMaybeException::ok();
+/// Changes the keyspace used by all connections of the session, like a `USE` statement.
+///
+/// Unless `case_sensitive` is set, the name is lowercased like an unquoted CQL identifier.
+/// Fails synchronously if the keyspace name is null or empty; requests already in flight
+/// keep using the previous keyspace.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_use_keyspace(
+    tcb: Tcb<EmptyAsyncResult>,
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    keyspace: CSharpStr<'_>,
+    case_sensitive: FFIBool,
+) {
+    let Some(keyspace) = keyspace
+        .as_cstr()
+        .map(|keyspace| keyspace.to_str().unwrap().to_owned())
+        .filter(|keyspace| !keyspace.is_empty())
+    else {
+        tcb.fail_sync(InvalidArgumentError(
+            "Keyspace name must not be null or empty",
+        ));
+        return;
+    };
+    let Some(session_arc) = ArcFFI::cloned_from_ptr(session_ptr) else {
+        tcb.fail_sync(InvalidArgumentError("invalid or null session pointer"));
+        return;
     };
 
-    let ffi_str = FFIStr::new(keyspace.as_ref());
-    write_cs_str(ffi_str, cs_string)
+    tracing::trace!("[FFI] Scheduling use of keyspace \"{}\"", keyspace);
+
+    let session_guard_res = session_arc.try_read_owned();
+
+    BridgedFuture::spawn::<_, _, SessionOperationError<UseKeyspaceError>, _>(tcb, async move {
+        let Ok(session_guard) = session_guard_res else {
+            return Err(SessionOperationError::AlreadyShutdown);
+        };
+
+        let Some(session) = session_guard.session.as_ref() else {
+            return Err(SessionOperationError::AlreadyShutdown);
+        };
+
+        session
+            .use_keyspace(keyspace, bool::from(case_sensitive))
+            .await
+            .map_err(SessionOperationError::Inner)?;
+
+        Ok(())
+    });
 }
 
 /// Sets `out_cluster_state` to the current cluster state as a ManuallyDestructible resource.
@@ -659,33 +1306,179 @@ pub extern "C" fn session_get_cluster_state(
     out_cluster_state: *mut ManuallyDestructible,
     constructors: &'static ExceptionConstructors,
 ) -> FFIMaybeException {
-    let session_arc =
-        ArcFFI::as_ref(session_ptr).expect("valid and non-null BridgedSession pointer");
+    FFIMaybeException::catch_panics(constructors, || {
+        let session_arc =
+            ArcFFI::as_ref(session_ptr).expect("valid and non-null BridgedSession pointer");
+
+        // Try to acquire a read lock synchronously.
+        let Ok(session_guard) = session_arc.try_read() else {
+            // Session is currently shutting down.
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
 
-    // Try to acquire a read lock synchronously.
-    let Ok(session_guard) = session_arc.try_read() else {
-        // Session is currently shutting down.
-        let ex = constructors
-            .already_shutdown_exception_constructor
-            .construct_from_rust("Session has been shut down and can no longer execute operations");
-        return FFIMaybeException::from_exception(ex);
-    };
+        // Check if session is connected or if it has been shut down.
+        let Some(session) = session_guard.session.as_ref() else {
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
 
-    // Check if session is connected or if it has been shut down.
-    let Some(session) = session_guard.session.as_ref() else {
-        let ex = constructors
-            .already_shutdown_exception_constructor
-            .construct_from_rust("Session has been shut down and can no longer execute operations");
-        return FFIMaybeException::from_exception(ex);
-    };
+        // Get the cluster state from the session and convert it into an ArcFFI-wrapped pointer.
+        let cluster_state = session.get_cluster_state();
+        let md = ManuallyDestructible::from_destructible::<ClusterState>(cluster_state);
+        unsafe {
+            *out_cluster_state = md;
+        }
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes whether the nodes of the cluster reported sharding information when connecting,
+/// i.e. whether requests are routed to the shard owning their partition.
+/// This is only the case for Scylla nodes, and does not depend on the shard-aware port being used.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_is_shard_aware(
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    out_is_shard_aware: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let session_arc =
+            ArcFFI::as_ref(session_ptr).expect("valid and non-null BridgedSession pointer");
+
+        // Try to acquire a read lock synchronously.
+        let Ok(session_guard) = session_arc.try_read() else {
+            // Session is currently shutting down.
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
+
+        // Check if session is connected or if it has been shut down.
+        let Some(session) = session_guard.session.as_ref() else {
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
+
+        *out_is_shard_aware = session
+            .get_cluster_state()
+            .get_nodes_info()
+            .iter()
+            .any(|node| node.sharder().is_some())
+            .into();
+        FFIMaybeException::ok()
+    })
+}
+
+/// Opaque type representing the C# list the pool state of each node is added to.
+#[derive(Clone, Copy)]
+enum HostPoolStateList {}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct HostPoolStateListPtr<'a>(FFIPtr<'a, HostPoolStateList>);
+
+/// Connection pool state of a node passed to C#.
+/// Any change to this struct must be reflected in the C# definition.
+#[repr(C)]
+pub struct BridgedHostPoolState<'a> {
+    id_bytes: FFISlice<'a, u8>,
 
-    // Get the cluster state from the session and convert it into an ArcFFI-wrapped pointer.
-    let cluster_state = session.get_cluster_state();
-    let md = ManuallyDestructible::from_destructible::<ClusterState>(cluster_state);
-    unsafe {
-        *out_cluster_state = md;
+    /// Number of connections the pool of the node is filled to, or 0 if the driver has
+    /// no open connection to the node. The Rust driver reopens broken connections right away,
+    /// but does not report how many of them are open at a given time.
+    open_connections: i32,
+}
+
+/// Callback adding the pool state of a node to the C# list. The host ID bytes are only valid
+/// for the duration of the call.
+type AddHostPoolState = unsafe extern "C" fn(
+    list_ptr: HostPoolStateListPtr<'_>,
+    state: BridgedHostPoolState<'_>,
+) -> FFIMaybeException;
+
+/// Returns the number of connections the driver keeps open to `node` with pools of `pool_size`.
+fn pool_connections(node: &Node, pool_size: PoolSize) -> usize {
+    if !node.is_connected() {
+        return 0;
+    }
+    match pool_size {
+        PoolSize::PerHost(size) => size.get(),
+        // Nodes without sharding information, i.e. Cassandra nodes, are treated as one shard.
+        PoolSize::PerShard(size) => {
+            let shards = node
+                .sharder()
+                .map_or(1, |sharder| sharder.nr_shards.get() as usize);
+            size.get() * shards
+        }
     }
-    FFIMaybeException::ok()
+}
+
+/// Reports the connection pool state of every node of the cluster to C#, calling `add_state`
+/// synchronously once per node, including nodes the driver is not connected to.
+///
+/// The pool size is fixed when the session is created; there is no way to change it afterwards.
+#[unsafe(no_mangle)]
+pub extern "C" fn session_get_pool_state(
+    session_ptr: BridgedBorrowedSharedPtr<'_, BridgedSession>,
+    list_ptr: HostPoolStateListPtr<'_>,
+    add_state: AddHostPoolState,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let session_arc =
+            ArcFFI::as_ref(session_ptr).expect("valid and non-null BridgedSession pointer");
+
+        // Try to acquire a read lock synchronously.
+        let Ok(session_guard) = session_arc.try_read() else {
+            // Session is currently shutting down.
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
+
+        // Check if session is connected or if it has been shut down.
+        let Some(session) = session_guard.session.as_ref() else {
+            let ex = constructors
+                .already_shutdown_exception_constructor
+                .construct_from_rust(
+                    "Session has been shut down and can no longer execute operations",
+                );
+            return FFIMaybeException::from_exception(ex);
+        };
+
+        let cluster_state = session.get_cluster_state();
+        for node in cluster_state.get_nodes_info() {
+            let open_connections = pool_connections(node, session_guard.pool_size);
+            let state = BridgedHostPoolState {
+                id_bytes: FFISlice::new(node.host_id.as_bytes()),
+                open_connections: i32::try_from(open_connections).unwrap_or(i32::MAX),
+            };
+            let exception = unsafe { add_state(list_ptr, state) };
+            if exception.has_exception() {
+                return exception;
+            }
+        }
+        FFIMaybeException::ok()
+    })
 }
 
 /// Ephemeral bridge for the `WaitForSchemaAgreement` family of FFI calls.
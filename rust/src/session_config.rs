@@ -1,11 +1,31 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::address_translator::BridgedAddressTranslator;
+use crate::authenticator::BridgedAuthenticator;
+use crate::error_conversion::SessionConfigError;
+use crate::execution_profile::BridgedExecutionProfileConfig;
 use crate::ffi::{CSharpStr, FFIBool};
+use crate::host_filter::BridgedHostFilter;
+use crate::retry_policy::BridgedCustomRetryPolicy;
+use crate::ssl_options::BridgedSslOptions;
 
-use scylla::client::SelfIdentity;
+use scylla::client::{PoolSize, SelfIdentity};
+use scylla::frame::Compression;
 use scylla::{
-    client::{execution_profile::ExecutionProfile, session_builder::SessionBuilder},
-    policies::load_balancing::DefaultPolicy,
+    client::{execution_profile::ExecutionProfileBuilder, session_builder::SessionBuilder},
+    policies::{
+        load_balancing::{DefaultPolicy, LatencyAwarenessBuilder},
+        retry::{
+            DefaultRetryPolicy, DowngradingConsistencyRetryPolicy, FallthroughRetryPolicy,
+            RetryPolicy,
+        },
+        speculative_execution::{
+            PercentileSpeculativeExecutionPolicy, SimpleSpeculativeExecutionPolicy,
+            SpeculativeExecutionPolicy,
+        },
+    },
 };
 
 const DEFAULT_DRIVER_NAME: &str = "ScyllaDB C# RS Driver";
@@ -40,6 +60,10 @@ pub(crate) struct BridgedTcpConfig {
     // Instead, Rust Driver exposes `tcp_zero_linger()` which sets SO_LINGER with a timeout of 0.
     // Therefore, we only expose a boolean here to indicate whether to enable SO_LINGER with a timeout of 0.
     so_linger: FFIBool,
+
+    /// Whether to coalesce requests written to a connection at about the same time into fewer,
+    /// larger socket writes. Improves throughput of many small requests, but adds latency jitter.
+    write_coalescing: FFIBool,
 }
 
 impl BridgedTcpConfig {
@@ -67,42 +91,420 @@ impl BridgedTcpConfig {
             builder = builder.tcp_zero_linger();
         }
 
+        builder = builder.write_coalescing(self.write_coalescing.into());
+
+        builder
+    }
+}
+
+/// Connection-level options passed from C#, as opposed to the TCP socket options.
+///
+/// The Rust driver keeps every connection of a pool open for as long as the node is up,
+/// so there is no idle connection timeout to configure.
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct BridgedConnectionConfig {
+    /// Interval between the heartbeats sent on each connection to detect dead ones,
+    /// in milliseconds. Values <= 0 disable heartbeats.
+    heartbeat_interval_millis: i32,
+
+    /// Number of connections kept open to each node, or to each shard of a Scylla node if
+    /// `pool_size_per_shard` is set. Values <= 0 keep the driver default of one per shard.
+    pool_size: i32,
+    pool_size_per_shard: FFIBool,
+}
+
+impl BridgedConnectionConfig {
+    /// Apply the connection options in this config to `builder` and return it.
+    pub(crate) fn apply_to_builder(self, mut builder: SessionBuilder) -> SessionBuilder {
+        if self.heartbeat_interval_millis > 0 {
+            builder = builder
+                .keepalive_interval(Duration::from_millis(self.heartbeat_interval_millis as u64));
+        } else {
+            // The builder has no setter disabling heartbeats, so the config is set directly.
+            builder.config.keepalive_interval = None;
+        }
+
+        if let Some(size) = NonZeroUsize::new(self.pool_size.max(0) as usize) {
+            let pool_size = if self.pool_size_per_shard.into() {
+                PoolSize::PerShard(size)
+            } else {
+                PoolSize::PerHost(size)
+            };
+            builder = builder.pool_size(pool_size);
+        }
         builder
     }
 }
 
+/// Latency awareness options passed from C#.
+///
+/// Fields with values <= 0 keep the Rust driver defaults.
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct BridgedLatencyAwarenessConfig {
+    /// Whether to penalize nodes that are slower than the fastest one.
+    enabled: FFIBool,
+
+    /// How many times slower than the fastest node a node must be to be excluded.
+    exclusion_threshold: f64,
+
+    /// Minimum number of measurements before a node's average latency is trusted.
+    minimum_measurements: i32,
+
+    /// How long an excluded node is penalized before being retried, in milliseconds.
+    retry_period_millis: i64,
+
+    /// How often the minimum average latency is recomputed, in milliseconds.
+    update_rate_millis: i64,
+
+    /// Weight given to older latencies when averaging, in milliseconds.
+    scale_millis: i64,
+}
+
+impl BridgedLatencyAwarenessConfig {
+    /// Returns the configured builder, or `None` if latency awareness is disabled.
+    fn into_builder(self) -> Option<LatencyAwarenessBuilder> {
+        if !bool::from(self.enabled) {
+            return None;
+        }
+
+        let millis = |value: i64| (value > 0).then(|| Duration::from_millis(value as u64));
+        let mut builder = LatencyAwarenessBuilder::new();
+
+        if self.exclusion_threshold > 0.0 {
+            builder = builder.exclusion_threshold(self.exclusion_threshold);
+        }
+
+        if self.minimum_measurements > 0 {
+            builder = builder.minimum_measurements(self.minimum_measurements as usize);
+        }
+
+        if let Some(retry_period) = millis(self.retry_period_millis) {
+            builder = builder.retry_period(retry_period);
+        }
+
+        if let Some(update_rate) = millis(self.update_rate_millis) {
+            builder = builder.update_rate(update_rate);
+        }
+
+        if let Some(scale) = millis(self.scale_millis) {
+            builder = builder.scale(scale);
+        }
+
+        Some(builder)
+    }
+}
+
+/// Load balancing options passed from C#.
+///
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct BridgedLoadBalancingPolicy<'a> {
+    /// Whether to route requests to replicas owning the token of the partition key.
+    ///
+    /// The token is only known for prepared statements whose partition key columns are
+    /// bound at execution time. Unprepared statements and statements without a bound
+    /// partition key are routed as if token awareness was disabled.
     is_token_aware: bool,
+
+    /// Whether the policy may fall back to nodes outside of `local_dc`.
     permit_dc_failover: bool,
+
+    /// Preferred (local) datacenter, or null for no preference.
     local_dc: CSharpStr<'a>,
+
+    /// Preferred rack within `local_dc`, or null (or empty) for no rack preference.
+    /// A non-empty rack requires a non-empty `local_dc`.
+    local_rack: CSharpStr<'a>,
+
+    /// Latency awareness options.
+    latency_awareness: BridgedLatencyAwarenessConfig,
 }
 
 impl<'a> BridgedLoadBalancingPolicy<'a> {
-    /// Returns the configured builder
-    pub(crate) fn apply_to_builder(self, builder: SessionBuilder) -> SessionBuilder {
-        let local_dc = self
-            .local_dc
-            .as_cstr()
-            .map(|cstr| cstr.to_str().unwrap().to_owned());
+    /// Returns the configured profile builder
+    ///
+    /// Fails if an empty local datacenter is given while cross-DC failover is disabled,
+    /// as such a policy would have no nodes to route requests to, or if a rack is given
+    /// without a datacenter.
+    pub(crate) fn apply_to_profile(
+        self,
+        profile: ExecutionProfileBuilder,
+    ) -> Result<ExecutionProfileBuilder, SessionConfigError> {
+        let to_owned_string =
+            |s: CSharpStr<'_>| s.as_cstr().map(|cstr| cstr.to_str().unwrap().to_owned());
+        let local_dc = to_owned_string(self.local_dc);
+        // An empty rack disables the rack preference, like a null one.
+        let local_rack = to_owned_string(self.local_rack).filter(|rack| !rack.is_empty());
 
         let mut lbpbuilder = DefaultPolicy::builder()
             .token_aware(self.is_token_aware)
             .permit_dc_failover(self.permit_dc_failover);
 
-        if let Some(preferred_dc) = local_dc {
-            lbpbuilder = lbpbuilder.prefer_datacenter(preferred_dc);
+        if let Some(latency_awareness) = self.latency_awareness.into_builder() {
+            lbpbuilder = lbpbuilder.latency_awareness(latency_awareness);
+        }
+
+        match (local_dc, local_rack) {
+            (Some(preferred_dc), rack) if !preferred_dc.is_empty() => {
+                lbpbuilder = match rack {
+                    Some(preferred_rack) => {
+                        lbpbuilder.prefer_datacenter_and_rack(preferred_dc, preferred_rack)
+                    }
+                    // No rack given - prefer the datacenter only.
+                    None => lbpbuilder.prefer_datacenter(preferred_dc),
+                };
+            }
+            // A rack preference is meaningless without a datacenter.
+            (_, Some(_)) => {
+                return Err(SessionConfigError::RackWithoutDatacenter);
+            }
+            // An empty name is treated as "no preference" only if the policy may use remote DCs.
+            (Some(_), None) if !self.permit_dc_failover => {
+                return Err(SessionConfigError::EmptyLocalDatacenter);
+            }
+            _ => {}
+        }
+
+        Ok(profile.load_balancing_policy(lbpbuilder.build()))
+    }
+}
+
+/// Speculative execution policy selected in C#.
+///
+/// Passed over FFI as a raw `u8`, so that unknown values can be rejected
+/// instead of being undefined behaviour.
+/// Any changes to this enum must be mirrored in the corresponding C# enum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum SpeculativeExecutionKind {
+    None = 0,
+    /// Speculative executions separated by a fixed delay.
+    Constant = 1,
+    /// Speculative executions started once a request is slower than a percentile of
+    /// the latencies measured by the session.
+    Percentile = 2,
+}
+
+impl TryFrom<u8> for SpeculativeExecutionKind {
+    type Error = SessionConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Constant),
+            2 => Ok(Self::Percentile),
+            _ => Err(SessionConfigError::UnknownSpeculativeExecutionPolicy(value)),
+        }
+    }
+}
+
+/// Speculative execution options passed from C#.
+///
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct BridgedSpeculativeExecutionConfig {
+    /// Raw [`SpeculativeExecutionKind`].
+    kind: u8,
+
+    /// Maximum number of speculative executions per request. Values <= 0 disable them.
+    max_retry_count: i32,
+
+    /// Delay before each speculative execution, in milliseconds.
+    /// Only used by [`SpeculativeExecutionKind::Constant`].
+    retry_interval_millis: i64,
+
+    /// Latency percentile after which a speculative execution is started, in (0, 100).
+    /// Only used by [`SpeculativeExecutionKind::Percentile`].
+    percentile: f64,
+}
+
+impl BridgedSpeculativeExecutionConfig {
+    /// Returns the configured profile builder.
+    ///
+    /// The Rust driver only ever runs speculative executions of idempotent statements,
+    /// so non-idempotent statements are unaffected by this policy.
+    ///
+    /// Fails if the kind is unknown, if constant speculative executions are enabled with
+    /// a non-positive delay, which would send every copy of a request at once,
+    /// or if the percentile is out of range.
+    pub(crate) fn apply_to_profile(
+        self,
+        profile: ExecutionProfileBuilder,
+    ) -> Result<ExecutionProfileBuilder, SessionConfigError> {
+        let max_retry_count = self.max_retry_count.max(0) as usize;
+        let policy: Option<Arc<dyn SpeculativeExecutionPolicy>> =
+            match SpeculativeExecutionKind::try_from(self.kind)? {
+                SpeculativeExecutionKind::None => None,
+                _ if max_retry_count == 0 => None,
+                SpeculativeExecutionKind::Constant => {
+                    if self.retry_interval_millis <= 0 {
+                        return Err(SessionConfigError::InvalidSpeculativeExecutionDelay(
+                            self.retry_interval_millis,
+                        ));
+                    }
+                    Some(Arc::new(SimpleSpeculativeExecutionPolicy {
+                        max_retry_count,
+                        retry_interval: Duration::from_millis(self.retry_interval_millis as u64),
+                    }))
+                }
+                SpeculativeExecutionKind::Percentile => {
+                    // Also rejects NaN.
+                    if !(self.percentile > 0.0 && self.percentile < 100.0) {
+                        return Err(SessionConfigError::InvalidSpeculativeExecutionPercentile(
+                            self.percentile,
+                        ));
+                    }
+                    Some(Arc::new(PercentileSpeculativeExecutionPolicy {
+                        max_retry_count,
+                        percentile: self.percentile,
+                    }))
+                }
+            };
+        Ok(profile.speculative_execution_policy(policy))
+    }
+}
+
+/// Retry policy selected in C#.
+///
+/// Passed over FFI as a raw `u8`, so that unknown values can be rejected
+/// instead of being undefined behaviour.
+/// Any changes to this enum must be mirrored in the corresponding C# enum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum RetryPolicyKind {
+    Default = 0,
+    Fallthrough = 1,
+    DowngradingConsistency = 2,
+    /// A policy implemented in C#, see [`BridgedCustomRetryPolicy`].
+    Custom = 3,
+}
+
+impl TryFrom<u8> for RetryPolicyKind {
+    type Error = SessionConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Default),
+            1 => Ok(Self::Fallthrough),
+            2 => Ok(Self::DowngradingConsistency),
+            3 => Ok(Self::Custom),
+            _ => Err(SessionConfigError::UnknownRetryPolicy(value)),
         }
+    }
+}
+
+impl RetryPolicyKind {
+    /// Returns the selected policy. `custom` is only used by [`RetryPolicyKind::Custom`].
+    pub(crate) fn into_policy(
+        self,
+        custom: BridgedCustomRetryPolicy,
+    ) -> Result<Arc<dyn RetryPolicy>, SessionConfigError> {
+        match self.into_builtin_policy() {
+            Some(policy) => Ok(policy),
+            None => custom
+                .into_policy()
+                .ok_or(SessionConfigError::MissingCustomRetryPolicy),
+        }
+    }
+
+    /// Returns the selected policy implemented by the Rust driver,
+    /// or `None` for [`RetryPolicyKind::Custom`].
+    pub(crate) fn into_builtin_policy(self) -> Option<Arc<dyn RetryPolicy>> {
+        match self {
+            Self::Default => Some(Arc::new(DefaultRetryPolicy::new())),
+            Self::Fallthrough => Some(Arc::new(FallthroughRetryPolicy::new())),
+            Self::DowngradingConsistency => {
+                Some(Arc::new(DowngradingConsistencyRetryPolicy::new()))
+            }
+            Self::Custom => None,
+        }
+    }
+}
+/// Compression of CQL frames selected in C#.
+///
+/// Passed over FFI as a raw `u8`, so that unknown values can be rejected
+/// instead of being undefined behaviour. The discriminants match the C# `CompressionType`.
+/// Any changes to this enum must be mirrored in the corresponding C# enum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum CompressionKind {
+    None = 0,
+    Snappy = 1,
+    Lz4 = 2,
+}
+
+impl TryFrom<u8> for CompressionKind {
+    type Error = SessionConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Snappy),
+            2 => Ok(Self::Lz4),
+            _ => Err(SessionConfigError::UnknownCompression(value)),
+        }
+    }
+}
+
+impl CompressionKind {
+    /// Returns `builder` set to compress frames with the selected algorithm.
+    ///
+    /// Compression is only used if the node supports the algorithm,
+    /// otherwise the connection silently falls back to uncompressed frames.
+    fn apply_to_builder(self, builder: SessionBuilder) -> SessionBuilder {
+        builder.compression(match self {
+            Self::None => None,
+            Self::Snappy => Some(Compression::Snappy),
+            Self::Lz4 => Some(Compression::Lz4),
+        })
+    }
+}
 
-        let profile_handle = ExecutionProfile::builder()
-            .load_balancing_policy(lbpbuilder.build())
-            .build()
-            .into_handle();
+/// Plain-text credentials passed from C#, used to log in with the SASL PLAIN mechanism.
+///
+/// A null username disables authentication.
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+pub(crate) struct BridgedCredentials<'a> {
+    username: CSharpStr<'a>,
+    password: CSharpStr<'a>,
+}
 
-        builder.default_execution_profile_handle(profile_handle)
+// Credentials must never end up in logs, so only their presence is printed.
+impl std::fmt::Debug for BridgedCredentials<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BridgedCredentials")
+            .field("is_set", &self.username.as_cstr().is_some())
+            .finish_non_exhaustive()
     }
 }
+
+impl BridgedCredentials<'_> {
+    /// Returns the builder with the Rust driver's plain-text authenticator enabled, if a username is set.
+    /// The builder copies both strings, so they only need to stay valid for this call.
+    ///
+    /// Fails if the username is set but empty, which no server would accept.
+    fn apply_to_builder(
+        self,
+        builder: SessionBuilder,
+    ) -> Result<SessionBuilder, SessionConfigError> {
+        let Some(username) = self.username.as_cstr() else {
+            return Ok(builder);
+        };
+        if username.is_empty() {
+            return Err(SessionConfigError::EmptyUsername);
+        }
+        let password = self.password.as_cstr().unwrap_or_default();
+        Ok(builder.user(username.to_str().unwrap(), password.to_str().unwrap()))
+    }
+}
+
 /// Output of [`BridgedSessionConfig::into_session_builder`]: a fully-configured
 /// [`SessionBuilder`] together with the URI and keyspace it was built from,
 /// borrowed directly from the C#-managed config memory.
@@ -118,7 +520,7 @@ pub(crate) struct BridgedSessionConfigResult<'a> {
 ///
 /// Any changes to this struct must be mirrored in the corresponding C# struct.
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug)]
 pub(crate) struct BridgedSessionConfig<'a> {
     /// Contact point URIs, comma-separated.
     uri: CSharpStr<'a>,
@@ -132,7 +534,39 @@ pub(crate) struct BridgedSessionConfig<'a> {
     /// TCP socket options.
     tcp: BridgedTcpConfig,
 
-    load_balancing_policy: BridgedLoadBalancingPolicy<'a>,
+    /// Connection-level options.
+    connection: BridgedConnectionConfig,
+
+    /// Whether to avoid connecting to the shard-aware port of Scylla nodes, e.g. when it is
+    /// firewalled. Connections are then assigned to shards by the node, so reaching every shard
+    /// may take more attempts.
+    disallow_shard_aware_port: FFIBool,
+
+    /// Compression of CQL frames, a [`CompressionKind`] discriminant.
+    compression: u8,
+
+    /// How long to wait for schema agreement after schema changes and in
+    /// `session_await_schema_agreement`, in milliseconds. Non-positive values keep the default.
+    schema_agreement_timeout_millis: i64,
+
+    /// Default execution profile, used by requests without a profile of their own.
+    default_profile: BridgedExecutionProfileConfig<'a>,
+
+    /// Address translator implemented in C#, if any.
+    address_translator: BridgedAddressTranslator,
+
+    /// Nodes the driver may connect to.
+    host_filter: BridgedHostFilter<'a>,
+
+    /// TLS options.
+    ssl: BridgedSslOptions<'a>,
+
+    /// Plain-text credentials.
+    credentials: BridgedCredentials<'a>,
+
+    /// Authenticator implemented in C#, if any. Set instead of `credentials`
+    /// for SASL mechanisms other than PLAIN.
+    authenticator: BridgedAuthenticator,
 }
 
 impl<'a> BridgedSessionConfig<'a> {
@@ -141,7 +575,10 @@ impl<'a> BridgedSessionConfig<'a> {
     ///
     /// This is the single place where all session configuration is applied, so
     /// adding new options only requires changes here and in the struct definition.
-    pub(crate) fn into_session_builder(self) -> BridgedSessionConfigResult<'a> {
+    /// Invalid combinations of options are reported as [`SessionConfigError`].
+    pub(crate) fn into_session_builder(
+        self,
+    ) -> Result<BridgedSessionConfigResult<'a>, SessionConfigError> {
         let uri = self.uri.as_cstr().unwrap().to_str().unwrap();
         let keyspace = self.keyspace.as_cstr().unwrap().to_str().unwrap();
 
@@ -160,17 +597,42 @@ impl<'a> BridgedSessionConfig<'a> {
         }
 
         builder = self.tcp.apply_to_builder(builder);
-        builder = self.load_balancing_policy.apply_to_builder(builder);
+        builder = self.connection.apply_to_builder(builder);
+        builder = builder.disallow_shard_aware_port(self.disallow_shard_aware_port.into());
+        builder = CompressionKind::try_from(self.compression)?.apply_to_builder(builder);
+
+        if self.schema_agreement_timeout_millis > 0 {
+            builder = builder.schema_agreement_timeout(Duration::from_millis(
+                self.schema_agreement_timeout_millis as u64,
+            ));
+        }
+
+        if let Some(translator) = self.address_translator.into_translator() {
+            builder = builder.address_translator(translator);
+        }
+
+        if let Some(host_filter) = self.host_filter.into_host_filter()? {
+            builder = builder.host_filter(host_filter);
+        }
+
+        builder = self.ssl.apply_to_builder(builder)?;
+        builder = self.credentials.apply_to_builder(builder)?;
+        if let Some(provider) = self.authenticator.into_provider() {
+            builder = builder.authenticator_provider(provider);
+        }
+
+        let profile = self.default_profile.into_profile()?;
+        builder = builder.default_execution_profile_handle(profile.into_handle());
 
         let identity = SelfIdentity::new()
             .with_custom_driver_name(DEFAULT_DRIVER_NAME)
             .with_custom_driver_version(DEFAULT_DRIVER_VERSION);
         builder = builder.custom_identity(identity);
 
-        BridgedSessionConfigResult {
+        Ok(BridgedSessionConfigResult {
             uri,
             keyspace,
             builder,
-        }
+        })
     }
 }
@@ -0,0 +1,273 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use rustls::client::WebPkiServerVerifier;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::ring;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{
+    CertificateError, ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme,
+    SupportedProtocolVersion,
+};
+use scylla::client::session_builder::SessionBuilder;
+
+use crate::error_conversion::SessionConfigError;
+use crate::ffi::{FFIBool, FFISlice};
+
+/// TLS protocol versions that may be negotiated, as a bitmask of these flags.
+/// An empty mask allows every version supported by rustls.
+///
+/// Any changes to these flags must be mirrored in the corresponding C# enum.
+const TLS_PROTOCOL_TLS12: u8 = 0x01;
+const TLS_PROTOCOL_TLS13: u8 = 0x02;
+
+/// TLS options passed from C#.
+///
+/// TLS is implemented with rustls (through the Rust driver's `rustls-023` feature), using
+/// the `ring` crypto provider, rather than with OpenSSL, so that the native library does not
+/// depend on the OpenSSL version installed on the host.
+///
+/// Certificates and keys are PEM-encoded, and each of them is given either inline or as the
+/// path of a PEM file, but not both. Files are read and everything is parsed when the session
+/// builder is created, so the slices only need to stay valid for the duration of `session_create`.
+/// Any changes to this struct must be mirrored in the corresponding C# struct.
+#[repr(C)]
+pub(crate) struct BridgedSslOptions<'a> {
+    enabled: FFIBool,
+
+    /// Certificates of the trusted certificate authorities. If empty, the platform trust store is used.
+    ca_certificates_pem: FFISlice<'a, u8>,
+
+    /// Client certificate chain, or empty for no client authentication.
+    client_certificate_pem: FFISlice<'a, u8>,
+
+    /// Private key of the client certificate, or empty for no client authentication.
+    client_key_pem: FFISlice<'a, u8>,
+
+    /// UTF-8 path of a PEM file holding `ca_certificates_pem`, or empty.
+    /// If both are empty, the platform trust store is used.
+    ca_certificates_path: FFISlice<'a, u8>,
+
+    /// UTF-8 path of a PEM file holding `client_certificate_pem`, or empty.
+    client_certificate_path: FFISlice<'a, u8>,
+
+    /// UTF-8 path of a PEM file holding `client_key_pem`, or empty.
+    client_key_path: FFISlice<'a, u8>,
+
+    /// Whether to check that the certificate of a node is valid for its address.
+    /// The chain of trust is verified regardless.
+    verify_hostname: FFIBool,
+
+    /// Allowed protocol versions, a bitmask of `TLS_PROTOCOL_*` flags.
+    protocols: u8,
+}
+
+// Certificates and keys are deliberately left out, so that the key never ends up in logs.
+impl Debug for BridgedSslOptions<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BridgedSslOptions")
+            .field("enabled", &self.enabled)
+            .field("verify_hostname", &self.verify_hostname)
+            .field("protocols", &self.protocols)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BridgedSslOptions<'_> {
+    /// Enables TLS on `builder` if requested, and returns it.
+    ///
+    /// Fails if a file cannot be read, a certificate or the key cannot be parsed,
+    /// or the options are inconsistent.
+    pub(crate) fn apply_to_builder(
+        self,
+        builder: SessionBuilder,
+    ) -> Result<SessionBuilder, SessionConfigError> {
+        if !bool::from(self.enabled) {
+            return Ok(builder);
+        }
+
+        let config = self.into_client_config()?;
+        Ok(builder.tls_context(Some(Arc::new(config))))
+    }
+
+    fn into_client_config(self) -> Result<ClientConfig, SessionConfigError> {
+        let ca_pem = pem_data(
+            "CA certificates",
+            self.ca_certificates_pem.as_slice(),
+            self.ca_certificates_path.as_slice(),
+        )?;
+        let certificate_pem = pem_data(
+            "client certificate",
+            self.client_certificate_pem.as_slice(),
+            self.client_certificate_path.as_slice(),
+        )?;
+        let key_pem = pem_data(
+            "client private key",
+            self.client_key_pem.as_slice(),
+            self.client_key_path.as_slice(),
+        )?;
+
+        let provider = Arc::new(ring::default_provider());
+        let versions = protocol_versions(self.protocols)?;
+        let roots = Arc::new(root_cert_store(&ca_pem)?);
+
+        let builder = ClientConfig::builder_with_provider(provider.clone())
+            .with_protocol_versions(&versions)
+            .map_err(|e| SessionConfigError::Tls(e.to_string()))?;
+        let builder = if self.verify_hostname.into() {
+            builder.with_root_certificates(roots)
+        } else {
+            let verifier = WebPkiServerVerifier::builder_with_provider(roots, provider)
+                .build()
+                .map_err(|e| SessionConfigError::Tls(e.to_string()))?;
+            builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(NoHostnameVerification(verifier)))
+        };
+
+        match (certificate_pem.is_empty(), key_pem.is_empty()) {
+            (true, true) => Ok(builder.with_no_client_auth()),
+            (false, false) => {
+                let chain = CertificateDer::pem_slice_iter(&certificate_pem)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| SessionConfigError::InvalidClientCertificate(e.to_string()))?;
+                if chain.is_empty() {
+                    return Err(SessionConfigError::InvalidClientCertificate(
+                        "no certificate found".to_owned(),
+                    ));
+                }
+                let key = PrivateKeyDer::from_pem_slice(&key_pem)
+                    .map_err(|e| SessionConfigError::InvalidClientKey(e.to_string()))?;
+                builder
+                    .with_client_auth_cert(chain, key)
+                    .map_err(|e| SessionConfigError::Tls(e.to_string()))
+            }
+            _ => Err(SessionConfigError::IncompleteClientIdentity),
+        }
+    }
+}
+
+/// Returns the PEM data of `what`, passed either `inline` or as the `path` of a file,
+/// which is read now. Empty if neither is set.
+fn pem_data<'a>(
+    what: &'static str,
+    inline: &'a [u8],
+    path: &[u8],
+) -> Result<Cow<'a, [u8]>, SessionConfigError> {
+    if path.is_empty() {
+        return Ok(Cow::Borrowed(inline));
+    }
+    if !inline.is_empty() {
+        return Err(SessionConfigError::ConflictingTlsSources(what));
+    }
+
+    let path = std::str::from_utf8(path)
+        .map(PathBuf::from)
+        .map_err(|_| SessionConfigError::InvalidTlsFilePath(what))?;
+    std::fs::read(&path)
+        .map(Cow::Owned)
+        .map_err(|source| SessionConfigError::UnreadableTlsFile { what, path, source })
+}
+
+fn protocol_versions(
+    protocols: u8,
+) -> Result<Vec<&'static SupportedProtocolVersion>, SessionConfigError> {
+    if protocols & !(TLS_PROTOCOL_TLS12 | TLS_PROTOCOL_TLS13) != 0 {
+        return Err(SessionConfigError::UnknownTlsProtocols(protocols));
+    }
+    if protocols == 0 {
+        return Ok(rustls::ALL_VERSIONS.to_vec());
+    }
+
+    let mut versions = Vec::with_capacity(2);
+    if protocols & TLS_PROTOCOL_TLS13 != 0 {
+        versions.push(&rustls::version::TLS13);
+    }
+    if protocols & TLS_PROTOCOL_TLS12 != 0 {
+        versions.push(&rustls::version::TLS12);
+    }
+    Ok(versions)
+}
+
+/// Builds the store of trusted certificate authorities from `ca_pem`,
+/// or from the platform trust store if it is empty.
+fn root_cert_store(ca_pem: &[u8]) -> Result<RootCertStore, SessionConfigError> {
+    let mut roots = RootCertStore::empty();
+
+    if ca_pem.is_empty() {
+        let native = rustls_native_certs::load_native_certs();
+        for error in &native.errors {
+            tracing::warn!("Failed to load a certificate from the platform trust store: {error}");
+        }
+        let (added, ignored) = roots.add_parsable_certificates(native.certs);
+        tracing::debug!(
+            "Loaded {added} certificates from the platform trust store, ignored {ignored}"
+        );
+        return Ok(roots);
+    }
+
+    for certificate in CertificateDer::pem_slice_iter(ca_pem) {
+        let certificate =
+            certificate.map_err(|e| SessionConfigError::InvalidCaCertificate(e.to_string()))?;
+        roots
+            .add(certificate)
+            .map_err(|e| SessionConfigError::InvalidCaCertificate(e.to_string()))?;
+    }
+    if roots.is_empty() {
+        return Err(SessionConfigError::InvalidCaCertificate(
+            "no certificate found".to_owned(),
+        ));
+    }
+    Ok(roots)
+}
+
+/// Verifies the chain of trust of node certificates like [`WebPkiServerVerifier`],
+/// but accepts certificates that are not valid for the address of the node.
+#[derive(Debug)]
+struct NoHostnameVerification(Arc<WebPkiServerVerifier>);
+
+impl ServerCertVerifier for NoHostnameVerification {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        match self
+            .0
+            .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)
+        {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+            )) => Ok(ServerCertVerified::assertion()),
+            result => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.0.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.0.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.supported_verify_schemes()
+    }
+}
@@ -9,17 +9,22 @@ use tokio::runtime::Runtime;
 
 use crate::error_conversion::{
     AlreadyExistsConstructor, AlreadyShutdownExceptionConstructor, ArgumentExceptionConstructor,
-    DeserializationExceptionConstructor, ErrorToException, FFIException,
-    FunctionFailureExceptionConstructor, InvalidArgumentExceptionConstructor,
+    ArgumentNullExceptionConstructor, AuthenticationExceptionConstructor,
+    DeserializationExceptionConstructor, DriverInternalErrorConstructor, ErrorToException,
+    FFIMaybeException, FunctionFailureExceptionConstructor, InvalidArgumentExceptionConstructor,
     InvalidConfigurationInQueryExceptionConstructor, InvalidQueryConstructor,
-    InvalidTypeExceptionConstructor, NoHostAvailableExceptionConstructor,
-    OperationTimedOutExceptionConstructor, PreparedQueryNotFoundExceptionConstructor,
-    RequestInvalidExceptionConstructor, RustExceptionConstructor,
+    InvalidTypeExceptionConstructor, IsBootstrappingExceptionConstructor,
+    NoHostAvailableExceptionConstructor, OperationTimedOutExceptionConstructor,
+    OverloadedExceptionConstructor, PreparedQueryNotFoundExceptionConstructor,
+    RateLimitReachedExceptionConstructor, ReadFailureExceptionConstructor,
+    ReadTimeoutExceptionConstructor, RequestInvalidExceptionConstructor, RustExceptionConstructor,
     SchemaAgreementRequiredHostAbsentExceptionConstructor,
     SchemaAgreementRowsResultExceptionConstructor, SchemaAgreementSingleRowExceptionConstructor,
     SchemaAgreementTimeoutExceptionConstructor, SerializationExceptionConstructor,
     SyntaxErrorExceptionConstructor, TraceRetrievalExceptionConstructor,
     TruncateExceptionConstructor, UnauthorizedExceptionConstructor,
+    UnavailableExceptionConstructor, WriteFailureExceptionConstructor,
+    WriteTimeoutExceptionConstructor,
 };
 use crate::ffi::{ArcFFI, BridgedOwnedSharedPtr, FFIGCHandle};
 
@@ -153,7 +158,7 @@ pub struct Tcb<R> {
     /// Function pointer type to complete a TaskCompletionSource with a result.
     complete_task: unsafe extern "C" fn(tcs: FFIGCHandle<Tcs<R>>, result: R),
     /// Function pointer type to fail a TaskCompletionSource with an exception handle.
-    fail_task: unsafe extern "C" fn(tcs: FFIGCHandle<Tcs<R>>, exception_handle: FFIException),
+    fail_task: unsafe extern "C" fn(tcs: FFIGCHandle<Tcs<R>>, exception: FFIMaybeException),
     /// Pointer to the collection of exception constructors.
     // SAFETY: The memory is a leaked unmanaged allocation on the C# side.
     // This guarantees that the pointer remains valid and is not moved or deallocated.
@@ -168,15 +173,23 @@ pub struct ExceptionConstructors {
     pub already_exists_constructor: AlreadyExistsConstructor,
     pub already_shutdown_exception_constructor: AlreadyShutdownExceptionConstructor,
     pub argument_exception_constructor: ArgumentExceptionConstructor,
+    pub argument_null_exception_constructor: ArgumentNullExceptionConstructor,
+    pub authentication_exception_constructor: AuthenticationExceptionConstructor,
     pub deserialization_exception_constructor: DeserializationExceptionConstructor,
+    pub driver_internal_error_constructor: DriverInternalErrorConstructor,
     pub function_failure_exception_constructor: FunctionFailureExceptionConstructor,
     pub invalid_argument_exception_constructor: InvalidArgumentExceptionConstructor,
     pub invalid_configuration_in_query_constructor: InvalidConfigurationInQueryExceptionConstructor,
     pub invalid_query_constructor: InvalidQueryConstructor,
     pub invalid_type_exception_constructor: InvalidTypeExceptionConstructor,
+    pub is_bootstrapping_exception_constructor: IsBootstrappingExceptionConstructor,
     pub no_host_available_exception_constructor: NoHostAvailableExceptionConstructor,
     pub operation_timed_out_exception_constructor: OperationTimedOutExceptionConstructor,
+    pub overloaded_exception_constructor: OverloadedExceptionConstructor,
     pub prepared_query_not_found_exception_constructor: PreparedQueryNotFoundExceptionConstructor,
+    pub rate_limit_reached_exception_constructor: RateLimitReachedExceptionConstructor,
+    pub read_failure_exception_constructor: ReadFailureExceptionConstructor,
+    pub read_timeout_exception_constructor: ReadTimeoutExceptionConstructor,
     pub request_invalid_exception_constructor: RequestInvalidExceptionConstructor,
     pub rust_exception_constructor: RustExceptionConstructor,
     pub schema_agreement_required_host_absent_exception_constructor:
@@ -191,6 +204,9 @@ pub struct ExceptionConstructors {
     pub trace_retrieval_exception_constructor: TraceRetrievalExceptionConstructor,
     pub truncate_exception_constructor: TruncateExceptionConstructor,
     pub unauthorized_exception_constructor: UnauthorizedExceptionConstructor,
+    pub unavailable_exception_constructor: UnavailableExceptionConstructor,
+    pub write_failure_exception_constructor: WriteFailureExceptionConstructor,
+    pub write_timeout_exception_constructor: WriteTimeoutExceptionConstructor,
 }
 
 impl<R> Tcb<R> {
@@ -202,14 +218,16 @@ impl<R> Tcb<R> {
     }
 
     /// Fails the task with the provided exception, consuming the TCB.
-    pub(crate) fn fail_task(self, exception: FFIException) {
+    fn fail_task(self, exception: FFIMaybeException) {
         unsafe {
             (self.fail_task)(self.tcs, exception);
         }
     }
 
+    /// Fails the task with the exception `e` converts to, consuming the TCB.
+    /// An already constructed `FFIException` can be passed as is.
     pub(crate) fn fail_sync(self, e: impl ErrorToException) {
-        let exception = e.to_exception(self.constructors);
+        let exception = FFIMaybeException::from_error(e, self.constructors);
         self.fail_task(exception);
     }
 }
@@ -253,24 +271,12 @@ impl BridgedFuture {
 
                 // On error, fail the task with exception.
                 Ok(Err(err)) => {
-                    let exception_ptr = err.to_exception(tcb.constructors);
-                    tcb.fail_task(exception_ptr);
+                    tcb.fail_sync(err);
                 }
                 // On panic, fail the task with the panic message.
                 Err(panic) => {
-                    // Panic payloads can be of any type, but `panic!()` macro only uses &str or String.
-                    let panic_msg = if let Some(s) = panic.downcast_ref::<&str>() {
-                        *s
-                    } else if let Some(s) = panic.downcast_ref::<String>() {
-                        s.as_str()
-                    } else {
-                        "Weird panic with non-string payload"
-                    };
-                    let exception_ptr = tcb
-                        .constructors
-                        .rust_exception_constructor
-                        .construct_from_rust(panic_msg);
-                    tcb.fail_task(exception_ptr);
+                    let exception = FFIMaybeException::from_panic(tcb.constructors, panic.as_ref());
+                    tcb.fail_task(exception);
                 }
             }
         });
@@ -0,0 +1,603 @@
+use bytes::Bytes;
+use scylla::frame::response::result::ColumnType;
+use uuid::Uuid;
+
+use crate::collection::{
+    BridgedCollection, CompositeValue, OpenComposite, open_result_cell, read_item, write_composite,
+};
+use crate::error_conversion::{FFIMaybeException, RowAccessError};
+use crate::ffi::{
+    ArcFFI, BridgedBorrowedSharedPtr, BridgedOwnedSharedPtr, FFI, FFIBool, FFISlice, FFIStr,
+    FromArc,
+};
+use crate::query_result::BridgedQueryResult;
+use crate::row::write_cell;
+use crate::row_set::column_type_to_code;
+use crate::task::ExceptionConstructors;
+
+/// Splits a serialized UDT or tuple into `field_count` fields.
+///
+/// Values written before a UDT was extended with `ALTER TYPE ... ADD` lack the trailing
+/// fields, so missing fields are read as null. Returns `None` for a malformed value.
+fn split_fields(value: &Bytes, field_count: usize) -> Option<Box<[Option<Bytes>]>> {
+    let mut offset = 0;
+    let mut fields = Vec::with_capacity(field_count);
+    while offset < value.len() {
+        if fields.len() == field_count {
+            return None;
+        }
+        fields.push(read_item(value, &mut offset)?);
+    }
+    fields.resize(field_count, None);
+
+    Some(fields.into_boxed_slice())
+}
+
+/// A user defined type value. Fields are addressed by their index in the type definition.
+#[derive(Debug)]
+pub(crate) struct BridgedUdtView(CompositeValue);
+
+impl FFI for BridgedUdtView {
+    type Origin = FromArc;
+}
+
+impl OpenComposite for BridgedUdtView {
+    fn open(
+        name: String,
+        typ: &ColumnType<'static>,
+        value: Option<&Bytes>,
+    ) -> Result<Option<Self>, RowAccessError> {
+        let ColumnType::UserDefinedType { definition, .. } = typ else {
+            return Err(RowAccessError::UnexpectedType {
+                name,
+                expected: "UDT",
+            });
+        };
+
+        let Some(value) = value else {
+            return Ok(None);
+        };
+
+        let Some(fields) = split_fields(value, definition.field_types.len()) else {
+            return Err(RowAccessError::MalformedValue { name });
+        };
+
+        let (field_names, field_types) = definition
+            .field_types
+            .iter()
+            .map(|(field_name, field_type)| (field_name.to_string(), field_type.clone()))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        Ok(Some(Self(CompositeValue::new(
+            name,
+            field_names.into(),
+            field_types.into(),
+            fields,
+        ))))
+    }
+}
+
+/// A tuple value.
+#[derive(Debug)]
+pub(crate) struct BridgedTupleView(CompositeValue);
+
+impl FFI for BridgedTupleView {
+    type Origin = FromArc;
+}
+
+impl OpenComposite for BridgedTupleView {
+    fn open(
+        name: String,
+        typ: &ColumnType<'static>,
+        value: Option<&Bytes>,
+    ) -> Result<Option<Self>, RowAccessError> {
+        let ColumnType::Tuple(field_types) = typ else {
+            return Err(RowAccessError::UnexpectedType {
+                name,
+                expected: "tuple",
+            });
+        };
+
+        let Some(value) = value else {
+            return Ok(None);
+        };
+
+        let Some(fields) = split_fields(value, field_types.len()) else {
+            return Err(RowAccessError::MalformedValue { name });
+        };
+
+        Ok(Some(Self(CompositeValue::new(
+            name,
+            Box::new([]),
+            field_types.clone().into(),
+            fields,
+        ))))
+    }
+}
+
+/// Opens the UDT cell at (`row`, `col`) of a result.
+/// C# becomes the owner of the view and must release it with `udt_view_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_udt(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedUdtView>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        write_composite(
+            open_result_cell(result, row, col),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+/// Opens the tuple cell at (`row`, `col`) of a result.
+/// C# becomes the owner of the view and must release it with `tuple_view_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn result_get_tuple(
+    result_ptr: BridgedBorrowedSharedPtr<'_, BridgedQueryResult>,
+    row: usize,
+    col: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedTupleView>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let result =
+            ArcFFI::as_ref(result_ptr).expect("valid and non-null BridgedQueryResult pointer");
+        write_composite(
+            open_result_cell(result, row, col),
+            out,
+            out_is_null,
+            constructors,
+        )
+    })
+}
+
+// UDT view accessors.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_free(udt_ptr: BridgedOwnedSharedPtr<BridgedUdtView>) {
+    ArcFFI::free(udt_ptr);
+    tracing::trace!("[FFI] BridgedUdtView freed");
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_field_count(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    out_count: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        *out_count = udt.0.items_count();
+        FFIMaybeException::ok()
+    })
+}
+
+/// Writes the name of field `index`. The name borrows from the view.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_field_name<'udt>(
+    udt_ptr: BridgedBorrowedSharedPtr<'udt, BridgedUdtView>,
+    index: usize,
+    out_name: &mut FFIStr<'udt>,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        match udt.0.item_name(index) {
+            Ok(name) => {
+                *out_name = FFIStr::new(name.unwrap_or_default());
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Returns the type code of field `index`.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_field_type(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out_type_code: &mut u8,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        match udt.0.checked_item_type(index) {
+            Ok(typ) => {
+                *out_type_code = column_type_to_code(typ);
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Opens field `index`, which must be a UDT.
+/// C# becomes the owner of the nested view and must release it with `udt_view_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_udt(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedUdtView>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        write_composite(udt.0.open_item(index), out, out_is_null, constructors)
+    })
+}
+
+/// Opens field `index`, which must be a tuple.
+/// C# becomes the owner of the view and must release it with `tuple_view_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_tuple(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedTupleView>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        write_composite(udt.0.open_item(index), out, out_is_null, constructors)
+    })
+}
+
+/// Opens field `index`, which must be a (frozen) collection.
+/// C# becomes the owner of the collection and must release it with `collection_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_collection(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedCollection>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        write_composite(udt.0.open_item(index), out, out_is_null, constructors)
+    })
+}
+
+// Typed field getters, with the same semantics as the typed cell getters of the result.
+// Fields missing from values written before the UDT was extended read as null.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_int32(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        write_cell(udt.0.get::<i32>(index), out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_int64(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        write_cell(udt.0.get::<i64>(index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `text` or `ascii` field. The string borrows from the view.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_text<'udt>(
+    udt_ptr: BridgedBorrowedSharedPtr<'udt, BridgedUdtView>,
+    index: usize,
+    out: &mut FFIStr<'udt>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        let field = udt.0.get::<&str>(index).map(|v| v.map(FFIStr::new));
+        write_cell(field, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_bool(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut FFIBool,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        let field = udt.0.get::<bool>(index).map(|v| v.map(FFIBool::from));
+        write_cell(field, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_double(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut f64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        write_cell(udt.0.get::<f64>(index), out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_float(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut f32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        write_cell(udt.0.get::<f32>(index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `blob` field. The bytes borrow from the view.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_blob<'udt>(
+    udt_ptr: BridgedBorrowedSharedPtr<'udt, BridgedUdtView>,
+    index: usize,
+    out: &mut FFISlice<'udt, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        let field = udt.0.get::<&[u8]>(index).map(|v| v.map(FFISlice::new));
+        write_cell(field, out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `uuid` or `timeuuid` field as 16 big-endian bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn udt_view_get_uuid(
+    udt_ptr: BridgedBorrowedSharedPtr<'_, BridgedUdtView>,
+    index: usize,
+    out: &mut [u8; 16],
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let udt = ArcFFI::as_ref(udt_ptr).expect("valid and non-null BridgedUdtView pointer");
+        let field = udt.0.get::<Uuid>(index).map(|v| v.map(Uuid::into_bytes));
+        write_cell(field, out, out_is_null, constructors)
+    })
+}
+
+// Tuple view accessors.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_free(tuple_ptr: BridgedOwnedSharedPtr<BridgedTupleView>) {
+    ArcFFI::free(tuple_ptr);
+    tracing::trace!("[FFI] BridgedTupleView freed");
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_field_count(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    out_count: &mut usize,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        *out_count = tuple.0.items_count();
+        FFIMaybeException::ok()
+    })
+}
+
+/// Returns the type code of field `index`.
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_field_type(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out_type_code: &mut u8,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        match tuple.0.checked_item_type(index) {
+            Ok(typ) => {
+                *out_type_code = column_type_to_code(typ);
+                FFIMaybeException::ok()
+            }
+            Err(e) => FFIMaybeException::from_error(e, constructors),
+        }
+    })
+}
+
+/// Opens field `index`, which must be a UDT.
+/// C# becomes the owner of the view and must release it with `udt_view_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_udt(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedUdtView>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        write_composite(tuple.0.open_item(index), out, out_is_null, constructors)
+    })
+}
+
+/// Opens field `index`, which must be a tuple.
+/// C# becomes the owner of the nested view and must release it with `tuple_view_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_tuple(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedTupleView>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        write_composite(tuple.0.open_item(index), out, out_is_null, constructors)
+    })
+}
+
+/// Opens field `index`, which must be a (frozen) collection.
+/// C# becomes the owner of the collection and must release it with `collection_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_collection(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut BridgedOwnedSharedPtr<BridgedCollection>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        write_composite(tuple.0.open_item(index), out, out_is_null, constructors)
+    })
+}
+
+// Typed field getters, with the same semantics as the typed cell getters of the result.
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_int32(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut i32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        write_cell(tuple.0.get::<i32>(index), out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_int64(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut i64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        write_cell(tuple.0.get::<i64>(index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `text` or `ascii` field. The string borrows from the view.
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_text<'tup>(
+    tuple_ptr: BridgedBorrowedSharedPtr<'tup, BridgedTupleView>,
+    index: usize,
+    out: &mut FFIStr<'tup>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        let field = tuple.0.get::<&str>(index).map(|v| v.map(FFIStr::new));
+        write_cell(field, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_bool(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut FFIBool,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        let field = tuple.0.get::<bool>(index).map(|v| v.map(FFIBool::from));
+        write_cell(field, out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_double(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut f64,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        write_cell(tuple.0.get::<f64>(index), out, out_is_null, constructors)
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_float(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut f32,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        write_cell(tuple.0.get::<f32>(index), out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `blob` field. The bytes borrow from the view.
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_blob<'tup>(
+    tuple_ptr: BridgedBorrowedSharedPtr<'tup, BridgedTupleView>,
+    index: usize,
+    out: &mut FFISlice<'tup, u8>,
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        let field = tuple.0.get::<&[u8]>(index).map(|v| v.map(FFISlice::new));
+        write_cell(field, out, out_is_null, constructors)
+    })
+}
+
+/// Reads a `uuid` or `timeuuid` field as 16 big-endian bytes.
+#[unsafe(no_mangle)]
+pub extern "C" fn tuple_view_get_uuid(
+    tuple_ptr: BridgedBorrowedSharedPtr<'_, BridgedTupleView>,
+    index: usize,
+    out: &mut [u8; 16],
+    out_is_null: &mut FFIBool,
+    constructors: &'static ExceptionConstructors,
+) -> FFIMaybeException {
+    FFIMaybeException::catch_panics(constructors, || {
+        let tuple = ArcFFI::as_ref(tuple_ptr).expect("valid and non-null BridgedTupleView pointer");
+        let field = tuple.0.get::<Uuid>(index).map(|v| v.map(Uuid::into_bytes));
+        write_cell(field, out, out_is_null, constructors)
+    })
+}
@@ -0,0 +1,113 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Collections.Generic;
+using System.Linq;
+using System.Net;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Translates the addresses advertised by the nodes of a two-node cluster with a custom translator.
+    /// The driver connects to the contact point directly, so the translator is asked about the other node.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class AddressTranslatorTests : SharedClusterTest
+    {
+        // An address from TEST-NET-1 (RFC 5737), which is never routable.
+        private static readonly IPAddress Unreachable = IPAddress.Parse("192.0.2.1");
+
+        public AddressTranslatorTests() : base(2, false)
+        {
+        }
+
+        private IPAddress NodeAddress(int node) => IPAddress.Parse(TestCluster.ClusterIpPrefix + node);
+
+        [Test]
+        public void CustomTranslator_Should_BeCalled_And_KeepNodesReachable_When_AddressIsUnchanged()
+        {
+            var translator = new RewritingAddressTranslator(null, null);
+            var cluster = GetNewTemporaryCluster(b => b.WithAddressTranslator(translator));
+            var session = cluster.Connect();
+
+            var queried = new HashSet<IPAddress>();
+            for (var i = 0; i < 20; i++)
+            {
+                queried.Add(session.Execute("SELECT key FROM system.local").Info.QueriedHost.Address);
+            }
+
+            CollectionAssert.AreEquivalent(new[] { NodeAddress(1), NodeAddress(2) }, queried);
+            lock (translator.Calls)
+            {
+                CollectionAssert.Contains(translator.Calls.Select(call => call.Address), NodeAddress(2));
+            }
+        }
+
+        [Test]
+        public void CustomTranslator_Should_RewriteAdvertisedAddress()
+        {
+            var translator = new RewritingAddressTranslator(NodeAddress(2), Unreachable);
+            var cluster = GetNewTemporaryCluster(b => b.WithAddressTranslator(translator));
+            var session = cluster.Connect();
+
+            for (var i = 0; i < 20; i++)
+            {
+                var rs = session.Execute("SELECT key FROM system.local");
+                Assert.AreEqual(NodeAddress(1), rs.Info.QueriedHost.Address);
+            }
+
+            var hosts = cluster.Metadata.AllHosts();
+            Assert.AreEqual(2, hosts.Count);
+            var translated = hosts.Single(h => !h.Address.Address.Equals(NodeAddress(1)));
+            Assert.AreEqual(Unreachable, translated.Address.Address);
+            Assert.IsFalse(translated.IsConnected);
+            lock (translator.Calls)
+            {
+                CollectionAssert.Contains(translator.Calls.Select(call => call.Address), NodeAddress(2));
+            }
+        }
+
+        /// <summary>
+        /// Rewrites the address of one node, keeping the port, and records every address it is asked about.
+        /// </summary>
+        private sealed class RewritingAddressTranslator : IAddressTranslator
+        {
+            private readonly IPAddress _from;
+            private readonly IPAddress _to;
+
+            public RewritingAddressTranslator(IPAddress from, IPAddress to)
+            {
+                _from = from;
+                _to = to;
+            }
+
+            public List<IPEndPoint> Calls { get; } = new List<IPEndPoint>();
+
+            public IPEndPoint Translate(IPEndPoint address)
+            {
+                lock (Calls)
+                {
+                    Calls.Add(address);
+                }
+                return address.Address.Equals(_from) ? new IPEndPoint(_to, address.Port) : address;
+            }
+        }
+    }
+}
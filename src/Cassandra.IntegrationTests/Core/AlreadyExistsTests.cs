@@ -0,0 +1,56 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class AlreadyExistsTests : SharedClusterTest
+    {
+        public AlreadyExistsTests() : base(1, true)
+        {
+        }
+
+        [Test]
+        public void CreateKeyspace_When_KeyspaceExists_ThrowsKeyspaceOnlyConflict()
+        {
+            var ex = Assert.Throws<AlreadyExistsException>(() => Session.Execute(
+                $"CREATE KEYSPACE {KeyspaceName} WITH replication = {{'class': 'SimpleStrategy', 'replication_factor': 1}}"));
+
+            Assert.AreEqual(KeyspaceName, ex.Keyspace);
+            Assert.IsNull(ex.Table);
+            Assert.IsFalse(ex.WasTableCreation);
+        }
+
+        [Test]
+        public void CreateTable_When_TableExists_ThrowsTableConflict()
+        {
+            var tableName = "already_exists_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY)");
+
+            var ex = Assert.Throws<AlreadyExistsException>(() =>
+                Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY)"));
+
+            Assert.AreEqual(KeyspaceName, ex.Keyspace);
+            Assert.AreEqual(tableName, ex.Table);
+            Assert.IsTrue(ex.WasTableCreation);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+using System.Linq;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class BatchTests : SharedClusterTest
+    {
+        public BatchTests() : base(1, true)
+        {
+        }
+
+        private string CreateTable(string columns)
+        {
+            var tableName = "batch_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} ({columns})");
+            return tableName;
+        }
+
+        [Test]
+        [TestCase(BatchType.Logged)]
+        [TestCase(BatchType.Unlogged)]
+        public void Batch_WithSimpleAndBoundStatements_AppliesAll(BatchType batchType)
+        {
+            var tableName = CreateTable("id int PRIMARY KEY, value text");
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+
+            var batch = new BatchStatement()
+                .SetBatchType(batchType)
+                .Add(insert.Bind(1, "one"))
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, value) VALUES (?, ?)", 2, "two"))
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, value) VALUES (3, 'three')"));
+            batch.SetConsistencyLevel(ConsistencyLevel.One);
+            Session.Execute(batch);
+
+            var values = Session.Execute($"SELECT id, value FROM {tableName}")
+                .ToDictionary(row => row.GetValue<int>("id"), row => row.GetValue<string>("value"));
+            CollectionAssert.AreEquivalent(
+                new[] { (1, "one"), (2, "two"), (3, "three") },
+                values.Select(kv => (kv.Key, kv.Value)));
+        }
+
+        [Test]
+        public void Batch_Unconditional_ReturnsNoRows()
+        {
+            var tableName = CreateTable("id int PRIMARY KEY, value text");
+
+            var rs = Session.Execute(new BatchStatement()
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, value) VALUES (1, 'one')")));
+
+            Assert.AreEqual(0, rs.Columns.Length);
+            Assert.AreEqual(0, rs.Count());
+        }
+
+        [Test]
+        public void Batch_Conditional_ReturnsAppliedRow()
+        {
+            var tableName = CreateTable("id int, ck int, value text, PRIMARY KEY (id, ck)");
+
+            var rs = Session.Execute(new BatchStatement()
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, ck, value) VALUES (1, 1, 'one') IF NOT EXISTS"))
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, ck, value) VALUES (1, 2, 'two') IF NOT EXISTS")));
+
+            var rows = rs.ToList();
+            Assert.AreEqual(1, rows.Count);
+            Assert.AreEqual("[applied]", rs.Columns[0].Name);
+            Assert.IsTrue(rows[0].GetValue<bool>("[applied]"));
+        }
+
+        [Test]
+        public void Batch_Conditional_ReturnsExistingValues_When_NotApplied()
+        {
+            var tableName = CreateTable("id int, ck int, value text, PRIMARY KEY (id, ck)");
+            Session.Execute($"INSERT INTO {tableName} (id, ck, value) VALUES (1, 1, 'existing')");
+
+            var rs = Session.Execute(new BatchStatement()
+                .Add(new SimpleStatement($"UPDATE {tableName} SET value = 'new' WHERE id = 1 AND ck = 1 IF value = 'other'"))
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, ck, value) VALUES (1, 2, 'two') IF NOT EXISTS")));
+
+            var rows = rs.ToList();
+            Assert.Greater(rows.Count, 0);
+            Assert.IsFalse(rows[0].GetValue<bool>("[applied]"));
+            var conflict = rows.Single(row => row.GetValue<int?>("ck") == 1);
+            Assert.AreEqual("existing", conflict.GetValue<string>("value"));
+            Assert.AreEqual("existing", Session.Execute($"SELECT value FROM {tableName} WHERE id = 1 AND ck = 1").First().GetValue<string>("value"));
+        }
+
+        [Test]
+        public void Batch_WithoutServerWarnings_HasEmptyWarnings()
+        {
+            var tableName = CreateTable("id int PRIMARY KEY, value text");
+
+            var rs = Session.Execute(new BatchStatement()
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, value) VALUES (1, 'one')")));
+
+            Assert.IsNotNull(rs.Info.Warnings);
+            Assert.AreEqual(0, rs.Info.Warnings.Length);
+        }
+
+        [Test]
+        public void Batch_ExceedingSizeWarnThreshold_ExposesServerWarning()
+        {
+            var tableName = CreateTable("id int PRIMARY KEY, value text");
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            // Scylla warns about batches above 128 KiB by default, and Cassandra above 5 KiB.
+            var largeValue = new string('x', 64 * 1024);
+
+            var batch = new BatchStatement();
+            for (var i = 0; i < 4; i++)
+            {
+                batch.Add(insert.Bind(i, largeValue));
+            }
+            var rs = Session.Execute(batch);
+
+            Assert.IsNotEmpty(rs.Info.Warnings);
+            StringAssert.Contains("batch", rs.Info.Warnings[0].ToLowerInvariant());
+        }
+
+        [Test]
+        public void Batch_Counter_AppliesAllIncrements()
+        {
+            var tableName = CreateTable("id int PRIMARY KEY, value counter");
+            var increment = Session.Prepare($"UPDATE {tableName} SET value = value + ? WHERE id = ?");
+
+            var batch = new BatchStatement()
+                .SetBatchType(BatchType.Counter)
+                .Add(increment.Bind(2L, 1))
+                .Add(increment.Bind(3L, 1))
+                .Add(new SimpleStatement($"UPDATE {tableName} SET value = value + 5 WHERE id = 1"));
+            Session.Execute(batch);
+
+            var row = Session.Execute($"SELECT value FROM {tableName} WHERE id = 1").Single();
+            Assert.AreEqual(10L, row.GetValue<long>("value"));
+        }
+
+        [Test]
+        public void Batch_Counter_WithNonCounterStatement_IsRejected()
+        {
+            var counterTable = CreateTable("id int PRIMARY KEY, value counter");
+            var regularTable = CreateTable("id int PRIMARY KEY, value text");
+            var increment = Session.Prepare($"UPDATE {counterTable} SET value = value + ? WHERE id = ?");
+            var insert = Session.Prepare($"INSERT INTO {regularTable} (id, value) VALUES (?, ?)");
+
+            var batch = new BatchStatement()
+                .SetBatchType(BatchType.Counter)
+                .Add(increment.Bind(1L, 1))
+                .Add(insert.Bind(1, "one"));
+
+            var ex = Assert.Throws<InvalidQueryException>(() => Session.Execute(batch));
+            StringAssert.Contains("Statement 1 of the batch cannot be executed in it", ex.Message);
+            Assert.AreEqual(0, Session.Execute($"SELECT * FROM {counterTable}").Count());
+        }
+
+        [Test]
+        public void Batch_Counter_WithNonCounterSimpleStatement_IsRejectedByServer()
+        {
+            var counterTable = CreateTable("id int PRIMARY KEY, value counter");
+            var regularTable = CreateTable("id int PRIMARY KEY, value text");
+
+            // Simple statements are not classified by the driver, so the mismatch is only caught by the server.
+            var batch = new BatchStatement()
+                .SetBatchType(BatchType.Counter)
+                .Add(new SimpleStatement($"UPDATE {counterTable} SET value = value + 1 WHERE id = 1"))
+                .Add(new SimpleStatement($"INSERT INTO {regularTable} (id, value) VALUES (1, 'one')"));
+
+            var ex = Assert.Throws<InvalidQueryException>(() => Session.Execute(batch));
+            StringAssert.DoesNotContain("of the batch cannot be executed in it", ex.Message);
+            Assert.AreEqual(0, Session.Execute($"SELECT * FROM {counterTable}").Count());
+        }
+
+        [Test]
+        [TestCase(1)]
+        [TestCase(3)]
+        public void Batch_WithBoundStatementOfWrongValueCount_IsRejectedBeforeSending(int valueCount)
+        {
+            var tableName = CreateTable("id int PRIMARY KEY, value text");
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            var values = new object[] { 1, "one", "extra" }.Take(valueCount).ToArray();
+
+            var batch = new BatchStatement().Add(insert.Bind(values));
+
+            var ex = Assert.Throws<RequestInvalidException>(() => Session.Execute(batch));
+            StringAssert.Contains("expects 2 bound values", ex.Message);
+            Assert.AreEqual(0, Session.Execute($"SELECT * FROM {tableName}").Count());
+        }
+    }
+}
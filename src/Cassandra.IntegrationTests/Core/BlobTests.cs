@@ -0,0 +1,102 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Writes blobs through the Rust bridge and reads them back, both as copies and
+    /// as spans borrowed from the result.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class BlobTests : BridgedResultTestBase
+    {
+        private const int OneMegabyte = 1024 * 1024;
+
+        private string CreateTable()
+        {
+            return CreateTable("blob", "id int PRIMARY KEY, data blob");
+        }
+
+        private static byte[] RandomBytes(int length)
+        {
+            var bytes = new byte[length];
+            new Random(42).NextBytes(bytes);
+            return bytes;
+        }
+
+        [Test]
+        public void Blob_Of_One_Megabyte_Should_RoundTrip()
+        {
+            var tableName = CreateTable();
+            var data = RandomBytes(OneMegabyte);
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, data) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, data));
+
+            var row = Session.Execute(new SimpleStatement($"SELECT data FROM {tableName} WHERE id = ?", 1)).First();
+
+            CollectionAssert.AreEqual(data, row.GetValue<byte[]>("data"));
+        }
+
+        [Test]
+        public async Task ReadBlob_Should_BorrowBytesFromResult()
+        {
+            var tableName = CreateTable();
+            var data = RandomBytes(OneMegabyte);
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, data) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, data));
+            var select = Session.Prepare($"SELECT data FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+            var matches = result.ReadBlob(0, 0, (bytes, isNull) => !isNull && bytes.SequenceEqual(data));
+
+            Assert.IsTrue(matches);
+            CollectionAssert.AreEqual(data, result.GetBlob(0, 0));
+        }
+
+        [Test]
+        public async Task GetBlob_Should_ReturnNull_When_CellIsNull()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (1)");
+            var select = Session.Prepare($"SELECT data FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsNull(result.GetBlob(0, 0));
+            Assert.IsTrue(result.ReadBlob(0, 0, (bytes, isNull) => isNull && bytes.IsEmpty));
+        }
+
+        [Test]
+        public void Empty_Blob_Should_RoundTrip()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, data) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, Array.Empty<byte>()));
+
+            var row = Session.Execute(new SimpleStatement($"SELECT data FROM {tableName} WHERE id = ?", 1)).First();
+
+            CollectionAssert.AreEqual(Array.Empty<byte>(), row.GetValue<byte[]>("data"));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Serialization;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Base for fixtures that run statements straight through the bridged session and
+    /// inspect the Rust results, each test working on its own freshly created table.
+    /// </summary>
+    public abstract class BridgedResultTestBase : SharedClusterTest
+    {
+        protected BridgedResultTestBase() : base(1, true)
+        {
+        }
+
+        /// <summary>
+        /// Creates a table named after <paramref name="prefix"/> with a random suffix and returns its name.
+        /// </summary>
+        protected string CreateTable(string prefix, string columns)
+        {
+            var tableName = prefix + "_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} ({columns})");
+            return tableName;
+        }
+
+        /// <summary>
+        /// Writes <paramref name="value"/> to a <c>value</c> column of type <paramref name="cqlType"/>
+        /// in a new table, then reads it back both through the bridge with <paramref name="read"/> and
+        /// through the regular session. A null value leaves the cell null.
+        /// </summary>
+        /// <param name="read">Reads the cell at row 0, column 0 of the bridged result, which is disposed afterwards.</param>
+        protected async Task<(T Bridged, Row Row)> RoundTrip<T>(string cqlType, object value, Func<BridgedQueryResult, T> read)
+        {
+            var tableName = CreateTable("round_trip", $"id int PRIMARY KEY, value {cqlType}");
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, value));
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+            return (read(result), Session.Execute(select.Bind(1)).First());
+        }
+
+        protected async Task<BridgedQueryResult> ExecuteBridged(
+            PreparedStatement prepared,
+            object[] values,
+            int pageSize = 1,
+            byte[] pagingState = null,
+            ConsistencyLevel? consistency = null)
+        {
+            var bridgedSession = ((Session)Session).BridgedSession;
+            var md = await bridgedSession.ExecutePreparedPage(
+                prepared.bridgedPreparedStatement.DangerousGetHandle(),
+                values,
+                SerializerManager.Default.GetCurrentSerializer(),
+                consistency.HasValue,
+                (ushort)consistency.GetValueOrDefault(),
+                true,
+                pageSize,
+                12000,
+                -1,
+                pagingState).ConfigureAwait(false);
+            return new BridgedQueryResult(md);
+        }
+
+        protected async Task<BridgedRowCursor> ExecuteIter(PreparedStatement prepared, object[] values, int pageSize)
+        {
+            var bridgedSession = ((Session)Session).BridgedSession;
+            var md = await bridgedSession.ExecuteIter(
+                prepared.bridgedPreparedStatement.DangerousGetHandle(),
+                values,
+                SerializerManager.Default.GetCurrentSerializer(),
+                false,
+                0,
+                true,
+                pageSize,
+                12000,
+                -1).ConfigureAwait(false);
+            return new BridgedRowCursor(md);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Net;
+using System.Numerics;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Reads every supported column type through the typed getters of rows taken from a row cursor.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class BridgedRowTests : BridgedResultTestBase
+    {
+        private static readonly Guid Uuid = Guid.Parse("b5f0d2a4-7e38-4a1c-9d02-3c6f1e8a9b47");
+        private static readonly TimeUuid TimeUuidValue = TimeUuid.NewId(new DateTimeOffset(2024, 5, 6, 7, 8, 9, TimeSpan.Zero));
+        private static readonly DateTimeOffset Timestamp = new DateTimeOffset(1969, 7, 20, 20, 17, 40, 123, TimeSpan.Zero);
+
+        private const string Columns =
+            "i, bi, si, ti, b, f, d, u, tu, bl, t, a, ip4, ip6, ts, dt, tm, dec, vi";
+
+        private string _tableName;
+
+        public override void OneTimeSetUp()
+        {
+            base.OneTimeSetUp();
+            _tableName = CreateTable(
+                "row",
+                "id int PRIMARY KEY, i int, bi bigint, si smallint, ti tinyint, b boolean, " +
+                "f float, d double, u uuid, tu timeuuid, bl blob, t text, a ascii, ip4 inet, ip6 inet, ts timestamp, " +
+                "dt date, tm time, dec decimal, vi varint");
+            var insert = Session.Prepare(
+                $"INSERT INTO {_tableName} (id, {Columns}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)");
+            Session.Execute(insert.Bind(
+                1, -42, long.MinValue, (short)-1234, (sbyte)-12, true, 1.5f, double.NaN, Uuid, TimeUuidValue,
+                new byte[] { 1, 2, 3 }, "zażółć", "ascii", IPAddress.Parse("10.1.2.3"), IPAddress.Parse("fe80::1"),
+                Timestamp, new LocalDate(1969, 12, 31), new LocalTime(23, 59, 59, 999999999), -123.456m,
+                BigInteger.Parse("-123456789012345678901234567890")));
+            Session.Execute($"INSERT INTO {_tableName} (id) VALUES (2)");
+        }
+
+        private Task<BridgedRow> ReadRow(int id)
+        {
+            return ReadRow(Session.Prepare($"SELECT {Columns} FROM {_tableName} WHERE id = ?"), id);
+        }
+
+        private async Task<BridgedRow> ReadRow(PreparedStatement select, int id)
+        {
+            using var cursor = await ExecuteIter(select, new object[] { id }, 10).ConfigureAwait(false);
+            var (rows, _) = await cursor.NextRows(1).ConfigureAwait(false);
+            Assert.AreEqual(1, rows.Count);
+            return rows[0];
+        }
+
+        [Test]
+        public async Task Getters_Should_ReadEveryColumnType()
+        {
+            using var row = await ReadRow(1).ConfigureAwait(false);
+
+            Assert.AreEqual(19, row.GetColumnsCount());
+            Assert.AreEqual(-42, row.GetInt32(0));
+            Assert.AreEqual(long.MinValue, row.GetInt64(1));
+            Assert.AreEqual((short)-1234, row.GetSmallInt(2));
+            Assert.AreEqual((sbyte)-12, row.GetTinyInt(3));
+            Assert.AreEqual(true, row.GetBoolean(4));
+            Assert.AreEqual(1.5f, row.GetFloat(5));
+            Assert.IsTrue(double.IsNaN(row.GetDouble(6).Value));
+            Assert.AreEqual(Uuid, row.GetUuid(7));
+            Assert.AreEqual((Guid)TimeUuidValue, row.GetUuid(8));
+            Assert.AreEqual(TimeUuidValue, row.GetTimeUuid(8));
+            CollectionAssert.AreEqual(new byte[] { 1, 2, 3 }, row.GetBlob(9));
+            Assert.AreEqual("zażółć", row.GetString(10));
+            Assert.AreEqual("ascii", row.GetString(11));
+            Assert.AreEqual(IPAddress.Parse("10.1.2.3"), row.GetInet(12));
+            Assert.AreEqual(IPAddress.Parse("fe80::1"), row.GetInet(13));
+            Assert.AreEqual(Timestamp, row.GetTimestamp(14));
+            Assert.AreEqual(new LocalDate(1969, 12, 31), row.GetDate(15));
+            Assert.AreEqual(new LocalTime(23, 59, 59, 999999999), row.GetTime(16));
+            Assert.AreEqual(-123.456m, row.GetDecimal(17));
+            Assert.AreEqual(BigInteger.Parse("-123456789012345678901234567890"), row.GetVarint(18));
+            Assert.IsFalse(row.IsNull(0));
+        }
+
+        [Test]
+        public async Task GetColumnInfo_Should_ReturnNameAndTypeCode()
+        {
+            using var row = await ReadRow(1).ConfigureAwait(false);
+
+            Assert.AreEqual(("i", ColumnTypeCode.Int), row.GetColumnInfo(0));
+            Assert.AreEqual(("vi", ColumnTypeCode.Varint), row.GetColumnInfo(18));
+        }
+
+        [Test]
+        public async Task Getters_Should_ReturnNull_When_CellIsNull()
+        {
+            using var row = await ReadRow(2).ConfigureAwait(false);
+
+            for (var column = 0; column < row.GetColumnsCount(); column++)
+            {
+                Assert.IsTrue(row.IsNull(column), $"column {column}");
+            }
+            Assert.IsNull(row.GetInt32(0));
+            Assert.IsNull(row.GetInt64(1));
+            Assert.IsNull(row.GetSmallInt(2));
+            Assert.IsNull(row.GetTinyInt(3));
+            Assert.IsNull(row.GetBoolean(4));
+            Assert.IsNull(row.GetFloat(5));
+            Assert.IsNull(row.GetDouble(6));
+            Assert.IsNull(row.GetUuid(7));
+            Assert.IsNull(row.GetTimeUuid(8));
+            Assert.IsNull(row.GetBlob(9));
+            Assert.IsNull(row.GetString(10));
+            Assert.IsNull(row.GetInet(12));
+            Assert.IsNull(row.GetTimestamp(14));
+            Assert.IsNull(row.GetDate(15));
+            Assert.IsNull(row.GetTime(16));
+            Assert.IsNull(row.GetDecimal(17));
+            Assert.IsNull(row.GetVarint(18));
+        }
+
+        [Test]
+        public async Task Getters_Should_Throw_When_ColumnTypeDiffers()
+        {
+            using var row = await ReadRow(1).ConfigureAwait(false);
+
+            Assert.Throws<InvalidTypeException>(() => row.GetInt64(0));
+            Assert.Throws<InvalidTypeException>(() => row.GetInt32(1));
+            Assert.Throws<InvalidTypeException>(() => row.GetCounter(1));
+            Assert.Throws<InvalidTypeException>(() => row.GetString(9));
+            Assert.Throws<InvalidTypeException>(() => row.GetTimeUuid(7));
+            Assert.Throws<InvalidTypeException>(() => row.GetDecimal(18));
+        }
+
+        [Test]
+        public async Task Getters_Should_Throw_When_CellIsNull_And_ColumnTypeDiffers()
+        {
+            using var row = await ReadRow(2).ConfigureAwait(false);
+
+            Assert.Throws<InvalidTypeException>(() => row.GetInt64(0));
+        }
+
+        [Test]
+        public async Task Getters_Should_Throw_When_ColumnIndexIsOutOfRange()
+        {
+            using var row = await ReadRow(1).ConfigureAwait(false);
+
+            Assert.Throws<InvalidArgumentException>(() => row.GetInt32(19));
+            Assert.Throws<InvalidArgumentException>(() => row.GetString(100));
+            Assert.Throws<InvalidArgumentException>(() => row.IsNull(19));
+            Assert.Throws<InvalidArgumentException>(() => row.GetColumnInfo(19));
+        }
+
+        [Test]
+        public async Task GetCounter_Should_ReadCounterColumn()
+        {
+            var tableName = "row_counter_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, c counter)");
+            Session.Execute($"UPDATE {tableName} SET c = c + 7 WHERE id = 1");
+            var select = Session.Prepare($"SELECT c FROM {tableName} WHERE id = ?");
+
+            using var row = await ReadRow(select, 1).ConfigureAwait(false);
+
+            Assert.AreEqual(7L, row.GetCounter(0));
+            Assert.Throws<InvalidTypeException>(() => row.GetInt32(0));
+        }
+    }
+}
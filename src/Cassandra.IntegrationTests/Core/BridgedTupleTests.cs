@@ -0,0 +1,80 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks tuples read field by field through <see cref="BridgedTupleView"/>, including null fields.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class BridgedTupleTests : BridgedResultTestBase
+    {
+        private const string TupleType = "frozen<tuple<int, text, uuid>>";
+
+        private static (int? Int, string Text, Guid? Uuid) ReadTuple(BridgedQueryResult result)
+        {
+            using var tuple = BridgedTupleView.Open(result, 0, 0);
+            Assert.AreEqual(3, tuple.Count);
+            Assert.AreEqual(ColumnTypeCode.Int, tuple.GetFieldType(0));
+            Assert.AreEqual(ColumnTypeCode.Text, tuple.GetFieldType(1));
+            Assert.AreEqual(ColumnTypeCode.Uuid, tuple.GetFieldType(2));
+            return (tuple.GetInt32(0), tuple.GetText(1), tuple.GetUuid(2));
+        }
+
+        [Test]
+        public async Task Tuple_Should_RoundTrip()
+        {
+            var value = Tuple.Create(42, "ąę", Guid.NewGuid());
+
+            var (read, row) = await RoundTrip(TupleType, value, ReadTuple).ConfigureAwait(false);
+
+            Assert.AreEqual(value.Item1, read.Int);
+            Assert.AreEqual(value.Item2, read.Text);
+            Assert.AreEqual(value.Item3, read.Uuid);
+            Assert.AreEqual(value, row.GetValue<Tuple<int, string, Guid>>("value"));
+        }
+
+        [Test]
+        public async Task Tuple_Should_KeepNullField_When_MiddleFieldIsNull()
+        {
+            var value = new Tuple<int, string, Guid>(7, null, Guid.NewGuid());
+
+            var (read, _) = await RoundTrip(TupleType, value, ReadTuple).ConfigureAwait(false);
+
+            Assert.AreEqual(7, read.Int);
+            Assert.IsNull(read.Text);
+            Assert.AreEqual(value.Item3, read.Uuid);
+        }
+
+        [Test]
+        public async Task Open_Should_ReturnNull_When_CellIsNull()
+        {
+            var (isNull, _) = await RoundTrip(TupleType, null, result =>
+            {
+                using var tuple = BridgedTupleView.Open(result, 0, 0);
+                return tuple == null;
+            }).ConfigureAwait(false);
+
+            Assert.IsTrue(isNull);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Binds user defined types through the Rust bridge and reads them back field by field from query results.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class BridgedUdtTests : BridgedResultTestBase
+    {
+        private class Point
+        {
+            public int X { get; set; }
+
+            public string Label { get; set; }
+        }
+
+        private class Segment
+        {
+            public Point Start { get; set; }
+
+            public Point End { get; set; }
+        }
+
+        /// <summary>
+        /// Creates a <c>point</c> type and a table holding it, and maps <see cref="Point"/> to the type.
+        /// </summary>
+        private string CreateTable(out string typeName)
+        {
+            var suffix = Guid.NewGuid().ToString("N").ToLower();
+            typeName = "point_" + suffix;
+            var tableName = "udt_" + suffix;
+            Session.Execute($"CREATE TYPE {typeName} (x int, label text)");
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, value frozen<{typeName}>)");
+            Session.UserDefinedTypes.Define(
+                UdtMap.For<Point>(typeName)
+                    .Map(v => v.X, "x")
+                    .Map(v => v.Label, "label"));
+            return tableName;
+        }
+
+        [Test]
+        public async Task Udt_Should_RoundTrip()
+        {
+            var tableName = CreateTable(out _);
+            var value = new Point { X = 42, Label = "ąę" };
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, value));
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+            using var udt = BridgedUdtView.Open(result, 0, 0);
+
+            Assert.AreEqual(2, udt.Count);
+            Assert.AreEqual("x", udt.GetFieldName(0));
+            Assert.AreEqual("label", udt.GetFieldName(1));
+            Assert.AreEqual(ColumnTypeCode.Int, udt.GetFieldType(0));
+            Assert.AreEqual(ColumnTypeCode.Text, udt.GetFieldType(1));
+            Assert.AreEqual(value.X, udt.GetInt32(0));
+            Assert.AreEqual(value.Label, udt.GetText(1));
+
+            var read = Session.Execute(select.Bind(1)).First().GetValue<Point>("value");
+            Assert.AreEqual(value.X, read.X);
+            Assert.AreEqual(value.Label, read.Label);
+        }
+
+        [Test]
+        public async Task Udt_Should_ReadMissingTrailingFieldAsNull_When_TypeWasExtended()
+        {
+            var tableName = CreateTable(out var typeName);
+            Session.Execute($"ALTER TYPE {typeName} ADD extra bigint");
+            // The mapping still holds the definition without the new field, so the bound value omits it.
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, new Point { X = 7, Label = null }));
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+            using var udt = BridgedUdtView.Open(result, 0, 0);
+
+            Assert.AreEqual(3, udt.Count);
+            Assert.AreEqual("extra", udt.GetFieldName(2));
+            Assert.AreEqual(7, udt.GetInt32(0));
+            Assert.IsNull(udt.GetText(1));
+            Assert.IsNull(udt.GetInt64(2));
+        }
+
+        [Test]
+        public async Task Open_Should_ReturnNull_When_CellIsNull()
+        {
+            var tableName = CreateTable(out _);
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (1)");
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsNull(BridgedUdtView.Open(result, 0, 0));
+        }
+
+        [Test]
+        public async Task Udt_InsideUdt_Should_RoundTrip()
+        {
+            CreateTable(out var pointType);
+            var suffix = Guid.NewGuid().ToString("N").ToLower();
+            var segmentType = "segment_" + suffix;
+            var tableName = "udt_nested_" + suffix;
+            Session.Execute($"CREATE TYPE {segmentType} (start frozen<{pointType}>, end frozen<{pointType}>)");
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, value frozen<{segmentType}>)");
+            Session.UserDefinedTypes.Define(
+                UdtMap.For<Segment>(segmentType)
+                    .Map(v => v.Start, "start")
+                    .Map(v => v.End, "end"));
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, new Segment { Start = new Point { X = 1, Label = "a" }, End = null }));
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+            using var segment = BridgedUdtView.Open(result, 0, 0);
+
+            Assert.AreEqual(ColumnTypeCode.Udt, segment.GetFieldType(0));
+            using (var start = segment.OpenUdt(0))
+            {
+                Assert.AreEqual(1, start.GetInt32(0));
+                Assert.AreEqual("a", start.GetText(1));
+            }
+            Assert.IsNull(segment.OpenUdt(1));
+            Assert.Throws<InvalidTypeException>(() => segment.GetInt32(0));
+        }
+
+        [Test]
+        public async Task Udt_InsideList_Should_RoundTrip()
+        {
+            CreateTable(out var pointType);
+            var tableName = "udt_list_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, value list<frozen<{pointType}>>)");
+            var points = new List<Point> { new Point { X = 1, Label = "a" }, new Point { X = 2, Label = null } };
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, points));
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+            using var list = BridgedCollection.Open(result, 0, 0);
+
+            Assert.AreEqual(points.Count, list.Count);
+            for (var i = 0; i < points.Count; i++)
+            {
+                Assert.AreEqual(ColumnTypeCode.Udt, list.GetItemType(i));
+                using var point = BridgedUdtView.Open(list, i);
+                Assert.AreEqual(points[i].X, point.GetInt32(0));
+                Assert.AreEqual(points[i].Label, point.GetText(1));
+            }
+            Assert.Throws<InvalidTypeException>(() => list.GetInt32(0));
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Linq;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks that client-side timestamps set with <see cref="IStatement.SetTimestamp"/> become the write time of the data.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class ClientTimestampTests : SharedClusterTest
+    {
+        public ClientTimestampTests() : base(1, true)
+        {
+        }
+
+        private string CreateTable()
+        {
+            var tableName = "client_timestamp_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, value text)");
+            return tableName;
+        }
+
+        private long GetWriteTime(string tableName)
+        {
+            return Session.Execute($"SELECT WRITETIME(value) AS wt FROM {tableName} WHERE id = 1").Single().GetValue<long>("wt");
+        }
+
+        [Test]
+        public void SimpleStatement_WithTimestamp_IsWrittenWithIt()
+        {
+            var tableName = CreateTable();
+            var timestamp = DateTimeOffset.FromUnixTimeMilliseconds(1_600_000_000_123L).AddTicks(4560);
+
+            Session.Execute(new SimpleStatement($"INSERT INTO {tableName} (id, value) VALUES (1, 'one')").SetTimestamp(timestamp));
+
+            Assert.AreEqual(1_600_000_000_123_456L, GetWriteTime(tableName));
+        }
+
+        [Test]
+        public void BoundStatement_WithTimestamp_IsWrittenWithIt()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            var timestamp = DateTimeOffset.FromUnixTimeMilliseconds(1_600_000_000_123L);
+
+            Session.Execute(insert.Bind(1, "one").SetTimestamp(timestamp));
+
+            Assert.AreEqual(1_600_000_000_123_000L, GetWriteTime(tableName));
+        }
+
+        [Test]
+        public void Statement_WithoutTimestamp_IsWrittenWithCurrentTime()
+        {
+            var tableName = CreateTable();
+            var before = DateTimeOffset.UtcNow.AddMinutes(-5);
+
+            Session.Execute(Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)").Bind(1, "one"));
+
+            var writeTime = DateTimeOffset.UnixEpoch.AddTicks(GetWriteTime(tableName) * 10);
+            Assert.Greater(writeTime, before);
+            Assert.Less(writeTime, DateTimeOffset.UtcNow.AddMinutes(5));
+        }
+
+        [Test]
+        public void LaterWrite_WithOlderTimestamp_DoesNotOverwrite()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            var timestamp = DateTimeOffset.FromUnixTimeMilliseconds(1_600_000_000_000L);
+
+            Session.Execute(insert.Bind(1, "newer").SetTimestamp(timestamp));
+            Session.Execute(insert.Bind(1, "older").SetTimestamp(timestamp.AddSeconds(-1)));
+
+            var value = Session.Execute($"SELECT value FROM {tableName} WHERE id = 1").Single().GetValue<string>("value");
+            Assert.AreEqual("newer", value);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System.Linq;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Queries a node over connections that compress CQL frames.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class CompressionTests : SharedClusterTest
+    {
+        public CompressionTests() : base(1, true)
+        {
+        }
+
+        [TestCase(CompressionType.LZ4)]
+        [TestCase(CompressionType.Snappy)]
+        public void Query_Should_RoundTripLargeValues_When_CompressionIsEnabled(CompressionType compression)
+        {
+            var tableName = "compression_" + compression.ToString().ToLower();
+            // Repetitive values compress well, so the frames carrying them are actually compressed.
+            var value = string.Concat(Enumerable.Repeat("compressible ", 10_000));
+
+            using (var cluster = ClusterBuilder()
+                   .AddContactPoint(TestCluster.InitialContactPoint)
+                   .WithCompression(compression)
+                   .Build())
+            {
+                var session = cluster.Connect(KeyspaceName);
+                session.Execute($"CREATE TABLE IF NOT EXISTS {tableName} (id int PRIMARY KEY, value text)");
+                var insert = session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+                session.Execute(insert.Bind(1, value));
+
+                var row = session.Execute(new SimpleStatement($"SELECT value FROM {tableName} WHERE id = ?", 1)).First();
+
+                Assert.AreEqual(value, row.GetValue<string>("value"));
+            }
+        }
+    }
+}
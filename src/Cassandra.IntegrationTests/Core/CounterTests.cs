@@ -0,0 +1,77 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks that counter columns are only changed by increments, and are read through the
+    /// Rust bridge as counters rather than as plain bigints.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class CounterTests : BridgedResultTestBase
+    {
+        private string CreateTable()
+        {
+            return CreateTable("counters", "id int PRIMARY KEY, value counter");
+        }
+
+        [Test]
+        public async Task Counter_ConcurrentIncrements_AreAllApplied()
+        {
+            var tableName = CreateTable();
+
+            var increment = Session.Prepare($"UPDATE {tableName} SET value = value + ? WHERE id = ?");
+            var tasks = Enumerable.Range(0, 100)
+                .Select(_ => Session.ExecuteAsync(increment.Bind(2L, 1)))
+                .ToArray();
+            await Task.WhenAll(tasks).ConfigureAwait(false);
+
+            var row = Session.Execute($"SELECT value FROM {tableName} WHERE id = 1").Single();
+            Assert.AreEqual(200L, row.GetValue<long>("value"));
+        }
+
+        [Test]
+        public async Task Counter_Should_BeReadAsCounter_And_NotAsOtherTypes()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"UPDATE {tableName} SET value = value + 5 WHERE id = 1");
+            Session.Execute($"UPDATE {tableName} SET value = value - 12 WHERE id = 1");
+            var select = Session.Prepare($"SELECT value, id FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.AreEqual(-7L, result.GetCounter(0, 0));
+            Assert.Throws<InvalidTypeException>(() => result.GetCounter(0, 1));
+            Assert.Throws<InvalidTypeException>(() => result.GetInt32(0, 0));
+        }
+
+        [Test]
+        public void Counter_Should_BeRejected_When_SetAsNormalValue()
+        {
+            var tableName = CreateTable();
+
+            // Counters can only be incremented or decremented, never bound as a plain value.
+            Assert.Throws<InvalidQueryException>(() => Session.Prepare($"UPDATE {tableName} SET value = ? WHERE id = ?"));
+            Assert.Throws<InvalidQueryException>(() => Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)"));
+        }
+    }
+}
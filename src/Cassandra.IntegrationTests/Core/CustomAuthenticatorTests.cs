@@ -0,0 +1,185 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Linq;
+using System.Net;
+using System.Text;
+using System.Threading;
+using Cassandra.IntegrationTests.TestBase;
+using Cassandra.IntegrationTests.TestClusterManagement;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Logs in to a node using <c>PasswordAuthenticator</c> with a SASL exchange implemented
+    /// by a custom <see cref="IAuthProvider"/>, which the Rust driver forwards to C#.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class CustomAuthenticatorTests : TestGlobals
+    {
+        private const string Password = "cassandra";
+        private const string Username = "cassandra";
+
+        private ITestCluster _testCluster;
+
+        [OneTimeSetUp]
+        public void OneTimeSetUp()
+        {
+            _testCluster = TestClusterManager.CreateNew(1, new TestClusterOptions
+            {
+                CassandraYaml = new[] { "authenticator: PasswordAuthenticator" },
+                JvmArgs = new[] { "-Dcassandra.superuser_setup_delay_ms=0" },
+            });
+            WaitForDefaultSuperuser();
+        }
+
+        [OneTimeTearDown]
+        public void OneTimeTearDown()
+        {
+            TestClusterManager.TryRemove();
+        }
+
+        [Test]
+        public void Connect_Should_Succeed_When_AuthenticatorSendsValidCredentials()
+        {
+            var provider = new SaslPlainAuthProvider(Username, Password);
+
+            using (var cluster = Connect(provider))
+            {
+                var session = cluster.Connect();
+                var row = session.Execute("SELECT release_version FROM system.local").First();
+                Assert.IsNotNull(row.GetValue<string>("release_version"));
+            }
+
+            Assert.Greater(provider.AuthenticatorsCreated, 0);
+            StringAssert.Contains("PasswordAuthenticator", provider.AuthenticatorName);
+        }
+
+        [Test]
+        public void Connect_Should_ThrowAuthenticationException_When_AuthenticatorSendsWrongPassword()
+        {
+            using (var cluster = Connect(new SaslPlainAuthProvider(Username, "wrong-password")))
+            {
+                var ex = Assert.Throws<AuthenticationException>(() => cluster.Connect());
+
+                Assert.AreEqual(IPAddress.Parse(_testCluster.InitialContactPoint), ex.Host.Address);
+            }
+        }
+
+        [Test]
+        public void Connect_Should_ThrowAuthenticationException_When_AuthenticatorThrows()
+        {
+            using (var cluster = Connect(new SaslPlainAuthProvider(Username, Password) { ThrowOnInitialResponse = true }))
+            {
+                var ex = Assert.Throws<AuthenticationException>(() => cluster.Connect());
+
+                StringAssert.Contains("initial response", ex.Message);
+            }
+        }
+
+        private Cluster Connect(IAuthProvider provider)
+        {
+            return ClusterBuilder()
+                   .AddContactPoint(_testCluster.InitialContactPoint)
+                   .WithAuthProvider(provider)
+                   .Build();
+        }
+
+        /// <summary>
+        /// The default superuser is created asynchronously after the node starts listening.
+        /// </summary>
+        private void WaitForDefaultSuperuser()
+        {
+            for (var attempt = 0; ; attempt++)
+            {
+                try
+                {
+                    using (var cluster = Connect(new SaslPlainAuthProvider(Username, Password)))
+                    {
+                        cluster.Connect();
+                        return;
+                    }
+                }
+                catch (AuthenticationException) when (attempt < 30)
+                {
+                    Thread.Sleep(1000);
+                }
+            }
+        }
+
+        /// <summary>
+        /// Implements the SASL PLAIN mechanism by hand, so that it is not passed to Rust as plain-text credentials.
+        /// </summary>
+        private class SaslPlainAuthProvider : IAuthProviderNamed
+        {
+            private readonly string _username;
+            private readonly string _password;
+            private int _authenticatorsCreated;
+
+            public SaslPlainAuthProvider(string username, string password)
+            {
+                _username = username;
+                _password = password;
+            }
+
+            public bool ThrowOnInitialResponse { get; set; }
+
+            public int AuthenticatorsCreated => Volatile.Read(ref _authenticatorsCreated);
+
+            public string AuthenticatorName { get; private set; }
+
+            public void SetName(string name)
+            {
+                AuthenticatorName = name;
+            }
+
+            public IAuthenticator NewAuthenticator(IPEndPoint host)
+            {
+                Interlocked.Increment(ref _authenticatorsCreated);
+                return new SaslPlainAuthenticator(this);
+            }
+
+            private class SaslPlainAuthenticator : IAuthenticator
+            {
+                private readonly SaslPlainAuthProvider _provider;
+
+                public SaslPlainAuthenticator(SaslPlainAuthProvider provider)
+                {
+                    _provider = provider;
+                }
+
+                public byte[] InitialResponse()
+                {
+                    if (_provider.ThrowOnInitialResponse)
+                    {
+                        throw new InvalidOperationException("No ticket available");
+                    }
+                    return Encoding.UTF8.GetBytes($"\0{_provider._username}\0{_provider._password}");
+                }
+
+                public byte[] EvaluateChallenge(byte[] challenge)
+                {
+                    return null;
+                }
+            }
+        }
+    }
+}
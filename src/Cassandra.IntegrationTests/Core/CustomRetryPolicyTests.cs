@@ -0,0 +1,113 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+using System;
+
+using System.Linq;
+using System.Threading;
+using Cassandra.IntegrationTests.SimulacronAPI.Models.Logs;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks that a retry policy implemented in C# is called back by the driver
+    /// and that its decisions are applied.
+    /// </summary>
+    public class CustomRetryPolicyTests : SimulacronTest
+    {
+        private const string Cql = "INSERT INTO ks.custom_retried (id) VALUES (1)";
+
+        private readonly CountingRetryPolicy _policy = new CountingRetryPolicy();
+
+        protected override Builder ConfigBuilder(Builder b)
+        {
+            return b.WithRetryPolicy(_policy);
+        }
+
+        [Test]
+        public void CustomRetryPolicy_Should_RetryWithDowngradedConsistency_When_WriteTimesOut()
+        {
+            TestCluster.PrimeFluent(
+                b => b.WhenQuery(Cql)
+                      .ThenWriteTimeout("write timeout", (int)ConsistencyLevel.Quorum, 0, 2, "SIMPLE"));
+            _policy.Reset();
+
+            Assert.Throws<WriteTimeoutException>(
+                () => Session.Execute(new SimpleStatement(Cql).SetConsistencyLevel(ConsistencyLevel.Quorum)));
+
+            Assert.AreEqual(2, _policy.WriteTimeouts);
+            Assert.AreEqual("SIMPLE", _policy.LastWriteType);
+            VerifyQuery(Cql, 2);
+            Assert.AreEqual(
+                new ConsistencyLevel?[] { ConsistencyLevel.Quorum, ConsistencyLevel.One },
+                TestCluster.GetQueries(Cql, QueryType.Query).Select(q => q.ConsistencyLevel).ToArray());
+        }
+
+        [Test]
+        public void CustomRetryPolicy_Should_IgnoreError_When_PolicyIgnores()
+        {
+            TestCluster.PrimeFluent(
+                b => b.WhenQuery(Cql)
+                      .ThenUnavailable("unavailable", (int)ConsistencyLevel.All, 3, 1));
+            _policy.Reset();
+
+            Session.Execute(new SimpleStatement(Cql).SetConsistencyLevel(ConsistencyLevel.All));
+
+            Assert.AreEqual(1, _policy.Unavailables);
+            VerifyQuery(Cql, 1);
+        }
+
+        /// <summary>
+        /// Retries a write timeout once with consistency ONE, and ignores unavailable errors.
+        /// </summary>
+        private class CountingRetryPolicy : IRetryPolicy
+        {
+            private int _writeTimeouts;
+            private int _unavailables;
+
+            public int WriteTimeouts => Volatile.Read(ref _writeTimeouts);
+
+            public int Unavailables => Volatile.Read(ref _unavailables);
+
+            public string LastWriteType { get; private set; }
+
+            public void Reset()
+            {
+                Interlocked.Exchange(ref _writeTimeouts, 0);
+                Interlocked.Exchange(ref _unavailables, 0);
+            }
+
+            public RetryDecision OnReadTimeout(IStatement query, ConsistencyLevel cl, int requiredResponses, int receivedResponses, bool dataRetrieved, int nbRetry)
+            {
+                return RetryDecision.Rethrow();
+            }
+
+            public RetryDecision OnWriteTimeout(IStatement query, ConsistencyLevel cl, string writeType, int requiredAcks, int receivedAcks, int nbRetry)
+            {
+                Interlocked.Increment(ref _writeTimeouts);
+                LastWriteType = writeType;
+                return nbRetry == 0 ? RetryDecision.Retry(ConsistencyLevel.One, true) : RetryDecision.Rethrow();
+            }
+
+            public RetryDecision OnUnavailable(IStatement query, ConsistencyLevel cl, int requiredReplica, int aliveReplica, int nbRetry)
+            {
+                Interlocked.Increment(ref _unavailables);
+                return RetryDecision.Ignore();
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Binds durations through the Rust bridge and reads them back from query results.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class DurationTests : BridgedResultTestBase
+    {
+        private static readonly Duration[] Values =
+        {
+            Duration.Zero,
+            new Duration(1, 2, 3),
+            new Duration(-1, -2, -3),
+            new Duration(0, 0, 1),
+            new Duration(12, 0, 0),
+            new Duration(0, -30, 0),
+            new Duration(int.MaxValue, int.MaxValue, long.MaxValue),
+            new Duration(int.MinValue, int.MinValue, long.MinValue),
+        };
+
+        /// <summary>
+        /// Durations with the zig-zag vint encodings of their components, at the boundaries between encoded lengths.
+        /// </summary>
+        private static readonly object[] Encodings =
+        {
+            new object[] { new Duration(0, 0, 63), new byte[] { 0x00, 0x00, 0x7E } },
+            new object[] { new Duration(0, 0, -64), new byte[] { 0x00, 0x00, 0x7F } },
+            new object[] { new Duration(0, 0, 64), new byte[] { 0x00, 0x00, 0x80, 0x80 } },
+            new object[] { new Duration(0, 0, -65), new byte[] { 0x00, 0x00, 0x80, 0x81 } },
+            new object[] { new Duration(-1, 0, 0), new byte[] { 0x01, 0x00, 0x00 } },
+            new object[] { new Duration(0, 8192, 0), new byte[] { 0x00, 0xC0, 0x40, 0x00, 0x00 } },
+        };
+
+        private string CreateTable()
+        {
+            return CreateTable("duration", "id int PRIMARY KEY, value duration");
+        }
+
+        [Test]
+        public async Task Duration_Should_RoundTrip([ValueSource(nameof(Values))] Duration value)
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, value));
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.AreEqual(value, result.GetDuration(0, 0));
+            Assert.AreEqual(value, Session.Execute(select.Bind(1)).First().GetValue<Duration>("value"));
+        }
+
+        [Test, TestCaseSource(nameof(Encodings))]
+        public void Duration_Should_BeSentAsZigZagVints(Duration value, byte[] expected)
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, value));
+
+            var row = Session.Execute(new SimpleStatement($"SELECT durationAsBlob(value) AS bytes FROM {tableName} WHERE id = ?", 1)).First();
+
+            CollectionAssert.AreEqual(expected, row.GetValue<byte[]>("bytes"));
+        }
+
+        [Test]
+        public void Duration_Should_BeRejected_When_ComponentsHaveMixedSigns()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+
+            Assert.Throws<SerializationException>(() => Session.Execute(insert.Bind(1, new Duration(1, -1, 0))));
+            Assert.Throws<SerializationException>(() => Session.Execute(insert.Bind(1, new Duration(0, 1, -1))));
+        }
+
+        [Test]
+        public async Task GetDuration_Should_ReturnNull_When_CellIsNull()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (1)");
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsNull(result.GetDuration(0, 0));
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Threading.Tasks;
+using Cassandra.Serialization;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Executes bound prepared statements through the Rust bridge, reading their whole result at once.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class ExecutePreparedTests : BridgedResultTestBase
+    {
+        private async Task<BridgedQueryResult> ExecutePrepared(PreparedStatement prepared, params object[] values)
+        {
+            var bridgedSession = ((Session)Session).BridgedSession;
+            var md = await bridgedSession.ExecutePrepared(
+                prepared.bridgedPreparedStatement.DangerousGetHandle(),
+                values,
+                SerializerManager.Default.GetCurrentSerializer(),
+                false,
+                0,
+                true,
+                12000,
+                -1).ConfigureAwait(false);
+            return new BridgedQueryResult(md);
+        }
+
+        private string CreateTable()
+        {
+            return CreateTable("execute_prepared", "id int, ck int, value int, PRIMARY KEY (id, ck)");
+        }
+
+        [Test]
+        public async Task ExecutePrepared_Should_ReturnRows_When_AllValuesAreBound()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, ck, value) VALUES (?, ?, ?)");
+            for (var ck = 0; ck < 3; ck++)
+            {
+                using var _ = await ExecutePrepared(insert, 1, ck, ck * 10).ConfigureAwait(false);
+            }
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ? AND ck >= ?");
+
+            using var result = await ExecutePrepared(select, 1, 1).ConfigureAwait(false);
+
+            Assert.AreEqual(2, result.GetRowsCount());
+            Assert.AreEqual(10, result.GetInt32(0, 0));
+            Assert.AreEqual(20, result.GetInt32(1, 0));
+        }
+
+        [Test]
+        public void ExecutePrepared_Should_Throw_When_TooFewValuesAreBound()
+        {
+            var tableName = CreateTable();
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ? AND ck = ?");
+
+            var ex = Assert.ThrowsAsync<RequestInvalidException>(() => ExecutePrepared(select, 1));
+            StringAssert.Contains("expects 2 bound values, but 1 were given", ex.Message);
+        }
+
+        [Test]
+        public void ExecutePrepared_Should_Throw_When_TooManyValuesAreBound()
+        {
+            var tableName = CreateTable();
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ? AND ck = ?");
+
+            var ex = Assert.ThrowsAsync<RequestInvalidException>(() => ExecutePrepared(select, 1, 2, 3));
+            StringAssert.Contains("expects 2 bound values, but 3 were given", ex.Message);
+        }
+    }
+}
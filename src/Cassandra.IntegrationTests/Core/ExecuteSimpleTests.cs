@@ -0,0 +1,91 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Serialization;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Executes unprepared statements through the Rust bridge, reading their whole result at once.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class ExecuteSimpleTests : BridgedResultTestBase
+    {
+        private async Task<BridgedQueryResult> ExecuteSimple(string statement, params object[] values)
+        {
+            var bridgedSession = ((Session)Session).BridgedSession;
+            var md = await bridgedSession.ExecuteSimple(
+                statement,
+                values,
+                SerializerManager.Default.GetCurrentSerializer(),
+                false,
+                0,
+                true,
+                12000,
+                -1).ConfigureAwait(false);
+            return new BridgedQueryResult(md);
+        }
+
+        private string CreateTable()
+        {
+            return CreateTable("execute_simple", "id int PRIMARY KEY, value int");
+        }
+
+        [Test]
+        public async Task ExecuteSimple_Should_ReadRowsOfSelect()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id, value) VALUES (1, 10)");
+            Session.Execute($"INSERT INTO {tableName} (id, value) VALUES (2, 20)");
+
+            using var result = await ExecuteSimple($"SELECT value FROM {tableName} WHERE id = ?", 2).ConfigureAwait(false);
+
+            Assert.AreEqual(1, result.GetRowsCount());
+            Assert.AreEqual(20, result.GetInt32(0, 0));
+        }
+
+        [Test]
+        public async Task ExecuteSimple_Should_ReturnNoRows_When_WritingValues()
+        {
+            var tableName = CreateTable();
+
+            using (var result = await ExecuteSimple($"INSERT INTO {tableName} (id, value) VALUES (?, ?)", 3, 30).ConfigureAwait(false))
+            {
+                Assert.AreEqual(0, result.GetRowsCount());
+            }
+
+            var row = Session.Execute($"SELECT value FROM {tableName} WHERE id = 3").First();
+            Assert.AreEqual(30, row.GetValue<int>("value"));
+        }
+
+        [Test]
+        public void ExecuteSimple_Should_Throw_When_StatementIsEmpty()
+        {
+            Assert.ThrowsAsync<RequestInvalidException>(() => ExecuteSimple("   "));
+        }
+
+        [Test]
+        public void ExecuteSimple_Should_Throw_When_StatementIsInvalid()
+        {
+            Assert.ThrowsAsync<SyntaxError>(() => ExecuteSimple("SELEC value FROM system.local"));
+        }
+    }
+}
@@ -1,15 +1,19 @@
+using System;
 using System.Linq;
+using System.Threading.Tasks;
 using Cassandra.Tests;
 using NUnit.Framework;
 using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
 
 namespace Cassandra.IntegrationTests.Core
 {
     [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
-    public class ExecutionInfoTests : SharedClusterTest
+    public class ExecutionInfoTests : BridgedResultTestBase
     {
-        public ExecutionInfoTests() : base(1, true)
+        private string CreateTable()
         {
+            return CreateTable("execution_info", "id int PRIMARY KEY, value int");
         }
 
         [Test]
@@ -52,5 +56,34 @@ namespace Cassandra.IntegrationTests.Core
             Assert.IsTrue(triedHosts.Count > 0);
             Assert.AreEqual(triedHosts[triedHosts.Count - 1], rs.Info.QueriedHost);
         }
+
+        [Test]
+        public async Task BridgedResult_Should_ReportCoordinator_AchievedConsistency_And_AttemptCount()
+        {
+            var ps = Session.Prepare("SELECT key FROM system.local WHERE key='local'");
+
+            using var result = await ExecuteBridged(ps, Array.Empty<object>(), consistency: ConsistencyLevel.One).ConfigureAwait(false);
+
+            var knownAddresses = Cluster.AllHosts().Select(h => h.Address).ToList();
+            CollectionAssert.Contains(knownAddresses, result.GetCoordinatorAddress());
+            Assert.AreEqual(ConsistencyLevel.One, result.GetAchievedConsistency());
+            Assert.AreEqual(1, result.GetAttemptCount());
+        }
+
+        [Test]
+        public void Batch_Should_ReportQueriedHost_And_AchievedConsistency()
+        {
+            var tableName = CreateTable();
+            var batch = new BatchStatement()
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, value) VALUES (1, 10)"))
+                .Add(new SimpleStatement($"INSERT INTO {tableName} (id, value) VALUES (2, 20)"))
+                .SetConsistencyLevel(ConsistencyLevel.One);
+
+            var rs = Session.Execute(batch);
+
+            var knownAddresses = Cluster.AllHosts().Select(h => h.Address).ToList();
+            CollectionAssert.Contains(knownAddresses, rs.Info.QueriedHost);
+            Assert.AreEqual(ConsistencyLevel.One, rs.Info.AchievedConsistency);
+        }
     }
 }
@@ -0,0 +1,148 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.IntegrationTests.SimulacronAPI.Models.Logs;
+using Cassandra.Serialization;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks that execution profiles attached to prepared statements and batches
+    /// override the consistency and timeout of the session.
+    /// </summary>
+    public class ExecutionProfileTests : SimulacronTest
+    {
+        private const string FastCql = "SELECT id FROM ks.profiled WHERE id = 1";
+        private const string SlowCql = "SELECT id FROM ks.profiled WHERE id = 2";
+        private const string InsertCql = "INSERT INTO ks.profiled (id) VALUES (3)";
+
+        protected override Builder ConfigBuilder(Builder b)
+        {
+            return b.WithExecutionProfiles(options => options
+                .WithProfile("fast", profile => profile
+                    .WithConsistencyLevel(ConsistencyLevel.One)
+                    .WithReadTimeoutMillis(100))
+                .WithProfile("slow", profile => profile
+                    .WithConsistencyLevel(ConsistencyLevel.Quorum)
+                    .WithReadTimeoutMillis(5000)));
+        }
+
+        private PreparedStatement PrimeDelayed(string cql)
+        {
+            TestCluster.PrimeFluent(
+                b => b.WhenQuery(cql)
+                      .ThenRowsSuccess(new[] { "id" }, r => r.WithRow(1))
+                      .WithDelayInMs(1000));
+            return Session.Prepare(cql);
+        }
+
+        private ConsistencyLevel?[] GetExecutedConsistencies(string cql)
+        {
+            return TestCluster.GetQueries(cql, QueryType.Execute).Select(q => q.ConsistencyLevel).ToArray();
+        }
+
+        [Test]
+        public void ExecutionProfile_Should_ApplyItsTimeoutAndConsistency_When_SetOnPreparedStatements()
+        {
+            var fast = PrimeDelayed(FastCql).SetExecutionProfile("fast");
+            var slow = PrimeDelayed(SlowCql).SetExecutionProfile("slow");
+
+            // The delay is shorter than the timeout of the slow profile, but longer than the one of the fast profile.
+            Assert.AreEqual(1, Session.Execute(slow.Bind()).Count());
+            Assert.Throws<OperationTimedOutException>(() => Session.Execute(fast.Bind()));
+
+            Assert.AreEqual(new ConsistencyLevel?[] { ConsistencyLevel.Quorum }, GetExecutedConsistencies(SlowCql));
+            Assert.AreEqual(new ConsistencyLevel?[] { ConsistencyLevel.One }, GetExecutedConsistencies(FastCql));
+        }
+
+        [Test]
+        public void ExecutionProfile_Should_BeOverriddenByStatementConsistency()
+        {
+            var slow = PrimeDelayed(SlowCql).SetExecutionProfile("slow");
+
+            Session.Execute(slow.Bind().SetConsistencyLevel(ConsistencyLevel.Two));
+
+            Assert.AreEqual(new ConsistencyLevel?[] { ConsistencyLevel.Two }, GetExecutedConsistencies(SlowCql));
+        }
+
+        [Test]
+        public void ExecutionProfile_Should_FallBackToSessionDefault_When_Unset()
+        {
+            TestCluster.PrimeFluent(b => b.WhenQuery(FastCql).ThenRowsSuccess(new[] { "id" }, r => r.WithRow(1)));
+            var prepared = Session.Prepare(FastCql).SetExecutionProfile("fast").SetExecutionProfile(null);
+
+            Session.Execute(prepared.Bind());
+
+            Assert.AreNotEqual(ConsistencyLevel.One, GetExecutedConsistencies(FastCql).Single());
+        }
+
+        [Test]
+        public void ExecutionProfile_Should_ApplyItsConsistency_When_ExecutingBatch()
+        {
+            var batch = new BatchStatement().Add(new SimpleStatement(InsertCql));
+
+            Session.Execute(batch, "fast");
+
+            Assert.AreEqual(ConsistencyLevel.One, TestCluster.GetQueries(null, QueryType.Batch).Single().ConsistencyLevel);
+        }
+
+        [Test]
+        public void ExecutionProfile_Should_BeReportedAsAchievedConsistency_When_ExecutingBatch()
+        {
+            var batch = new BatchStatement().Add(new SimpleStatement(InsertCql));
+
+            var rs = Session.Execute(batch, "fast");
+
+            Assert.AreEqual(ConsistencyLevel.One, rs.Info.AchievedConsistency);
+        }
+
+        [Test]
+        public async Task ExecutionProfile_Should_BeReportedAsAchievedConsistency_When_SetOnPreparedStatement()
+        {
+            TestCluster.PrimeFluent(b => b.WhenQuery(FastCql).ThenRowsSuccess(new[] { "id" }, r => r.WithRow(1)));
+            var prepared = Session.Prepare(FastCql).SetExecutionProfile("fast");
+
+            var md = await ((Session)Session).BridgedSession.ExecutePreparedPage(
+                prepared.bridgedPreparedStatement.DangerousGetHandle(),
+                Array.Empty<object>(),
+                SerializerManager.Default.GetCurrentSerializer(),
+                false,
+                0,
+                true,
+                1,
+                12000,
+                -1,
+                null).ConfigureAwait(false);
+            using var result = new BridgedQueryResult(md);
+
+            Assert.AreEqual(ConsistencyLevel.One, result.GetAchievedConsistency());
+        }
+
+        [Test]
+        public void SetExecutionProfile_Should_Throw_When_ProfileWasNotAdded()
+        {
+            TestCluster.PrimeFluent(b => b.WhenQuery(FastCql).ThenRowsSuccess(new[] { "id" }, r => r.WithRow(1)));
+            var prepared = Session.Prepare(FastCql);
+
+            Assert.Throws<ArgumentException>(() => prepared.SetExecutionProfile("missing"));
+        }
+    }
+}
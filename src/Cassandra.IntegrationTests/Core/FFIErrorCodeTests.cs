@@ -0,0 +1,108 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Runtime.InteropServices;
+using Cassandra.Tests;
+using NUnit.Framework;
+using static Cassandra.RustBridge;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks the error codes passed by Rust alongside exceptions. Native functions are called
+    /// directly, so that the exception package can be inspected before it is thrown.
+    /// No cluster is needed, as only null handles are used.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short)]
+    public class FFIErrorCodeTests
+    {
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIMaybeException prepared_statement_set_consistency_level(IntPtr prepared_statement, ushort consistency_level, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIMaybeException result_get_int32(IntPtr result, nuint row, nuint column, out int value, out FFIBool isNull, IntPtr constructors);
+
+        private static IntPtr Constructors
+        {
+            get
+            {
+                unsafe
+                {
+                    return (IntPtr)Globals.ConstructorsPtr;
+                }
+            }
+        }
+
+        [Test]
+        public void Ok_Should_HaveNoErrorCodeNorHost()
+        {
+            var ok = FFIMaybeException.Ok();
+
+            Assert.AreEqual(FFIErrorCode.None, ok.ErrorCode);
+            Assert.IsNull(ok.ErrorHost);
+        }
+
+        [Test]
+        public void Exception_FromCSharp_Should_HaveOtherErrorCode()
+        {
+            var res = FFIMaybeException.FromException(new InvalidOperationException());
+            try
+            {
+                Assert.AreEqual(FFIErrorCode.Other, res.ErrorCode);
+                Assert.IsNull(res.ErrorHost);
+            }
+            finally
+            {
+                FreeExceptionHandle(ref res);
+            }
+        }
+
+        [Test]
+        public void Null_Argument_Should_HaveInvalidArgumentErrorCode()
+        {
+            var res = prepared_statement_set_consistency_level(IntPtr.Zero, (ushort)ConsistencyLevel.One, Constructors);
+            try
+            {
+                Assert.IsTrue(res.HasException);
+                Assert.AreEqual(FFIErrorCode.InvalidArgument, res.ErrorCode);
+                Assert.IsNull(res.ErrorHost);
+            }
+            finally
+            {
+                FreeExceptionHandle(ref res);
+            }
+        }
+
+        [Test]
+        public void Panic_Should_HaveInternalErrorCode()
+        {
+            var res = result_get_int32(IntPtr.Zero, 0, 0, out _, out _, Constructors);
+            try
+            {
+                Assert.IsTrue(res.HasException);
+                Assert.AreEqual(FFIErrorCode.InternalError, res.ErrorCode);
+            }
+            finally
+            {
+                FreeExceptionHandle(ref res);
+            }
+
+            Assert.AreEqual(FFIErrorCode.InternalError, res.ErrorCode);
+        }
+    }
+}
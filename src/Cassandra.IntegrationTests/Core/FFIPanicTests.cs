@@ -0,0 +1,92 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks that a panic in the Rust driver surfaces as a <see cref="DriverInternalError"/>
+    /// instead of crashing the process, and that validated null handles surface as
+    /// <see cref="ArgumentNullException"/>. No cluster is needed, as only null handles are used.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short)]
+    public class FFIPanicTests
+    {
+        [Test]
+        public void Panic_In_Native_Call_Should_Throw_DriverInternalError()
+        {
+            using var result = new BridgedQueryResult(default(RustBridge.ManuallyDestructible));
+
+            var ex = Assert.Throws<DriverInternalError>(() => result.GetInt32(0, 0));
+
+            StringAssert.Contains("BridgedQueryResult pointer", ex.Message);
+        }
+
+        [Test]
+        public void Native_Calls_Should_Work_After_Caught_Panic()
+        {
+            using var result = new BridgedQueryResult(default(RustBridge.ManuallyDestructible));
+            Assert.Throws<DriverInternalError>(() => result.GetInt32(0, 0));
+
+            // The same call panics again rather than crashing, and valid objects keep working.
+            Assert.Throws<DriverInternalError>(() => result.GetInt32(0, 1));
+            using var batch = BridgedBatch.Create(BatchType.Logged);
+            Assert.DoesNotThrow(() => batch.SetConsistencyLevel(ConsistencyLevel.Quorum));
+        }
+
+        [Test]
+        public void Panic_In_Result_Getter_Should_Throw_DriverInternalError()
+        {
+            using var result = new BridgedQueryResult(default(RustBridge.ManuallyDestructible));
+
+            Assert.Throws<DriverInternalError>(() => result.GetRowsCount());
+            Assert.Throws<DriverInternalError>(() => result.GetColumns());
+            Assert.Throws<DriverInternalError>(() => result.GetWarnings());
+            Assert.Throws<DriverInternalError>(() => result.GetPagingState());
+            Assert.Throws<DriverInternalError>(() => result.GetCoordinatorAddress());
+            Assert.Throws<DriverInternalError>(() => result.GetAchievedConsistency());
+            Assert.Throws<DriverInternalError>(() => result.GetAttemptCount());
+        }
+
+        [Test]
+        public void Null_PreparedStatement_Should_Throw_ArgumentNullException()
+        {
+            using var statement = new BridgedPreparedStatement(default(RustBridge.ManuallyDestructible));
+
+            var exceptions = new[]
+            {
+                Assert.Throws<ArgumentNullException>(() => statement.SetConsistencyLevel(ConsistencyLevel.One)),
+                Assert.Throws<ArgumentNullException>(() => statement.GetConsistencyLevel()),
+                Assert.Throws<ArgumentNullException>(() => statement.IsLwt()),
+                Assert.Throws<ArgumentNullException>(() => statement.IsIdempotent()),
+                Assert.Throws<ArgumentNullException>(() => statement.SetIsIdempotent(true)),
+                Assert.Throws<ArgumentNullException>(() => statement.SetTracing(true)),
+                Assert.Throws<ArgumentNullException>(() => statement.SetTimestamp(0)),
+                Assert.Throws<ArgumentNullException>(() => statement.ExtractVariablesMetadataFromRust()),
+            };
+
+            foreach (var ex in exceptions)
+            {
+                Assert.AreEqual("prepared_statement_ptr", ex.ParamName);
+            }
+        }
+    }
+}
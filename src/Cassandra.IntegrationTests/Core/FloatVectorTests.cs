@@ -0,0 +1,101 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.IntegrationTests.TestBase;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Binds <c>vector&lt;float, N&gt;</c> values through the Rust bridge and reads them back,
+    /// both through the bridge and through the rows returned by the session.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    [TestCassandraOrScyllaVersion(5, 0, 2025, 4)]
+    public class FloatVectorTests : BridgedResultTestBase
+    {
+        private string CreateTable(int dimension)
+        {
+            return CreateTable("float_vector", $"id int PRIMARY KEY, embedding vector<float, {dimension}>");
+        }
+
+        private static CqlVector<float> RandomVector(int dimension)
+        {
+            var random = new Random(42);
+            return new CqlVector<float>(Enumerable.Range(0, dimension).Select(_ => (float)random.NextDouble() - 0.5f).ToArray());
+        }
+
+        private async Task AssertRoundTrip(int dimension)
+        {
+            var tableName = CreateTable(dimension);
+            var vector = RandomVector(dimension);
+            Session.Execute(Session.Prepare($"INSERT INTO {tableName} (id, embedding) VALUES (?, ?)").Bind(1, vector));
+
+            var select = Session.Prepare($"SELECT embedding FROM {tableName} WHERE id = ?");
+            using (var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false))
+            {
+                CollectionAssert.AreEqual(vector.AsArray(), result.GetFloatVector(0, 0).AsArray());
+            }
+
+            var row = Session.Execute(select.Bind(1)).Single();
+            CollectionAssert.AreEqual(vector.AsArray(), row.GetValue<CqlVector<float>>("embedding").AsArray());
+        }
+
+        [Test]
+        public Task FloatVector_Of_Three_Dimensions_Should_RoundTrip()
+        {
+            return AssertRoundTrip(3);
+        }
+
+        [Test]
+        public Task FloatVector_Of_1536_Dimensions_Should_RoundTrip()
+        {
+            return AssertRoundTrip(1536);
+        }
+
+        [Test]
+        public async Task FloatVector_Should_ReadNull_When_Unset()
+        {
+            var tableName = CreateTable(3);
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (1)");
+
+            var select = Session.Prepare($"SELECT id, embedding FROM {tableName} WHERE id = ?");
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsNull(result.GetFloatVector(0, 1));
+            Assert.Throws<InvalidTypeException>(() => result.GetFloatVector(0, 0));
+        }
+
+        [Test]
+        public void FloatVector_Should_BeRejected_When_DimensionDiffersFromColumn()
+        {
+            var tableName = CreateTable(3);
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, embedding) VALUES (?, ?)");
+
+            var ex = Assert.Catch<DriverException>(() => Session.Execute(insert.Bind(1, new CqlVector<float>(1f, 2f))));
+
+            StringAssert.Contains("dimension 3", ex.Message);
+        }
+    }
+}
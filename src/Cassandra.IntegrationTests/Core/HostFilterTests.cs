@@ -0,0 +1,107 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System.Collections.Generic;
+using System.Linq;
+using System.Net;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Restricts the nodes the driver connects to, on a two-node cluster.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class HostFilterTests : SharedClusterTest
+    {
+        public HostFilterTests() : base(2, false)
+        {
+        }
+
+        private IPAddress NodeAddress(int node) => IPAddress.Parse(TestCluster.ClusterIpPrefix + node);
+
+        [Test]
+        public void AddressListHostFilter_Should_NotConnectToDeniedNode()
+        {
+            var denied = NodeAddress(2);
+            var cluster = GetNewTemporaryCluster(b => b.WithHostFilter(new AddressListHostFilter(null, new[] { denied })));
+
+            AssertOnlyNodeOneIsUsed(cluster, denied);
+        }
+
+        [Test]
+        public void AddressListHostFilter_Should_OnlyConnectToAllowedNode()
+        {
+            var cluster = GetNewTemporaryCluster(b => b.WithHostFilter(new AddressListHostFilter(new[] { NodeAddress(1) })));
+
+            AssertOnlyNodeOneIsUsed(cluster, NodeAddress(2));
+        }
+
+        [Test]
+        public void CustomHostFilter_Should_BeCalledWithNodeMetadata()
+        {
+            var filter = new RecordingHostFilter(NodeAddress(1));
+            var cluster = GetNewTemporaryCluster(b => b.WithHostFilter(filter));
+
+            AssertOnlyNodeOneIsUsed(cluster, NodeAddress(2));
+            lock (filter.Calls)
+            {
+                CollectionAssert.IsSupersetOf(filter.Calls.Select(call => call.Address.Address), new[] { NodeAddress(1), NodeAddress(2) });
+                Assert.IsTrue(filter.Calls.All(call => call.Datacenter != null));
+            }
+        }
+
+        private static void AssertOnlyNodeOneIsUsed(ICluster cluster, IPAddress filteredOut)
+        {
+            var session = cluster.Connect();
+            for (var i = 0; i < 20; i++)
+            {
+                var rs = session.Execute("SELECT key FROM system.local");
+                Assert.AreNotEqual(filteredOut, rs.Info.QueriedHost.Address);
+            }
+
+            var hosts = cluster.Metadata.AllHosts();
+            Assert.AreEqual(2, hosts.Count);
+            Assert.IsFalse(hosts.Single(h => h.Address.Address.Equals(filteredOut)).IsConnected);
+            Assert.IsTrue(hosts.Single(h => !h.Address.Address.Equals(filteredOut)).IsConnected);
+        }
+
+        private sealed class RecordingHostFilter : IHostFilter
+        {
+            private readonly IPAddress _accepted;
+
+            public RecordingHostFilter(IPAddress accepted)
+            {
+                _accepted = accepted;
+            }
+
+            public List<(IPEndPoint Address, string Datacenter, string Rack)> Calls { get; } = new List<(IPEndPoint, string, string)>();
+
+            public bool Accept(IPEndPoint address, string datacenter, string rack)
+            {
+                lock (Calls)
+                {
+                    Calls.Add((address, datacenter, rack));
+                }
+                return address.Address.Equals(_accepted);
+            }
+        }
+    }
+}
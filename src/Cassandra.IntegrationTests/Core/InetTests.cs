@@ -0,0 +1,84 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Linq;
+using System.Net;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks that IPv4 and IPv6 addresses keep their address family through the Rust bridge,
+    /// and that they are sent as network order octets.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class InetTests : BridgedResultTestBase
+    {
+        private static readonly IPAddress[] Values =
+        {
+            IPAddress.Parse("127.0.0.1"),
+            IPAddress.Parse("0.0.0.0"),
+            IPAddress.Parse("255.255.255.255"),
+            IPAddress.Parse("::1"),
+            IPAddress.Parse("2001:db8::ff00:42:8329"),
+            IPAddress.Parse("::ffff:192.0.2.1"),
+        };
+
+        private string CreateTable()
+        {
+            return CreateTable("inet", "id int PRIMARY KEY, value inet");
+        }
+
+        [Test]
+        public async Task Inet_Should_RoundTrip([ValueSource(nameof(Values))] IPAddress value)
+        {
+            var (read, row) = await RoundTrip("inet", value, result => result.GetInet(0, 0)).ConfigureAwait(false);
+
+            Assert.AreEqual(value, read);
+            Assert.AreEqual(value.AddressFamily, read.AddressFamily);
+            Assert.AreEqual(value, row.GetValue<IPAddress>("value"));
+        }
+
+        [Test]
+        public void Inet_Should_BeSentAsNetworkOrderOctets()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, IPAddress.Parse("192.0.2.1")));
+            Session.Execute(insert.Bind(2, IPAddress.Parse("2001:db8::1")));
+
+            var select = $"SELECT inetAsBlob(value) AS bytes FROM {tableName} WHERE id = ?";
+            CollectionAssert.AreEqual(
+                new byte[] { 192, 0, 2, 1 },
+                Session.Execute(new SimpleStatement(select, 1)).First().GetValue<byte[]>("bytes"));
+            CollectionAssert.AreEqual(
+                new byte[] { 0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1 },
+                Session.Execute(new SimpleStatement(select, 2)).First().GetValue<byte[]>("bytes"));
+        }
+
+        [Test]
+        public async Task GetInet_Should_ReturnNull_When_CellIsNull()
+        {
+            var (read, _) = await RoundTrip("inet", null, result => result.GetInet(0, 0)).ConfigureAwait(false);
+
+            Assert.IsNull(read);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Collections.Generic;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks lists and sets read element by element through <see cref="BridgedCollection"/>,
+    /// and how null and duplicate elements are bound.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class ListSetTests : BridgedResultTestBase
+    {
+        private string CreateTable()
+        {
+            return CreateTable("list_set", "id int PRIMARY KEY, ints list<int>, texts set<text>");
+        }
+
+        [Test]
+        public async Task ListOfInts_Should_RoundTrip()
+        {
+            var values = new List<int> { 3, -1, 3, int.MaxValue, 0 };
+
+            var (read, row) = await RoundTrip("list<int>", values, result =>
+            {
+                using var list = BridgedCollection.Open(result, 0, 0);
+                return Enumerable.Range(0, list.Count).Select(i => list.GetInt32(i).Value).ToList();
+            }).ConfigureAwait(false);
+
+            CollectionAssert.AreEqual(values, read);
+            CollectionAssert.AreEqual(values, row.GetValue<List<int>>("value"));
+        }
+
+        [Test]
+        public async Task SetOfTexts_Should_RoundTrip()
+        {
+            var values = new SortedSet<string> { "a", "ąę", "zebra", "" };
+
+            var (read, row) = await RoundTrip("set<text>", values, result =>
+            {
+                using var set = BridgedCollection.Open(result, 0, 0);
+                return Enumerable.Range(0, set.Count).Select(set.GetText).ToList();
+            }).ConfigureAwait(false);
+
+            CollectionAssert.AreEquivalent(values, read);
+            CollectionAssert.AreEquivalent(values, row.GetValue<ISet<string>>("value"));
+        }
+
+        [Test]
+        public void Set_Should_CollapseDuplicates_When_BoundAsList()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, texts) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, new[] { "a", "b", "a" }));
+
+            var texts = Session.Execute(new SimpleStatement($"SELECT texts FROM {tableName} WHERE id = ?", 1)).First().GetValue<ISet<string>>("texts");
+
+            CollectionAssert.AreEquivalent(new[] { "a", "b" }, texts);
+        }
+
+        [Test]
+        public void List_Should_BeRejected_When_ElementIsNull()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, ints) VALUES (?, ?)");
+
+            Assert.Throws<SerializationException>(() => Session.Execute(insert.Bind(1, new List<int?> { 1, null })));
+        }
+
+        [Test]
+        public async Task Open_Should_ReturnNull_When_CellIsNull()
+        {
+            var (isNull, _) = await RoundTrip("list<int>", null, result =>
+            {
+                using var list = BridgedCollection.Open(result, 0, 0);
+                return list == null;
+            }).ConfigureAwait(false);
+
+            Assert.IsTrue(isNull);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Collections.Generic;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks maps read as alternating keys and values through <see cref="BridgedCollection"/>,
+    /// including maps with nested collections as values.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class MapTests : BridgedResultTestBase
+    {
+        private string CreateTable()
+        {
+            return CreateTable("map", "id int PRIMARY KEY, counts map<text, int>, lists map<text, frozen<list<int>>>");
+        }
+
+        [Test]
+        public async Task MapOfTextToInt_Should_RoundTrip()
+        {
+            var values = new Dictionary<string, int> { { "a", 1 }, { "ąę", -7 }, { "", int.MaxValue } };
+
+            var (read, row) = await RoundTrip("map<text, int>", values, result =>
+            {
+                using var map = BridgedCollection.Open(result, 0, 0);
+                Assert.AreEqual(ColumnTypeCode.Text, map.GetItemType(0));
+                Assert.AreEqual(ColumnTypeCode.Int, map.GetItemType(1));
+                return Enumerable.Range(0, map.Count).ToDictionary(i => map.GetText(2 * i), i => map.GetInt32(2 * i + 1).Value);
+            }).ConfigureAwait(false);
+
+            CollectionAssert.AreEquivalent(values, read);
+            CollectionAssert.AreEquivalent(values, row.GetValue<IDictionary<string, int>>("value"));
+        }
+
+        [Test]
+        public async Task MapOfTextToList_Should_RoundTrip()
+        {
+            var values = new Dictionary<string, List<int>>
+            {
+                { "empty", new List<int>() },
+                { "primes", new List<int> { 2, 3, 5, 7 } },
+            };
+
+            var (read, _) = await RoundTrip("map<text, frozen<list<int>>>", values, result =>
+            {
+                using var map = BridgedCollection.Open(result, 0, 0);
+                Assert.AreEqual(ColumnTypeCode.List, map.GetItemType(1));
+                return Enumerable.Range(0, map.Count).ToDictionary(i => map.GetText(2 * i), i =>
+                {
+                    using var list = map.OpenCollection(2 * i + 1);
+                    return Enumerable.Range(0, list.Count).Select(j => list.GetInt32(j).Value).ToList();
+                });
+            }).ConfigureAwait(false);
+
+            Assert.AreEqual(values.Count, read.Count);
+            foreach (var entry in values)
+            {
+                CollectionAssert.AreEqual(entry.Value, read[entry.Key]);
+            }
+        }
+
+        [Test]
+        public void Map_Should_BeRejected_When_ValueIsNull()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, counts) VALUES (?, ?)");
+
+            Assert.Throws<SerializationException>(() => Session.Execute(insert.Bind(1, new Dictionary<string, int?> { { "a", null } })));
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks that null cells are told apart from empty ones, both through the Rust bridge
+    /// and through the rows returned by the session.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class NullAndEmptyValueTests : BridgedResultTestBase
+    {
+        private string CreateTable()
+        {
+            return CreateTable("null_empty", "id int PRIMARY KEY, txt text, data blob");
+        }
+
+        [Test]
+        public async Task IsNull_Should_BeTrue_For_NullColumns()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (1)");
+
+            var select = Session.Prepare($"SELECT id, txt, data FROM {tableName} WHERE id = ?");
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsFalse(result.IsNull(0, 0));
+            Assert.IsTrue(result.IsNull(0, 1));
+            Assert.IsTrue(result.IsNull(0, 2));
+            Assert.IsNull(result.GetBlob(0, 2));
+
+            var row = Session.Execute(select.Bind(1)).Single();
+            Assert.IsTrue(row.IsNull("txt"));
+            Assert.IsTrue(row.IsNull("data"));
+        }
+
+        [Test]
+        public async Task IsNull_Should_BeFalse_For_EmptyString()
+        {
+            var tableName = CreateTable();
+            Session.Execute(Session.Prepare($"INSERT INTO {tableName} (id, txt) VALUES (?, ?)").Bind(1, ""));
+
+            var select = Session.Prepare($"SELECT txt FROM {tableName} WHERE id = ?");
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsFalse(result.IsNull(0, 0));
+
+            var row = Session.Execute(select.Bind(1)).Single();
+            Assert.IsFalse(row.IsNull("txt"));
+            Assert.AreEqual("", row.GetValue<string>("txt"));
+        }
+
+        [Test]
+        public async Task IsNull_Should_BeFalse_For_ZeroLengthBlob()
+        {
+            var tableName = CreateTable();
+            Session.Execute(Session.Prepare($"INSERT INTO {tableName} (id, data) VALUES (?, ?)").Bind(1, Array.Empty<byte>()));
+
+            var select = Session.Prepare($"SELECT data FROM {tableName} WHERE id = ?");
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsFalse(result.IsNull(0, 0));
+            CollectionAssert.AreEqual(Array.Empty<byte>(), result.GetBlob(0, 0));
+
+            var row = Session.Execute(select.Bind(1)).Single();
+            Assert.IsFalse(row.IsNull("data"));
+            CollectionAssert.AreEqual(Array.Empty<byte>(), row.GetValue<byte[]>("data"));
+        }
+
+        [Test]
+        public async Task IsNull_Should_Throw_When_ColumnIsOutOfRange()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (1)");
+
+            var select = Session.Prepare($"SELECT txt FROM {tableName} WHERE id = ?");
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.Throws<InvalidArgumentException>(() => result.IsNull(0, 1));
+        }
+    }
+}
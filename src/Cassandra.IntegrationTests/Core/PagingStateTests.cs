@@ -0,0 +1,107 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Collections.Generic;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Pages through a result one row at a time by passing the paging state of each page
+    /// back to the Rust bridge, as a cursor-style result set would.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class PagingStateTests : BridgedResultTestBase
+    {
+        [Test]
+        public async Task ExecutePreparedPage_WithPagingState_ResumesAfterPreviousPage()
+        {
+            var tableName = "paging_state_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (pk int, ck int, PRIMARY KEY (pk, ck))");
+            for (var ck = 0; ck < 3; ck++)
+            {
+                Session.Execute($"INSERT INTO {tableName} (pk, ck) VALUES (1, {ck})");
+            }
+            var select = Session.Prepare($"SELECT ck FROM {tableName} WHERE pk = ?");
+
+            var clusteringKeys = new List<int>();
+            byte[] pagingState = null;
+            do
+            {
+                using var page = await ExecuteBridged(select, new object[] { 1 }, pagingState: pagingState).ConfigureAwait(false);
+                Assert.LessOrEqual(page.GetRowsCount(), 1);
+                if (page.GetRowsCount() == 1)
+                {
+                    clusteringKeys.Add(page.GetInt32(0, 0).Value);
+                }
+                pagingState = page.GetPagingState();
+            } while (pagingState != null && clusteringKeys.Count < 10);
+
+            CollectionAssert.AreEqual(new[] { 0, 1, 2 }, clusteringKeys);
+        }
+
+        [Test]
+        public async Task ExecutePreparedPage_WithoutPagingState_StartsFromFirstRow()
+        {
+            var tableName = "paging_state_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (pk int, ck int, PRIMARY KEY (pk, ck))");
+            Session.Execute($"INSERT INTO {tableName} (pk, ck) VALUES (1, 0)");
+            Session.Execute($"INSERT INTO {tableName} (pk, ck) VALUES (1, 1)");
+            var select = Session.Prepare($"SELECT ck FROM {tableName} WHERE pk = ?");
+
+            using var first = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+            using var again = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.AreEqual(0, first.GetInt32(0, 0));
+            Assert.AreEqual(0, again.GetInt32(0, 0));
+            Assert.IsNotNull(first.GetPagingState());
+        }
+
+        [Test]
+        public async Task ExecutePreparedPage_Should_ReadMultiPageResult_InTwoCalls_WithStoredPagingState()
+        {
+            var tableName = "paging_state_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (pk int, ck int, PRIMARY KEY (pk, ck))");
+            for (var ck = 0; ck < 5; ck++)
+            {
+                Session.Execute($"INSERT INTO {tableName} (pk, ck) VALUES (1, {ck})");
+            }
+            var select = Session.Prepare($"SELECT ck FROM {tableName} WHERE pk = ?");
+
+            // The paging state is opaque, so it can be stored, e.g. in a web page, and replayed later.
+            string storedPagingState;
+            using (var first = await ExecuteBridged(select, new object[] { 1 }, 3).ConfigureAwait(false))
+            {
+                Assert.AreEqual(3, first.GetRowsCount());
+                CollectionAssert.AreEqual(new int?[] { 0, 1, 2 }, new[] { first.GetInt32(0, 0), first.GetInt32(1, 0), first.GetInt32(2, 0) });
+                var pagingState = first.GetPagingState();
+                Assert.IsNotNull(pagingState);
+                storedPagingState = Convert.ToBase64String(pagingState);
+            }
+
+            using var second = await ExecuteBridged(select, new object[] { 1 }, 3, Convert.FromBase64String(storedPagingState)).ConfigureAwait(false);
+
+            Assert.AreEqual(2, second.GetRowsCount());
+            CollectionAssert.AreEqual(new int?[] { 3, 4 }, new[] { second.GetInt32(0, 0), second.GetInt32(1, 0) });
+            Assert.IsNull(second.GetPagingState());
+        }
+    }
+}
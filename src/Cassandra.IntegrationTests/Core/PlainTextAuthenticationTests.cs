@@ -0,0 +1,121 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Linq;
+using System.Net;
+using System.Threading;
+using Cassandra.IntegrationTests.TestBase;
+using Cassandra.IntegrationTests.TestClusterManagement;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Logs in with the SASL PLAIN mechanism to a node using <c>PasswordAuthenticator</c>,
+    /// with the default superuser.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class PlainTextAuthenticationTests : TestGlobals
+    {
+        private const string Password = "cassandra";
+        private const string Username = "cassandra";
+
+        private ITestCluster _testCluster;
+
+        [OneTimeSetUp]
+        public void OneTimeSetUp()
+        {
+            _testCluster = TestClusterManager.CreateNew(1, new TestClusterOptions
+            {
+                CassandraYaml = new[] { "authenticator: PasswordAuthenticator" },
+                JvmArgs = new[] { "-Dcassandra.superuser_setup_delay_ms=0" },
+            });
+            WaitForDefaultSuperuser();
+        }
+
+        [OneTimeTearDown]
+        public void OneTimeTearDown()
+        {
+            TestClusterManager.TryRemove();
+        }
+
+        [Test]
+        public void Connect_Should_Succeed_When_CredentialsAreValid()
+        {
+            using (var cluster = Connect(Username, Password))
+            {
+                var session = cluster.Connect();
+                var row = session.Execute("SELECT release_version FROM system.local").First();
+                Assert.IsNotNull(row.GetValue<string>("release_version"));
+            }
+        }
+
+        [Test]
+        public void Connect_Should_ThrowAuthenticationException_When_PasswordIsWrong()
+        {
+            using (var cluster = Connect(Username, "wrong-password"))
+            {
+                var ex = Assert.Throws<AuthenticationException>(() => cluster.Connect());
+
+                Assert.AreEqual(IPAddress.Parse(_testCluster.InitialContactPoint), ex.Host.Address);
+                StringAssert.Contains(_testCluster.InitialContactPoint, ex.Message);
+                StringAssert.DoesNotContain("wrong-password", ex.Message);
+            }
+        }
+
+        [Test]
+        public void Connect_Should_ThrowAuthenticationException_When_CredentialsAreMissing()
+        {
+            using (var cluster = ClusterBuilder().AddContactPoint(_testCluster.InitialContactPoint).Build())
+            {
+                Assert.Throws<AuthenticationException>(() => cluster.Connect());
+            }
+        }
+
+        private Cluster Connect(string username, string password)
+        {
+            return ClusterBuilder()
+                   .AddContactPoint(_testCluster.InitialContactPoint)
+                   .WithCredentials(username, password)
+                   .Build();
+        }
+
+        /// <summary>
+        /// The default superuser is created asynchronously after the node starts listening.
+        /// </summary>
+        private void WaitForDefaultSuperuser()
+        {
+            for (var attempt = 0; ; attempt++)
+            {
+                try
+                {
+                    using (var cluster = Connect(Username, Password))
+                    {
+                        cluster.Connect();
+                        return;
+                    }
+                }
+                catch (AuthenticationException) when (attempt < 30)
+                {
+                    Thread.Sleep(1000);
+                }
+            }
+        }
+    }
+}
@@ -763,6 +763,12 @@ namespace Cassandra.IntegrationTests.Core
             Assert.Throws<InvalidArgumentException>(() => Session.Execute(bound));
         }
 
+        [Test]
+        public void Prepare_InvalidSyntax_ThrowsSyntaxError()
+        {
+            Assert.Throws<SyntaxError>(() => Session.Prepare("SELEC key FROM system.local"));
+        }
+
         //////////////////////////////
         // Test Helpers
         //////////////////////////////
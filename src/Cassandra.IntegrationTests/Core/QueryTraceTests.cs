@@ -0,0 +1,75 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Net;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Reads query traces from <c>system_traces</c> through the Rust bridge.
+    /// Traces are written by hand, so that every field has a known value.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class QueryTraceTests : SharedClusterTest
+    {
+        public QueryTraceTests() : base(1, true)
+        {
+        }
+
+        [Test]
+        public async Task LoadAsync_Should_ReadSessionAndEvents()
+        {
+            var traceId = Guid.NewGuid();
+            var startedAt = DateTimeOffset.FromUnixTimeMilliseconds(1_700_000_000_123L);
+            Session.Execute(new SimpleStatement(
+                "INSERT INTO system_traces.sessions (session_id, client, command, coordinator, duration, request, started_at) " +
+                "VALUES (?, ?, 'QUERY', ?, 1234, 'Execute CQL3 query', ?)",
+                traceId, IPAddress.Parse("10.0.0.2"), IPAddress.Parse("10.0.0.1"), startedAt));
+            Session.Execute(new SimpleStatement(
+                "INSERT INTO system_traces.events (session_id, event_id, activity, source, source_elapsed, thread) " +
+                "VALUES (?, now(), 'Parsing SELECT', ?, 42, 'Native-Transport-Requests-1')",
+                traceId, IPAddress.Parse("10.0.0.1")));
+
+            var trace = await new QueryTrace(traceId, Session).LoadAsync().ConfigureAwait(false);
+
+            Assert.AreEqual("Execute CQL3 query", trace.RequestType);
+            Assert.AreEqual(IPAddress.Parse("10.0.0.1"), trace.Coordinator);
+            Assert.AreEqual(IPAddress.Parse("10.0.0.2"), trace.ClientAddress);
+            Assert.AreEqual(1234, trace.DurationMicros);
+            Assert.AreEqual(startedAt.ToUnixTimeMilliseconds(), trace.StartedAt);
+            Assert.AreEqual(1, trace.Events.Count);
+            var traceEvent = trace.Events[0];
+            Assert.AreEqual("Parsing SELECT", traceEvent.Description);
+            Assert.AreEqual(IPAddress.Parse("10.0.0.1"), traceEvent.Source);
+            Assert.AreEqual(42, traceEvent.SourceElapsedMicros);
+            Assert.AreEqual("Native-Transport-Requests-1", traceEvent.ThreadName);
+            Assert.Less((DateTimeOffset.UtcNow - traceEvent.Timestamp).Duration(), TimeSpan.FromMinutes(5));
+        }
+
+        [Test]
+        public void LoadAsync_Should_ThrowTraceRetrievalException_When_TraceDoesNotExist()
+        {
+            var trace = new QueryTrace(Guid.NewGuid(), Session);
+
+            Assert.ThrowsAsync<TraceRetrievalException>(() => trace.LoadAsync());
+        }
+    }
+}
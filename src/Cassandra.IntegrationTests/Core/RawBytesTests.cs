@@ -0,0 +1,79 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Text;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Reads the serialized bytes of cells from results of the Rust bridge, for custom deserialization.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class RawBytesTests : BridgedResultTestBase
+    {
+        private string CreateTable()
+        {
+            return CreateTable("raw_bytes", "id int PRIMARY KEY, i int, t text, l list<int>, b blob");
+        }
+
+        [Test]
+        public async Task GetRawBytes_Should_ReturnSerializedCells_WithoutDeserializing()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id, i, t, l, b) VALUES (1, 258, 'abc', [1, 2], 0xcafe)");
+            var select = Session.Prepare($"SELECT i, t, l, b FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            CollectionAssert.AreEqual(new byte[] { 0, 0, 1, 2 }, result.GetRawBytes(0, 0));
+            CollectionAssert.AreEqual(Encoding.UTF8.GetBytes("abc"), result.GetRawBytes(0, 1));
+            CollectionAssert.AreEqual(
+                new byte[] { 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 2 },
+                result.GetRawBytes(0, 2));
+            CollectionAssert.AreEqual(new byte[] { 0xca, 0xfe }, result.GetRawBytes(0, 3));
+        }
+
+        [Test]
+        public async Task GetRawBytes_Should_ReturnNull_When_CellIsNull()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (1)");
+            var select = Session.Prepare($"SELECT i, b FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsNull(result.GetRawBytes(0, 0));
+            Assert.IsNull(result.GetRawBytes(0, 1));
+        }
+
+        [Test]
+        public async Task GetRawBytes_Should_Throw_When_ColumnIsOutOfRange()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id, i) VALUES (1, 1)");
+            var select = Session.Prepare($"SELECT i FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.Throws<InvalidArgumentException>(() => result.GetRawBytes(0, 1));
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Streams rows through the Rust row cursor, which fetches pages in the background
+    /// and hands rows over to C# one by one or in batches.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class RowCursorTests : BridgedResultTestBase
+    {
+        private const int RowsCount = 10;
+
+        /// <summary>
+        /// Creates a table with <see cref="RowsCount"/> rows in partition 1 and none in partition 2,
+        /// returning the statement selecting the clustering keys of a partition.
+        /// </summary>
+        private PreparedStatement CreateTable()
+        {
+            var tableName = CreateTable("cursor", "pk int, ck int, PRIMARY KEY (pk, ck)");
+            var insert = Session.Prepare($"INSERT INTO {tableName} (pk, ck) VALUES (?, ?)");
+            for (var i = 0; i < RowsCount; i++)
+            {
+                Session.Execute(insert.Bind(1, i));
+            }
+            return Session.Prepare($"SELECT ck FROM {tableName} WHERE pk = ?");
+        }
+
+        private static List<int> ReadAndDispose(List<BridgedRow> rows)
+        {
+            var values = rows.Select(row => row.GetInt32(0).Value).ToList();
+            rows.ForEach(row => row.Dispose());
+            return values;
+        }
+
+        [Test]
+        public async Task NextRows_Should_ReturnEmptyFinalBatch_When_ResultIsEmpty()
+        {
+            var select = CreateTable();
+
+            using var cursor = await ExecuteIter(select, new object[] { 2 }, 5).ConfigureAwait(false);
+            var (rows, hasMore) = await cursor.NextRows(100).ConfigureAwait(false);
+
+            Assert.IsFalse(hasMore);
+            Assert.AreEqual(0, rows.Count);
+        }
+
+        [Test]
+        public async Task NextRows_Should_ReadAllRows_AcrossPages_And_SignalEndOfStream()
+        {
+            var select = CreateTable();
+            var values = new List<int>();
+
+            using var cursor = await ExecuteIter(select, new object[] { 1 }, 3).ConfigureAwait(false);
+            bool hasMore;
+            do
+            {
+                List<BridgedRow> rows;
+                (rows, hasMore) = await cursor.NextRows(4).ConfigureAwait(false);
+
+                Assert.LessOrEqual(rows.Count, 4);
+                if (hasMore)
+                {
+                    // Only the final batch may be empty, as a batch waits for at least one row.
+                    Assert.Greater(rows.Count, 0);
+                }
+                values.AddRange(ReadAndDispose(rows));
+            } while (hasMore);
+
+            CollectionAssert.AreEqual(Enumerable.Range(0, RowsCount), values);
+
+            // The stream stays ended.
+            var (remaining, stillHasMore) = await cursor.NextRows(4).ConfigureAwait(false);
+            Assert.IsFalse(stillHasMore);
+            Assert.AreEqual(0, remaining.Count);
+        }
+
+        [Test]
+        public async Task NextRows_Should_ReturnEmptyFinalBatch_When_AllRowsWereTaken()
+        {
+            var select = CreateTable();
+
+            using var cursor = await ExecuteIter(select, new object[] { 1 }, RowsCount).ConfigureAwait(false);
+            var values = new List<int>();
+            while (values.Count < RowsCount)
+            {
+                var (rows, hasMore) = await cursor.NextRows(RowsCount - values.Count).ConfigureAwait(false);
+                Assert.IsTrue(hasMore || values.Count + rows.Count == RowsCount);
+                values.AddRange(ReadAndDispose(rows));
+            }
+
+            var (last, lastHasMore) = await cursor.NextRows(RowsCount).ConfigureAwait(false);
+
+            Assert.IsFalse(lastHasMore);
+            Assert.AreEqual(0, last.Count);
+            CollectionAssert.AreEqual(Enumerable.Range(0, RowsCount), values);
+        }
+
+        [Test]
+        public async Task NextRow_Should_ReadAllRows_AcrossPages_And_ReturnNull_AtEndOfStream()
+        {
+            var select = CreateTable();
+            var values = new List<int>();
+
+            using var cursor = await ExecuteIter(select, new object[] { 1 }, 3).ConfigureAwait(false);
+            BridgedRow row;
+            while ((row = await cursor.NextRow().ConfigureAwait(false)) != null)
+            {
+                using (row)
+                {
+                    values.Add(row.GetInt32(0).Value);
+                }
+                Assert.LessOrEqual(values.Count, RowsCount);
+            }
+
+            CollectionAssert.AreEqual(Enumerable.Range(0, RowsCount), values);
+
+            // The stream stays ended.
+            Assert.IsNull(await cursor.NextRow().ConfigureAwait(false));
+        }
+
+        [Test]
+        public async Task NextRow_Should_ReturnNull_When_ResultIsEmpty()
+        {
+            var select = CreateTable();
+
+            using var cursor = await ExecuteIter(select, new object[] { 2 }, 5).ConfigureAwait(false);
+
+            Assert.IsNull(await cursor.NextRow().ConfigureAwait(false));
+        }
+
+        [Test]
+        public async Task Dispose_Should_StopStreaming_And_KeepTakenRowsReadable()
+        {
+            var select = CreateTable();
+
+            var cursor = await ExecuteIter(select, new object[] { 1 }, 2).ConfigureAwait(false);
+            var (rows, hasMore) = await cursor.NextRows(1).ConfigureAwait(false);
+            cursor.Dispose();
+
+            Assert.IsTrue(hasMore);
+            Assert.AreEqual(1, rows.Count);
+            Assert.AreEqual(0, rows[0].GetInt32(0));
+            rows[0].Dispose();
+            Assert.ThrowsAsync<ObjectDisposedException>(() => cursor.NextRows(1));
+
+            // Dropping the cursor mid-stream leaves the session usable.
+            using var another = await ExecuteIter(select, new object[] { 1 }, 2).ConfigureAwait(false);
+            var values = new List<int>();
+            do
+            {
+                (rows, hasMore) = await another.NextRows(RowsCount).ConfigureAwait(false);
+                values.AddRange(ReadAndDispose(rows));
+            } while (hasMore);
+            CollectionAssert.AreEqual(Enumerable.Range(0, RowsCount), values);
+        }
+    }
+}
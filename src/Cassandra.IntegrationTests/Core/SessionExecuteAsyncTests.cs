@@ -19,6 +19,7 @@ using System.Threading.Tasks;
 
 using NUnit.Framework;
 using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
 
 namespace Cassandra.IntegrationTests.Core
 {
@@ -108,5 +109,24 @@ namespace Cassandra.IntegrationTests.Core
                 Assert.NotNull(row.GetValue<string[]>("tokens"));
             }
         }
+
+        [Test]
+        public async Task SessionExecuteAsync_WithReadTimeoutMillis_OverridesSessionDefault()
+        {
+            const string query = "SELECT key FROM system.local WHERE key = 'slow'";
+            TestCluster.PrimeFluent(
+                b => b.WhenQuery(query)
+                      .ThenRowsSuccess(new[] { "key" }, r => r.WithRow("slow"))
+                      .WithDelayInMs(1000));
+
+            // A longer timeout than the delay lets the query complete.
+            var rowSet = await Session.ExecuteAsync(new SimpleStatement(query).SetReadTimeoutMillis(5000));
+            Assert.AreEqual("slow", rowSet.First().GetValue<string>("key"));
+
+            // A shorter one than the delay makes it time out, whatever the session default is.
+            var ex = Assert.ThrowsAsync<OperationTimedOutException>(
+                () => Session.ExecuteAsync(new SimpleStatement(query).SetReadTimeoutMillis(100)));
+            StringAssert.Contains("timeout 100ms", ex.Message);
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,76 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+using System.Linq;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks the connection pool sizing options and the pool state reported by <see cref="Extensions.GetState"/>.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class SessionStateTests : SharedClusterTest
+    {
+        public SessionStateTests() : base(1, true)
+        {
+        }
+
+        private ISession Connect(PoolingOptions poolingOptions)
+        {
+            return GetNewTemporaryCluster(b => b.WithPoolingOptions(poolingOptions)).Connect();
+        }
+
+        [Test]
+        public void GetState_Should_ReportPoolSize_When_SetPerHost()
+        {
+            var session = Connect(new PoolingOptions().SetConnectionsPerHost(3));
+
+            var state = session.GetState();
+
+            var host = state.GetConnectedHosts().Single();
+            Assert.AreEqual(3, state.GetOpenConnections(host));
+            Assert.AreEqual(0, state.GetInFlightQueries(host));
+        }
+
+        [Test]
+        public void GetState_Should_ReportAtLeastOneConnectionPerShard_ByDefault()
+        {
+            var session = Connect(new PoolingOptions());
+
+            var state = session.GetState();
+
+            var host = state.GetConnectedHosts().Single();
+            Assert.GreaterOrEqual(state.GetOpenConnections(host), 1);
+        }
+
+        [Test]
+        public void PoolSize_Should_BeRejected_When_ChangedAfterConnecting()
+        {
+            var poolingOptions = new PoolingOptions().SetConnectionsPerShard(1);
+            Connect(poolingOptions);
+
+            var ex = Assert.Throws<InvalidOperationException>(() => poolingOptions.SetConnectionsPerHost(4));
+            StringAssert.Contains("cannot be changed", ex.Message);
+            Assert.AreEqual(1, poolingOptions.GetPoolSize());
+            Assert.IsTrue(poolingOptions.IsPoolSizePerShard());
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System.Threading.Tasks;
+using Cassandra.IntegrationTests.TestBase;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Switches the keyspace of a session through the Rust bridge, without a <c>USE</c> statement.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class SessionUseKeyspaceTests : SharedClusterTest
+    {
+        public SessionUseKeyspaceTests() : base(1, true)
+        {
+        }
+
+        private BridgedSession BridgedSession => ((Session)Session).BridgedSession;
+
+        private string CreateKeyspace()
+        {
+            var keyspace = TestUtils.GetUniqueKeyspaceName().ToLowerInvariant();
+            Session.Execute(
+                $"CREATE KEYSPACE {keyspace} WITH replication = {{'class': 'SimpleStrategy', 'replication_factor': 1}}");
+            return keyspace;
+        }
+
+        [Test]
+        public async Task UseKeyspace_Should_SwitchBetweenKeyspaces()
+        {
+            var first = CreateKeyspace();
+            var second = CreateKeyspace();
+
+            await BridgedSession.UseKeyspace(first, true).ConfigureAwait(false);
+            Assert.AreEqual(first, Session.Keyspace);
+            Session.Execute("CREATE TABLE used (id int PRIMARY KEY)");
+
+            await BridgedSession.UseKeyspace(second, true).ConfigureAwait(false);
+            Assert.AreEqual(second, Session.Keyspace);
+
+            // Unqualified names now resolve in the second keyspace, which has no such table.
+            Assert.Throws<InvalidQueryException>(() => Session.Execute("SELECT id FROM used"));
+            Session.Execute($"SELECT id FROM {first}.used");
+        }
+
+        [Test]
+        public async Task UseKeyspace_Should_LowercaseName_When_CaseInsensitive()
+        {
+            var keyspace = CreateKeyspace();
+
+            await BridgedSession.UseKeyspace(keyspace.ToUpperInvariant(), false).ConfigureAwait(false);
+
+            Assert.AreEqual(keyspace, Session.Keyspace);
+        }
+
+        [Test]
+        public void UseKeyspace_Should_Throw_When_NameIsEmpty()
+        {
+            Assert.ThrowsAsync<InvalidArgumentException>(() => BridgedSession.UseKeyspace("", false));
+            Assert.ThrowsAsync<InvalidArgumentException>(() => BridgedSession.UseKeyspace(null, false));
+        }
+
+        [Test]
+        public void UseKeyspace_Should_Throw_When_KeyspaceDoesNotExist()
+        {
+            Assert.ThrowsAsync<InvalidQueryException>(
+                () => BridgedSession.UseKeyspace(TestUtils.GetUniqueKeyspaceName().ToLowerInvariant(), true));
+        }
+    }
+}
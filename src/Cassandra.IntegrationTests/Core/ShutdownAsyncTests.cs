@@ -14,9 +14,11 @@
 using System;
 using System.Threading;
 using System.Threading.Tasks;
+using Cassandra.Serialization;
 using Cassandra.Tests;
 using NUnit.Framework;
 using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
 
 namespace Cassandra.IntegrationTests.Core
 {
@@ -141,6 +143,28 @@ namespace Cassandra.IntegrationTests.Core
             );
         }
 
+        [Test]
+        public async Task BridgedSession_DoubleShutdownIsNoOp_And_LaterQueriesFailWithClearError()
+        {
+            var localCluster = GetNewTemporaryCluster();
+            var localSession = (Session)localCluster.Connect();
+            var bridgedSession = localSession.BridgedSession;
+
+            // Shut the native session down twice, bypassing the managed guard against repeated shutdowns.
+            for (int i = 0; i < 2; i++)
+            {
+                using var emptyResource = new EmptyRustResource(await bridgedSession.Shutdown().ConfigureAwait(false));
+            }
+
+            // The handle is still alive, so the query reaches Rust, which reports the shutdown.
+            var ex = Assert.ThrowsAsync<AlreadyShutdownException>(() =>
+                bridgedSession.Query("SELECT * FROM system.local", false, 0, true, 100, -1, -1));
+            StringAssert.Contains("shut down", ex.Message);
+
+            // Shutting down through the session afterwards is still safe.
+            Assert.DoesNotThrowAsync(() => localSession.ShutdownAsync());
+        }
+
         [Test]
         public void Session_PreparedExecuteDuringShutdown()
         {
@@ -306,6 +330,54 @@ namespace Cassandra.IntegrationTests.Core
             }
         }
 
+        [Test]
+        public async Task BridgedRowCursor_KeepsFetchingPagesAfterShutdown()
+        {
+            var localSession = (Session)GetNewTemporarySession(KeyspaceName);
+            string tableName = "tbl" + Guid.NewGuid().ToString("N").ToLower();
+            CreateTable(localSession, tableName);
+
+            const int rowCount = 10;
+            for (int i = 0; i < rowCount; i++)
+            {
+                localSession.Execute(new SimpleStatement($"INSERT INTO {tableName} (id, text_sample) VALUES (?, ?)", i, "text"));
+            }
+            var preparedSelect = localSession.Prepare($"SELECT * FROM {tableName}");
+
+            // One row per page, so that most pages are fetched after the shutdown.
+            var md = await localSession.BridgedSession.ExecuteIter(
+                preparedSelect.bridgedPreparedStatement.DangerousGetHandle(),
+                Array.Empty<object>(),
+                SerializerManager.Default.GetCurrentSerializer(),
+                false,
+                0,
+                true,
+                1,
+                12000,
+                -1).ConfigureAwait(false);
+            using var cursor = new BridgedRowCursor(md);
+
+            using (var firstRow = await cursor.NextRow().ConfigureAwait(false))
+            {
+                Assert.IsNotNull(firstRow);
+            }
+
+            await localSession.ShutdownAsync().ConfigureAwait(false);
+
+            // The cursor does not hold the session lock, so it is not cut off by the shutdown.
+            var fetched = 1;
+            BridgedRow row;
+            while ((row = await cursor.NextRow().ConfigureAwait(false)) != null)
+            {
+                row.Dispose();
+                fetched++;
+            }
+            Assert.AreEqual(rowCount, fetched);
+
+            // New requests are rejected all the same.
+            Assert.Throws<ObjectDisposedException>(() => localSession.Execute($"SELECT * FROM {tableName}"));
+        }
+
         //////////////////////////////
         // Test Helpers
         //////////////////////////////
@@ -0,0 +1,100 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+using System;
+
+using System.Diagnostics;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.IntegrationTests.TestClusterManagement.Simulacron;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Runs idempotent queries against a cluster with one artificially slow node,
+    /// checking that speculative executions on the other node answer first.
+    /// </summary>
+    public class SpeculativeExecutionTests : SimulacronTest
+    {
+        private const string Query = "SELECT key FROM system.local WHERE key = 'speculative'";
+        private const int SlowNodeDelayMillis = 3000;
+
+        public SpeculativeExecutionTests() : base(false, new SimulacronOptions { Nodes = "2" }, false)
+        {
+        }
+
+        private ICluster BuildCluster(ISpeculativeExecutionPolicy policy)
+        {
+            return ClusterBuilder()
+                   .AddContactPoint(TestCluster.InitialContactPoint)
+                   .WithLoadBalancingPolicy(new RoundRobinPolicy())
+                   .WithSpeculativeExecutionPolicy(policy)
+                   .WithSocketOptions(new SocketOptions().SetReadTimeoutMillis(2 * SlowNodeDelayMillis))
+                   .Build();
+        }
+
+        private void PrimeSlowFirstNode()
+        {
+            var nodes = TestCluster.GetNodes().ToList();
+            nodes[0].PrimeFluent(
+                b => b.WhenQuery(Query)
+                      .ThenRowsSuccess(new[] { "key" }, r => r.WithRow("slow"))
+                      .WithDelayInMs(SlowNodeDelayMillis));
+            nodes[1].PrimeFluent(
+                b => b.WhenQuery(Query)
+                      .ThenRowsSuccess(new[] { "key" }, r => r.WithRow("fast")));
+        }
+
+        [Test]
+        public async Task SpeculativeExecution_Should_AnswerFromFastNode_When_StatementIsIdempotent()
+        {
+            PrimeSlowFirstNode();
+            using var cluster = BuildCluster(new ConstantSpeculativeExecutionPolicy(50, 1));
+            var session = cluster.Connect();
+
+            // Round robin sends every other query to the slow node first.
+            for (var i = 0; i < 4; i++)
+            {
+                var stopwatch = Stopwatch.StartNew();
+                var rowSet = await session.ExecuteAsync(new SimpleStatement(Query).SetIdempotence(true)).ConfigureAwait(false);
+                stopwatch.Stop();
+
+                Assert.AreEqual("fast", rowSet.First().GetValue<string>("key"));
+                Assert.Less(stopwatch.ElapsedMilliseconds, SlowNodeDelayMillis);
+            }
+            Assert.Greater(TestCluster.GetNodes().First().GetQueries(Query).Count, 0);
+        }
+
+        [Test]
+        public async Task SpeculativeExecution_Should_WaitForSlowNode_When_StatementIsNotIdempotent()
+        {
+            PrimeSlowFirstNode();
+            using var cluster = BuildCluster(new ConstantSpeculativeExecutionPolicy(50, 1));
+            var session = cluster.Connect();
+
+            var keys = await Task.WhenAll(Enumerable.Range(0, 4).Select(async _ =>
+            {
+                var rowSet = await session.ExecuteAsync(new SimpleStatement(Query).SetIdempotence(false)).ConfigureAwait(false);
+                return rowSet.First().GetValue<string>("key");
+            })).ConfigureAwait(false);
+
+            // Without speculative executions, the queries sent to the slow node are answered by it.
+            CollectionAssert.Contains(keys, "slow");
+        }
+    }
+}
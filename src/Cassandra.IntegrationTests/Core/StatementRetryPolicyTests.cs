@@ -0,0 +1,70 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+using System;
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks the retry policy set on the session and the one overriding it on a prepared statement,
+    /// against a node primed to time out batch log writes.
+    /// </summary>
+    public class StatementRetryPolicyTests : SimulacronTest
+    {
+        private const string Cql = "INSERT INTO ks.retried (id) VALUES (1)";
+
+        private PreparedStatement PrimeWriteTimeout()
+        {
+            TestCluster.PrimeFluent(
+                b => b.WhenQuery(Cql)
+                      .ThenWriteTimeout("write timeout", (int)ConsistencyLevel.One, 0, 1, "BATCH_LOG"));
+            return Session.Prepare(Cql).SetIdempotence(true);
+        }
+
+        [Test]
+        public void DefaultRetryPolicy_Should_RetryIdempotentWriteTimeoutOnce()
+        {
+            var prepared = PrimeWriteTimeout();
+
+            Assert.Throws<WriteTimeoutException>(() => Session.Execute(prepared.Bind()));
+
+            VerifyBoundStatement(Cql, 2);
+        }
+
+        [Test]
+        public void FallthroughRetryPolicy_Should_SurfaceFirstError_When_SetOnStatement()
+        {
+            var prepared = PrimeWriteTimeout().SetRetryPolicy(new FallthroughRetryPolicy());
+
+            Assert.Throws<WriteTimeoutException>(() => Session.Execute(prepared.Bind()));
+
+            VerifyBoundStatement(Cql, 1);
+        }
+
+        [Test]
+        public void StatementRetryPolicy_Should_FallBackToSessionPolicy_When_Unset()
+        {
+            var prepared = PrimeWriteTimeout().SetRetryPolicy(new FallthroughRetryPolicy()).SetRetryPolicy(null);
+
+            Assert.IsNull(prepared.RetryPolicy);
+            Assert.Throws<WriteTimeoutException>(() => Session.Execute(prepared.Bind()));
+
+            VerifyBoundStatement(Cql, 2);
+        }
+    }
+}
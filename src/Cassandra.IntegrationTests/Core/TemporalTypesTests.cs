@@ -0,0 +1,124 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks timestamps, dates and times through the Rust bridge, up to the extremes of their encodings.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class TemporalTypesTests : BridgedResultTestBase
+    {
+        private static readonly DateTimeOffset[] Timestamps =
+        {
+            DateTimeOffset.UnixEpoch,
+            DateTimeOffset.UnixEpoch.AddMilliseconds(-1),
+            new DateTimeOffset(1, 1, 1, 0, 0, 0, 0, TimeSpan.Zero),
+            new DateTimeOffset(9999, 12, 31, 23, 59, 59, 999, TimeSpan.Zero),
+        };
+
+        private static readonly LocalDate[] Dates =
+        {
+            new LocalDate(1970, 1, 1),
+            new LocalDate(1969, 12, 31),
+            new LocalDate(2400, 2, 29),
+            // The first and last dates of the protocol encoding.
+            new LocalDate(-5877641, 6, 23),
+            new LocalDate(5881580, 7, 11),
+        };
+
+        private static readonly LocalTime[] Times =
+        {
+            new LocalTime(0),
+            new LocalTime(1),
+            new LocalTime(12, 0, 0, 0),
+            new LocalTime(23, 59, 59, 999999999),
+        };
+
+        private string CreateTable()
+        {
+            return CreateTable("temporal", "id int PRIMARY KEY, ts timestamp, d date, t time");
+        }
+
+        [Test]
+        public async Task Timestamp_Should_RoundTrip([ValueSource(nameof(Timestamps))] DateTimeOffset value)
+        {
+            var (read, _) = await RoundTrip("timestamp", value, result => result.GetTimestamp(0, 0)).ConfigureAwait(false);
+
+            Assert.AreEqual(value, read);
+        }
+
+        [Test]
+        public async Task Timestamp_Should_TreatUnspecifiedDateTimeAsUtc()
+        {
+            var value = new DateTime(2024, 2, 29, 13, 14, 15, 16, DateTimeKind.Unspecified);
+
+            var (read, _) = await RoundTrip("timestamp", value, result => result.GetTimestamp(0, 0)).ConfigureAwait(false);
+
+            Assert.AreEqual(new DateTimeOffset(value, TimeSpan.Zero), read);
+        }
+
+        [Test]
+        public async Task Date_Should_RoundTrip([ValueSource(nameof(Dates))] LocalDate value)
+        {
+            var (read, _) = await RoundTrip("date", value, result => result.GetDate(0, 0)).ConfigureAwait(false);
+
+            Assert.AreEqual(value, read);
+        }
+
+        [Test]
+        public async Task Time_Should_RoundTrip([ValueSource(nameof(Times))] LocalTime value)
+        {
+            var (read, _) = await RoundTrip("time", value, result => result.GetTime(0, 0)).ConfigureAwait(false);
+
+            Assert.AreEqual(value, read);
+        }
+
+        [Test]
+        public void Date_Should_BeSentWithEpochCenteredAtTwoToThe31()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, d) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, new LocalDate(1970, 1, 2)));
+
+            var row = Session.Execute(new SimpleStatement($"SELECT dateAsBlob(d) AS bytes FROM {tableName} WHERE id = ?", 1)).First();
+
+            CollectionAssert.AreEqual(new byte[] { 0x80, 0x00, 0x00, 0x01 }, row.GetValue<byte[]>("bytes"));
+        }
+
+        [Test]
+        public async Task Getters_Should_ReturnNull_When_CellsAreNull()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id) VALUES (1)");
+            var select = Session.Prepare($"SELECT ts, d, t FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.IsNull(result.GetTimestamp(0, 0));
+            Assert.IsNull(result.GetDate(0, 1));
+            Assert.IsNull(result.GetTime(0, 2));
+        }
+    }
+}
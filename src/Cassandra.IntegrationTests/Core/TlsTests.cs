@@ -0,0 +1,197 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.IO;
+using System.Linq;
+using System.Security.Authentication;
+using System.Security.Cryptography;
+using System.Security.Cryptography.X509Certificates;
+using System.Text;
+using Cassandra.IntegrationTests.TestBase;
+using Cassandra.IntegrationTests.TestClusterManagement;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Connects to a node with client encryption enabled. The node certificate is read from
+    /// <c>cassandra.crt</c> in <c>CCM_SSL_PATH</c> or <c>~/ssl</c>, as when the cluster is created.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class TlsTests : TestGlobals
+    {
+        private ITestCluster _testCluster;
+
+        [OneTimeSetUp]
+        public void OneTimeSetUp()
+        {
+            _testCluster = TestClusterManager.CreateNew(1, new TestClusterOptions { UseSsl = true });
+        }
+
+        [OneTimeTearDown]
+        public void OneTimeTearDown()
+        {
+            TestClusterManager.TryRemove();
+        }
+
+        [Test]
+        public void Connect_Should_QueryOverTls_When_NodeCertificateIsTrusted()
+        {
+            // CCM certificates are not issued for the address of the node.
+            var sslOptions = new SSLOptions()
+                .SetCertificateAuthorities(new X509CertificateCollection { LoadNodeCertificate() })
+                .SetHostNameVerification(false);
+
+            using (var cluster = Connect(sslOptions))
+            {
+                var session = cluster.Connect();
+                var row = session.Execute("SELECT release_version FROM system.local").First();
+                Assert.IsNotNull(row.GetValue<string>("release_version"));
+            }
+        }
+
+        [Test]
+        public void Connect_Should_QueryOverTls_When_ProtocolIsTls12()
+        {
+            var sslOptions = new SSLOptions(SslProtocols.Tls12, false, null)
+                .SetCertificateAuthorities(new X509CertificateCollection { LoadNodeCertificate() })
+                .SetHostNameVerification(false);
+
+            using (var cluster = Connect(sslOptions))
+            {
+                var session = cluster.Connect();
+                Assert.AreEqual(1, session.Execute("SELECT key FROM system.local").GetRows().Count());
+            }
+        }
+
+        [Test]
+        public void Connect_Should_Fail_When_NodeCertificateIsNotTrusted()
+        {
+            using (var rsa = RSA.Create(2048))
+            {
+                var request = new CertificateRequest("CN=untrusted", rsa, HashAlgorithmName.SHA256, RSASignaturePadding.Pkcs1);
+                request.CertificateExtensions.Add(new X509BasicConstraintsExtension(true, false, 0, true));
+                var untrusted = request.CreateSelfSigned(DateTimeOffset.UtcNow.AddDays(-1), DateTimeOffset.UtcNow.AddDays(1));
+                var sslOptions = new SSLOptions()
+                    .SetCertificateAuthorities(new X509CertificateCollection { untrusted })
+                    .SetHostNameVerification(false);
+
+                using (var cluster = Connect(sslOptions))
+                {
+                    Assert.Throws<NoHostAvailableException>(() => cluster.Connect());
+                }
+            }
+        }
+
+        [Test]
+        public void Connect_Should_QueryOverTls_When_CertificateAuthoritiesAreReadFromFile()
+        {
+            var caPath = Path.GetTempFileName();
+            try
+            {
+                File.WriteAllText(caPath, LoadNodeCertificate().ExportCertificatePem());
+                var sslOptions = new SSLOptions()
+                    .SetCertificateAuthoritiesPath(caPath)
+                    .SetHostNameVerification(false);
+
+                using (var cluster = Connect(sslOptions))
+                {
+                    var session = cluster.Connect();
+                    Assert.AreEqual(1, session.Execute("SELECT key FROM system.local").GetRows().Count());
+                }
+            }
+            finally
+            {
+                File.Delete(caPath);
+            }
+        }
+
+        [Test]
+        public void Connect_Should_ThrowArgumentException_When_CertificateAuthoritiesFileIsMissing()
+        {
+            var caPath = Path.Combine(Path.GetTempPath(), Guid.NewGuid().ToString("N") + ".pem");
+            var sslOptions = new SSLOptions().SetCertificateAuthoritiesPath(caPath);
+
+            using (var cluster = Connect(sslOptions))
+            {
+                var ex = Assert.Throws<ArgumentException>(() => cluster.Connect());
+                StringAssert.Contains(caPath, ex.Message);
+            }
+        }
+
+        [Test]
+        public void Connect_Should_ThrowArgumentException_When_CertificateAuthoritiesAreSetTwice()
+        {
+            var caPath = Path.GetTempFileName();
+            try
+            {
+                var sslOptions = new SSLOptions()
+                    .SetCertificateAuthorities(new X509CertificateCollection { LoadNodeCertificate() })
+                    .SetCertificateAuthoritiesPath(caPath);
+
+                using (var cluster = Connect(sslOptions))
+                {
+                    var ex = Assert.Throws<ArgumentException>(() => cluster.Connect());
+                    StringAssert.Contains("either inline or as a file path", ex.Message);
+                }
+            }
+            finally
+            {
+                File.Delete(caPath);
+            }
+        }
+
+        [Test]
+        public void Connect_Should_ThrowNotSupportedException_When_ProtocolIsOlderThanTls12()
+        {
+#pragma warning disable SYSLIB0039 // TLS 1.0 and 1.1 are obsolete, which is what is being tested.
+            var sslOptions = new SSLOptions(SslProtocols.Tls11, false, null);
+#pragma warning restore SYSLIB0039
+
+            using (var cluster = Connect(sslOptions))
+            {
+                Assert.Throws<NotSupportedException>(() => cluster.Connect());
+            }
+        }
+
+        private Cluster Connect(SSLOptions sslOptions)
+        {
+            return ClusterBuilder()
+                   .AddContactPoint(_testCluster.InitialContactPoint)
+                   .WithSSL(sslOptions)
+                   .Build();
+        }
+
+        private static X509Certificate2 LoadNodeCertificate()
+        {
+            var sslPath = Environment.GetEnvironmentVariable("CCM_SSL_PATH") ?? Path.Combine(TestHelper.GetHomePath(), "ssl");
+            var bytes = File.ReadAllBytes(Path.Combine(sslPath, "cassandra.crt"));
+            if (Encoding.ASCII.GetString(bytes).Contains("-----BEGIN CERTIFICATE-----"))
+            {
+                return X509Certificate2.CreateFromPem(Encoding.ASCII.GetString(bytes));
+            }
+#if NET9_0_OR_GREATER
+            return X509CertificateLoader.LoadCertificate(bytes);
+#else
+            return new X509Certificate2(bytes);
+#endif
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.Linq;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Writes values of CQL types and reads them back through the Rust bridge.
+    /// Covers edge cases of the FFI representation of each type.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class TypeRoundTripTests : SharedClusterTest
+    {
+        public TypeRoundTripTests() : base(1, true)
+        {
+        }
+
+        private T RoundTrip<T>(string cqlType, T value)
+        {
+            var tableName = "round_trip_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, value {cqlType})");
+            Session.Execute(Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (1, ?)").Bind(value));
+            return Session.Execute($"SELECT value FROM {tableName} WHERE id = 1").Single().GetValue<T>("value");
+        }
+
+        [Test]
+        [TestCase(0L)]
+        [TestCase(1_700_000_000_123L)]
+        [TestCase(-1L)]
+        [TestCase(-2_208_988_800_000L)] // 1900-01-01
+        public void Timestamp_RoundTrips_IncludingBeforeUnixEpoch(long epochMillis)
+        {
+            var value = DateTimeOffset.FromUnixTimeMilliseconds(epochMillis);
+            Assert.AreEqual(value, RoundTrip("timestamp", value));
+        }
+
+        [Test]
+        [TestCase("123.456")]
+        [TestCase("-0.001")]
+        [TestCase("79228162514264337593543950335")]
+        [TestCase("-79228162514264337593543950335")]
+        [TestCase("0.0000000000000000000000000001")]
+        [TestCase("-7.9228162514264337593543950335")]
+        public void Decimal_RoundTrips_WithScale(string value)
+        {
+            var decimalValue = decimal.Parse(value, CultureInfo.InvariantCulture);
+            Assert.AreEqual(decimalValue, RoundTrip("decimal", decimalValue));
+        }
+
+        [Test]
+        public void Decimal_WrittenAsLiteral_IsReadPreservingScale()
+        {
+            var tableName = "round_trip_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, value decimal)");
+            Session.Execute($"INSERT INTO {tableName} (id, value) VALUES (1, -12345.678900)");
+
+            var negative = Session.Execute($"SELECT value FROM {tableName} WHERE id = 1").Single().GetValue<decimal>("value");
+            Assert.AreEqual(-12345.678900m, negative);
+            Assert.AreEqual(6, (decimal.GetBits(negative)[3] >> 16) & 0xFF);
+        }
+
+        [Test]
+        [TestCase(short.MinValue)]
+        [TestCase((short)0)]
+        [TestCase(short.MaxValue)]
+        public void SmallInt_RoundTrips_AtRangeBoundaries(short value)
+        {
+            Assert.AreEqual(value, RoundTrip("smallint", value));
+        }
+
+        [Test]
+        [TestCase(sbyte.MinValue)]
+        [TestCase((sbyte)0)]
+        [TestCase(sbyte.MaxValue)]
+        public void TinyInt_RoundTrips_AtRangeBoundaries(sbyte value)
+        {
+            Assert.AreEqual(value, RoundTrip("tinyint", value));
+        }
+
+        [Test]
+        public void Uuid_RoundTrips_PreservingByteOrder()
+        {
+            var value = Guid.Parse("00112233-4455-6677-8899-aabbccddeeff");
+            Assert.AreEqual(value, RoundTrip("uuid", value));
+            Assert.AreEqual(Guid.Empty, RoundTrip("uuid", Guid.Empty));
+        }
+
+        [Test]
+        public void TimeUuid_RoundTrips()
+        {
+            var value = TimeUuid.NewId(DateTimeOffset.FromUnixTimeMilliseconds(1_700_000_000_123L));
+            var read = RoundTrip("timeuuid", value);
+            Assert.AreEqual(value, read);
+            Assert.AreEqual(value.GetDate(), read.GetDate());
+        }
+
+        [Test]
+        public void TimeUuid_WhenNotVersion1_IsRejectedBeforeSending()
+        {
+            var tableName = "round_trip_" + Guid.NewGuid().ToString("N").ToLower();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, value timeuuid)");
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (1, ?)");
+            TimeUuid randomUuid = Guid.NewGuid();
+
+            Assert.Throws<SerializationException>(() => Session.Execute(insert.Bind(randomUuid)));
+        }
+
+        [Test]
+        public void ListOfText_RoundTrips_PreservingOrderAndDuplicates()
+        {
+            var value = new List<string> { "b", "a", "", "b", "zażółć" };
+            CollectionAssert.AreEqual(value, RoundTrip<IEnumerable<string>>("list<text>", value));
+        }
+
+        [Test]
+        public void ListOfInt_RoundTrips_PreservingOrderAndDuplicates()
+        {
+            var value = new List<int> { int.MaxValue, 0, -1, 0, int.MinValue };
+            CollectionAssert.AreEqual(value, RoundTrip<IEnumerable<int>>("list<int>", value));
+        }
+
+        [Test]
+        public void ListOfUuid_RoundTrips_PreservingOrder()
+        {
+            var value = new List<Guid> { Guid.NewGuid(), Guid.Empty, Guid.NewGuid() };
+            CollectionAssert.AreEqual(value, RoundTrip<IEnumerable<Guid>>("list<uuid>", value));
+        }
+
+        [Test]
+        public void MapOfTextToInt_RoundTrips()
+        {
+            var value = new Dictionary<string, int> { { "a", 1 }, { "", 0 }, { "zażółć", int.MinValue } };
+            CollectionAssert.AreEquivalent(value, RoundTrip<IDictionary<string, int>>("map<text, int>", value));
+        }
+
+        [Test]
+        public void MapOfUuidToBigint_RoundTrips()
+        {
+            var value = new Dictionary<Guid, long>
+            {
+                { Guid.NewGuid(), long.MaxValue },
+                { Guid.Empty, 0L },
+                { Guid.NewGuid(), long.MinValue },
+            };
+            CollectionAssert.AreEquivalent(value, RoundTrip<IDictionary<Guid, long>>("map<uuid, bigint>", value));
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Linq;
+using System.Numerics;
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CollectionAssert = NUnit.Framework.Legacy.CollectionAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Checks varints well beyond the range of <c>long</c> through the Rust bridge,
+    /// and that they are sent in their shortest two's complement encoding.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class VarintTests : BridgedResultTestBase
+    {
+        private static readonly BigInteger[] Values =
+        {
+            BigInteger.Zero,
+            BigInteger.One,
+            BigInteger.MinusOne,
+            new BigInteger(127),
+            new BigInteger(128),
+            new BigInteger(-128),
+            new BigInteger(-129),
+            new BigInteger(long.MaxValue) + 1,
+            new BigInteger(long.MinValue) - 1,
+            BigInteger.Pow(2, 200) + 12345,
+            -BigInteger.Pow(3, 150),
+        };
+
+        private string CreateTable()
+        {
+            return CreateTable("varint", "id int PRIMARY KEY, value varint");
+        }
+
+        [Test]
+        public async Task Varint_Should_RoundTrip([ValueSource(nameof(Values))] BigInteger value)
+        {
+            var (read, row) = await RoundTrip("varint", value, result => result.GetVarint(0, 0)).ConfigureAwait(false);
+
+            Assert.AreEqual(value, read);
+            Assert.AreEqual(value, row.GetValue<BigInteger>("value"));
+        }
+
+        [Test]
+        public void Varint_Should_BeSentInShortestEncoding([ValueSource(nameof(Values))] BigInteger value)
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?)");
+            Session.Execute(insert.Bind(1, value));
+
+            var row = Session.Execute(new SimpleStatement($"SELECT varintAsBlob(value) AS bytes FROM {tableName} WHERE id = ?", 1)).First();
+
+            CollectionAssert.AreEqual(value.ToByteArray(isUnsigned: false, isBigEndian: true), row.GetValue<byte[]>("bytes"));
+        }
+
+        [Test]
+        public async Task GetVarint_Should_ReturnNull_When_CellIsNull()
+        {
+            var (read, _) = await RoundTrip("varint", null, result => result.GetVarint(0, 0)).ConfigureAwait(false);
+
+            Assert.IsNull(read);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Threading.Tasks;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Reads the outcome of lightweight transactions from results of the Rust bridge.
+    /// </summary>
+    [TestFixture, Category(TestCategory.Short), Category(TestCategory.RealCluster)]
+    public class WasAppliedTests : BridgedResultTestBase
+    {
+        private string CreateTable()
+        {
+            return CreateTable("was_applied", "id int PRIMARY KEY, value int");
+        }
+
+        [Test]
+        public async Task WasApplied_Should_ReflectOutcomeOfConditionalInsert()
+        {
+            var tableName = CreateTable();
+            var insert = Session.Prepare($"INSERT INTO {tableName} (id, value) VALUES (?, ?) IF NOT EXISTS");
+
+            using var first = await ExecuteBridged(insert, new object[] { 1, 10 }).ConfigureAwait(false);
+            using var second = await ExecuteBridged(insert, new object[] { 1, 20 }).ConfigureAwait(false);
+
+            Assert.IsTrue(first.WasApplied());
+            Assert.IsFalse(second.WasApplied());
+        }
+
+        [Test]
+        public async Task WasApplied_Should_ReflectOutcomeOfConditionalUpdate()
+        {
+            var tableName = CreateTable();
+            Session.Execute($"INSERT INTO {tableName} (id, value) VALUES (1, 10)");
+            var update = Session.Prepare($"UPDATE {tableName} SET value = ? WHERE id = ? IF value = ?");
+
+            using var mismatch = await ExecuteBridged(update, new object[] { 30, 1, 99 }).ConfigureAwait(false);
+            using var match = await ExecuteBridged(update, new object[] { 30, 1, 10 }).ConfigureAwait(false);
+
+            Assert.IsFalse(mismatch.WasApplied());
+            Assert.IsTrue(match.WasApplied());
+        }
+
+        [Test]
+        public async Task WasApplied_Should_Throw_When_ResultIsNotConditional()
+        {
+            var tableName = CreateTable();
+            var select = Session.Prepare($"SELECT value FROM {tableName} WHERE id = ?");
+
+            using var result = await ExecuteBridged(select, new object[] { 1 }).ConfigureAwait(false);
+
+            Assert.Throws<InvalidArgumentException>(() => result.WasApplied());
+        }
+    }
+}
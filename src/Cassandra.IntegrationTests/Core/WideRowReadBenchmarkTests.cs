@@ -0,0 +1,149 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Diagnostics;
+using System.Linq;
+using System.Threading.Tasks;
+using Cassandra.Serialization;
+using Cassandra.Tests;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.IntegrationTests.Core
+{
+    /// <summary>
+    /// Benchmarks reading a few columns of a wide table through the Rust bridge, comparing a baseline
+    /// that materializes every cell of a row with the lazy path that deserializes only the cells read.
+    /// Run explicitly; timings and managed allocations are written to the test output.
+    /// </summary>
+    [TestFixture, Explicit, Category(TestCategory.RealClusterLong)]
+    public class WideRowReadBenchmarkTests : SharedClusterTest
+    {
+        private const int ColumnsCount = 100;
+        private const int RowsCount = 500;
+        private const int Passes = 20;
+        private static readonly int[] ReadColumns = { 0, 50, 99 };
+
+        public WideRowReadBenchmarkTests() : base(1, true)
+        {
+        }
+
+        [Test]
+        public async Task Read_ThreeOfHundredColumns()
+        {
+            var tableName = "wide_" + Guid.NewGuid().ToString("N").ToLower();
+            var columns = Enumerable.Range(0, ColumnsCount).Select(i => $"c{i}").ToArray();
+            Session.Execute($"CREATE TABLE {tableName} (id int PRIMARY KEY, {string.Join(", ", columns.Select(c => c + " int"))})");
+            var insert = Session.Prepare(
+                $"INSERT INTO {tableName} (id, {string.Join(", ", columns)}) VALUES (?{string.Concat(Enumerable.Repeat(", ?", ColumnsCount))})");
+            for (var id = 0; id < RowsCount; id++)
+            {
+                var values = new object[ColumnsCount + 1];
+                values[0] = id;
+                for (var c = 0; c < ColumnsCount; c++)
+                {
+                    values[c + 1] = id + c;
+                }
+                await Session.ExecuteAsync(insert.Bind(values)).ConfigureAwait(false);
+            }
+            var select = Session.Prepare($"SELECT {string.Join(", ", columns)} FROM {tableName}");
+
+            var md = await ((Session)Session).BridgedSession.ExecutePreparedPage(
+                select.bridgedPreparedStatement.DangerousGetHandle(),
+                Array.Empty<object>(),
+                SerializerManager.Default.GetCurrentSerializer(),
+                false,
+                0,
+                true,
+                RowsCount,
+                12000,
+                -1,
+                null).ConfigureAwait(false);
+            using var result = new BridgedQueryResult(md);
+            Assert.AreEqual(RowsCount, result.GetRowsCount());
+
+            long expectedSum = 0;
+            for (var id = 0; id < RowsCount; id++)
+            {
+                expectedSum += ReadColumns.Sum(c => (long)(id + c));
+            }
+
+            // Baseline: every cell of a row is deserialized and boxed up front, as a fully
+            // materialized row would, and only then are the three columns picked out of it.
+            var materialized = Measure(() =>
+            {
+                long sum = 0;
+                for (var row = 0; row < RowsCount; row++)
+                {
+                    var values = new object[ColumnsCount];
+                    for (var column = 0; column < ColumnsCount; column++)
+                    {
+                        values[column] = result.GetInt32(row, column);
+                    }
+                    foreach (var column in ReadColumns)
+                    {
+                        sum += (int)values[column];
+                    }
+                }
+                return sum;
+            }, expectedSum);
+
+            // Lazy path: only the three columns read are ever deserialized.
+            var lazy = Measure(() =>
+            {
+                long sum = 0;
+                for (var row = 0; row < RowsCount; row++)
+                {
+                    foreach (var column in ReadColumns)
+                    {
+                        sum += result.GetInt32(row, column).Value;
+                    }
+                }
+                return sum;
+            }, expectedSum);
+
+            TestContext.Out.WriteLine(
+                $"{RowsCount} rows x {ReadColumns.Length} of {ColumnsCount} columns, average of {Passes} passes:");
+            TestContext.Out.WriteLine(
+                $"  materialized: {materialized.Milliseconds:F3} ms, {materialized.AllocatedBytes} bytes allocated");
+            TestContext.Out.WriteLine(
+                $"  lazy:         {lazy.Milliseconds:F3} ms, {lazy.AllocatedBytes} bytes allocated");
+            Assert.Less(lazy.AllocatedBytes, materialized.AllocatedBytes);
+        }
+
+        /// <summary>
+        /// Runs <paramref name="readAll"/> <see cref="Passes"/> times, checking the sum it returns,
+        /// and returns the average time and managed bytes allocated per pass.
+        /// </summary>
+        private static (double Milliseconds, long AllocatedBytes) Measure(Func<long> readAll, long expectedSum)
+        {
+            double totalMilliseconds = 0;
+            long totalAllocatedBytes = 0;
+            for (var pass = 0; pass < Passes; pass++)
+            {
+                var allocatedBefore = GC.GetAllocatedBytesForCurrentThread();
+                var stopwatch = Stopwatch.StartNew();
+                var sum = readAll();
+                stopwatch.Stop();
+                totalAllocatedBytes += GC.GetAllocatedBytesForCurrentThread() - allocatedBefore;
+                totalMilliseconds += stopwatch.Elapsed.TotalMilliseconds;
+                Assert.AreEqual(expectedSum, sum);
+            }
+            return (totalMilliseconds / Passes, totalAllocatedBytes / Passes);
+        }
+    }
+}
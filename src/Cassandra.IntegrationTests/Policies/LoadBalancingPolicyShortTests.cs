@@ -19,6 +19,7 @@ using System.Collections.Generic;
 using System.Linq;
 using System.Net;
 using System.Threading;
+using System.Threading.Tasks;
 using Cassandra.IntegrationTests.TestBase;
 using Cassandra.IntegrationTests.TestClusterManagement;
 using Cassandra.Serialization;
@@ -114,6 +115,81 @@ namespace Cassandra.IntegrationTests.Policies.Tests
             Assert.AreEqual(1, coordinators.Count, "With RF=1, the same partition key must always route to the same coordinator, when tablets disabled");
         }
 
+        /// <summary>
+        /// With RF=1 and token awareness, a single-partition read is sent straight to the only replica,
+        /// so the trace shows no event on another node (which would mean the coordinator forwarded it).
+        /// </summary>
+        [Test]
+        public async Task TokenAware_SinglePartitionQuery_IsServedByReplicaAsCoordinator()
+        {
+            var cluster = GetNewTemporaryCluster(b => b.WithLoadBalancingPolicy(new TokenAwarePolicy(new RoundRobinPolicy())));
+            var session = cluster.Connect();
+            var ks = TestUtils.GetUniqueKeyspaceName().ToLowerInvariant();
+            session.Execute($"CREATE KEYSPACE \"{ks}\" WITH replication = {{'class': 'NetworkTopologyStrategy', 'replication_factor': 1}} AND tablets = {{'enabled': false}}");
+            session.ChangeKeyspace(ks);
+            session.Execute("CREATE TABLE tbl (k int PRIMARY KEY, v int)");
+            session.Execute("INSERT INTO tbl (k, v) VALUES (42, 1)");
+
+            var ps = session.Prepare("SELECT v FROM tbl WHERE k = ?");
+            for (var i = 0; i < 5; i++)
+            {
+                var rs = session.Execute(ps.Bind(42).EnableTracing());
+                var trace = await rs.Info.GetQueryTraceAsync().ConfigureAwait(false);
+
+                Assert.AreEqual(rs.Info.QueriedHost.Address, trace.Coordinator);
+                Assert.IsNotEmpty(trace.Events);
+                Assert.IsTrue(trace.Events.All(e => trace.Coordinator.Equals(e.Source)),
+                    "The coordinator must be the replica, so no other node may take part in the read");
+            }
+        }
+
+        /// <summary>
+        /// With RF=1 and a token-aware rack-aware policy preferring the rack of the nodes, a single-partition
+        /// read is served by the replica alone. The test cluster puts all nodes in a single rack, so this
+        /// checks that the rack preference keeps replicas first rather than ordering racks against each other.
+        /// </summary>
+        [Test]
+        public async Task TokenAware_RackAware_SinglePartitionQuery_IsServedByReplicaInLocalRack()
+        {
+            var local = Session.Execute("SELECT data_center, rack FROM system.local").First();
+            var dc = local.GetValue<string>("data_center");
+            var rack = local.GetValue<string>("rack");
+            var cluster = GetNewTemporaryCluster(b => b.WithLoadBalancingPolicy(new TokenAwarePolicy(new RackAwareRoundRobinPolicy(dc, rack))));
+            var session = cluster.Connect();
+            var ks = TestUtils.GetUniqueKeyspaceName().ToLowerInvariant();
+            session.Execute($"CREATE KEYSPACE \"{ks}\" WITH replication = {{'class': 'NetworkTopologyStrategy', 'replication_factor': 1}} AND tablets = {{'enabled': false}}");
+            session.ChangeKeyspace(ks);
+            session.Execute("CREATE TABLE tbl (k int PRIMARY KEY, v int)");
+
+            var ps = session.Prepare("SELECT v FROM tbl WHERE k = ?");
+            for (var key = 0; key < 5; key++)
+            {
+                var rs = session.Execute(ps.Bind(key).EnableTracing());
+                var trace = await rs.Info.GetQueryTraceAsync().ConfigureAwait(false);
+
+                Assert.AreEqual(rs.Info.QueriedHost.Address, trace.Coordinator);
+                Assert.IsTrue(trace.Events.All(e => trace.Coordinator.Equals(e.Source)),
+                    "The coordinator must be the replica, so no other node may take part in the read");
+                Assert.AreEqual(rack, cluster.Metadata.AllHosts().Single(h => h.Address.Address.Equals(trace.Coordinator)).Rack);
+            }
+        }
+
+        /// <summary>
+        /// Scylla nodes report sharding information regardless of whether the shard-aware port is used.
+        /// </summary>
+        [Test]
+        public void ShardAwareness_IsReported_When_ShardAwarePortIsDisabled()
+        {
+            var defaultCluster = GetNewTemporaryCluster();
+            var defaultSession = (Session)defaultCluster.Connect();
+            Assert.IsTrue(defaultSession.BridgedSession.IsShardAware());
+
+            var cluster = GetNewTemporaryCluster(b => b.WithPoolingOptions(PoolingOptions.Create().DisableShardAwareness()));
+            var session = (Session)cluster.Connect();
+            Assert.IsTrue(session.BridgedSession.IsShardAware());
+            Assert.AreEqual(1, session.Execute("SELECT key FROM system.local").GetRows().Count());
+        }
+
         /// <summary>
         /// With RF=1 and 3 nodes, 100 distinct partition keys should be distributed
         /// across more than one coordinator.
@@ -191,6 +267,15 @@ namespace Cassandra.IntegrationTests.Policies.Tests
             });
         }
 
+        [Test]
+        public void DcAware_WrongDc_Connects_When_NotFailingOnUnknownDc()
+        {
+            // The unknown datacenter is only logged, and failover routes requests to the existing nodes.
+            var cluster = GetNewTemporaryCluster(b => b.WithLoadBalancingPolicy(new DCAwareRoundRobinPolicy("nonexistent_dc", true, false)));
+            var session = cluster.Connect();
+            Assert.AreEqual(1, session.Execute("SELECT key FROM system.local").GetRows().Count());
+        }
+
         /// <summary>
         /// Validate that TokenAwarePolicy wrapping RoundRobinPolicy allows queries to succeed.
         /// Tests that token-aware config is correctly translated to the Rust layer.
@@ -0,0 +1,76 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Net;
+using System.Runtime.InteropServices;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using AddressTranslator = Cassandra.BridgedSession.BridgedAddressTranslator;
+
+namespace Cassandra.Tests
+{
+    public class BridgedAddressTranslatorTests : BaseUnitTest
+    {
+        [Test]
+        public void BuildFrom_LeavesTranslatorUnset_When_NoTranslator()
+        {
+            var translator = AddressTranslator.BuildFrom(null);
+
+            Assert.IsTrue(translator.translator.IsEmpty());
+            Assert.AreEqual(IntPtr.Zero, translator.translate);
+        }
+
+        [Test]
+        public void BuildFrom_LeavesTranslatorUnset_When_DefaultTranslator()
+        {
+            var translator = AddressTranslator.BuildFrom(new DefaultAddressTranslator());
+
+            Assert.IsTrue(translator.translator.IsEmpty());
+            Assert.AreEqual(IntPtr.Zero, translator.translate);
+        }
+
+        [Test]
+        public void BuildFrom_PassesCustomTranslator_WithCallback()
+        {
+            var custom = new FixedAddressTranslator();
+
+            var translator = AddressTranslator.BuildFrom(custom);
+
+            Assert.IsFalse(translator.translator.IsEmpty());
+            Assert.AreNotEqual(IntPtr.Zero, translator.translator.free);
+            Assert.AreNotEqual(IntPtr.Zero, translator.translate);
+            var handle = GCHandle.FromIntPtr(translator.translator.gchandle);
+            try
+            {
+                Assert.AreSame(custom, handle.Target);
+            }
+            finally
+            {
+                // Rust would own and free the handle once the session is created.
+                handle.Free();
+            }
+        }
+
+        private sealed class FixedAddressTranslator : IAddressTranslator
+        {
+            public IPEndPoint Translate(IPEndPoint address)
+            {
+                return new IPEndPoint(IPAddress.Loopback, address.Port);
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    public class BridgedCompressionTests : BaseUnitTest
+    {
+        [Test]
+        public void BuildCompression_MapsCompressionTypes()
+        {
+            Assert.AreEqual(BridgedSession.BridgedCompression.None, BridgedSession.BuildCompression(new ProtocolOptions()));
+            Assert.AreEqual(BridgedSession.BridgedCompression.Snappy, BridgedSession.BuildCompression(new ProtocolOptions().SetCompression(CompressionType.Snappy)));
+            Assert.AreEqual(BridgedSession.BridgedCompression.Lz4, BridgedSession.BuildCompression(new ProtocolOptions().SetCompression(CompressionType.LZ4)));
+        }
+
+        [Test]
+        public void BuildCompression_DisablesCompressionWithoutOptions()
+        {
+            Assert.AreEqual(BridgedSession.BridgedCompression.None, BridgedSession.BuildCompression(null));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using ConnectionConfig = Cassandra.BridgedSession.BridgedConnectionConfig;
+
+namespace Cassandra.Tests
+{
+    public class BridgedConnectionConfigTests : BaseUnitTest
+    {
+        [Test]
+        public void BuildFrom_UsesDefaultHeartbeatInterval_When_PoolingOptionsAreNotSet()
+        {
+            Assert.AreEqual(PoolingOptions.DefaultHeartBeatInterval, ConnectionConfig.BuildFrom(null).heartbeatIntervalMillis);
+        }
+
+        [Test]
+        public void BuildFrom_PassesHeartbeatInterval()
+        {
+            var config = ConnectionConfig.BuildFrom(new PoolingOptions().SetHeartBeatInterval(5000));
+
+            Assert.AreEqual(5000, config.heartbeatIntervalMillis);
+        }
+
+        [Test]
+        public void BuildFrom_DisablesHeartbeats_When_IntervalIsZero()
+        {
+            var config = ConnectionConfig.BuildFrom(new PoolingOptions().SetHeartBeatInterval(0));
+
+            Assert.AreEqual(0, config.heartbeatIntervalMillis);
+        }
+
+        [Test]
+        public void BuildFrom_KeepsDefaultPoolSize_When_NotSet()
+        {
+            Assert.AreEqual(0, ConnectionConfig.BuildFrom(null).poolSize);
+            Assert.AreEqual(0, ConnectionConfig.BuildFrom(new PoolingOptions()).poolSize);
+        }
+
+        [Test]
+        public void BuildFrom_PassesPoolSize()
+        {
+            var perHost = ConnectionConfig.BuildFrom(new PoolingOptions().SetConnectionsPerHost(3));
+            Assert.AreEqual(3, perHost.poolSize);
+            Assert.IsFalse(perHost.poolSizePerShard);
+
+            var perShard = ConnectionConfig.BuildFrom(new PoolingOptions().SetConnectionsPerShard(2));
+            Assert.AreEqual(2, perShard.poolSize);
+            Assert.IsTrue(perShard.poolSizePerShard);
+        }
+
+        [Test]
+        public void PoolSize_CannotBeChanged_When_Frozen()
+        {
+            var options = new PoolingOptions().SetConnectionsPerShard(2);
+            options.FreezePoolSize();
+
+            Assert.Throws<System.InvalidOperationException>(() => options.SetConnectionsPerHost(4));
+            Assert.Throws<System.InvalidOperationException>(() => options.SetConnectionsPerShard(4));
+            Assert.AreEqual(2, options.GetPoolSize());
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Runtime.InteropServices;
+using Moq;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using Authenticator = Cassandra.BridgedSession.BridgedAuthenticator;
+using Credentials = Cassandra.BridgedSession.BridgedCredentials;
+
+namespace Cassandra.Tests
+{
+    public class BridgedCredentialsTests : BaseUnitTest
+    {
+        [Test]
+        public void BuildFrom_PassesPlainTextCredentials()
+        {
+            var credentials = Credentials.BuildFrom(new PlainTextAuthProvider("user", "secret"));
+            Assert.AreEqual("user", credentials.username);
+            Assert.AreEqual("secret", credentials.password);
+        }
+
+        [Test]
+        public void PlainTextAuthProvider_RejectsEmptyUsername()
+        {
+            Assert.Throws<ArgumentNullException>(() => new PlainTextAuthProvider("", "secret"));
+            Assert.Throws<ArgumentNullException>(() => new PlainTextAuthProvider(null, "secret"));
+        }
+
+        [Test]
+        public void BuildFrom_DisablesAuthenticationWithoutProvider()
+        {
+            Assert.IsNull(Credentials.BuildFrom(null).username);
+            Assert.IsNull(Credentials.BuildFrom(NoneAuthProvider.Instance).username);
+        }
+
+        [Test]
+        public void BuildFrom_LeavesCustomProvidersToAuthenticator()
+        {
+            var provider = Mock.Of<IAuthProvider>();
+
+            Assert.IsNull(Credentials.BuildFrom(provider).username);
+
+            var authenticator = Authenticator.BuildFrom(provider);
+            try
+            {
+                Assert.IsFalse(authenticator.provider.IsEmpty());
+                Assert.AreNotEqual(IntPtr.Zero, authenticator.newAuthenticator);
+                Assert.AreNotEqual(IntPtr.Zero, authenticator.initialResponse);
+                Assert.AreNotEqual(IntPtr.Zero, authenticator.evaluateChallenge);
+                Assert.AreSame(provider, GCHandle.FromIntPtr(authenticator.provider.gchandle).Target);
+            }
+            finally
+            {
+                GCHandle.FromIntPtr(authenticator.provider.gchandle).Free();
+            }
+        }
+
+        [Test]
+        public void BuildFrom_DoesNotBridgeBuiltInProviders()
+        {
+            Assert.IsTrue(Authenticator.BuildFrom(null).provider.IsEmpty());
+            Assert.IsTrue(Authenticator.BuildFrom(NoneAuthProvider.Instance).provider.IsEmpty());
+            Assert.IsTrue(Authenticator.BuildFrom(new PlainTextAuthProvider("user", "secret")).provider.IsEmpty());
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using ExecutionProfileConfig = Cassandra.BridgedSession.BridgedExecutionProfileConfig;
+
+namespace Cassandra.Tests
+{
+    public class BridgedExecutionProfileConfigTests : BaseUnitTest
+    {
+        private static Configuration CreateConfiguration()
+        {
+            return Cluster.Builder()
+                          .AddContactPoint("127.0.0.1")
+                          .WithExecutionProfiles(options => options
+                              .WithProfile("fast", profile => profile
+                                  .WithConsistencyLevel(ConsistencyLevel.One)
+                                  .WithSerialConsistencyLevel(ConsistencyLevel.LocalSerial)
+                                  .WithReadTimeoutMillis(100))
+                              .WithProfile("partial", profile => profile
+                                  .WithConsistencyLevel(ConsistencyLevel.Quorum)))
+                          .GetConfiguration();
+        }
+
+        [Test]
+        public void BuildFrom_MapsConsistenciesAndTimeout()
+        {
+            var config = CreateConfiguration();
+
+            var profile = ExecutionProfileConfig.BuildFrom(config.RequestOptions["fast"], config);
+
+            Assert.IsTrue(profile.hasConsistency);
+            Assert.AreEqual((ushort)ConsistencyLevel.One, profile.consistency);
+            Assert.IsTrue(profile.hasSerialConsistency);
+            Assert.AreEqual((ushort)ConsistencyLevel.LocalSerial, profile.serialConsistency);
+            Assert.AreEqual(100, profile.requestTimeoutMillis);
+        }
+
+        [Test]
+        public void BuildFrom_FallsBackToClusterOptions_When_UnsetInProfile()
+        {
+            var config = CreateConfiguration();
+
+            var profile = ExecutionProfileConfig.BuildFrom(config.RequestOptions["partial"], config);
+
+            Assert.AreEqual((ushort)ConsistencyLevel.Quorum, profile.consistency);
+            Assert.AreEqual((ushort)ConsistencyLevel.Serial, profile.serialConsistency);
+            Assert.AreEqual(SocketOptions.DefaultReadTimeoutMillis, profile.requestTimeoutMillis);
+            Assert.AreEqual(BridgedSession.BridgedRetryPolicyKind.Default, profile.retryPolicy);
+        }
+
+        [Test]
+        public void BuildDefault_KeepsRustDriverConsistencyAndTimeout()
+        {
+            var profile = ExecutionProfileConfig.BuildDefault(CreateConfiguration());
+
+            Assert.IsFalse(profile.hasConsistency);
+            Assert.IsFalse(profile.hasSerialConsistency);
+            Assert.Less(profile.requestTimeoutMillis, 0);
+        }
+    }
+}
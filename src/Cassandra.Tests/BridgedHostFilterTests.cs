@@ -0,0 +1,70 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+using System.Net;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using HostFilter = Cassandra.BridgedSession.BridgedHostFilter;
+
+namespace Cassandra.Tests
+{
+    public class BridgedHostFilterTests : BaseUnitTest
+    {
+        private static readonly IPAddress Node1 = IPAddress.Parse("10.0.0.1");
+        private static readonly IPAddress Node2 = IPAddress.Parse("10.0.0.2");
+
+        [Test]
+        public void BuildFrom_LeavesFilterUnset_When_NoFilter()
+        {
+            var filter = HostFilter.BuildFrom(null);
+
+            Assert.IsNull(filter.allowed);
+            Assert.IsNull(filter.denied);
+            Assert.AreEqual(IntPtr.Zero, filter.accept);
+        }
+
+        [Test]
+        public void BuildFrom_PassesAddressLists_WithoutCallback()
+        {
+            var filter = HostFilter.BuildFrom(new AddressListHostFilter(new[] { Node1, Node2 }, new[] { Node2 }));
+
+            Assert.AreEqual("10.0.0.1,10.0.0.2", filter.allowed);
+            Assert.AreEqual("10.0.0.2", filter.denied);
+            Assert.AreEqual(IntPtr.Zero, filter.accept);
+        }
+
+        [Test]
+        public void BuildFrom_LeavesAllowListUnset_When_OnlyDenyListIsGiven()
+        {
+            var filter = HostFilter.BuildFrom(new AddressListHostFilter(null, new[] { Node1 }));
+
+            Assert.IsNull(filter.allowed);
+            Assert.AreEqual("10.0.0.1", filter.denied);
+        }
+
+        [Test]
+        public void AddressListHostFilter_DenyListTakesPrecedence()
+        {
+            var filter = new AddressListHostFilter(new[] { Node1, Node2 }, new[] { Node2 });
+
+            Assert.IsTrue(filter.Accept(new IPEndPoint(Node1, 9042), "dc1", "rack1"));
+            Assert.IsFalse(filter.Accept(new IPEndPoint(Node2, 9042), "dc1", "rack1"));
+            Assert.IsFalse(filter.Accept(new IPEndPoint(IPAddress.Parse("10.0.0.3"), 9042), null, null));
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using LoadBalancingPolicy = Cassandra.BridgedSession.BridgedLoadBalancingPolicy;
+using ExecutionProfileConfig = Cassandra.BridgedSession.BridgedExecutionProfileConfig;
+
+namespace Cassandra.Tests
+{
+    public class BridgedLoadBalancingPolicyTests : BaseUnitTest
+    {
+        private static BridgedExecutionProfile BuildProfile(LoadBalancingPolicy policy)
+        {
+            var config = ExecutionProfileConfig.BuildDefault(Cluster.Builder().AddContactPoint("127.0.0.1").GetConfiguration());
+            config.loadBalancingPolicy = policy;
+            return BridgedExecutionProfile.Build(config);
+        }
+
+        [Test]
+        public void BuildFrom_EnablesTokenAwareness_When_WrappedInTokenAwarePolicy()
+        {
+            Assert.IsTrue(LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new RoundRobinPolicy())).isTokenAware);
+            Assert.IsTrue(LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new DCAwareRoundRobinPolicy("dc1"))).isTokenAware);
+        }
+
+        [Test]
+        public void BuildFrom_EnablesTokenAwareness_ForDefaultPolicy()
+        {
+            Assert.IsTrue(LoadBalancingPolicy.BuildFrom(Policies.DefaultLoadBalancingPolicy).isTokenAware);
+        }
+
+        [Test]
+        public void BuildFrom_DisablesTokenAwareness_When_NotWrapped()
+        {
+            Assert.IsFalse(LoadBalancingPolicy.BuildFrom(new RoundRobinPolicy()).isTokenAware);
+            Assert.IsFalse(LoadBalancingPolicy.BuildFrom(new DCAwareRoundRobinPolicy("dc1")).isTokenAware);
+        }
+
+        [Test]
+        public void BuildFrom_KeepsLocalDatacenter_When_TokenAware()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new DCAwareRoundRobinPolicy("dc1")));
+
+            Assert.AreEqual("dc1", policy.localDC);
+        }
+
+        [Test]
+        public void BuildFrom_SetsLocalRack_When_RackAwarePolicy()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new RackAwareRoundRobinPolicy("dc1", "rack1")));
+
+            Assert.AreEqual("dc1", policy.localDC);
+            Assert.AreEqual("rack1", policy.localRack);
+            Assert.IsTrue(policy.isTokenAware);
+        }
+
+        [Test]
+        public void BuildFrom_LeavesLocalRackUnset_When_DcAwarePolicy()
+        {
+            Assert.IsNull(LoadBalancingPolicy.BuildFrom(new DCAwareRoundRobinPolicy("dc1")).localRack);
+        }
+
+        [Test]
+        public void RackAwareRoundRobinPolicy_Throws_When_RackOrDatacenterIsEmpty()
+        {
+            Assert.Throws<System.ArgumentException>(() => new RackAwareRoundRobinPolicy("dc1", ""));
+            Assert.Throws<System.ArgumentException>(() => new RackAwareRoundRobinPolicy(null, "rack1"));
+        }
+
+        [Test]
+        public void FailsOnUnknownLocalDc_ByDefault()
+        {
+            Assert.IsTrue(LoadBalancingPolicy.FailsOnUnknownLocalDc(new DCAwareRoundRobinPolicy("dc1")));
+            Assert.IsTrue(LoadBalancingPolicy.FailsOnUnknownLocalDc(new RackAwareRoundRobinPolicy("dc1", "rack1")));
+            Assert.IsTrue(LoadBalancingPolicy.FailsOnUnknownLocalDc(new TokenAwarePolicy(new RoundRobinPolicy())));
+        }
+
+        [Test]
+        public void FailsOnUnknownLocalDc_UnwrapsChildPolicies_When_DisabledOnPolicy()
+        {
+            var policy = new DefaultLoadBalancingPolicy(new TokenAwarePolicy(new DCAwareRoundRobinPolicy("dc1", true, false)));
+            Assert.IsFalse(LoadBalancingPolicy.FailsOnUnknownLocalDc(policy));
+            Assert.IsFalse(LoadBalancingPolicy.FailsOnUnknownLocalDc(new RackAwareRoundRobinPolicy("dc1", "rack1", false, false)));
+        }
+
+        [Test]
+        public void BuildFrom_DisablesLatencyAwareness_When_NotWrapped()
+        {
+            Assert.IsFalse(LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new RoundRobinPolicy())).latencyAwareness.enabled);
+        }
+
+        [Test]
+        public void BuildFrom_PassesLatencyAwarenessOptions_When_WrappedInLatencyAwarePolicy()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(
+                new TokenAwarePolicy(new LatencyAwarePolicy(new DCAwareRoundRobinPolicy("dc1"), 3.0, 200, 5000, minimumMeasurements: 20)));
+
+            Assert.IsTrue(policy.latencyAwareness.enabled);
+            Assert.AreEqual(3.0, policy.latencyAwareness.exclusionThreshold);
+            Assert.AreEqual(200, policy.latencyAwareness.scaleMillis);
+            Assert.AreEqual(5000, policy.latencyAwareness.retryPeriodMillis);
+            Assert.AreEqual(0, policy.latencyAwareness.updateRateMillis);
+            Assert.AreEqual(20, policy.latencyAwareness.minimumMeasurements);
+            Assert.IsTrue(policy.isTokenAware);
+            Assert.AreEqual("dc1", policy.localDC);
+        }
+
+        [Test]
+        public void Build_Succeeds_When_LocalDcOnly()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new DCAwareRoundRobinPolicy("dc1")));
+
+            Assert.IsFalse(policy.permitDcFailover);
+            Assert.AreEqual("dc1", policy.localDC);
+            using (BuildProfile(policy))
+            {
+            }
+        }
+
+        [Test]
+        public void Build_Succeeds_When_LocalDcWithFallback()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new DCAwareRoundRobinPolicy("dc1", true)));
+
+            Assert.IsTrue(policy.permitDcFailover);
+            Assert.AreEqual("dc1", policy.localDC);
+            using (BuildProfile(policy))
+            {
+            }
+        }
+
+        [Test]
+        public void Build_Succeeds_When_EmptyLocalDcWithFallback()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new RoundRobinPolicy());
+            policy.localDC = "";
+            policy.permitDcFailover = true;
+
+            using (BuildProfile(policy))
+            {
+            }
+        }
+
+        [Test]
+        public void Build_Throws_When_EmptyLocalDcWithoutFallback()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new RoundRobinPolicy());
+            policy.localDC = "";
+            policy.permitDcFailover = false;
+
+            Assert.Throws<System.ArgumentException>(() => BuildProfile(policy));
+        }
+
+        [Test]
+        public void Build_Succeeds_When_LocalDcAndRack()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new RackAwareRoundRobinPolicy("dc1", "rack1")));
+
+            using (BuildProfile(policy))
+            {
+            }
+        }
+
+        [Test]
+        public void Build_Succeeds_When_LocalDcWithoutRack()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new TokenAwarePolicy(new DCAwareRoundRobinPolicy("dc1")));
+            policy.localRack = "";
+
+            using (BuildProfile(policy))
+            {
+            }
+        }
+
+        [Test]
+        public void Build_Throws_EmptyLocalDcError_When_RackAndLocalDcAreEmpty()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new RoundRobinPolicy());
+            policy.localDC = "";
+            policy.localRack = "";
+            policy.permitDcFailover = false;
+
+            // An empty rack means no rack preference, so only the empty datacenter is reported.
+            Assert.Throws<System.ArgumentException>(() => BuildProfile(policy));
+
+            policy.permitDcFailover = true;
+            using (BuildProfile(policy))
+            {
+            }
+        }
+
+        [Test]
+        public void Build_Throws_When_RackWithoutLocalDc()
+        {
+            var policy = LoadBalancingPolicy.BuildFrom(new RoundRobinPolicy());
+            policy.localRack = "rack1";
+
+            Assert.Throws<ArgumentException>(() => BuildProfile(policy));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using Moq;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using CustomRetryPolicy = Cassandra.BridgedSession.BridgedCustomRetryPolicy;
+using RetryDecisionCode = Cassandra.BridgedSession.BridgedRetryDecisionCode;
+using RetryDecisionInput = Cassandra.BridgedSession.BridgedRetryDecisionInput;
+using RetryErrorKind = Cassandra.BridgedSession.BridgedRetryErrorKind;
+using RetryPolicyKind = Cassandra.BridgedSession.BridgedRetryPolicyKind;
+
+namespace Cassandra.Tests
+{
+    // The retry policy crosses the FFI boundary as a single byte selecting one of the policies
+    // implemented by the Rust driver, so every supported C# policy must map to the right kind.
+    // Any other policy is called back by Rust, and its decisions must be translated faithfully.
+    public class BridgedRetryPolicyKindTests : BaseUnitTest
+    {
+        [Test]
+        public void BuildRetryPolicyKind_MapsBuiltInPolicies()
+        {
+            Assert.AreEqual(RetryPolicyKind.Default, BridgedSession.BuildRetryPolicyKind(null));
+            Assert.AreEqual(RetryPolicyKind.Default, BridgedSession.BuildRetryPolicyKind(new DefaultRetryPolicy()));
+            Assert.AreEqual(RetryPolicyKind.Fallthrough, BridgedSession.BuildRetryPolicyKind(FallthroughRetryPolicy.Instance));
+            Assert.AreEqual(
+                RetryPolicyKind.DowngradingConsistency,
+                BridgedSession.BuildRetryPolicyKind(DowngradingConsistencyRetryPolicy.Instance));
+        }
+
+        [Test]
+        public void BuildRetryPolicyKind_UnwrapsWrappingPolicies()
+        {
+            var policy = new LoggingRetryPolicy(new IdempotenceAwareRetryPolicy(FallthroughRetryPolicy.Instance));
+            Assert.AreEqual(RetryPolicyKind.Fallthrough, BridgedSession.BuildRetryPolicyKind(policy));
+        }
+
+        [Test]
+        public void BuildRetryPolicyKind_MapsOtherPoliciesToCustom()
+        {
+            Assert.AreEqual(RetryPolicyKind.Custom, BridgedSession.BuildRetryPolicyKind(Mock.Of<IRetryPolicy>()));
+            Assert.AreEqual(
+                RetryPolicyKind.Custom,
+                BridgedSession.BuildRetryPolicyKind(new LoggingRetryPolicy(Mock.Of<IRetryPolicy>())));
+        }
+
+        [Test]
+        public void CustomRetryPolicy_IsOnlyBuiltForCustomPolicies()
+        {
+            var config = new Configuration();
+            Assert.AreEqual(IntPtr.Zero, CustomRetryPolicy.BuildFrom(new DefaultRetryPolicy(), config).decideRetry);
+            Assert.AreNotEqual(IntPtr.Zero, CustomRetryPolicy.BuildFrom(Mock.Of<IRetryPolicy>(), config).decideRetry);
+        }
+
+        [Test]
+        public void Decide_PassesReadTimeoutToPolicy_And_DowngradesConsistency()
+        {
+            var policy = new Mock<IRetryPolicy>();
+            policy
+                .Setup(p => p.OnReadTimeout(It.IsAny<IStatement>(), ConsistencyLevel.Quorum, 2, 1, true, 0))
+                .Returns(RetryDecision.Retry(ConsistencyLevel.One, true));
+
+            var decision = CustomRetryPolicy.Decide(
+                policy.Object, new Configuration(), Input(RetryErrorKind.ReadTimeout, ConsistencyLevel.Quorum, received: 1, required: 2, dataPresent: true));
+
+            Assert.AreEqual(RetryDecisionCode.RetrySameHost, decision.decision);
+            Assert.IsTrue(decision.hasConsistency);
+            Assert.AreEqual((ushort)ConsistencyLevel.One, decision.consistency);
+        }
+
+        [Test]
+        public void Decide_PassesIdempotenceToPolicy()
+        {
+            var policy = new Mock<IRetryPolicy>();
+            policy
+                .Setup(p => p.OnUnavailable(It.Is<IStatement>(s => s.IsIdempotent == true), ConsistencyLevel.All, 3, 2, 1))
+                .Returns(RetryDecision.Retry(null, false));
+
+            var decision = CustomRetryPolicy.Decide(
+                policy.Object, new Configuration(), Input(RetryErrorKind.Unavailable, ConsistencyLevel.All, received: 2, required: 3, retryCount: 1, isIdempotent: true));
+
+            Assert.AreEqual(RetryDecisionCode.RetryNextHost, decision.decision);
+            Assert.IsFalse(decision.hasConsistency);
+        }
+
+        [Test]
+        public void Decide_TranslatesIgnoreAndRethrow()
+        {
+            var policy = new Mock<IRetryPolicy>();
+            policy
+                .Setup(p => p.OnUnavailable(It.IsAny<IStatement>(), It.IsAny<ConsistencyLevel>(), It.IsAny<int>(), It.IsAny<int>(), 0))
+                .Returns(RetryDecision.Ignore());
+            policy
+                .Setup(p => p.OnUnavailable(It.IsAny<IStatement>(), It.IsAny<ConsistencyLevel>(), It.IsAny<int>(), It.IsAny<int>(), 1))
+                .Returns(RetryDecision.Rethrow());
+
+            Assert.AreEqual(
+                RetryDecisionCode.Ignore,
+                CustomRetryPolicy.Decide(policy.Object, new Configuration(), Input(RetryErrorKind.Unavailable, ConsistencyLevel.One)).decision);
+            Assert.AreEqual(
+                RetryDecisionCode.Rethrow,
+                CustomRetryPolicy.Decide(policy.Object, new Configuration(), Input(RetryErrorKind.Unavailable, ConsistencyLevel.One, retryCount: 1)).decision);
+        }
+
+        [Test]
+        public void Decide_Rethrows_When_PolicyIsNotExtendedAndErrorIsNotATimeout()
+        {
+            var decision = CustomRetryPolicy.Decide(
+                Mock.Of<IRetryPolicy>(), new Configuration(), Input(RetryErrorKind.Overloaded, ConsistencyLevel.One));
+
+            Assert.AreEqual(RetryDecisionCode.Rethrow, decision.decision);
+        }
+
+        [Test]
+        public void Decide_PassesOtherErrorsToExtendedPolicy()
+        {
+            var policy = new Mock<IExtendedRetryPolicy>();
+            policy
+                .Setup(p => p.OnRequestError(It.IsAny<IStatement>(), It.IsAny<Configuration>(), It.IsAny<OverloadedException>(), 0))
+                .Returns(RetryDecision.Retry(null, false));
+
+            var decision = CustomRetryPolicy.Decide(
+                policy.Object, new Configuration(), Input(RetryErrorKind.Overloaded, ConsistencyLevel.One));
+
+            Assert.AreEqual(RetryDecisionCode.RetryNextHost, decision.decision);
+        }
+
+        [Test]
+        public void Decide_Rethrows_When_PolicyThrows()
+        {
+            var policy = new Mock<IRetryPolicy>();
+            policy
+                .Setup(p => p.OnReadTimeout(It.IsAny<IStatement>(), It.IsAny<ConsistencyLevel>(), It.IsAny<int>(), It.IsAny<int>(), It.IsAny<bool>(), It.IsAny<int>()))
+                .Throws(new InvalidOperationException("Policy failure"));
+
+            var decision = CustomRetryPolicy.Decide(
+                policy.Object, new Configuration(), Input(RetryErrorKind.ReadTimeout, ConsistencyLevel.Quorum, received: 1, required: 2));
+
+            Assert.AreEqual(RetryDecisionCode.Rethrow, decision.decision);
+            Assert.IsFalse(decision.hasConsistency);
+        }
+
+        private static RetryDecisionInput Input(
+            RetryErrorKind errorKind,
+            ConsistencyLevel consistency,
+            int received = -1,
+            int required = -1,
+            bool dataPresent = false,
+            uint retryCount = 0,
+            bool isIdempotent = false)
+        {
+            return new RetryDecisionInput
+            {
+                errorKind = errorKind,
+                consistency = (ushort)consistency,
+                received = received,
+                required = required,
+                dataPresent = dataPresent,
+                retryCount = retryCount,
+                isIdempotent = isIdempotent,
+            };
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    public class BridgedSchemaAgreementTimeoutTests : BaseUnitTest
+    {
+        [Test]
+        public void BuildSchemaAgreementTimeoutMillis_ConvertsConfiguredSeconds()
+        {
+            var options = new ProtocolOptions().SetMaxSchemaAgreementWaitSeconds(30);
+            Assert.AreEqual(30_000L, BridgedSession.BuildSchemaAgreementTimeoutMillis(options));
+        }
+
+        [Test]
+        public void BuildSchemaAgreementTimeoutMillis_UsesDefaultWithoutOptions()
+        {
+            Assert.AreEqual(
+                ProtocolOptions.DefaultMaxSchemaAgreementWaitSeconds * 1000L,
+                BridgedSession.BuildSchemaAgreementTimeoutMillis(null));
+        }
+
+        [Test]
+        public void BuildSchemaAgreementTimeoutMillis_KeepsRustDefaultForNegativeValues()
+        {
+            var options = new ProtocolOptions().SetMaxSchemaAgreementWaitSeconds(-1);
+            Assert.AreEqual(0L, BridgedSession.BuildSchemaAgreementTimeoutMillis(options));
+        }
+    }
+}
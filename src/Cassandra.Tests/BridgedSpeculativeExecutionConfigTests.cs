@@ -0,0 +1,74 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using Moq;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using SpeculativeExecutionConfig = Cassandra.BridgedSession.BridgedSpeculativeExecutionConfig;
+
+namespace Cassandra.Tests
+{
+    public class BridgedSpeculativeExecutionConfigTests : BaseUnitTest
+    {
+        [Test]
+        public void BuildFrom_MapsConstantPolicy()
+        {
+            var config = SpeculativeExecutionConfig.BuildFrom(new ConstantSpeculativeExecutionPolicy(150, 2));
+            Assert.AreEqual(BridgedSession.BridgedSpeculativeExecutionKind.Constant, config.kind);
+            Assert.AreEqual(2, config.maxRetryCount);
+            Assert.AreEqual(150, config.retryIntervalMillis);
+        }
+
+        [Test]
+        public void BuildFrom_DisablesSpeculativeExecutionsWithoutPolicy()
+        {
+            Assert.AreEqual(BridgedSession.BridgedSpeculativeExecutionKind.None, SpeculativeExecutionConfig.BuildFrom(null).kind);
+            Assert.AreEqual(BridgedSession.BridgedSpeculativeExecutionKind.None, SpeculativeExecutionConfig.BuildFrom(NoSpeculativeExecutionPolicy.Instance).kind);
+        }
+
+        [Test]
+        public void BuildFrom_MapsPercentilePolicy()
+        {
+            var config = SpeculativeExecutionConfig.BuildFrom(new PercentileSpeculativeExecutionPolicy(99.5, 3));
+            Assert.AreEqual(BridgedSession.BridgedSpeculativeExecutionKind.Percentile, config.kind);
+            Assert.AreEqual(3, config.maxRetryCount);
+            Assert.AreEqual(99.5, config.percentile);
+        }
+
+        [Test]
+        public void PercentilePolicy_RejectsOutOfRangePercentile()
+        {
+            Assert.Throws<ArgumentOutOfRangeException>(() => new PercentileSpeculativeExecutionPolicy(0, 2));
+            Assert.Throws<ArgumentOutOfRangeException>(() => new PercentileSpeculativeExecutionPolicy(100, 2));
+            Assert.Throws<ArgumentOutOfRangeException>(() => new PercentileSpeculativeExecutionPolicy(double.NaN, 2));
+        }
+
+        [Test]
+        public void ConstantPolicy_RejectsNonPositiveDelay()
+        {
+            Assert.Throws<ArgumentOutOfRangeException>(() => new ConstantSpeculativeExecutionPolicy(0, 2));
+            Assert.Throws<ArgumentOutOfRangeException>(() => new ConstantSpeculativeExecutionPolicy(-10, 2));
+        }
+
+        [Test]
+        public void BuildFrom_RejectsCustomPolicies()
+        {
+            Assert.Throws<NotSupportedException>(
+                () => SpeculativeExecutionConfig.BuildFrom(Mock.Of<ISpeculativeExecutionPolicy>()));
+        }
+    }
+}
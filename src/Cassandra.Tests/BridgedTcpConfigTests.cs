@@ -0,0 +1,41 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using TcpConfig = Cassandra.BridgedSession.BridgedTcpConfig;
+
+namespace Cassandra.Tests
+{
+    public class BridgedTcpConfigTests : BaseUnitTest
+    {
+        [Test]
+        public void BuildFrom_EnablesWriteCoalescing_ByDefault()
+        {
+            Assert.IsTrue(TcpConfig.BuildFrom(null).writeCoalescing);
+            Assert.IsTrue(TcpConfig.BuildFrom(new SocketOptions()).writeCoalescing);
+        }
+
+        [Test]
+        public void BuildFrom_DisablesWriteCoalescing_When_SetOnSocketOptions()
+        {
+            var config = TcpConfig.BuildFrom(new SocketOptions().SetWriteCoalescing(false));
+
+            Assert.IsFalse(config.writeCoalescing);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Buffers.Binary;
+using System.Globalization;
+using System.Linq;
+using Cassandra.Serialization.Primitive;
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+
+namespace Cassandra.Tests
+{
+    // Decimals cross the FFI boundary as a scale and big-endian two's-complement unscaled bytes,
+    // which Rust concatenates into the CQL wire format. The result must match what
+    // DecimalSerializer writes for the same value.
+    public class DecimalFFIFormatTests : BaseUnitTest
+    {
+        [Test]
+        [TestCase("0")]
+        [TestCase("123.456")]
+        [TestCase("-123.456")]
+        [TestCase("-0.001")]
+        [TestCase("128")]
+        [TestCase("-128")]
+        [TestCase("0.0000000000000000000000000001")]
+        [TestCase("-0.0000000000000000000000000001")]
+        [TestCase("79228162514264337593543950335")]
+        [TestCase("-79228162514264337593543950335")]
+        [TestCase("-7.9228162514264337593543950335")]
+        public void DecimalToFFIFormat_MatchesDecimalSerializer(string text)
+        {
+            var value = decimal.Parse(text, CultureInfo.InvariantCulture);
+            Span<byte> unscaled = stackalloc byte[13];
+
+            var length = SerializationHandler.DecimalToFFIFormat(value, unscaled, out var scale);
+
+            var expected = new DecimalSerializer().Serialize(4, value);
+            Assert.AreEqual(BinaryPrimitives.ReadInt32BigEndian(expected), scale);
+            NUnit.Framework.Legacy.CollectionAssert.AreEqual(expected.Skip(4).ToArray(), unscaled.Slice(0, length).ToArray());
+        }
+
+        [Test]
+        public void DecimalToFFIFormat_KeepsTrailingZerosInScale()
+        {
+            Span<byte> unscaled = stackalloc byte[13];
+
+            var length = SerializationHandler.DecimalToFFIFormat(1.500m, unscaled, out var scale);
+
+            Assert.AreEqual(3, scale);
+            NUnit.Framework.Legacy.CollectionAssert.AreEqual(new byte[] { 0x05, 0xdc }, unscaled.Slice(0, length).ToArray());
+        }
+    }
+}
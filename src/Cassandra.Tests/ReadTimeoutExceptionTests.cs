@@ -0,0 +1,46 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using NUnit.Framework;
+using Assert = NUnit.Framework.Legacy.ClassicAssert;
+using StringAssert = NUnit.Framework.Legacy.StringAssert;
+
+namespace Cassandra.Tests
+{
+    public class ReadTimeoutExceptionTests : BaseUnitTest
+    {
+        [Test]
+        public void FromFFI_KeepsAllFields()
+        {
+            var exception = ReadTimeoutException.FromFFI((ushort)ConsistencyLevel.LocalQuorum, 2, 3, true);
+
+            Assert.AreEqual(ConsistencyLevel.LocalQuorum, exception.ConsistencyLevel);
+            Assert.AreEqual(2, exception.ReceivedAcknowledgements);
+            Assert.AreEqual(3, exception.RequiredAcknowledgements);
+            Assert.IsTrue(exception.WasDataRetrieved);
+        }
+
+        [Test]
+        public void FromFFI_KeepsMissingData_When_EnoughReplicasResponded()
+        {
+            var exception = ReadTimeoutException.FromFFI((ushort)ConsistencyLevel.One, 1, 1, false);
+
+            Assert.AreEqual(ConsistencyLevel.One, exception.ConsistencyLevel);
+            Assert.IsFalse(exception.WasDataRetrieved);
+            StringAssert.Contains("the replica queried for data didn't respond", exception.Message);
+        }
+    }
+}
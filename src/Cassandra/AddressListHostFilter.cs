@@ -0,0 +1,63 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Net;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// A <see cref="IHostFilter"/> accepting nodes by IP address, regardless of the port.
+    /// </summary>
+    public class AddressListHostFilter : IHostFilter
+    {
+        private readonly HashSet<IPAddress> _allowed;
+        private readonly HashSet<IPAddress> _denied;
+
+        /// <summary>
+        /// Creates a new filter from an allow-list and a deny-list of addresses.
+        /// </summary>
+        /// <param name="allowed">The only addresses to connect to, or <c>null</c> to allow every address.</param>
+        /// <param name="denied">Addresses never to connect to, or <c>null</c> to deny none. Takes precedence over <paramref name="allowed"/>.</param>
+        public AddressListHostFilter(IEnumerable<IPAddress> allowed, IEnumerable<IPAddress> denied = null)
+        {
+            _allowed = allowed?.ToHashSet();
+            _denied = denied?.ToHashSet() ?? new HashSet<IPAddress>();
+            if (_allowed?.Contains(null) == true || _denied.Contains(null))
+            {
+                throw new ArgumentException("Host filter addresses cannot be null");
+            }
+        }
+
+        /// <summary>
+        /// The only addresses to connect to, or <c>null</c> if every address is allowed.
+        /// </summary>
+        public IReadOnlyCollection<IPAddress> Allowed => _allowed;
+
+        /// <summary>
+        /// Addresses never to connect to.
+        /// </summary>
+        public IReadOnlyCollection<IPAddress> Denied => _denied;
+
+        /// <inheritdoc />
+        public bool Accept(IPEndPoint address, string datacenter, string rack)
+        {
+            return (_allowed == null || _allowed.Contains(address.Address)) && !_denied.Contains(address.Address);
+        }
+    }
+}
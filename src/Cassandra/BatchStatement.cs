@@ -171,6 +171,13 @@ namespace Cassandra
 
         /// <summary>
         /// Sets the <see cref="BatchType"/>
+        /// <para>
+        /// Counter statements must be sent in a <see cref="BatchType.Counter"/> batch and other statements in a
+        /// logged or unlogged one. A <see cref="BoundStatement"/> on the wrong kind of table is rejected by the
+        /// driver before the batch is sent, when the table is known from the schema metadata. A
+        /// <see cref="SimpleStatement"/> is not checked, as its table is only known to the server, which rejects
+        /// the batch instead.
+        /// </para>
         /// </summary>
         /// <returns></returns>
         public BatchStatement SetBatchType(BatchType batchType)
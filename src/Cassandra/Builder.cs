@@ -66,6 +66,7 @@ namespace Cassandra
         private MetadataSyncOptions _metadataSyncOptions;
         private IDriverMetricsProvider _driverMetricsProvider;
         private IRequestTracker _requestTracker;
+        private IHostFilter _hostFilter;
         private DriverMetricsOptions _metricsOptions;
         private MonitorReportingOptions _monitorReportingOptions = new MonitorReportingOptions();
         private string _sessionName;
@@ -180,7 +181,8 @@ namespace Cassandra
                 typeSerializerDefinitions,
                 _keepContactPointsUnresolved,
                 _allowBetaProtocolVersions,
-                requestTracker: _requestTracker);
+                requestTracker: _requestTracker,
+                hostFilter: _hostFilter);
 
             return config;
         }
@@ -784,6 +786,21 @@ namespace Cassandra
             return this;
         }
 
+        /// <summary>
+        ///  Configures which nodes the new cluster connects to, e.g. to isolate tenants on a subset of nodes.
+        /// </summary>
+        /// <remarks>
+        /// See <see cref="IHostFilter"/>. <see cref="AddressListHostFilter"/> filters nodes by address.
+        /// By default, the driver connects to every node.
+        /// </remarks>
+        /// <param name="hostFilter">the filter to use, or <c>null</c> to connect to every node.</param>
+        /// <returns>this Builder</returns>
+        public Builder WithHostFilter(IHostFilter hostFilter)
+        {
+            _hostFilter = hostFilter;
+            return this;
+        }
+
         /// <summary>
         /// <para>Limits the maximum protocol version used to connect to the nodes.</para>
         /// <para>
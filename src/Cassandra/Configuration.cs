@@ -83,6 +83,11 @@ namespace Cassandra
         /// <returns>the address translator in use.</returns>
         public IAddressTranslator AddressTranslator { get; private set; }
 
+        /// <summary>
+        ///  The filter deciding which nodes the driver connects to, or <c>null</c> to connect to every node.
+        /// </summary>
+        public IHostFilter HostFilter { get; private set; }
+
         /// <summary>
         /// Gets a read only key value map of execution profiles that were configured with
         /// <see cref="Builder.WithExecutionProfiles"/>. The keys are execution profile names and the values
@@ -199,9 +204,11 @@ namespace Cassandra
                                TypeSerializerDefinitions typeSerializerDefinitions,
                                bool? keepContactPointsUnresolved,
                                bool? allowBetaProtocolVersions,
-                               IRequestTracker requestTracker = null)
+                               IRequestTracker requestTracker = null,
+                               IHostFilter hostFilter = null)
         {
             AddressTranslator = addressTranslator ?? throw new ArgumentNullException(nameof(addressTranslator));
+            HostFilter = hostFilter;
             QueryOptions = queryOptions ?? throw new ArgumentNullException(nameof(queryOptions));
 
             ClusterId = clusterId ?? Guid.NewGuid();
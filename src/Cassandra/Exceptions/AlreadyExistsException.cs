@@ -54,10 +54,11 @@ namespace Cassandra
         }
 
         [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
-        internal static FFIGCHandle AlreadyExistsExceptionFromRust(FFIString keyspace, FFIString table)
+        internal static FFIGCHandle AlreadyExistsExceptionFromRust(FFIString keyspace, FFIString table, FFIBool wasTableCreation)
         {
             string keyspaceStr = keyspace.ToManagedString();
-            string tableStr = table.ToManagedString();
+            // Keyspace conflicts come with an empty table name, which the constructor maps to a null Table.
+            string tableStr = wasTableCreation ? table.ToManagedString() : "";
 
             var exception = new AlreadyExistsException(keyspaceStr, tableStr);
 
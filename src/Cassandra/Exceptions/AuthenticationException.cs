@@ -14,7 +14,11 @@
 //   limitations under the License.
 //
 
+using System;
 using System.Net;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
 
 namespace Cassandra
 {
@@ -38,5 +42,23 @@ namespace Cassandra
         {
             Host = host;
         }
+
+        /// <summary>
+        /// Builds the exception from a login failure reported by Rust. <paramref name="host"/> is the
+        /// address of the node as <c>ip:port</c>, or a list of contact points if the node is not known.
+        /// </summary>
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle AuthenticationExceptionFromRust(FFIString message, FFIString host)
+        {
+            string msg = message.ToManagedString();
+            string hostStr = host.ToManagedString();
+
+            var exception = IPEndPoint.TryParse(hostStr, out var endPoint)
+                ? new AuthenticationException(msg, endPoint)
+                : new AuthenticationException(string.Format("Authentication error on host(s) {0}: {1}", hostStr, msg));
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
     }
 }
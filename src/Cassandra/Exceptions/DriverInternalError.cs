@@ -15,6 +15,9 @@
 //
 
 using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
 
 namespace Cassandra
 {
@@ -37,5 +40,16 @@ namespace Cassandra
             : base(message, innerException)
         {
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle DriverInternalErrorFromRust(FFIString message)
+        {
+            string msg = message.ToManagedString();
+
+            var exception = new DriverInternalError(msg);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
     }
 }
@@ -102,11 +102,31 @@ namespace Cassandra
             return builder.ToString();
         }
 
+        /// <summary>
+        /// The failure of a single host, as reported by Rust.
+        /// The exception handle stays owned by Rust, which frees it once the constructor returns.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal readonly struct FFIHostError
+        {
+            internal readonly FFIString address;
+            internal readonly FFIGCHandle exception;
+        }
+
         [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
-        internal static FFIGCHandle NoHostAvailableExceptionFromRust(FFIString message)
+        internal static FFIGCHandle NoHostAvailableExceptionFromRust(FFIString message, FFISliceRaw hostErrors)
         {
-            string msg = message.ToManagedString();
-            var exception = new NoHostAvailableException(msg);
+            var errors = new Dictionary<IPEndPoint, Exception>();
+            foreach (var hostError in hostErrors.As<FFIHostError>().ToSpan())
+            {
+                var address = IPEndPoint.Parse(hostError.address.ToManagedString());
+                var hostException = GCHandle.FromIntPtr(hostError.exception.gchandle).Target as Exception;
+                errors[address] = hostException;
+            }
+
+            var exception = errors.Count > 0
+                ? new NoHostAvailableException(errors)
+                : new NoHostAvailableException(message.ToManagedString());
 
             GCHandle handle = GCHandle.Alloc(exception);
             return new(handle);
@@ -0,0 +1,59 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    ///  Exception thrown when a ScyllaDB per-partition rate limit was exceeded.
+    /// </summary>
+    public class RateLimitReachedException : QueryExecutionException
+    {
+        /// <summary>
+        ///  Gets whether the rejected operation was a write, as opposed to a read.
+        /// </summary>
+        public bool IsWrite { get; private set; }
+
+        /// <summary>
+        ///  Gets whether the operation was rejected by the coordinator itself,
+        ///  rather than by a replica.
+        /// </summary>
+        public bool RejectedByCoordinator { get; private set; }
+
+        public RateLimitReachedException(bool isWrite, bool rejectedByCoordinator) :
+            base(string.Format("Per-partition rate limit reached for {0} operation (rejected by {1})",
+                isWrite ? "write" : "read", rejectedByCoordinator ? "coordinator" : "replica"))
+        {
+            IsWrite = isWrite;
+            RejectedByCoordinator = rejectedByCoordinator;
+        }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle RateLimitReachedExceptionFromRust(byte opType, FFIBool rejectedByCoordinator)
+        {
+            // Operation type codes follow the protocol extension: 0 for reads, 1 for writes.
+            var exception = new RateLimitReachedException(opType == 1, rejectedByCoordinator);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
+    }
+}
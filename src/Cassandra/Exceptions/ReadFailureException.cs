@@ -14,10 +14,14 @@
 //   limitations under the License.
 //
 
+using System;
 using System.Collections.Generic;
 using System.Collections.ObjectModel;
 using System.Net;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 using System.Text;
+using static Cassandra.RustBridge;
 
 namespace Cassandra
 {
@@ -97,6 +101,15 @@ namespace Cassandra
             Reasons = reasons ?? DefaultReasons;
         }
 
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle ReadFailureExceptionFromRust(ushort consistency, int received, int required, int failures, FFIBool dataPresent)
+        {
+            var exception = new ReadFailureException((ConsistencyLevel)consistency, received, required, dataPresent, failures);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
+
         private static string FormatMessage(ConsistencyLevel consistency, int received, int required, bool dataPresent,
                                             int failures)
         {
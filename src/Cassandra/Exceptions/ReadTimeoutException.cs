@@ -14,6 +14,11 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
 namespace Cassandra
 {
     /// <summary>
@@ -33,6 +38,23 @@ namespace Cassandra
             WasDataRetrieved = dataPresent;
         }
 
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle ReadTimeoutExceptionFromRust(ushort consistency, int received, int required, FFIBool dataPresent)
+        {
+            var exception = FromFFI(consistency, received, required, dataPresent);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
+
+        /// <summary>
+        /// Builds the exception from the fields of a read timeout reported by Rust, with the consistency as its protocol code.
+        /// </summary>
+        internal static ReadTimeoutException FromFFI(ushort consistency, int received, int required, bool dataPresent)
+        {
+            return new ReadTimeoutException((ConsistencyLevel)consistency, received, required, dataPresent);
+        }
+
         private static string FormatDetails(int received, int required, bool dataPresent)
         {
             if (received < required)
@@ -14,6 +14,11 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
 namespace Cassandra
 {
     /// <summary>
@@ -46,5 +51,14 @@ namespace Cassandra
             RequiredReplicas = required;
             AliveReplicas = alive;
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle UnavailableExceptionFromRust(ushort consistency, int required, int alive)
+        {
+            var exception = new UnavailableException((ConsistencyLevel)consistency, required, alive);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
     }
 }
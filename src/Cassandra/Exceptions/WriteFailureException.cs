@@ -14,9 +14,13 @@
 //   limitations under the License.
 //
 
+using System;
 using System.Collections.Generic;
 using System.Collections.ObjectModel;
 using System.Net;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
 
 namespace Cassandra
 {
@@ -107,5 +111,15 @@ namespace Cassandra
         {
             Reasons = reasons ?? DefaultReasons;
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle WriteFailureExceptionFromRust(ushort consistency, int received, int required, int failures, FFIString writeType)
+        {
+            var exception = new WriteFailureException(
+                (ConsistencyLevel)consistency, received, required, writeType.ToManagedString(), failures);
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
     }
 }
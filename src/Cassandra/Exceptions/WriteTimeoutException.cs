@@ -14,6 +14,11 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
 namespace Cassandra
 {
     /// <summary>
@@ -48,6 +53,16 @@ namespace Cassandra
             WriteType = writeType;
         }
 
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle WriteTimeoutExceptionFromRust(ushort consistency, int received, int required, FFIString writeType)
+        {
+            var exception = new WriteTimeoutException(
+                (ConsistencyLevel)consistency, received, required, writeType.ToManagedString());
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
+
         private static string GetMessage(string writeType, ConsistencyLevel consistency, int received, int required)
         {
             var message = writeType == BatchLogWriteType ? BatchLogMessage : QueryMessage;
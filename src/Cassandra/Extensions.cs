@@ -38,9 +38,14 @@ namespace Cassandra
         /// </summary>
         /// <param name="instance"></param>
         /// <returns></returns>
+        /// <exception cref="NotSupportedException">Thrown when the session was not created by the driver.</exception>
         public static ISessionState GetState(this ISession instance)
         {
-            throw new NotImplementedException();
+            if (instance is Session session)
+            {
+                return session.GetState();
+            }
+            throw new NotSupportedException($"Getting the state of a {instance?.GetType().Name} is not supported.");
         }
 
         /// <summary>
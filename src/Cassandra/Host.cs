@@ -43,6 +43,12 @@ namespace Cassandra
             get { return IsUp; }
         }
 
+        /// <summary>
+        /// Determines if the driver had open connections to the host when the metadata was last refreshed.
+        /// Hosts rejected by the <see cref="IHostFilter"/> are never connected.
+        /// </summary>
+        public bool IsConnected { get; internal set; }
+
         /// <summary>
         ///  Gets the node address.
         /// </summary>
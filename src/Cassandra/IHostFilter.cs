@@ -0,0 +1,41 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System.Net;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Decides which nodes of the cluster the driver connects to.
+    /// </summary>
+    /// <remarks>
+    /// Nodes that are not accepted still appear in <see cref="Metadata.AllHosts"/>, with
+    /// <see cref="Host.IsConnected"/> set to <c>false</c>, but no connections are opened to them and
+    /// requests are never routed to them. The filter is called on driver threads whenever the
+    /// topology is refreshed, so it must be thread-safe and must return quickly.
+    /// </remarks>
+    public interface IHostFilter
+    {
+        /// <summary>
+        /// Returns whether the driver may connect to the node.
+        /// </summary>
+        /// <param name="address">The address the driver would connect to.</param>
+        /// <param name="datacenter">The datacenter of the node, or <c>null</c> if it is unknown.</param>
+        /// <param name="rack">The rack of the node, or <c>null</c> if it is unknown.</param>
+        /// <returns><c>true</c> to connect to the node. An exception rejects the node.</returns>
+        bool Accept(IPEndPoint address, string datacenter, string rack);
+    }
+}
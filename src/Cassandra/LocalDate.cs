@@ -47,6 +47,11 @@ namespace Cassandra
         /// </summary>
         internal uint DaysSinceEpochCentered { get; }
 
+        /// <summary>
+        /// Days since the unix epoch (January 1st, 1970), negative for earlier dates.
+        /// </summary>
+        internal long DaysSinceEpoch => DaysSinceEpochCentered - LocalDate.DateCenter;
+
         public int Day { get; set; }
 
         public int Month { get; set; }
@@ -63,6 +68,14 @@ namespace Cassandra
             InitializeFromDaysSinceEpoch(days - LocalDate.DateCenter);
         }
 
+        /// <summary>
+        /// Creates a new instance based on the signed days since unix epoch.
+        /// </summary>
+        internal static LocalDate FromDaysSinceEpoch(int days)
+        {
+            return new LocalDate((uint)(days + LocalDate.DateCenter));
+        }
+
         /// <summary>
         /// Port from https://github.com/HowardHinnant/date which is based on http://howardhinnant.github.io/date_algorithms.html
         /// There's a good explanation of the algorithm over there.
@@ -14,6 +14,11 @@
 //   limitations under the License.
 //
 
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
 namespace Cassandra
 {
     public class IsBootstrappingException : QueryValidationException
@@ -21,5 +26,14 @@ namespace Cassandra
         public IsBootstrappingException(string message) : base(message)
         {
         }
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        internal static FFIGCHandle IsBootstrappingExceptionFromRust(FFIString message)
+        {
+            var exception = new IsBootstrappingException(message.ToManagedString());
+
+            GCHandle handle = GCHandle.Alloc(exception);
+            return new(handle);
+        }
     }
 }
\ No newline at end of file
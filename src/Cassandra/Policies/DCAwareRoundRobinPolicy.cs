@@ -110,6 +110,24 @@ namespace Cassandra
         {
         }
 
+        /// <summary>
+        /// Creates a new datacenter aware round robin policy given the name of the local
+        /// datacenter, whether to permit datacenter failover, and whether connecting to a cluster
+        /// without any node in the local datacenter should fail.
+        /// <p>
+        /// When <paramref name="failOnUnknownLocalDc"/> is <c>false</c>, an unknown local datacenter is only
+        /// logged as a warning. Requests are then sent to remote datacenters only if
+        /// <paramref name="permitDcFailover"/> is set, and fail otherwise until a node of the local datacenter joins.</p>
+        /// </summary>
+        /// <param name="localDc">The name of the local datacenter (as known by Cassandra).</param>
+        /// <param name="permitDcFailover">Whether to permit failover to remote datacenters.</param>
+        /// <param name="failOnUnknownLocalDc">Whether to fail connecting when no node belongs to <paramref name="localDc"/>.</param>
+        public DCAwareRoundRobinPolicy(string localDc, bool permitDcFailover, bool failOnUnknownLocalDc)
+            : this(localDc, 0, permitDcFailover)
+        {
+            FailOnUnknownLocalDc = failOnUnknownLocalDc;
+        }
+
         private DCAwareRoundRobinPolicy(string localDc, int usedHostsPerRemoteDc, bool permitDcFailover)
         {
             _localDc = localDc;
@@ -137,6 +155,12 @@ namespace Cassandra
         /// </summary>
         public bool PermitDcFailover { get; }
 
+        /// <summary>
+        /// Gets whether connecting fails when no node of the cluster belongs to the local datacenter.
+        /// Defaults to <c>true</c>. Otherwise, a warning is logged.
+        /// </summary>
+        public bool FailOnUnknownLocalDc { get; } = true;
+
         [Obsolete("Initialize is not supported. Load balancing is handled by the Rust driver internally.")]
         public void Initialize(ICluster cluster)
         {
@@ -0,0 +1,95 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+using System.Collections.Generic;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// A wrapper load balancing policy that penalizes nodes whose average latency is much higher
+    /// than that of the fastest node, moving them to the end of query plans.
+    /// <para>
+    /// Latencies are measured and averaged by the Rust driver. Options that are not strictly positive
+    /// keep the Rust driver defaults.
+    /// </para>
+    /// </summary>
+    public class LatencyAwarePolicy : ILoadBalancingPolicy
+    {
+        /// <summary>
+        /// Creates a new <c>LatencyAware</c> policy that wraps the provided child load balancing policy.
+        /// </summary>
+        /// <param name="childPolicy">The load balancing policy to wrap with latency awareness.</param>
+        /// <param name="exclusionThreshold">How many times slower than the fastest node a node must be to be penalized.</param>
+        /// <param name="scaleMillis">Weight given to older latencies when averaging, in milliseconds.</param>
+        /// <param name="retryPeriodMillis">How long a penalized node is avoided before being retried, in milliseconds.</param>
+        /// <param name="updateRateMillis">How often the latency of the fastest node is recomputed, in milliseconds.</param>
+        /// <param name="minimumMeasurements">How many latencies must be measured for a node before it can be penalized.</param>
+        public LatencyAwarePolicy(
+            ILoadBalancingPolicy childPolicy,
+            double exclusionThreshold = 0,
+            long scaleMillis = 0,
+            long retryPeriodMillis = 0,
+            long updateRateMillis = 0,
+            int minimumMeasurements = 0)
+        {
+            ChildPolicy = childPolicy ?? throw new ArgumentNullException(nameof(childPolicy));
+            ExclusionThreshold = exclusionThreshold;
+            ScaleMillis = scaleMillis;
+            RetryPeriodMillis = retryPeriodMillis;
+            UpdateRateMillis = updateRateMillis;
+            MinimumMeasurements = minimumMeasurements;
+        }
+
+        public ILoadBalancingPolicy ChildPolicy { get; }
+
+        public double ExclusionThreshold { get; }
+
+        public long ScaleMillis { get; }
+
+        public long RetryPeriodMillis { get; }
+
+        public long UpdateRateMillis { get; }
+
+        public int MinimumMeasurements { get; }
+
+        [Obsolete("Initialize is not supported. Load balancing is handled by the Rust driver internally.")]
+        public void Initialize(ICluster cluster)
+        {
+            throw new NotSupportedException(
+                "Initialize is not supported. Load balancing is handled by the Rust driver internally.");
+        }
+
+        /// <summary>
+        ///  Return the HostDistance for the provided host, as returned by the wrapped policy.
+        /// </summary>
+        public HostDistance Distance(Host host)
+        {
+            return ChildPolicy.Distance(host);
+        }
+
+        /// <summary>
+        /// <b> This function is not supported. All query routing is handled by the Rust driver internally.</b>
+        /// </summary>
+        [Obsolete("NewQueryPlan is not supported. Load balancing is handled by the Rust driver internally.")]
+        public IEnumerable<HostShard> NewQueryPlan(string loggedKeyspace, IStatement query)
+        {
+            throw new NotSupportedException(
+                "NewQueryPlan is not supported. Query routing is handled by the Rust driver internally.");
+        }
+    }
+}
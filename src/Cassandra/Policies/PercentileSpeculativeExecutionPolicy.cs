@@ -0,0 +1,74 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System;
+
+// ReSharper disable once CheckNamespace
+namespace Cassandra
+{
+    /// <summary>
+    /// A <see cref="ISpeculativeExecutionPolicy"/> that schedules a speculative execution whenever a request
+    /// is slower than a given percentile of the latencies measured by the session.
+    /// <para>
+    /// Latencies are measured by the Rust driver, which also schedules the speculative executions.
+    /// Like with any policy, only idempotent statements are executed speculatively.
+    /// </para>
+    /// </summary>
+    public class PercentileSpeculativeExecutionPolicy : ISpeculativeExecutionPolicy
+    {
+        /// <summary>
+        /// Creates a new instance of a <see cref="ISpeculativeExecutionPolicy"/> that schedules up to a given
+        /// number of speculative executions, each once a request is slower than the given latency percentile.
+        /// </summary>
+        /// <param name="percentile">The latency percentile, strictly between 0 and 100, e.g. 99.</param>
+        /// <param name="maxSpeculativeExecutions">The number of speculative executions. Must be strictly positive.</param>
+        public PercentileSpeculativeExecutionPolicy(double percentile, int maxSpeculativeExecutions)
+        {
+            Percentile = percentile;
+            MaxSpeculativeExecutions = maxSpeculativeExecutions;
+            if (!(percentile > 0 && percentile < 100))
+            {
+                throw new ArgumentOutOfRangeException("percentile", "The percentile must be between 0 and 100 exclusive");
+            }
+            if (maxSpeculativeExecutions <= 0)
+            {
+                throw new ArgumentOutOfRangeException("maxSpeculativeExecutions", "The maximum amount of speculative executions must be a positive number");
+            }
+        }
+
+        public double Percentile { get; }
+
+        public int MaxSpeculativeExecutions { get; }
+
+        public void Dispose()
+        {
+
+        }
+
+        public void Initialize(ICluster cluster)
+        {
+
+        }
+
+        [Obsolete("NewPlan is not supported. Speculative executions are scheduled by the Rust driver internally.")]
+        public ISpeculativeExecutionPlan NewPlan(string keyspace, IStatement statement)
+        {
+            throw new NotSupportedException(
+                "NewPlan is not supported. Speculative executions are scheduled by the Rust driver internally.");
+        }
+    }
+}
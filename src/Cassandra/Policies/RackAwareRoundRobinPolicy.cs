@@ -0,0 +1,134 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+using System;
+using System.Collections.Generic;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// A rack aware Round-robin load balancing policy.
+    /// <para>
+    /// This policy provides queries over the nodes of the local rack first, then over the other nodes
+    /// of the local datacenter. If the `PermitDcFailover` flag is set, it also includes in the query plans
+    /// hosts in remote datacenters, which are always tried after the local ones.
+    /// </para>
+    /// <para>
+    /// Wrap it in a <see cref="TokenAwarePolicy"/> to also prefer the replicas of the partition.
+    /// Replicas in the local rack are then tried first.
+    /// </para>
+    /// </summary>
+    public class RackAwareRoundRobinPolicy : ILoadBalancingPolicy
+    {
+        /// <summary>
+        /// Creates a new rack aware round robin policy given the names of the local datacenter and rack,
+        /// which ignores remote datacenters.
+        /// </summary>
+        /// <param name="localDc">The name of the local datacenter (as known by Cassandra).</param>
+        /// <param name="localRack">The name of the local rack within <paramref name="localDc"/>.</param>
+        public RackAwareRoundRobinPolicy(string localDc, string localRack) : this(localDc, localRack, false)
+        {
+        }
+
+        /// <summary>
+        /// Creates a new rack aware round robin policy given the names of the local datacenter and rack,
+        /// and whether to permit datacenter failover.
+        /// </summary>
+        /// <param name="localDc">The name of the local datacenter (as known by Cassandra).</param>
+        /// <param name="localRack">The name of the local rack within <paramref name="localDc"/>.</param>
+        /// <param name="permitDcFailover">Whether to permit failover to remote datacenters.</param>
+        public RackAwareRoundRobinPolicy(string localDc, string localRack, bool permitDcFailover)
+            : this(localDc, localRack, permitDcFailover, true)
+        {
+        }
+
+        /// <summary>
+        /// Creates a new rack aware round robin policy given the names of the local datacenter and rack,
+        /// whether to permit datacenter failover, and whether connecting to a cluster without any node
+        /// in the local datacenter should fail.
+        /// </summary>
+        /// <param name="localDc">The name of the local datacenter (as known by Cassandra).</param>
+        /// <param name="localRack">The name of the local rack within <paramref name="localDc"/>.</param>
+        /// <param name="permitDcFailover">Whether to permit failover to remote datacenters.</param>
+        /// <param name="failOnUnknownLocalDc">Whether to fail connecting when no node belongs to <paramref name="localDc"/>.
+        /// If <c>false</c>, a warning is logged instead.</param>
+        public RackAwareRoundRobinPolicy(string localDc, string localRack, bool permitDcFailover, bool failOnUnknownLocalDc)
+        {
+            if (string.IsNullOrWhiteSpace(localDc))
+            {
+                throw new ArgumentException("Local datacenter cannot be null or empty.", nameof(localDc));
+            }
+            if (string.IsNullOrWhiteSpace(localRack))
+            {
+                throw new ArgumentException("Local rack cannot be null or empty.", nameof(localRack));
+            }
+            LocalDc = localDc;
+            LocalRack = localRack;
+            PermitDcFailover = permitDcFailover;
+            FailOnUnknownLocalDc = failOnUnknownLocalDc;
+        }
+
+        /// <summary>
+        /// Gets the Local Datacenter. This value is provided in the constructor.
+        /// </summary>
+        public string LocalDc { get; }
+
+        /// <summary>
+        /// Gets the Local Rack. This value is provided in the constructor.
+        /// </summary>
+        public string LocalRack { get; }
+
+        /// <summary>
+        /// Gets whether this policy permits failover to remote datacenters.
+        /// </summary>
+        public bool PermitDcFailover { get; }
+
+        /// <summary>
+        /// Gets whether connecting fails when no node of the cluster belongs to the local datacenter.
+        /// Otherwise, a warning is logged.
+        /// </summary>
+        public bool FailOnUnknownLocalDc { get; }
+
+        [Obsolete("Initialize is not supported. Load balancing is handled by the Rust driver internally.")]
+        public void Initialize(ICluster cluster)
+        {
+            throw new NotSupportedException(
+                "Initialize is not supported. Load balancing is handled by the Rust driver internally.");
+        }
+
+        /// <summary>
+        /// Return the HostDistance for the provided host. Nodes in the local datacenter,
+        /// whichever their rack, are considered <c>Local</c>, and the others <c>Remote</c>.
+        /// </summary>
+        /// <param name="host"> the host of which to return the distance of. </param>
+        /// <returns>the HostDistance to <c>host</c>.</returns>
+        public HostDistance Distance(Host host)
+        {
+            var dc = host.Datacenter ?? LocalDc;
+            return dc == LocalDc ? HostDistance.Local : HostDistance.Remote;
+        }
+
+        /// <summary>
+        /// <b>This function is not supported. All query routing is handled by the Rust driver internally.</b>
+        /// </summary>
+        [Obsolete("NewQueryPlan is not supported. Load balancing is handled by the Rust driver internally.")]
+        public IEnumerable<HostShard> NewQueryPlan(string keyspace, IStatement query)
+        {
+            throw new NotSupportedException(
+                "NewQueryPlan is not supported. Query routing is handled by the Rust driver internally.");
+        }
+    }
+}
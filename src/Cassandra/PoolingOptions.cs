@@ -92,6 +92,9 @@ namespace Cassandra
         private bool _warmup = true;
 
         private bool _disableShardAwareness = false;
+        private int _poolSize;
+        private bool _poolSizePerShard = true;
+        private volatile bool _poolSizeFrozen;
 
         /// <summary>
         /// DEPRECATED: It will be removed in future versions. Use <see cref="PoolingOptions.Create"/> instead.
@@ -237,6 +240,9 @@ namespace Cassandra
             return _warmup;
         }
 
+        /// <summary>
+        /// Gets whether connecting to the shard-aware port of Scylla nodes is disabled. Default: false.
+        /// </summary>
         public bool GetDisableShardAwareness()
         {
             return _disableShardAwareness;
@@ -381,12 +387,85 @@ namespace Cassandra
             return this;
         }
 
+        /// <summary>
+        /// Disables connecting to the shard-aware port of Scylla nodes, e.g. when it is firewalled.
+        /// Requests are still routed to the shard owning their partition, but nodes assign
+        /// connections to shards themselves, so it may take more connection attempts to reach every shard.
+        /// </summary>
         public PoolingOptions DisableShardAwareness()
         {
             _disableShardAwareness = true;
             return this;
         }
 
+        /// <summary>
+        /// Gets the number of connections kept open to each host, or to each shard of a Scylla host
+        /// if <see cref="IsPoolSizePerShard"/> is true. Values &lt;= 0 mean the driver default of one connection per shard.
+        /// </summary>
+        public int GetPoolSize()
+        {
+            return _poolSize;
+        }
+
+        /// <summary>
+        /// Gets whether <see cref="GetPoolSize"/> is a number of connections per shard rather than per host.
+        /// </summary>
+        public bool IsPoolSizePerShard()
+        {
+            return _poolSizePerShard;
+        }
+
+        /// <summary>
+        /// Sets the number of connections kept open to each host, regardless of how many shards it has.
+        /// Values &lt;= 0 restore the driver default of one connection per shard.
+        /// <para>
+        /// The pool size can only be set before connecting: once a session was created with these options,
+        /// it cannot be changed anymore.
+        /// </para>
+        /// </summary>
+        /// <exception cref="InvalidOperationException">Thrown when a session was already created with these options.</exception>
+        public PoolingOptions SetConnectionsPerHost(int connections)
+        {
+            SetPoolSize(connections, false);
+            return this;
+        }
+
+        /// <summary>
+        /// Sets the number of connections kept open to each shard of Scylla hosts.
+        /// Hosts without shards, e.g. Apache Cassandra ones, get this many connections in total.
+        /// Values &lt;= 0 restore the driver default of one connection per shard.
+        /// <para>
+        /// The pool size can only be set before connecting: once a session was created with these options,
+        /// it cannot be changed anymore.
+        /// </para>
+        /// </summary>
+        /// <exception cref="InvalidOperationException">Thrown when a session was already created with these options.</exception>
+        public PoolingOptions SetConnectionsPerShard(int connections)
+        {
+            SetPoolSize(connections, true);
+            return this;
+        }
+
+        private void SetPoolSize(int connections, bool perShard)
+        {
+            if (_poolSizeFrozen)
+            {
+                throw new InvalidOperationException(
+                    "The pool size cannot be changed once a session was created with these pooling options. " +
+                    "Set it before connecting, or connect with new pooling options.");
+            }
+            _poolSize = connections;
+            _poolSizePerShard = perShard;
+        }
+
+        /// <summary>
+        /// Prevents further changes to the pool size, which is only read when a session is created.
+        /// </summary>
+        internal void FreezePoolSize()
+        {
+            _poolSizeFrozen = true;
+        }
+
         /// <summary>
         /// Creates a new instance of <see cref="PoolingOptions"/> using the default amount of connections
         /// and settings based on the protocol version.
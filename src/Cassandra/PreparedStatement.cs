@@ -34,6 +34,7 @@ namespace Cassandra
         internal readonly BridgedPreparedStatement bridgedPreparedStatement;
         private readonly RowSetMetadata _variablesMetadata;
         private readonly ISerializerManager _serializerManager = SerializerManager.Default;
+        private readonly Session _session;
         private volatile RoutingKey _routingKey;
         private string[] _routingNames;
         private volatile int[] _routingIndexes;
@@ -119,8 +120,9 @@ namespace Cassandra
         public bool IsLwt => _isLwt;
 
         // For use by the Rust interop code.
-        internal PreparedStatement(RustBridge.ManuallyDestructible mdPreparedStatement, string cql, ISerializerManager serializerManager)
+        internal PreparedStatement(RustBridge.ManuallyDestructible mdPreparedStatement, string cql, ISerializerManager serializerManager, Session session)
         {
+            _session = session;
             bridgedPreparedStatement = new BridgedPreparedStatement(mdPreparedStatement);
             _variablesMetadata = bridgedPreparedStatement.ExtractVariablesMetadataFromRust();
             Cql = cql;
@@ -288,6 +290,51 @@ namespace Cassandra
             return this;
         }
 
+        /// <summary>
+        /// Gets the retry policy set for executions of this statement,
+        /// or null if the retry policy of the session is used.
+        /// </summary>
+        public IRetryPolicy RetryPolicy { get; private set; }
+
+        /// <summary>
+        /// Sets the retry policy used by every execution of this statement, overriding the one of the session.
+        /// Pass null to use the retry policy of the session again.
+        /// <para>
+        /// Only the policies implemented by the driver are supported, i.e. <see cref="DefaultRetryPolicy"/>,
+        /// <see cref="FallthroughRetryPolicy"/> and <see cref="DowngradingConsistencyRetryPolicy"/>,
+        /// optionally wrapped in a <see cref="LoggingRetryPolicy"/> or <see cref="IdempotenceAwareRetryPolicy"/>.
+        /// </para>
+        /// </summary>
+        /// <exception cref="NotSupportedException">Thrown when the policy type is not supported.</exception>
+        public PreparedStatement SetRetryPolicy(IRetryPolicy policy)
+        {
+            var kind = policy == null ? (BridgedSession.BridgedRetryPolicyKind?)null : BridgedSession.BuildRetryPolicyKind(policy);
+            if (kind == BridgedSession.BridgedRetryPolicyKind.Custom)
+            {
+                throw new NotSupportedException($"Retry policy {policy.GetType().Name} is not supported per statement.");
+            }
+            bridgedPreparedStatement.SetRetryPolicy(kind);
+            RetryPolicy = policy;
+            return this;
+        }
+
+        /// <summary>
+        /// Sets the execution profile used by every execution of this statement, overriding the default profile of the session.
+        /// The profile must have been added through the Cluster Builder. Pass null, or the name of the default profile,
+        /// to use the default profile of the session again.
+        /// <para>
+        /// Settings of the statement itself, e.g. its consistency level or retry policy, take precedence over the profile.
+        /// </para>
+        /// </summary>
+        /// <exception cref="ArgumentException">Thrown when no execution profile with the given name was added.</exception>
+        /// <exception cref="NotSupportedException">Thrown when a policy of the profile is not supported.</exception>
+        public PreparedStatement SetExecutionProfile(string executionProfileName)
+        {
+            var profile = executionProfileName == null ? null : _session.GetExecutionProfile(executionProfileName);
+            bridgedPreparedStatement.SetExecutionProfile(profile);
+            return this;
+        }
+
         /// <summary>
         /// Sets a custom outgoing payload for this statement.
         /// Each time an statement generated using this prepared statement is executed, this payload will be included in the request.
@@ -33,6 +33,7 @@ namespace Cassandra
     public class QueryTrace
     {
         private readonly object _fetchLock = new object();
+        private readonly ISession _session;
         private readonly Metadata _metadata;
         private readonly Guid _traceId;
         private IPAddress _coordinator;
@@ -43,6 +44,7 @@ namespace Cassandra
         private long _startedAt;
         private IPAddress _clientAddress;
         private readonly int _metadataFetchSyncTimeout;
+        private volatile bool _isLoaded;
 
         /// <summary>
         /// The identifier of this trace.
@@ -176,6 +178,7 @@ namespace Cassandra
             //The instance is created before fetching the actual trace metadata
             //The properties will be populated later.
             _traceId = traceId;
+            _session = session;
             _metadata = session.Cluster.Metadata;
             _metadataFetchSyncTimeout = session.Cluster.Configuration.DefaultRequestOptions.QueryAbortTimeout;
         }
@@ -188,14 +191,29 @@ namespace Cassandra
 
         private void MaybeFetchTrace()
         {
-            throw new NotImplementedException();
+            if (_isLoaded)
+            {
+                return;
+            }
+            lock (_fetchLock)
+            {
+                if (_isLoaded)
+                {
+                    return;
+                }
+                TaskHelper.WaitToComplete(LoadAsync(), _metadataFetchSyncTimeout);
+            }
         }
 
-        internal Task<QueryTrace> LoadAsync()
+        internal async Task<QueryTrace> LoadAsync()
         {
-            // FIXME
-            // return _metadata.GetQueryTraceAsync(this);
-            throw new NotImplementedException();
+            if (_session is not Session session)
+            {
+                throw new NotSupportedException("Query traces can only be loaded through the driver's own session");
+            }
+            await session.LoadQueryTraceAsync(this).ConfigureAwait(false);
+            _isLoaded = true;
+            return this;
         }
 
         /// <summary>
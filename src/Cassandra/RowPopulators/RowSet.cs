@@ -51,6 +51,11 @@ namespace Cassandra
         private readonly BridgedRowSet bridgedRowSet;
         private readonly IGenericSerializer _genericSerializer;
 
+        /// <summary>
+        /// Rows read upfront from a fully fetched result, for RowSet instances without a bridged row set.
+        /// </summary>
+        private readonly ConcurrentQueue<Row> _materializedRows;
+
         /// <summary>
         /// Disposes the underlying Rust-allocated bridged row set.
         /// </summary>
@@ -127,6 +132,27 @@ namespace Cassandra
             _genericSerializer = serializerManager?.GetGenericSerializer() ?? new GenericSerializer();
         }
 
+        /// <summary>
+        /// Creates a new instance of RowSet holding all rows of a fully fetched result,
+        /// e.g. the <c>[applied]</c> row of a conditional batch.
+        /// </summary>
+        internal RowSet(BridgedQueryResult result, ISerializerManager serializerManager)
+        {
+            bridgedRowSet = null;
+            Info = new ExecutionInfo();
+            Columns = result.GetColumns();
+            _genericSerializer = serializerManager?.GetGenericSerializer() ?? new GenericSerializer();
+
+            var columnIndexes = GetColumnIndexes(Columns);
+            var rowsCount = result.GetRowsCount();
+            _materializedRows = new ConcurrentQueue<Row>();
+            for (var i = 0; i < rowsCount; i++)
+            {
+                _materializedRows.Enqueue(new Row(result.ReadRow(i, Columns, _genericSerializer), Columns, columnIndexes));
+            }
+            _exhausted = rowsCount == 0;
+        }
+
         internal BridgedRowSet BridgedRowSet =>
             bridgedRowSet
             ?? throw new InvalidOperationException("This RowSet does not have an underlying native resource.");
@@ -150,6 +176,11 @@ namespace Cassandra
         {
             if (bridgedRowSet == null)
             {
+                if (_materializedRows != null && _materializedRows.TryDequeue(out var materializedRow))
+                {
+                    return materializedRow;
+                }
+                _exhausted = true;
                 return null;
             }
             object[] values = new object[Columns.Length];
@@ -163,17 +194,24 @@ namespace Cassandra
                 return null;
             }
 
+            return new Row(values, Columns, GetColumnIndexes(Columns));
+        }
+
+        /// <summary>
+        /// Maps the names of <paramref name="columns"/> to their indexes, keeping the first of duplicate names.
+        /// </summary>
+        private static Dictionary<string, int> GetColumnIndexes(CqlColumn[] columns)
+        {
             var columnIndexes = new Dictionary<string, int>(StringComparer.Ordinal);
-            for (int i = 0; i < Columns.Length; ++i)
+            for (int i = 0; i < columns.Length; ++i)
             {
-                var name = Columns[i].Name;
+                var name = columns[i].Name;
                 if (string.IsNullOrEmpty(name))
                     continue;
                 if (!columnIndexes.ContainsKey(name))
                     columnIndexes[name] = i;
             }
-
-            return new Row(values, Columns, columnIndexes);
+            return columnIndexes;
         }
 
         /// <summary>
@@ -0,0 +1,131 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using Cassandra.Serialization;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges a Rust-owned batch, together with the values of its statements, to C#.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedBatch : RustResource
+    {
+        private BridgedBatch(ManuallyDestructible mdBatch) : base(mdBatch)
+        {
+        }
+
+        /// <summary>
+        /// Creates an empty batch of the given type.
+        /// </summary>
+        internal static BridgedBatch Create(BatchType batchType)
+        {
+            ManuallyDestructible mdBatch = default;
+            unsafe
+            {
+                var exception = batch_new((byte)batchType, out mdBatch, (IntPtr)Globals.ConstructorsPtr);
+                try
+                {
+                    ThrowIfException(ref exception);
+                }
+                finally
+                {
+                    FreeExceptionHandle(ref exception);
+                }
+            }
+            return new BridgedBatch(mdBatch);
+        }
+
+        /// <summary>
+        /// Appends an unprepared statement, serializing its values with <paramref name="serializer"/>.
+        /// </summary>
+        internal unsafe void AddSimple(string statement, object[] values, ISerializer serializer)
+        {
+            var populateCtx = SerializationHandler.CreateContext(values, serializer);
+            var ctxIntPtr = (IntPtr)Unsafe.AsPointer(ref populateCtx);
+            RunWithIncrement(handle =>
+                batch_add_simple(
+                    handle, statement,
+                    ctxIntPtr,
+                    (IntPtr)SerializationHandler.PopulateValuesPtr,
+                    (IntPtr)Globals.ConstructorsPtr));
+            GC.KeepAlive(populateCtx);
+        }
+
+        /// <summary>
+        /// Appends a prepared statement, serializing its values with <paramref name="serializer"/>.
+        /// </summary>
+        internal unsafe void AddPrepared(BridgedPreparedStatement preparedStatement, object[] values, ISerializer serializer)
+        {
+            var populateCtx = SerializationHandler.CreateContext(values, serializer);
+            var ctxIntPtr = (IntPtr)Unsafe.AsPointer(ref populateCtx);
+            RunWithIncrement(handle =>
+            {
+                preparedStatement.RunWithIncrement(preparedHandle =>
+                    batch_add_prepared(
+                        handle, preparedHandle,
+                        ctxIntPtr,
+                        (IntPtr)SerializationHandler.PopulateValuesPtr,
+                        (IntPtr)Globals.ConstructorsPtr));
+                return FFIMaybeException.Ok();
+            });
+            GC.KeepAlive(populateCtx);
+        }
+
+        internal void SetConsistencyLevel(ConsistencyLevel consistencyLevel)
+        {
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    batch_set_consistency_level(
+                        handle,
+                        (ushort)consistencyLevel,
+                        (IntPtr)Globals.ConstructorsPtr)
+                    );
+            }
+        }
+
+        internal void SetIsIdempotent(bool isIdempotent)
+        {
+            FFIBool ffiIsIdempotent = isIdempotent;
+            unsafe
+            {
+                RunWithIncrement(handle => batch_set_is_idempotent(handle, ffiIsIdempotent, (IntPtr)Globals.ConstructorsPtr));
+            }
+        }
+
+        /// <summary>
+        /// Attaches an execution profile to the batch, overriding the default profile of the session.
+        /// </summary>
+        internal void SetExecutionProfile(BridgedExecutionProfile profile)
+        {
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    profile.RunWithIncrement(profileHandle => batch_set_execution_profile(handle, profileHandle, (IntPtr)Globals.ConstructorsPtr));
+                    return FFIMaybeException.Ok();
+                });
+            }
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException batch_new(byte batchType, out ManuallyDestructible batch, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException batch_add_simple(IntPtr batch, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, IntPtr populateValuesContext, IntPtr populateValuesCallback, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException batch_add_prepared(IntPtr batch, IntPtr preparedStatement, IntPtr populateValuesContext, IntPtr populateValuesCallback, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException batch_set_consistency_level(IntPtr batch, ushort consistencyLevel, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException batch_set_is_idempotent(IntPtr batch, FFIBool isIdempotent, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException batch_set_execution_profile(IntPtr batch, IntPtr profile, IntPtr constructors);
+    }
+}
@@ -28,13 +28,15 @@ namespace Cassandra
             public ushort Port;
             public FFIString Datacenter;
             public FFIString Rack;
+            public FFIBool IsConnected;
         }
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
         private static extern RustBridge.FFIMaybeException cluster_state_fill_nodes(
             IntPtr clusterState,
             IntPtr contextPtr,
-            IntPtr callback);
+            IntPtr callback,
+            IntPtr constructors);
 
         private static readonly unsafe delegate* unmanaged[Cdecl]<IntPtr, CSharpHostData, FFIMaybeException> AddHostPtr = &AddHostToList;
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
@@ -89,6 +91,7 @@ namespace Cassandra
                     // If the address matches, reuse the instance.
                     if (host.Address.Equals(address))
                     {
+                        host.IsConnected = hostData.IsConnected;
                         context.AddHost(host);
                         return FFIMaybeException.Ok();
                     }
@@ -99,7 +102,10 @@ namespace Cassandra
                 var rackString = hostData.Rack.ToManagedString();
 
                 // Create Host instance and add it to the dictionaries.
-                host = new Host(address, hostId, dcString, rackString);
+                host = new Host(address, hostId, dcString, rackString)
+                {
+                    IsConnected = hostData.IsConnected,
+                };
                 context.AddHost(host);
             }
             catch (Exception ex)
@@ -118,7 +124,8 @@ namespace Cassandra
                     cluster_state_fill_nodes(
                         handle,
                         (IntPtr)Unsafe.AsPointer(ref context),
-                        (IntPtr)AddHostPtr
+                        (IntPtr)AddHostPtr,
+                        (IntPtr)Globals.ConstructorsPtr
                     )
                 );
             }
@@ -395,7 +402,8 @@ namespace Cassandra
         unsafe private static extern FFIMaybeException cluster_state_get_keyspace_names(
             IntPtr clusterState,
             IntPtr keyspaceNameListPtr,
-            IntPtr callback);
+            IntPtr callback,
+            IntPtr constructors);
 
         private static readonly unsafe delegate* unmanaged[Cdecl]<IntPtr, FFIString, FFIMaybeException> AddKeyspaceNamePtr = &AddKeyspaceName;
         [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
@@ -426,7 +434,8 @@ namespace Cassandra
                     cluster_state_get_keyspace_names(
                         handle,
                         (IntPtr)Unsafe.AsPointer(ref keyspaceNames),
-                        (IntPtr)AddKeyspaceNamePtr
+                        (IntPtr)AddKeyspaceNamePtr,
+                        (IntPtr)Globals.ConstructorsPtr
                     )
                 );
             }
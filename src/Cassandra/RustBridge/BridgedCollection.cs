@@ -0,0 +1,173 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges a Rust-owned <c>list</c>, <c>set</c> or <c>map</c> value, read from a query result, to C#.
+    /// Items are deserialized only when read, and strings are copied out of the response frame,
+    /// which the collection keeps alive until it is disposed.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedCollection : RustResource
+    {
+        private static readonly unsafe delegate* unmanaged[Cdecl]<IntPtr, void> FreePtr = &Free;
+
+        private BridgedCollection(IntPtr collectionPtr) : base(CreateManuallyDestructible(collectionPtr))
+        {
+        }
+
+        private static unsafe ManuallyDestructible CreateManuallyDestructible(IntPtr collectionPtr)
+        {
+            return new ManuallyDestructible(collectionPtr, (IntPtr)FreePtr);
+        }
+
+        // Rust hands out collections as plain owned pointers, so the destructor is a C# trampoline to collection_free.
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void Free(IntPtr collectionPtr)
+        {
+            collection_free(collectionPtr);
+        }
+
+        /// <summary>
+        /// Opens the collection cell at the given row and column of <paramref name="result"/>,
+        /// or returns null if the cell is null. Cassandra returns an empty non-frozen collection as null.
+        /// </summary>
+        internal static BridgedCollection Open(BridgedQueryResult result, int row, int column)
+        {
+            IntPtr collectionPtr = IntPtr.Zero;
+            FFIBool isNull = false;
+            unsafe
+            {
+                result.RunWithIncrement(handle =>
+                    result_get_collection(handle, (nuint)row, (nuint)column, out collectionPtr, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new BridgedCollection(collectionPtr);
+        }
+
+        /// <summary>
+        /// Number of elements of a list or set, or number of entries of a map.
+        /// </summary>
+        internal int Count
+        {
+            get
+            {
+                nuint length = 0;
+                unsafe
+                {
+                    RunWithIncrement(handle => collection_get_length(handle, out length, (IntPtr)Globals.ConstructorsPtr));
+                }
+                return (int)length;
+            }
+        }
+
+        /// <summary>
+        /// Type of the item at <paramref name="index"/>: the element type of a list or set, or for a map
+        /// the key type at even indexes and the value type at odd ones. The key of map entry <c>i</c>
+        /// is item <c>2 * i</c>, and its value item <c>2 * i + 1</c>.
+        /// </summary>
+        internal ColumnTypeCode GetItemType(int index)
+        {
+            byte typeCode = 0;
+            unsafe
+            {
+                RunWithIncrement(handle => collection_get_item_type(handle, (nuint)index, out typeCode, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return (ColumnTypeCode)typeCode;
+        }
+
+        /// <summary>
+        /// Opens the nested (frozen) collection item at <paramref name="index"/>, or returns null if the item is null.
+        /// The nested collection is owned by the caller and must be disposed independently.
+        /// </summary>
+        internal BridgedCollection OpenCollection(int index)
+        {
+            IntPtr collectionPtr = IntPtr.Zero;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    collection_get_collection(handle, (nuint)index, out collectionPtr, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new BridgedCollection(collectionPtr);
+        }
+
+        /// <summary>
+        /// Reads the <c>int</c> item at <paramref name="index"/>, or null if the item is null.
+        /// </summary>
+        internal int? GetInt32(int index)
+        {
+            int value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    collection_get_int32(handle, (nuint)index, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the <c>bigint</c> item at <paramref name="index"/>, or null if the item is null.
+        /// </summary>
+        internal long? GetInt64(int index)
+        {
+            long value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    collection_get_int64(handle, (nuint)index, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the <c>text</c> or <c>ascii</c> item at <paramref name="index"/>, or null if the item is null.
+        /// </summary>
+        internal string GetText(int index)
+        {
+            string value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = collection_get_text(handle, (nuint)index, out var text, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    // The string borrows from the collection, so it is copied while the handle is held.
+                    if (!exception.HasException && !isNull)
+                    {
+                        value = text.ToManagedString();
+                    }
+                    return exception;
+                });
+            }
+            return value;
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_collection(IntPtr result, nuint row, nuint column, out IntPtr collection, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern void collection_free(IntPtr collection);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIMaybeException collection_get_length(IntPtr collection, out nuint length, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIMaybeException collection_get_item_type(IntPtr collection, nuint index, out byte typeCode, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException collection_get_collection(IntPtr collection, nuint index, out IntPtr nested, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException collection_get_int32(IntPtr collection, nuint index, out int value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException collection_get_int64(IntPtr collection, nuint index, out long value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException collection_get_text(IntPtr collection, nuint index, out FFIString value, out FFIBool isNull, IntPtr constructors);
+    }
+}
@@ -0,0 +1,53 @@
+using System;
+using System.Runtime.InteropServices;
+using Cassandra.ExecutionProfiles;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges an immutable Rust execution profile to C#.
+    /// Statements and batches the profile is attached to keep it alive on the Rust side,
+    /// so it can be disposed while they are still in use.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedExecutionProfile : RustResource
+    {
+        private BridgedExecutionProfile(ManuallyDestructible mdProfile) : base(mdProfile)
+        {
+        }
+
+        /// <summary>
+        /// Builds a profile from the options of a named execution profile.
+        /// </summary>
+        /// <exception cref="NotSupportedException">Thrown when a policy type is not supported.</exception>
+        internal static BridgedExecutionProfile Build(IRequestOptions options, Configuration clusterConfig)
+        {
+            return Build(BridgedSession.BridgedExecutionProfileConfig.BuildFrom(options, clusterConfig));
+        }
+
+        /// <summary>
+        /// Builds a profile from an already mapped configuration, which Rust validates.
+        /// </summary>
+        internal static BridgedExecutionProfile Build(BridgedSession.BridgedExecutionProfileConfig config)
+        {
+            ManuallyDestructible mdProfile = default;
+            unsafe
+            {
+                var exception = execution_profile_build(config, out mdProfile, (IntPtr)Globals.ConstructorsPtr);
+                try
+                {
+                    ThrowIfException(ref exception);
+                }
+                finally
+                {
+                    FreeExceptionHandle(ref exception);
+                }
+            }
+            return new BridgedExecutionProfile(mdProfile);
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException execution_profile_build(BridgedSession.BridgedExecutionProfileConfig config, out ManuallyDestructible profile, IntPtr constructors);
+    }
+}
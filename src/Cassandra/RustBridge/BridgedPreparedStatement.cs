@@ -41,7 +41,8 @@ namespace Cassandra
                         (IntPtr)Unsafe.AsPointer(ref columns),
                         (IntPtr)setColumnMetaPtr,
                         (IntPtr)Unsafe.AsPointer(ref pkIndexes),
-                        (IntPtr)AddPkIndexPtr
+                        (IntPtr)AddPkIndexPtr,
+                        (IntPtr)Globals.ConstructorsPtr
                     )
                 );
             }
@@ -55,7 +56,7 @@ namespace Cassandra
             FFIBool isLwt = false;
             unsafe
             {
-                RunWithIncrement(handle => prepared_statement_is_lwt(handle, out isLwt));
+                RunWithIncrement(handle => prepared_statement_is_lwt(handle, out isLwt, (IntPtr)Globals.ConstructorsPtr));
             }
             return isLwt;
         }
@@ -67,7 +68,8 @@ namespace Cassandra
             {
                 RunWithIncrement(handle => prepared_statement_get_consistency_level(
                     handle,
-                    out clInt));
+                    out clInt,
+                    (IntPtr)Globals.ConstructorsPtr));
             }
 
             if (clInt < 0)
@@ -96,7 +98,7 @@ namespace Cassandra
             FFIBool isIdempotent = false;
             unsafe
             {
-                RunWithIncrement(handle => prepared_statement_get_is_idempotent(handle, out isIdempotent));
+                RunWithIncrement(handle => prepared_statement_get_is_idempotent(handle, out isIdempotent, (IntPtr)Globals.ConstructorsPtr));
             }
             return isIdempotent;
         }
@@ -106,30 +108,103 @@ namespace Cassandra
             FFIBool ffiIsIdempotent = isIdempotent;
             unsafe
             {
-                RunWithIncrement(handle => prepared_statement_set_is_idempotent(handle, ffiIsIdempotent));
+                RunWithIncrement(handle => prepared_statement_set_is_idempotent(handle, ffiIsIdempotent, (IntPtr)Globals.ConstructorsPtr));
+            }
+        }
+
+        internal void SetTracing(bool tracing)
+        {
+            FFIBool ffiTracing = tracing;
+            unsafe
+            {
+                RunWithIncrement(handle => prepared_statement_set_tracing(handle, ffiTracing, (IntPtr)Globals.ConstructorsPtr));
+            }
+        }
+
+        /// <summary>
+        /// Sets the client-side write timestamp in microseconds since the Unix epoch.
+        /// A negative value lets the driver or server assign one.
+        /// </summary>
+        internal void SetTimestamp(long timestampMicros)
+        {
+            unsafe
+            {
+                RunWithIncrement(handle => prepared_statement_set_timestamp(handle, timestampMicros, (IntPtr)Globals.ConstructorsPtr));
+            }
+        }
+
+        /// <summary>
+        /// Value passed to Rust to make the statement use the retry policy of the session again.
+        /// </summary>
+        private const byte SessionRetryPolicy = byte.MaxValue;
+
+        /// <summary>
+        /// Overrides the retry policy of the session for this statement,
+        /// or uses the one of the session again if <paramref name="retryPolicy"/> is null.
+        /// </summary>
+        internal void SetRetryPolicy(BridgedSession.BridgedRetryPolicyKind? retryPolicy)
+        {
+            var policy = retryPolicy.HasValue ? (byte)retryPolicy.Value : SessionRetryPolicy;
+            unsafe
+            {
+                RunWithIncrement(handle => prepared_statement_set_retry_policy(handle, policy, (IntPtr)Globals.ConstructorsPtr));
+            }
+        }
+
+        /// <summary>
+        /// Attaches an execution profile to this statement, overriding the default profile of the session,
+        /// or detaches it if <paramref name="profile"/> is null.
+        /// </summary>
+        internal void SetExecutionProfile(BridgedExecutionProfile profile)
+        {
+            unsafe
+            {
+                if (profile == null)
+                {
+                    RunWithIncrement(handle => prepared_statement_set_execution_profile(handle, IntPtr.Zero, (IntPtr)Globals.ConstructorsPtr));
+                    return;
+                }
+                RunWithIncrement(handle =>
+                {
+                    profile.RunWithIncrement(profileHandle =>
+                        prepared_statement_set_execution_profile(handle, profileHandle, (IntPtr)Globals.ConstructorsPtr));
+                    return FFIMaybeException.Ok();
+                });
             }
         }
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern FFIMaybeException prepared_statement_get_variables_column_specs_count(IntPtr prepared_statement, out nuint count);
+        unsafe private static extern FFIMaybeException prepared_statement_get_variables_column_specs_count(IntPtr prepared_statement, out nuint count, IntPtr constructors);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern FFIMaybeException prepared_statement_fill_column_specs_metadata(IntPtr prepared_statement, IntPtr columnsPtr, IntPtr metadataSetter, IntPtr pkIndexesPtr, IntPtr addPkIndex);
+        unsafe private static extern FFIMaybeException prepared_statement_fill_column_specs_metadata(IntPtr prepared_statement, IntPtr columnsPtr, IntPtr metadataSetter, IntPtr pkIndexesPtr, IntPtr addPkIndex, IntPtr constructors);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern FFIMaybeException prepared_statement_is_lwt(IntPtr prepared_statement, out FFIBool isLwt);
+        unsafe private static extern FFIMaybeException prepared_statement_is_lwt(IntPtr prepared_statement, out FFIBool isLwt, IntPtr constructors);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern FFIMaybeException prepared_statement_get_consistency_level(IntPtr prepared_statement, out int consistency_level);
+        unsafe private static extern FFIMaybeException prepared_statement_get_consistency_level(IntPtr prepared_statement, out int consistency_level, IntPtr constructors);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern FFIMaybeException prepared_statement_set_consistency_level(IntPtr prepared_statement, ushort consistency_level, IntPtr constructors);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern FFIMaybeException prepared_statement_get_is_idempotent(IntPtr prepared_statement, out FFIBool isIdempotent);
+        unsafe private static extern FFIMaybeException prepared_statement_get_is_idempotent(IntPtr prepared_statement, out FFIBool isIdempotent, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException prepared_statement_set_is_idempotent(IntPtr prepared_statement, FFIBool isIdempotent, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException prepared_statement_set_tracing(IntPtr prepared_statement, FFIBool tracing, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException prepared_statement_set_timestamp(IntPtr prepared_statement, long timestampMicros, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException prepared_statement_set_retry_policy(IntPtr prepared_statement, byte policy, IntPtr constructors);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern FFIMaybeException prepared_statement_set_is_idempotent(IntPtr prepared_statement, FFIBool isIdempotent);
+        unsafe private static extern FFIMaybeException prepared_statement_set_execution_profile(IntPtr prepared_statement, IntPtr profile, IntPtr constructors);
 
         private static readonly unsafe delegate* unmanaged[Cdecl]<IntPtr, ushort, FFIMaybeException> AddPkIndexPtr = &AddPkIndex;
         [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
@@ -155,7 +230,7 @@ namespace Cassandra
             nuint count = 0;
             unsafe
             {
-                RunWithIncrement(handle => prepared_statement_get_variables_column_specs_count(handle, out count));
+                RunWithIncrement(handle => prepared_statement_get_variables_column_specs_count(handle, out count, (IntPtr)Globals.ConstructorsPtr));
             }
             return count;
         }
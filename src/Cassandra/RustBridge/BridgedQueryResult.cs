@@ -0,0 +1,571 @@
+using System;
+using System.Buffers.Binary;
+using System.Net;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using Cassandra.Serialization;
+using Cassandra.Serialization.Primitive;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges a Rust-owned, fully fetched query result to C#.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedQueryResult : RustResource
+    {
+        internal BridgedQueryResult(ManuallyDestructible mdResult) : base(mdResult)
+        {
+        }
+
+        /// <summary>
+        /// Returns the number of rows in the result, which is at most the page size for a single page.
+        /// </summary>
+        internal int GetRowsCount()
+        {
+            nuint count = 0;
+            unsafe
+            {
+                RunWithIncrement(handle => result_get_rows_count(handle, out count, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return (int)count;
+        }
+
+        /// <summary>
+        /// Returns the columns of the result, with the type information needed to deserialize their cells.
+        /// </summary>
+        internal CqlColumn[] GetColumns()
+        {
+            CqlColumn[] columns = Array.Empty<CqlColumn>();
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_columns_count(handle, out var columnsCount, (IntPtr)Globals.ConstructorsPtr);
+                    var count = (int)columnsCount;
+                    if (exception.HasException || count == 0)
+                    {
+                        return exception;
+                    }
+
+                    // A local of the lambda, so that the pointer passed to Rust is to the stack.
+                    var filled = new CqlColumn[count];
+                    for (var i = 0; i < count; i++)
+                    {
+                        filled[i] = new CqlColumn();
+                    }
+                    exception = result_fill_columns_metadata(
+                        handle,
+                        (IntPtr)Unsafe.AsPointer(ref filled),
+                        (IntPtr)BridgedRowSet.setColumnMetaPtr,
+                        (IntPtr)Globals.ConstructorsPtr);
+                    columns = filled;
+                    return exception;
+                });
+            }
+            return columns;
+        }
+
+        /// <summary>
+        /// Deserializes the cells of the row at <paramref name="row"/> with <paramref name="serializer"/>,
+        /// like the rows of a <see cref="RowSet"/> are.
+        /// </summary>
+        /// <param name="columns">The columns of the result, as returned by <see cref="GetColumns"/>.</param>
+        internal object[] ReadRow(int row, CqlColumn[] columns, IGenericSerializer serializer)
+        {
+            var values = new object[columns.Length];
+            for (var i = 0; i < columns.Length; i++)
+            {
+                var column = columns[i];
+                values[i] = ReadRawBytes(row, i, (bytes, isNull) =>
+                    isNull ? null : serializer.Deserialize(ProtocolVersion.V4, bytes, column.TypeCode, column.TypeInfo));
+            }
+            return values;
+        }
+
+        /// <summary>
+        /// Returns whether the cell at the given row and column is null, for a column of any type.
+        /// Empty values, such as an empty <c>text</c> or a zero-length <c>blob</c>, are not null.
+        /// </summary>
+        internal bool IsNull(int row, int column)
+        {
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_row_is_null(handle, (nuint)row, (nuint)column, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>int</c> cell at the given row and column, or null if the cell is null.
+        /// </summary>
+        internal int? GetInt32(int row, int column)
+        {
+            int value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_get_int32(handle, (nuint)row, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>counter</c> cell at the given row and column, or null if the cell is null.
+        /// Unlike reading a <c>bigint</c>, throws <see cref="InvalidTypeException"/> if the column is not a counter.
+        /// </summary>
+        internal long? GetCounter(int row, int column)
+        {
+            long value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_get_counter(handle, (nuint)row, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>decimal</c> cell at the given row and column, or null if the cell is null.
+        /// Throws <see cref="ArgumentOutOfRangeException"/> if the value does not fit a <see cref="decimal"/>.
+        /// </summary>
+        internal decimal? GetDecimal(int row, int column)
+        {
+            decimal? value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_decimal(handle, (nuint)row, (nuint)column, out var scale, out var unscaled, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException || isNull)
+                    {
+                        return exception;
+                    }
+                    var unscaledValue = new BigInteger(unscaled.As<byte>().ToSpan(), isUnsigned: false, isBigEndian: true);
+                    value = DecimalSerializer.ToDecimal(unscaledValue, scale);
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>varint</c> cell at the given row and column, or null if the cell is null.
+        /// </summary>
+        internal BigInteger? GetVarint(int row, int column)
+        {
+            BigInteger? value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_varint(handle, (nuint)row, (nuint)column, out var bytes, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException || isNull)
+                    {
+                        return exception;
+                    }
+                    value = new BigInteger(bytes.As<byte>().ToSpan(), isUnsigned: false, isBigEndian: true);
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>timestamp</c> cell at the given row and column, or null if the cell is null.
+        /// Throws <see cref="ArgumentOutOfRangeException"/> if the value does not fit a <see cref="DateTimeOffset"/>.
+        /// </summary>
+        internal DateTimeOffset? GetTimestamp(int row, int column)
+        {
+            long millis = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_get_timestamp(handle, (nuint)row, (nuint)column, out millis, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : DateTimeOffset.FromUnixTimeMilliseconds(millis);
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>date</c> cell at the given row and column, or null if the cell is null.
+        /// </summary>
+        internal LocalDate GetDate(int row, int column)
+        {
+            int days = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_get_date(handle, (nuint)row, (nuint)column, out days, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : LocalDate.FromDaysSinceEpoch(days);
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>time</c> cell at the given row and column, or null if the cell is null.
+        /// </summary>
+        internal LocalTime GetTime(int row, int column)
+        {
+            long nanos = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_get_time(handle, (nuint)row, (nuint)column, out nanos, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new LocalTime(nanos);
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>duration</c> cell at the given row and column, or null if the cell is null.
+        /// </summary>
+        internal Duration? GetDuration(int row, int column)
+        {
+            FFIDuration value = default;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_get_duration(handle, (nuint)row, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new Duration(value.months, value.days, value.nanoseconds);
+        }
+
+        /// <summary>
+        /// Components of a CQL <c>duration</c>.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private struct FFIDuration
+        {
+            internal int months;
+            internal int days;
+            internal long nanoseconds;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>inet</c> cell at the given row and column, or null if the cell is null.
+        /// </summary>
+        internal IPAddress GetInet(int row, int column)
+        {
+            FFIInet value = default;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_get_inet(handle, (nuint)row, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+                // The captured variable lives in a closure, so its octets are read from a copy on the stack.
+                var inet = value;
+                return isNull ? null : new IPAddress(new ReadOnlySpan<byte>(inet.octets, inet.length));
+            }
+        }
+
+        /// <summary>
+        /// Octets of a CQL <c>inet</c> address, of which the first <c>length</c> are set.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private unsafe struct FFIInet
+        {
+            internal fixed byte octets[16];
+            internal byte length;
+        }
+
+        /// <summary>
+        /// Returns whether the conditional statement that produced this result was applied.
+        /// Throws <see cref="InvalidArgumentException"/> if the result has no <c>[applied]</c> column.
+        /// </summary>
+        internal bool WasApplied()
+        {
+            FFIBool applied = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    result_was_applied(handle, out applied, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return applied;
+        }
+
+        /// <summary>
+        /// Reads a span over the bytes of a blob cell.
+        /// </summary>
+        /// <param name="bytes">Borrows from the result. It must not outlive the call, as it is invalidated once the result is freed.</param>
+        /// <param name="isNull">Whether the cell is null, in which case <paramref name="bytes"/> is empty.</param>
+        internal delegate T BlobReader<T>(ReadOnlySpan<byte> bytes, bool isNull);
+
+        /// <summary>
+        /// Reads the CQL <c>blob</c> cell at the given row and column without copying it.
+        /// The bytes are only valid inside <paramref name="reader"/>, which runs while the result is held alive.
+        /// </summary>
+        internal T ReadBlob<T>(int row, int column, BlobReader<T> reader)
+        {
+            T value = default;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_blob(handle, (nuint)row, (nuint)column, out var slice, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    value = reader(isNull ? ReadOnlySpan<byte>.Empty : slice.As<byte>().ToSpan(), isNull);
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Returns a copy of the CQL <c>blob</c> cell at the given row and column, or null if the cell is null.
+        /// </summary>
+        internal byte[] GetBlob(int row, int column)
+        {
+            return ReadBlob(row, column, (bytes, isNull) => isNull ? null : bytes.ToArray());
+        }
+
+        /// <summary>
+        /// Reads the serialized bytes of the cell at the given row and column without deserializing it,
+        /// for a column of any type, including custom types.
+        /// The bytes are only valid inside <paramref name="reader"/>, which runs while the result is held alive.
+        /// </summary>
+        internal T ReadRawBytes<T>(int row, int column, BlobReader<T> reader)
+        {
+            T value = default;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_raw_bytes(handle, (nuint)row, (nuint)column, out var slice, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    value = reader(isNull ? ReadOnlySpan<byte>.Empty : slice.As<byte>().ToSpan(), isNull);
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Returns a copy of the serialized bytes of the cell at the given row and column, or null if the cell is null.
+        /// </summary>
+        internal byte[] GetRawBytes(int row, int column)
+        {
+            return ReadRawBytes(row, column, (bytes, isNull) => isNull ? null : bytes.ToArray());
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>vector&lt;float, N&gt;</c> cell at the given row and column, or null if the cell is null.
+        /// Throws <see cref="InvalidTypeException"/> if the column is not a vector of floats.
+        /// </summary>
+        internal CqlVector<float> GetFloatVector(int row, int column)
+        {
+            CqlVector<float> value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_float_vector(handle, (nuint)row, (nuint)column, out var slice, out var dimension, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException || isNull)
+                    {
+                        return exception;
+                    }
+
+                    // The slice borrows from the result, so it must be copied before the handle is released.
+                    var bytes = slice.As<byte>().ToSpan();
+                    var elements = new float[dimension];
+                    for (var i = 0; i < dimension; i++)
+                    {
+                        elements[i] = BinaryPrimitives.ReadSingleBigEndian(bytes.Slice(i * sizeof(float)));
+                    }
+                    value = new CqlVector<float>(elements);
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Returns a copy of the opaque paging state of the next page, to be passed back
+        /// when fetching that page, or null if there are no more pages.
+        /// </summary>
+        internal byte[] GetPagingState()
+        {
+            byte[] pagingState = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_paging_state(handle, out var state, out var hasMorePages, (IntPtr)Globals.ConstructorsPtr);
+                    if (!exception.HasException && hasMorePages)
+                    {
+                        // The slice borrows from the result, so it must be copied before the handle is released.
+                        pagingState = state.As<byte>().ToSpan().ToArray();
+                    }
+                    return exception;
+                });
+            }
+            return pagingState;
+        }
+
+        /// <summary>
+        /// Returns copies of the warnings sent by the server along with the result, e.g. about
+        /// a batch exceeding the size threshold. The array is empty if there were none.
+        /// </summary>
+        internal string[] GetWarnings()
+        {
+            string[] warnings = Array.Empty<string>();
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_warnings_count(handle, out var count, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException || count == 0)
+                    {
+                        return exception;
+                    }
+
+                    var copied = new string[(int)count];
+                    for (nuint i = 0; i < count; i++)
+                    {
+                        exception = result_get_warning(handle, i, out var warning, (IntPtr)Globals.ConstructorsPtr);
+                        if (exception.HasException)
+                        {
+                            return exception;
+                        }
+                        copied[(int)i] = warning.ToManagedString();
+                    }
+                    warnings = copied;
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return warnings;
+        }
+
+        /// <summary>
+        /// Returns the address of the coordinator that served the request.
+        /// </summary>
+        internal IPEndPoint GetCoordinatorAddress()
+        {
+            IPEndPoint address = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = result_get_coordinator_address(handle, out var coordinator, (IntPtr)Globals.ConstructorsPtr);
+                    if (!exception.HasException)
+                    {
+                        address = IPEndPoint.Parse(coordinator.ToManagedString());
+                    }
+                    return exception;
+                });
+            }
+            return address;
+        }
+
+        /// <summary>
+        /// Returns the consistency level the request succeeded with, which may be lower than the requested
+        /// one if the retry policy downgraded it.
+        /// </summary>
+        internal ConsistencyLevel GetAchievedConsistency()
+        {
+            ushort consistency = 0;
+            unsafe
+            {
+                RunWithIncrement(handle => result_get_achieved_consistency(handle, out consistency, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return (ConsistencyLevel)consistency;
+        }
+
+        /// <summary>
+        /// Returns the number of attempts made to execute the request, including retries and speculative executions.
+        /// </summary>
+        internal int GetAttemptCount()
+        {
+            uint count = 0;
+            unsafe
+            {
+                RunWithIncrement(handle => result_get_attempt_count(handle, out count, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return (int)count;
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_rows_count(IntPtr result, out nuint count, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_columns_count(IntPtr result, out nuint count, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_fill_columns_metadata(IntPtr result, IntPtr columns, IntPtr setColumnMeta, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_row_is_null(IntPtr result, nuint row, nuint column, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_int32(IntPtr result, nuint row, nuint column, out int value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_counter(IntPtr result, nuint row, nuint column, out long value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_decimal(IntPtr result, nuint row, nuint column, out int scale, out FFISliceRaw unscaled, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_varint(IntPtr result, nuint row, nuint column, out FFISliceRaw value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_timestamp(IntPtr result, nuint row, nuint column, out long epochMillis, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_date(IntPtr result, nuint row, nuint column, out int epochDays, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_time(IntPtr result, nuint row, nuint column, out long nanos, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_duration(IntPtr result, nuint row, nuint column, out FFIDuration value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_inet(IntPtr result, nuint row, nuint column, out FFIInet value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_was_applied(IntPtr result, out FFIBool applied, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_blob(IntPtr result, nuint row, nuint column, out FFISliceRaw value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_raw_bytes(IntPtr result, nuint row, nuint column, out FFISliceRaw value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_float_vector(IntPtr result, nuint row, nuint column, out FFISliceRaw value, out int dimension, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_paging_state(IntPtr result, out FFISliceRaw state, out FFIBool hasMorePages, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_warnings_count(IntPtr result, out nuint count, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_warning(IntPtr result, nuint index, out FFIString warning, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_coordinator_address(IntPtr result, out FFIString address, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_achieved_consistency(IntPtr result, out ushort consistency, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_attempt_count(IntPtr result, out uint count, IntPtr constructors);
+    }
+}
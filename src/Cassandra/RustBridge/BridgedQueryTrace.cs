@@ -0,0 +1,142 @@
+using System;
+using System.Collections.Generic;
+using System.Net;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges a Rust-owned server-side query trace to C#.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedQueryTrace : RustResource
+    {
+        internal BridgedQueryTrace(ManuallyDestructible mdTrace) : base(mdTrace)
+        {
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        private readonly struct FFITraceEvent
+        {
+            internal readonly FFIString activity;
+            internal readonly FFIBool hasActivity;
+            internal readonly FFIString source;
+            internal readonly FFIBool hasSource;
+            internal readonly FFIString thread;
+            internal readonly FFIBool hasThread;
+            internal readonly int sourceElapsedMicros;
+            internal readonly FFIBool hasSourceElapsedMicros;
+            internal readonly long timestampMillis;
+            internal readonly FFIBool hasTimestampMillis;
+        }
+
+        /// <summary>
+        /// Copies the trace into <paramref name="trace"/>. Missing fields keep the defaults
+        /// documented on <see cref="QueryTrace"/>.
+        /// </summary>
+        internal void CopyTo(QueryTrace trace)
+        {
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = query_trace_get_request(handle, out var request, out var requestIsNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    if (!requestIsNull)
+                    {
+                        trace.RequestType = request.ToManagedString();
+                    }
+
+                    exception = query_trace_get_coordinator(handle, out var coordinator, out var coordinatorIsNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    if (!coordinatorIsNull)
+                    {
+                        trace.Coordinator = IPAddress.Parse(coordinator.ToManagedString());
+                    }
+
+                    exception = query_trace_get_client(handle, out var client, out var clientIsNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    if (!clientIsNull)
+                    {
+                        trace.ClientAddress = IPAddress.Parse(client.ToManagedString());
+                    }
+
+                    exception = query_trace_get_duration_micros(handle, out var duration, out var durationIsNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    if (!durationIsNull)
+                    {
+                        trace.DurationMicros = duration;
+                    }
+
+                    exception = query_trace_get_started_at(handle, out var startedAt, out var startedAtIsNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    if (!startedAtIsNull)
+                    {
+                        trace.StartedAt = startedAt;
+                    }
+
+                    exception = query_trace_get_events_count(handle, out var count, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    var events = new List<QueryTrace.Event>((int)count);
+                    for (nuint i = 0; i < count; i++)
+                    {
+                        exception = query_trace_get_event(handle, i, out var e, (IntPtr)Globals.ConstructorsPtr);
+                        if (exception.HasException)
+                        {
+                            return exception;
+                        }
+                        events.Add(new QueryTrace.Event(
+                            e.hasActivity ? e.activity.ToManagedString() : null,
+                            e.hasTimestampMillis ? DateTimeOffset.FromUnixTimeMilliseconds(e.timestampMillis) : default,
+                            e.hasSource ? IPAddress.Parse(e.source.ToManagedString()) : null,
+                            e.hasSourceElapsedMicros ? e.sourceElapsedMicros : 0,
+                            e.hasThread ? e.thread.ToManagedString() : null));
+                    }
+                    trace.Events = events;
+
+                    return FFIMaybeException.Ok();
+                });
+            }
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException query_trace_get_request(IntPtr trace, out FFIString request, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException query_trace_get_coordinator(IntPtr trace, out FFIString coordinator, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException query_trace_get_client(IntPtr trace, out FFIString client, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException query_trace_get_duration_micros(IntPtr trace, out int durationMicros, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException query_trace_get_started_at(IntPtr trace, out long epochMillis, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException query_trace_get_events_count(IntPtr trace, out nuint count, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException query_trace_get_event(IntPtr trace, nuint index, out FFITraceEvent traceEvent, IntPtr constructors);
+    }
+}
@@ -0,0 +1,481 @@
+using System;
+using System.Net;
+using System.Numerics;
+using System.Runtime.InteropServices;
+using Cassandra.Serialization.Primitive;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges a single Rust-owned row, taken from a <see cref="BridgedRowCursor"/>, to C#.
+    /// Cells are deserialized only when read.
+    /// Getters throw <see cref="InvalidArgumentException"/> if the column index is out of range,
+    /// and <see cref="InvalidTypeException"/> if the column is of a different type, even for a null cell.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedRow : RustResource
+    {
+        internal BridgedRow(ManuallyDestructible mdRow) : base(mdRow)
+        {
+        }
+
+        /// <summary>
+        /// Returns the number of columns of the row.
+        /// </summary>
+        internal int GetColumnsCount()
+        {
+            nuint count = 0;
+            unsafe
+            {
+                RunWithIncrement(handle => row_get_columns_count(handle, out count, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return (int)count;
+        }
+
+        /// <summary>
+        /// Returns the name and the type code of the given column.
+        /// </summary>
+        internal (string Name, ColumnTypeCode TypeCode) GetColumnInfo(int column)
+        {
+            string name = null;
+            byte typeCode = 0;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = row_get_column_info(handle, (nuint)column, out var ffiName, out typeCode, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException)
+                    {
+                        return exception;
+                    }
+                    // The name borrows from the row, so it must be copied before the handle is released.
+                    name = ffiName.ToManagedString();
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return (name, (ColumnTypeCode)typeCode);
+        }
+
+        /// <summary>
+        /// Returns whether the cell at the given column is null, for a column of any type.
+        /// </summary>
+        internal bool IsNull(int column)
+        {
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_is_null(handle, (nuint)column, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>int</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal int? GetInt32(int column)
+        {
+            int value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_int32(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>bigint</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal long? GetInt64(int column)
+        {
+            long value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_int64(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>counter</c> cell at the given column, or null if the cell is null.
+        /// Unlike reading a <c>bigint</c>, throws <see cref="InvalidTypeException"/> if the column is not a counter.
+        /// </summary>
+        internal long? GetCounter(int column)
+        {
+            long value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_counter(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>smallint</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal short? GetSmallInt(int column)
+        {
+            short value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_smallint(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>tinyint</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal sbyte? GetTinyInt(int column)
+        {
+            sbyte value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_tinyint(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>boolean</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal bool? GetBoolean(int column)
+        {
+            FFIBool value = false;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_boolean(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : (bool)value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>float</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal float? GetFloat(int column)
+        {
+            float value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_float(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>double</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal double? GetDouble(int column)
+        {
+            double value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_double(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>uuid</c> or <c>timeuuid</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal Guid? GetUuid(int column)
+        {
+            FFIUuid value = default;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_uuid(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+                var uuid = value;
+                return isNull ? null : GuidFromFFIFormat(new ReadOnlySpan<byte>(uuid.bytes, 16));
+            }
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>timeuuid</c> cell at the given column, or null if the cell is null.
+        /// Unlike <see cref="GetUuid"/>, throws <see cref="InvalidTypeException"/> for a <c>uuid</c> column.
+        /// </summary>
+        internal TimeUuid? GetTimeUuid(int column)
+        {
+            FFIUuid value = default;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_timeuuid(handle, (nuint)column, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+                var uuid = value;
+                return isNull ? null : (TimeUuid)GuidFromFFIFormat(new ReadOnlySpan<byte>(uuid.bytes, 16));
+            }
+        }
+
+        /// <summary>
+        /// A UUID as 16 bytes in RFC 4122 (big-endian) order.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private unsafe struct FFIUuid
+        {
+            internal fixed byte bytes[16];
+        }
+
+        /// <summary>
+        /// Returns a copy of the CQL <c>blob</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal byte[] GetBlob(int column)
+        {
+            byte[] value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = row_get_col_as_blob(handle, (nuint)column, out var slice, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException || isNull)
+                    {
+                        return exception;
+                    }
+                    // The slice borrows from the row, so it must be copied before the handle is released.
+                    value = slice.As<byte>().ToSpan().ToArray();
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>text</c> or <c>ascii</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal string GetString(int column)
+        {
+            string value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = row_get_col_as_string(handle, (nuint)column, out var str, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException || isNull)
+                    {
+                        return exception;
+                    }
+                    value = str.ToManagedString();
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>inet</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal IPAddress GetInet(int column)
+        {
+            FFIInetV4 v4 = default;
+            FFIInetV6 v6 = default;
+            FFIBool isV6 = false;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_inet(handle, (nuint)column, out v4, out v6, out isV6, out isNull, (IntPtr)Globals.ConstructorsPtr));
+                if (isNull)
+                {
+                    return null;
+                }
+                // The captured variables live in a closure, so their octets are read from copies on the stack.
+                var octetsV4 = v4;
+                var octetsV6 = v6;
+                return isV6
+                    ? new IPAddress(new ReadOnlySpan<byte>(octetsV6.octets, 16))
+                    : new IPAddress(new ReadOnlySpan<byte>(octetsV4.octets, 4));
+            }
+        }
+
+        /// <summary>
+        /// Octets of an IPv4 address, in network byte order.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private unsafe struct FFIInetV4
+        {
+            internal fixed byte octets[4];
+        }
+
+        /// <summary>
+        /// Octets of an IPv6 address, in network byte order.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private unsafe struct FFIInetV6
+        {
+            internal fixed byte octets[16];
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>timestamp</c> cell at the given column, or null if the cell is null.
+        /// Throws <see cref="ArgumentOutOfRangeException"/> if the value does not fit a <see cref="DateTimeOffset"/>.
+        /// </summary>
+        internal DateTimeOffset? GetTimestamp(int column)
+        {
+            long millis = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_timestamp(handle, (nuint)column, out millis, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : DateTimeOffset.FromUnixTimeMilliseconds(millis);
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>date</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal LocalDate GetDate(int column)
+        {
+            int days = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_date(handle, (nuint)column, out days, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : LocalDate.FromDaysSinceEpoch(days);
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>time</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal LocalTime GetTime(int column)
+        {
+            long nanos = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    row_get_col_as_time(handle, (nuint)column, out nanos, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new LocalTime(nanos);
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>decimal</c> cell at the given column, or null if the cell is null.
+        /// Throws <see cref="ArgumentOutOfRangeException"/> if the value does not fit a <see cref="decimal"/>.
+        /// </summary>
+        internal decimal? GetDecimal(int column)
+        {
+            decimal? value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = row_get_col_as_decimal(handle, (nuint)column, out var scale, out var unscaled, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException || isNull)
+                    {
+                        return exception;
+                    }
+                    var unscaledValue = new BigInteger(unscaled.As<byte>().ToSpan(), isUnsigned: false, isBigEndian: true);
+                    value = DecimalSerializer.ToDecimal(unscaledValue, scale);
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Reads the CQL <c>varint</c> cell at the given column, or null if the cell is null.
+        /// </summary>
+        internal BigInteger? GetVarint(int column)
+        {
+            BigInteger? value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = row_get_col_as_varint(handle, (nuint)column, out var bytes, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    if (exception.HasException || isNull)
+                    {
+                        return exception;
+                    }
+                    value = new BigInteger(bytes.As<byte>().ToSpan(), isUnsigned: false, isBigEndian: true);
+                    return FFIMaybeException.Ok();
+                });
+            }
+            return value;
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_columns_count(IntPtr row, out nuint count, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_column_info(IntPtr row, nuint column, out FFIString name, out byte typeCode, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_is_null(IntPtr row, nuint column, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_int32(IntPtr row, nuint column, out int value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_int64(IntPtr row, nuint column, out long value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_counter(IntPtr row, nuint column, out long value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_smallint(IntPtr row, nuint column, out short value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_tinyint(IntPtr row, nuint column, out sbyte value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_boolean(IntPtr row, nuint column, out FFIBool value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_float(IntPtr row, nuint column, out float value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_double(IntPtr row, nuint column, out double value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_uuid(IntPtr row, nuint column, out FFIUuid value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_timeuuid(IntPtr row, nuint column, out FFIUuid value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_blob(IntPtr row, nuint column, out FFISliceRaw value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_string(IntPtr row, nuint column, out FFIString value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_inet(IntPtr row, nuint column, out FFIInetV4 v4, out FFIInetV6 v6, out FFIBool isV6, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_timestamp(IntPtr row, nuint column, out long epochMillis, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_date(IntPtr row, nuint column, out int epochDays, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_time(IntPtr row, nuint column, out long nanos, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_decimal(IntPtr row, nuint column, out int scale, out FFISliceRaw unscaled, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException row_get_col_as_varint(IntPtr row, nuint column, out FFISliceRaw value, out FFIBool isNull, IntPtr constructors);
+    }
+}
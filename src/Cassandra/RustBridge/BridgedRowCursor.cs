@@ -0,0 +1,88 @@
+using System;
+using System.Collections.Generic;
+using System.Runtime.InteropServices;
+using System.Threading.Tasks;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges a Rust-owned row cursor to C#. Rust fetches the rows in the background,
+    /// and C# takes them one by one or in batches of <see cref="BridgedRow"/>.
+    /// Disposing the cursor stops the background fetching, but rows already taken stay readable.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedRowCursor : RustResource
+    {
+        internal BridgedRowCursor(ManuallyDestructible mdCursor) : base(mdCursor)
+        {
+        }
+
+        /// <summary>
+        /// Takes the next row, waiting until it is available, or returns null once the stream has ended.
+        /// <see cref="NextRows"/> should be preferred for bulk processing, as it crosses the FFI boundary once per batch.
+        /// </summary>
+        internal async Task<BridgedRow> NextRow()
+        {
+            Task<ManuallyDestructible> task;
+            unsafe
+            {
+                task = RunAsyncWithIncrement<ManuallyDestructible>((tcb, cursor) => cursor_next_row(tcb, cursor));
+            }
+            var row = await task.ConfigureAwait(false);
+            return row.Ptr == IntPtr.Zero ? null : new BridgedRow(row);
+        }
+
+        /// <summary>
+        /// Takes up to <paramref name="maxRows"/> next rows, waiting until at least one is available.
+        /// </summary>
+        /// <returns>
+        /// The rows, and whether more rows may follow. Once the stream has ended, the final batch
+        /// may still contain rows or be empty, so the batch size alone does not mark the end.
+        /// </returns>
+        internal async Task<(List<BridgedRow> Rows, bool HasMore)> NextRows(int maxRows)
+        {
+            var rows = new List<BridgedRow>();
+
+            Task<FFIBool> task;
+            unsafe
+            {
+                // The handle is allocated only once the cursor is known not to be disposed,
+                // as Rust becomes its owner and frees it.
+                task = RunAsyncWithIncrement<FFIBool>((tcb, cursor) =>
+                    cursor_next_rows(tcb, cursor, (nuint)maxRows, (IntPtr)receiveRowPtr, new FFIGCHandle(GCHandle.Alloc(rows))));
+            }
+            var hasMore = await task.ConfigureAwait(false);
+            return (rows, hasMore);
+        }
+
+        unsafe static readonly delegate* unmanaged[Cdecl]<IntPtr, ManuallyDestructible, FFIMaybeException> receiveRowPtr = &ReceiveRow;
+
+        /// <summary>
+        /// This shall be called by Rust code for each row of a batch, transferring its ownership to C#.
+        /// </summary>
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static FFIMaybeException ReceiveRow(IntPtr batchPtr, ManuallyDestructible row)
+        {
+            // Wrap the row first, so that it is released even if it cannot be added to the batch.
+            var bridgedRow = new BridgedRow(row);
+            try
+            {
+                var rows = (List<BridgedRow>)GCHandle.FromIntPtr(batchPtr).Target;
+                rows.Add(bridgedRow);
+                return FFIMaybeException.Ok();
+            }
+            catch (Exception ex)
+            {
+                bridgedRow.Dispose();
+                return FFIMaybeException.FromException(ex);
+            }
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void cursor_next_row(Tcb<ManuallyDestructible> tcb, IntPtr cursor);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void cursor_next_rows(Tcb<FFIBool> tcb, IntPtr cursor, nuint maxRows, IntPtr receiveRow, FFIGCHandle batchHandle);
+    }
+}
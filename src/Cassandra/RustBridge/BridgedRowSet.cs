@@ -144,7 +144,7 @@ namespace Cassandra
             return columns;
         }
 
-        unsafe static readonly delegate* unmanaged[Cdecl]<IntPtr, nuint, FFIString, FFIString, FFIString, byte, IntPtr, byte, FFIMaybeException> setColumnMetaPtr = &SetColumnMeta;
+        internal unsafe static readonly delegate* unmanaged[Cdecl]<IntPtr, nuint, FFIString, FFIString, FFIString, byte, IntPtr, byte, FFIMaybeException> setColumnMetaPtr = &SetColumnMeta;
 
         /// <summary>
         /// This shall be called by Rust code for each column.
@@ -212,10 +212,10 @@ namespace Cassandra
         unsafe private static extern FFIMaybeException row_set_try_next_row_sync(IntPtr rowSetPtr, IntPtr deserializeValue, IntPtr columnsPtr, IntPtr valuesPtr, IntPtr serializerPtr, IntPtr constructorsPtr, out SyncNextRowResult result);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern FFIMaybeException row_set_get_columns_count(IntPtr rowSetPtr, out nuint count);
+        unsafe private static extern FFIMaybeException row_set_get_columns_count(IntPtr rowSetPtr, out nuint count, IntPtr constructors);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        unsafe private static extern FFIMaybeException row_set_fill_columns_metadata(IntPtr rowSetPtr, IntPtr columnsPtr, IntPtr metadataSetter);
+        unsafe private static extern FFIMaybeException row_set_fill_columns_metadata(IntPtr rowSetPtr, IntPtr columnsPtr, IntPtr metadataSetter, IntPtr constructors);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern byte row_set_type_info_get_code(IntPtr typeInfoHandle);
@@ -252,13 +252,19 @@ namespace Cassandra
 
         private void FillColumnsMetadata(IntPtr columnsPtr, IntPtr metadataSetter)
         {
-            RunWithIncrement(handle => row_set_fill_columns_metadata(handle, columnsPtr, metadataSetter));
+            unsafe
+            {
+                RunWithIncrement(handle => row_set_fill_columns_metadata(handle, columnsPtr, metadataSetter, (IntPtr)Globals.ConstructorsPtr));
+            }
         }
 
         private nuint GetColumnsCount()
         {
             nuint count = 0;
-            RunWithIncrement(handle => row_set_get_columns_count(handle, out count));
+            unsafe
+            {
+                RunWithIncrement(handle => row_set_get_columns_count(handle, out count, (IntPtr)Globals.ConstructorsPtr));
+            }
             return count;
         }
 
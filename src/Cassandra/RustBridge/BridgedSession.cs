@@ -1,7 +1,14 @@
 using System;
+using System.Collections.Generic;
+using System.Net;
 using System.Runtime.CompilerServices;
 using System.Runtime.InteropServices;
+using System.Security.Authentication;
+using System.Security.Cryptography;
+using System.Security.Cryptography.X509Certificates;
+using System.Text;
 using System.Threading.Tasks;
+using Cassandra.ExecutionProfiles;
 using Cassandra.Serialization;
 using static Cassandra.RustBridge;
 
@@ -43,7 +50,13 @@ namespace Cassandra
         private static extern FFIMaybeException session_get_cluster_state(IntPtr sessionPtr, out ManuallyDestructible clusterState, IntPtr constructorsPtr);
 
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
-        private static extern FFIMaybeException session_check_local_dc_existence(IntPtr sessionPtr, [MarshalAs(UnmanagedType.LPUTF8Str)] string localDc, IntPtr constructorsPtr);
+        private static extern FFIMaybeException session_is_shard_aware(IntPtr sessionPtr, out FFIBool isShardAware, IntPtr constructorsPtr);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIMaybeException session_get_pool_state(IntPtr sessionPtr, IntPtr statesPtr, IntPtr addState, IntPtr constructorsPtr);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIMaybeException session_check_local_dc_existence(IntPtr sessionPtr, [MarshalAs(UnmanagedType.LPUTF8Str)] string localDc, FFIBool failIfUnknown, IntPtr constructorsPtr);
 
         /// <summary>
         /// Executes a query with values supplied via the populate-callback pattern.
@@ -56,6 +69,9 @@ namespace Cassandra
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern void session_query_with_values(Tcb<ManuallyDestructible> tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, IntPtr populateValuesContext, IntPtr populateValuesCallback, SimpleStatementExecutionOptions executionOptions);
 
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_execute_simple(Tcb<ManuallyDestructible> tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement, IntPtr populateValuesContext, IntPtr populateValuesCallback, SimpleStatementExecutionOptions executionOptions);
+
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern void session_prepare(Tcb<ManuallyDestructible> tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string statement);
 
@@ -74,6 +90,41 @@ namespace Cassandra
             IntPtr populateValuesContext, IntPtr populateValuesCallback,
             PreparedStatementExecutionOptions executionOptions);
 
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_execute_prepared(
+            Tcb<ManuallyDestructible> tcb,
+            IntPtr session,
+            IntPtr preparedStatement,
+            IntPtr populateValuesContext, IntPtr populateValuesCallback,
+            PreparedStatementExecutionOptions executionOptions);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_execute_prepared_page(
+            Tcb<ManuallyDestructible> tcb,
+            IntPtr session,
+            IntPtr preparedStatement,
+            IntPtr populateValuesContext, IntPtr populateValuesCallback,
+            PreparedStatementExecutionOptions executionOptions,
+            FFISlice<byte> pagingState,
+            FFIBool hasPagingState);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_execute_iter(
+            Tcb<ManuallyDestructible> tcb,
+            IntPtr session,
+            IntPtr preparedStatement,
+            IntPtr populateValuesContext, IntPtr populateValuesCallback,
+            PreparedStatementExecutionOptions executionOptions);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_execute_batch(Tcb<ManuallyDestructible> tcb, IntPtr session, IntPtr batch);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_get_query_trace(Tcb<ManuallyDestructible> tcb, IntPtr session, IntPtr tracingId);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern void session_use_keyspace(Tcb<EmptyAsyncResult> tcb, IntPtr session, [MarshalAs(UnmanagedType.LPUTF8Str)] string keyspace, FFIBool caseSensitive);
+
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
         unsafe private static extern FFIMaybeException session_get_keyspace(IntPtr session, IntPtr writeToStr, IntPtr context, IntPtr constructorsPtr);
 
@@ -111,23 +162,32 @@ namespace Cassandra
             // So we pass a pointer to the method and Rust code will call it via that pointer.
             // This is a common pattern to call C# code from native code ("reversed P/Invoke").
             var tcb = Tcb<ManuallyDestructible>.WithTcs(tcs);
-            var bridgedSessionConfig = BridgedSessionConfig.BuildFrom(uri, keyspace, clusterConfig);
-            session_create(tcb, bridgedSessionConfig);
+            BridgedSessionConfig bridgedSessionConfig;
+            using (var ssl = new PinnedSslOptions(clusterConfig.ProtocolOptions?.SslOptions))
+            {
+                bridgedSessionConfig = BridgedSessionConfig.BuildFrom(uri, keyspace, clusterConfig, ssl);
+                // Rust parses the certificates before returning, so the buffers can be released right after.
+                session_create(tcb, bridgedSessionConfig);
+            }
 
             var bridgedSession = new BridgedSession(await tcs.Task.ConfigureAwait(false));
+            // Rust fixed the pool size when creating the session, so later changes would be silently ignored.
+            clusterConfig.PoolingOptions?.FreezePoolSize();
 
             // Validate the configured local datacenter against the connected cluster, mirroring
             // the post-connect check the old driver performed in DCAwareRoundRobinPolicy.
             // Only DC-aware policies set a local DC; null means there is nothing to validate.
-            string localDc = bridgedSessionConfig.loadBalancingPolicy.localDC;
+            // Policies may opt out of failing, in which case Rust only logs a warning.
+            string localDc = bridgedSessionConfig.defaultProfile.loadBalancingPolicy.localDC;
             if (localDc != null)
             {
+                FFIBool failIfUnknown = BridgedLoadBalancingPolicy.FailsOnUnknownLocalDc(clusterConfig.Policies.LoadBalancingPolicy);
                 try
                 {
                     unsafe
                     {
                         bridgedSession.RunWithIncrement(handle =>
-                            session_check_local_dc_existence(handle, localDc, (IntPtr)Globals.ConstructorsPtr));
+                            session_check_local_dc_existence(handle, localDc, failIfUnknown, (IntPtr)Globals.ConstructorsPtr));
                     }
                 }
                 catch
@@ -156,15 +216,19 @@ namespace Cassandra
         /// <param name="consistencyLevel">Consistency level to use for the query.</param>
         /// <param name="isIdempotent">Whether the query is idempotent.</param>
         /// <param name="pageSize">Page size for the query (must be positive).</param>
+        /// <param name="requestTimeoutMillis">Timeout of the request; non-positive values keep the session default.</param>
+        /// <param name="timestampMicros">Client-side write timestamp in microseconds since the Unix epoch; negative values let the driver or server assign one.</param>
         internal Task<ManuallyDestructible> Query(
             string statement,
             bool hasConsistencyLevel,
             ushort consistencyLevel,
             bool isIdempotent,
-            int pageSize)
+            int pageSize,
+            int requestTimeoutMillis,
+            long timestampMicros)
         {
             var executionOptions = new SimpleStatementExecutionOptions(
-                hasConsistencyLevel, consistencyLevel, isIdempotent, pageSize);
+                hasConsistencyLevel, consistencyLevel, isIdempotent, pageSize, requestTimeoutMillis, timestampMicros);
             return RunAsyncWithIncrement<ManuallyDestructible>((tcb, ptr) => session_query(tcb, ptr, statement, executionOptions));
         }
 
@@ -178,6 +242,8 @@ namespace Cassandra
         /// <param name="consistencyLevel">Consistency level to use for the query.</param>
         /// <param name="isIdempotent">Whether the query is idempotent.</param>
         /// <param name="pageSize">Page size for the query (must be positive).</param>
+        /// <param name="requestTimeoutMillis">Timeout of the request; non-positive values keep the session default.</param>
+        /// <param name="timestampMicros">Client-side write timestamp in microseconds since the Unix epoch; negative values let the driver or server assign one.</param>
         internal unsafe Task<ManuallyDestructible> QueryWithValues(
             string statement,
             object[] queryValues,
@@ -185,12 +251,14 @@ namespace Cassandra
             bool hasConsistencyLevel,
             ushort consistencyLevel,
             bool isIdempotent,
-            int pageSize)
+            int pageSize,
+            int requestTimeoutMillis,
+            long timestampMicros)
         {
             var populateCtx = SerializationHandler.CreateContext(queryValues, serializer);
             var ctxIntPtr = (IntPtr)Unsafe.AsPointer(ref populateCtx);
             var executionOptions = new SimpleStatementExecutionOptions(
-                hasConsistencyLevel, consistencyLevel, isIdempotent, pageSize);
+                hasConsistencyLevel, consistencyLevel, isIdempotent, pageSize, requestTimeoutMillis, timestampMicros);
             var task = RunAsyncWithIncrement<ManuallyDestructible>((tcb, ptr) =>
                 session_query_with_values(
                     tcb, ptr, statement,
@@ -201,6 +269,44 @@ namespace Cassandra
             return task;
         }
 
+        /// <summary>
+        /// Executes an unprepared statement with serialized values and returns its whole result, without paging.
+        /// Empty statements fail with <see cref="RequestInvalidException"/> without reaching the server.
+        /// </summary>
+        /// <param name="statement">CQL statement to be executed on the session.</param>
+        /// <param name="queryValues">Values to be serialized on demand and bound to the query.</param>
+        /// <param name="serializer">Serializer to use for converting CLR values to CQL bytes.</param>
+        /// <param name="hasConsistencyLevel">Whether a consistency level override was specified.</param>
+        /// <param name="consistencyLevel">Consistency level to use for the query.</param>
+        /// <param name="isIdempotent">Whether the query is idempotent.</param>
+        /// <param name="requestTimeoutMillis">Timeout of the request; non-positive values keep the session default.</param>
+        /// <param name="timestampMicros">Client-side write timestamp in microseconds since the Unix epoch; negative values let the driver or server assign one.</param>
+        /// <returns>A handle to be wrapped in a <see cref="BridgedQueryResult"/>.</returns>
+        internal unsafe Task<ManuallyDestructible> ExecuteSimple(
+            string statement,
+            object[] queryValues,
+            ISerializer serializer,
+            bool hasConsistencyLevel,
+            ushort consistencyLevel,
+            bool isIdempotent,
+            int requestTimeoutMillis,
+            long timestampMicros)
+        {
+            var populateCtx = SerializationHandler.CreateContext(queryValues, serializer);
+            var ctxIntPtr = (IntPtr)Unsafe.AsPointer(ref populateCtx);
+            // The result is not paged, so the page size is ignored.
+            var executionOptions = new SimpleStatementExecutionOptions(
+                hasConsistencyLevel, consistencyLevel, isIdempotent, 0, requestTimeoutMillis, timestampMicros);
+            var task = RunAsyncWithIncrement<ManuallyDestructible>((tcb, ptr) =>
+                session_execute_simple(
+                    tcb, ptr, statement,
+                    ctxIntPtr,
+                    (IntPtr)SerializationHandler.PopulateValuesPtr,
+                    executionOptions));
+            GC.KeepAlive(populateCtx);
+            return task;
+        }
+
         /// <summary>
         /// Prepares a statement on the session.
         /// </summary>
@@ -218,18 +324,24 @@ namespace Cassandra
         /// <param name="consistencyLevel">Consistency level to use for the query.</param>
         /// <param name="isIdempotent">Indicates whether the query is idempotent.</param>
         /// <param name="pageSize">Page size for the query (must be positive).</param>
+        /// <param name="requestTimeoutMillis">Timeout of the request; non-positive values keep the session default.</param>
+        /// <param name="timestampMicros">Client-side write timestamp in microseconds since the Unix epoch; negative values let the driver or server assign one.</param>
         internal Task<ManuallyDestructible> QueryBound(
             IntPtr preparedStatement,
             bool hasConsistencyLevel,
             ushort consistencyLevel,
             bool isIdempotent,
-            int pageSize)
+            int pageSize,
+            int requestTimeoutMillis,
+            long timestampMicros)
         {
             var executionOptions = new PreparedStatementExecutionOptions(
                 hasConsistencyLevel,
                 consistencyLevel,
                 isIdempotent,
-                pageSize);
+                pageSize,
+                requestTimeoutMillis,
+                timestampMicros);
 
             return RunAsyncWithIncrement<ManuallyDestructible>((tcb, ptr) => session_query_bound(
                 tcb,
@@ -248,6 +360,8 @@ namespace Cassandra
         /// <param name="consistencyLevel">Consistency level to use for the query.</param>
         /// <param name="isIdempotent">Indicates whether the query is idempotent.</param>
         /// <param name="pageSize">Page size for the query (must be positive).</param>
+        /// <param name="requestTimeoutMillis">Timeout of the request; non-positive values keep the session default.</param>
+        /// <param name="timestampMicros">Client-side write timestamp in microseconds since the Unix epoch; negative values let the driver or server assign one.</param>
         internal unsafe Task<ManuallyDestructible> QueryBoundWithValues(
             IntPtr preparedStatement,
             object[] queryValues,
@@ -255,7 +369,9 @@ namespace Cassandra
             bool hasConsistencyLevel,
             ushort consistencyLevel,
             bool isIdempotent,
-            int pageSize)
+            int pageSize,
+            int requestTimeoutMillis,
+            long timestampMicros)
         {
             var populateCtx = SerializationHandler.CreateContext(queryValues, serializer);
             var ctxIntPtr = (IntPtr)Unsafe.AsPointer(ref populateCtx);
@@ -264,7 +380,9 @@ namespace Cassandra
                 hasConsistencyLevel,
                 consistencyLevel,
                 isIdempotent,
-                pageSize);
+                pageSize,
+                requestTimeoutMillis,
+                timestampMicros);
 
             var task = RunAsyncWithIncrement<ManuallyDestructible>((tcb, ptr) =>
                 session_query_bound_with_values(
@@ -276,6 +394,177 @@ namespace Cassandra
             return task;
         }
 
+        /// <summary>
+        /// Executes a prepared statement with values and fetches its whole result, without paging,
+        /// resolving to a <see cref="BridgedQueryResult"/>.
+        /// Fails with <see cref="RequestInvalidException"/> before anything is sent if the number of values
+        /// does not match the number of bind markers.
+        /// </summary>
+        internal unsafe Task<ManuallyDestructible> ExecutePrepared(
+            IntPtr preparedStatement,
+            object[] queryValues,
+            ISerializer serializer,
+            bool hasConsistencyLevel,
+            ushort consistencyLevel,
+            bool isIdempotent,
+            int requestTimeoutMillis,
+            long timestampMicros)
+        {
+            var populateCtx = SerializationHandler.CreateContext(queryValues, serializer);
+            var ctxIntPtr = (IntPtr)Unsafe.AsPointer(ref populateCtx);
+
+            // The result is not paged, so the page size is ignored.
+            var executionOptions = new PreparedStatementExecutionOptions(
+                hasConsistencyLevel,
+                consistencyLevel,
+                isIdempotent,
+                0,
+                requestTimeoutMillis,
+                timestampMicros);
+
+            var task = RunAsyncWithIncrement<ManuallyDestructible>((tcb, ptr) =>
+                session_execute_prepared(
+                    tcb, ptr, preparedStatement,
+                    ctxIntPtr,
+                    (IntPtr)SerializationHandler.PopulateValuesPtr,
+                    executionOptions));
+            GC.KeepAlive(populateCtx);
+            return task;
+        }
+
+        /// <summary>
+        /// Executes a prepared statement with values and fetches a single page of <paramref name="pageSize"/> rows,
+        /// resolving to a <see cref="BridgedQueryResult"/>.
+        /// </summary>
+        /// <param name="pagingState">
+        /// Paging state of the page to fetch, as returned by <see cref="BridgedQueryResult.GetPagingState"/>
+        /// for the previous page, or null to fetch the first page. It is copied before this method returns.
+        /// </param>
+        internal unsafe Task<ManuallyDestructible> ExecutePreparedPage(
+            IntPtr preparedStatement,
+            object[] queryValues,
+            ISerializer serializer,
+            bool hasConsistencyLevel,
+            ushort consistencyLevel,
+            bool isIdempotent,
+            int pageSize,
+            int requestTimeoutMillis,
+            long timestampMicros,
+            byte[] pagingState)
+        {
+            var populateCtx = SerializationHandler.CreateContext(queryValues, serializer);
+            var ctxIntPtr = (IntPtr)Unsafe.AsPointer(ref populateCtx);
+
+            var executionOptions = new PreparedStatementExecutionOptions(
+                hasConsistencyLevel,
+                consistencyLevel,
+                isIdempotent,
+                pageSize,
+                requestTimeoutMillis,
+                timestampMicros);
+
+            var task = RunAsyncWithIncrement<ManuallyDestructible>((tcb, ptr) =>
+            {
+                // Pinned only for the duration of the call, as Rust copies the paging state.
+                fixed (byte* statePtr = pagingState)
+                {
+                    var stateSlice = new FFISlice<byte>((IntPtr)statePtr, (nuint)(pagingState?.Length ?? 0));
+                    session_execute_prepared_page(
+                        tcb, ptr, preparedStatement,
+                        ctxIntPtr,
+                        (IntPtr)SerializationHandler.PopulateValuesPtr,
+                        executionOptions,
+                        stateSlice,
+                        pagingState != null);
+                }
+            });
+            GC.KeepAlive(populateCtx);
+            return task;
+        }
+
+        /// <summary>
+        /// Executes a prepared statement with values, resolving to a <see cref="BridgedRowCursor"/>
+        /// that streams its rows, fetching pages of <paramref name="pageSize"/> rows in the background.
+        /// </summary>
+        internal unsafe Task<ManuallyDestructible> ExecuteIter(
+            IntPtr preparedStatement,
+            object[] queryValues,
+            ISerializer serializer,
+            bool hasConsistencyLevel,
+            ushort consistencyLevel,
+            bool isIdempotent,
+            int pageSize,
+            int requestTimeoutMillis,
+            long timestampMicros)
+        {
+            var populateCtx = SerializationHandler.CreateContext(queryValues, serializer);
+            var ctxIntPtr = (IntPtr)Unsafe.AsPointer(ref populateCtx);
+
+            var executionOptions = new PreparedStatementExecutionOptions(
+                hasConsistencyLevel,
+                consistencyLevel,
+                isIdempotent,
+                pageSize,
+                requestTimeoutMillis,
+                timestampMicros);
+
+            var task = RunAsyncWithIncrement<ManuallyDestructible>((tcb, ptr) =>
+                session_execute_iter(
+                    tcb, ptr, preparedStatement,
+                    ctxIntPtr,
+                    (IntPtr)SerializationHandler.PopulateValuesPtr,
+                    executionOptions));
+            GC.KeepAlive(populateCtx);
+            return task;
+        }
+
+        /// <summary>
+        /// Executes a batch on the session.
+        /// </summary>
+        /// <param name="batch">The batch to execute. It may be disposed once this method returns.</param>
+        internal Task<ManuallyDestructible> ExecuteBatch(BridgedBatch batch)
+        {
+            // The native side snapshots the batch synchronously, so it only needs to be
+            // referenced until session_execute_batch returns.
+            return RunAsyncWithIncrement<ManuallyDestructible>((tcb, sessionPtr) =>
+            {
+                try
+                {
+                    batch.RunWithIncrement(batchHandle =>
+                    {
+                        session_execute_batch(tcb, sessionPtr, batchHandle);
+                        return FFIMaybeException.Ok();
+                    });
+                }
+                catch (Exception ex)
+                {
+                    // The native call never started, so the TCB must be completed here to avoid leaking it.
+                    Tcb<ManuallyDestructible>.FailTask(tcb.tcs, FFIMaybeException.FromException(ex));
+                }
+            });
+        }
+
+        /// <summary>
+        /// Fetches the server-side trace of a traced request, resolving to a <see cref="BridgedQueryTrace"/>.
+        /// Fails with <see cref="TraceRetrievalException"/> if the trace cannot be read.
+        /// </summary>
+        internal Task<ManuallyDestructible> GetQueryTrace(Guid tracingId)
+        {
+            return RunAsyncWithIncrement<ManuallyDestructible>((tcb, sessionPtr) =>
+            {
+                // The native side copies the id before returning, so a stack buffer suffices.
+                Span<byte> tracingIdBytes = stackalloc byte[16];
+                GuidToFFIFormat(tracingId, tracingIdBytes);
+                unsafe
+                {
+                    fixed (byte* tracingIdPtr = tracingIdBytes)
+                    {
+                        session_get_query_trace(tcb, sessionPtr, (IntPtr)tracingIdPtr);
+                    }
+                }
+            });
+        }
+
         /// <summary>
         /// Waits for schema agreement on the session, requiring agreement from the coordinator
         /// node that served the given <paramref name="rowSet"/>. 
@@ -350,6 +639,69 @@ namespace Cassandra
             return new BridgedClusterState(mdClusterState);
         }
 
+        /// <summary>
+        /// Returns whether the nodes reported sharding information, so that requests are routed to the
+        /// shard owning their partition. Only Scylla nodes do so.
+        /// </summary>
+        internal bool IsShardAware()
+        {
+            FFIBool isShardAware = false;
+            unsafe
+            {
+                RunWithIncrement(handle => session_is_shard_aware(handle, out isShardAware, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isShardAware;
+        }
+
+        /// <summary>
+        /// Connection pool state of a host passed from Rust.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        private struct BridgedHostPoolState
+        {
+            internal FFISliceRaw IdBytes;
+            internal int OpenConnections;
+        }
+
+        /// <summary>
+        /// Gets the number of connections the driver keeps open to each host of the cluster, keyed by host ID.
+        /// Hosts the driver is not connected to are reported with 0 connections.
+        /// </summary>
+        internal Dictionary<Guid, int> GetPoolState()
+        {
+            var states = new Dictionary<Guid, int>();
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    session_get_pool_state(
+                        handle,
+                        (IntPtr)Unsafe.AsPointer(ref states),
+                        (IntPtr)AddHostPoolStatePtr,
+                        (IntPtr)Globals.ConstructorsPtr));
+            }
+            return states;
+        }
+
+        private static readonly unsafe delegate* unmanaged[Cdecl]<IntPtr, BridgedHostPoolState, FFIMaybeException> AddHostPoolStatePtr = &AddHostPoolState;
+
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static unsafe FFIMaybeException AddHostPoolState(IntPtr statesPtr, BridgedHostPoolState state)
+        {
+            try
+            {
+                var states = Unsafe.AsRef<Dictionary<Guid, int>>((void*)statesPtr);
+                // The host ID bytes are only valid during this call, so they are copied right away.
+                states[GuidFromFFIFormat(state.IdBytes.As<byte>().ToSpan())] = state.OpenConnections;
+            }
+            catch (Exception ex)
+            {
+                return FFIMaybeException.FromException(ex);
+            }
+
+            return FFIMaybeException.Ok();
+        }
+
         /// <summary>
         /// Gets the keyspace of the session. Returns the name of the current keyspace as a string, or null if no keyspace is set.
         /// Note: This method involves marshaling a string from native code, which can be expensive.
@@ -372,6 +724,18 @@ namespace Cassandra
             return stringContainer.Value;
         }
 
+        /// <summary>
+        /// Changes the keyspace used by all connections of the session, without executing a <c>USE</c> statement.
+        /// Unless <paramref name="caseSensitive"/> is set, the name is lowercased like an unquoted CQL identifier.
+        /// Throws <see cref="InvalidArgumentException"/> if the name is null or empty,
+        /// and <see cref="InvalidQueryException"/> if the keyspace does not exist.
+        /// </summary>
+        internal Task UseKeyspace(string keyspace, bool caseSensitive)
+        {
+            return RunAsyncWithIncrement<EmptyAsyncResult>(
+                (tcb, ptr) => session_use_keyspace(tcb, ptr, keyspace, caseSensitive));
+        }
+
         /// <summary>
         /// TCP socket options passed to Rust.
         /// Any changes to this struct must be mirrored in the corresponding Rust struct.
@@ -386,6 +750,7 @@ namespace Cassandra
             internal FFIBool reuseAddress;
             internal int sendBufferSize;
             internal FFIBool soLinger;
+            internal FFIBool writeCoalescing;
 
             internal static BridgedTcpConfig BuildFrom(SocketOptions socketOptions)
             {
@@ -402,16 +767,100 @@ namespace Cassandra
                     reuseAddress = socketOptions?.ReuseAddress ?? false,
                     sendBufferSize = socketOptions?.SendBufferSize ?? 0,
                     soLinger = (socketOptions?.SoLinger ?? -1) == 0,
+                    writeCoalescing = socketOptions?.WriteCoalescing ?? SocketOptions.DefaultWriteCoalescing,
+                };
+            }
+        }
+
+        /// <summary>
+        /// Connection-level options passed to Rust.
+        /// There is no idle connection timeout: the Rust driver keeps every pooled connection
+        /// open while its node is up, and idle connections are kept alive by the heartbeats.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedConnectionConfig
+        {
+            /// <summary>
+            /// Interval between heartbeats in milliseconds. Values &lt;= 0 disable heartbeats.
+            /// </summary>
+            internal int heartbeatIntervalMillis;
+
+            /// <summary>
+            /// Connections per host, or per shard if <see cref="poolSizePerShard"/> is set.
+            /// Values &lt;= 0 keep the Rust driver default of one connection per shard.
+            /// </summary>
+            internal int poolSize;
+
+            internal FFIBool poolSizePerShard;
+
+            internal static BridgedConnectionConfig BuildFrom(PoolingOptions poolingOptions)
+            {
+                return new BridgedConnectionConfig
+                {
+                    heartbeatIntervalMillis = poolingOptions == null
+                        ? PoolingOptions.DefaultHeartBeatInterval
+                        : poolingOptions.GetHeartBeatInterval() ?? 0,
+                    poolSize = poolingOptions?.GetPoolSize() ?? 0,
+                    poolSizePerShard = poolingOptions?.IsPoolSizePerShard() ?? true,
                 };
             }
         }
+
+        /// <summary>
+        /// Latency awareness options passed to Rust. Values &lt;= 0 keep the Rust driver defaults.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedLatencyAwarenessConfig
+        {
+            internal FFIBool enabled;
+            internal double exclusionThreshold;
+            internal int minimumMeasurements;
+            internal long retryPeriodMillis;
+            internal long updateRateMillis;
+            internal long scaleMillis;
+
+            /// <summary>
+            /// Latency awareness is disabled unless explicitly requested.
+            /// </summary>
+            internal static BridgedLatencyAwarenessConfig Disabled => new BridgedLatencyAwarenessConfig
+            {
+                enabled = false,
+            };
+
+            internal static BridgedLatencyAwarenessConfig BuildFrom(LatencyAwarePolicy policy)
+            {
+                return new BridgedLatencyAwarenessConfig
+                {
+                    enabled = true,
+                    exclusionThreshold = policy.ExclusionThreshold,
+                    minimumMeasurements = policy.MinimumMeasurements,
+                    retryPeriodMillis = policy.RetryPeriodMillis,
+                    updateRateMillis = policy.UpdateRateMillis,
+                    scaleMillis = policy.ScaleMillis,
+                };
+            }
+        }
+        /// <summary>
+        /// Load balancing options passed to Rust.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
         [StructLayout(LayoutKind.Sequential)]
         internal struct BridgedLoadBalancingPolicy
         {
+            /// <summary>
+            /// Set when the policy is wrapped in a <see cref="TokenAwarePolicy"/>. Requests are then routed
+            /// to the replicas of the partition, which is only known for prepared statements
+            /// whose partition key is bound at execution time.
+            /// </summary>
             internal FFIBool isTokenAware;
             internal FFIBool permitDcFailover;
             [MarshalAs(UnmanagedType.LPUTF8Str)]
             internal string localDC;
+            [MarshalAs(UnmanagedType.LPUTF8Str)]
+            internal string localRack;
+            internal BridgedLatencyAwarenessConfig latencyAwareness;
 
             /// <summary>
             /// Extracts the relevant information from the provided ILoadBalancingPolicy and its potential child policies.
@@ -419,8 +868,11 @@ namespace Cassandra
             /// <list type="bullet">
             /// <item>RoundRobinPolicy</item>
             /// <item>DCAwareRoundRobinPolicy</item>
+            /// <item>RackAwareRoundRobinPolicy</item>
             /// <item>TokenAwarePolicy(RoundRobinPolicy)</item>
             /// <item>TokenAwarePolicy(DCAwareRoundRobinPolicy)</item>
+            /// <item>TokenAwarePolicy(RackAwareRoundRobinPolicy)</item>
+            /// <item>LatencyAwarePolicy wrapping any of the above</item>
             /// <item>DefaultLoadBalancingPolicy(TokenAwarePolicy(DCAwareRoundRobinPolicy))</item>
             /// <item>DefaultLoadBalancingPolicy(TokenAwarePolicy(RoundRobinPolicy)) (that policy is constructed when the user does not specify any policy when creating a cluster)</item>
             /// </list>
@@ -434,9 +886,11 @@ namespace Cassandra
                 {
                     isTokenAware = false,
                     localDC = null,
+                    localRack = null,
+                    latencyAwareness = BridgedLatencyAwarenessConfig.Disabled,
                 };
 
-                // The loop unwraps layers of TokenAwarePolicy and DefaultLoadBalancingPolicy until it finds DCAwareRoundRobinPolicy or RoundRobinPolicy.
+                // The loop unwraps layers of TokenAwarePolicy, LatencyAwarePolicy and DefaultLoadBalancingPolicy until it finds DCAwareRoundRobinPolicy or RoundRobinPolicy.
                 // The chain is finite and acyclic because every child policy is assigned once at construction and is
                 // exposed through a get-only property, so this loop is guaranteed to terminate.
                 while (lbp != null)
@@ -456,11 +910,22 @@ namespace Cassandra
                             lbp = defaultPolicy.ChildPolicy;
                             break;
 
+                        case LatencyAwarePolicy latencyAware:
+                            rustLBP.latencyAwareness = BridgedLatencyAwarenessConfig.BuildFrom(latencyAware);
+                            lbp = latencyAware.ChildPolicy;
+                            break;
+
                         case DCAwareRoundRobinPolicy dcAware:
                             rustLBP.permitDcFailover = dcAware.PermitDcFailover;
                             rustLBP.localDC = dcAware.LocalDc;
                             return rustLBP;
 
+                        case RackAwareRoundRobinPolicy rackAware:
+                            rustLBP.permitDcFailover = rackAware.PermitDcFailover;
+                            rustLBP.localDC = rackAware.LocalDc;
+                            rustLBP.localRack = rackAware.LocalRack;
+                            return rustLBP;
+
                         case RoundRobinPolicy:
                             return rustLBP;
 
@@ -476,12 +941,978 @@ namespace Cassandra
 
                 throw new NotSupportedException("Load balancing policy cannot be null or have a null child policy.");
             }
-        }
-        /// <summary>
-        /// Configuration struct used to pass session creation parameters from C# to Rust.
-        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
-        /// </summary>
-        [StructLayout(LayoutKind.Sequential)]
+
+            /// <summary>
+            /// Returns whether connecting should fail when no node belongs to the local datacenter of
+            /// <paramref name="lbp"/>, unwrapping the same layers as <see cref="BuildFrom"/>.
+            /// Policies without a local datacenter return <c>true</c>, as there is nothing to check.
+            /// </summary>
+            internal static bool FailsOnUnknownLocalDc(ILoadBalancingPolicy lbp)
+            {
+                while (true)
+                {
+                    switch (lbp)
+                    {
+                        case TokenAwarePolicy tokenAware:
+                            lbp = tokenAware.ChildPolicy;
+                            break;
+                        case DefaultLoadBalancingPolicy defaultPolicy:
+                            lbp = defaultPolicy.ChildPolicy;
+                            break;
+                        case LatencyAwarePolicy latencyAware:
+                            lbp = latencyAware.ChildPolicy;
+                            break;
+                        case DCAwareRoundRobinPolicy dcAware:
+                            return dcAware.FailOnUnknownLocalDc;
+                        case RackAwareRoundRobinPolicy rackAware:
+                            return rackAware.FailOnUnknownLocalDc;
+                        default:
+                            return true;
+                    }
+                }
+            }
+        }
+        /// <summary>
+        /// Speculative execution policy selected for the session.
+        /// Any changes to this enum must be mirrored in the corresponding Rust enum.
+        /// </summary>
+        internal enum BridgedSpeculativeExecutionKind : byte
+        {
+            None = 0,
+            Constant = 1,
+            Percentile = 2,
+        }
+
+        /// <summary>
+        /// Speculative execution options passed to Rust. A count &lt;= 0 disables speculative executions.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedSpeculativeExecutionConfig
+        {
+            internal BridgedSpeculativeExecutionKind kind;
+            internal int maxRetryCount;
+            internal long retryIntervalMillis;
+            internal double percentile;
+
+            /// <exception cref="NotSupportedException">Thrown when the policy type is not supported.</exception>
+            internal static BridgedSpeculativeExecutionConfig BuildFrom(ISpeculativeExecutionPolicy policy)
+            {
+                switch (policy)
+                {
+                    case null:
+                    case NoSpeculativeExecutionPolicy:
+                        return new BridgedSpeculativeExecutionConfig { kind = BridgedSpeculativeExecutionKind.None };
+
+                    case ConstantSpeculativeExecutionPolicy constant:
+                        return new BridgedSpeculativeExecutionConfig
+                        {
+                            kind = BridgedSpeculativeExecutionKind.Constant,
+                            maxRetryCount = constant.MaxSpeculativeExecutions,
+                            retryIntervalMillis = constant.Delay,
+                        };
+
+                    case PercentileSpeculativeExecutionPolicy percentile:
+                        return new BridgedSpeculativeExecutionConfig
+                        {
+                            kind = BridgedSpeculativeExecutionKind.Percentile,
+                            maxRetryCount = percentile.MaxSpeculativeExecutions,
+                            percentile = percentile.Percentile,
+                        };
+
+                    default:
+                        throw new NotSupportedException($"Speculative execution policy {policy.GetType().Name} is not supported.");
+                }
+            }
+        }
+
+        /// <summary>
+        /// Compression of CQL frames selected for the session. The values match <see cref="CompressionType"/>.
+        /// Any changes to this enum must be mirrored in the corresponding Rust enum.
+        /// </summary>
+        internal enum BridgedCompression : byte
+        {
+            None = 0,
+            Snappy = 1,
+            Lz4 = 2,
+        }
+
+        /// <summary>
+        /// Returns the compression selected in <paramref name="protocolOptions"/>.
+        /// The Rust driver compresses frames itself, so a custom compressor is ignored.
+        /// </summary>
+        internal static BridgedCompression BuildCompression(ProtocolOptions protocolOptions)
+        {
+            if (protocolOptions?.CustomCompressor != null)
+            {
+                Logger.Warning("ProtocolOptions.CustomCompressor is not supported and will be ignored. " +
+                               "Frames are compressed by the Rust driver with the selected compression type.");
+            }
+            switch (protocolOptions?.Compression ?? CompressionType.NoCompression)
+            {
+                case CompressionType.NoCompression:
+                    return BridgedCompression.None;
+                case CompressionType.Snappy:
+                    return BridgedCompression.Snappy;
+                case CompressionType.LZ4:
+                    return BridgedCompression.Lz4;
+                default:
+                    throw new NotSupportedException($"Compression type {protocolOptions.Compression} is not supported.");
+            }
+        }
+
+        /// <summary>
+        /// Retry policy selected for the session.
+        /// Any changes to this enum must be mirrored in the corresponding Rust enum.
+        /// </summary>
+        internal enum BridgedRetryPolicyKind : byte
+        {
+            Default = 0,
+            Fallthrough = 1,
+            DowngradingConsistency = 2,
+            Custom = 3,
+        }
+
+        /// <summary>
+        /// Kind of error a request attempt failed with, as passed to the custom retry policy callback.
+        /// Any changes to this enum must be mirrored in the corresponding Rust enum.
+        /// </summary>
+        internal enum BridgedRetryErrorKind : byte
+        {
+            ReadTimeout = 0,
+            WriteTimeout = 1,
+            Unavailable = 2,
+            Overloaded = 3,
+            ServerError = 4,
+            TruncateError = 5,
+            IsBootstrapping = 6,
+            ConnectionBroken = 7,
+            Other = 8,
+        }
+
+        /// <summary>
+        /// Decision of the custom retry policy, as returned to Rust.
+        /// Any changes to this enum must be mirrored in the corresponding Rust enum.
+        /// </summary>
+        internal enum BridgedRetryDecisionCode : byte
+        {
+            RetrySameHost = 0,
+            RetryNextHost = 1,
+            Rethrow = 2,
+            Ignore = 3,
+        }
+
+        /// <summary>
+        /// Description of a failed request attempt, passed by Rust to the custom retry policy.
+        /// Received and required are -1 when the error does not carry them.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedRetryDecisionInput
+        {
+            internal BridgedRetryErrorKind errorKind;
+            internal ushort consistency;
+            internal int received;
+            internal int required;
+            internal FFIBool dataPresent;
+            internal FFIString writeType;
+            internal uint retryCount;
+            internal FFIBool isIdempotent;
+        }
+
+        /// <summary>
+        /// Decision of the custom retry policy, written back to Rust.
+        /// Rust initializes it to a rethrow, so it is left untouched when the policy fails.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedRetryDecision
+        {
+            internal BridgedRetryDecisionCode decision;
+            internal ushort consistency;
+            internal FFIBool hasConsistency;
+        }
+
+        /// <summary>
+        /// Retry policy implemented in C#, used when <see cref="BridgedRetryPolicyKind.Custom"/> is selected.
+        /// Rust takes ownership of the policy handle and calls <c>decideRetry</c> on Tokio worker threads,
+        /// possibly concurrently, so the policy must be thread-safe and must not block.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedCustomRetryPolicy
+        {
+            internal FFIMaybeGCHandle policy;
+            internal IntPtr decideRetry;
+
+            internal static BridgedCustomRetryPolicy None => new BridgedCustomRetryPolicy
+            {
+                policy = FFIMaybeGCHandle.Empty(),
+                decideRetry = IntPtr.Zero,
+            };
+
+            unsafe private static readonly delegate* unmanaged[Cdecl]<IntPtr, BridgedRetryDecisionInput*, BridgedRetryDecision*, void> DecideRetryPtr = &DecideRetry;
+
+            /// <summary>
+            /// The policy called back by Rust, along with the configuration passed to
+            /// <see cref="IExtendedRetryPolicy.OnRequestError"/>.
+            /// </summary>
+            private sealed class CustomPolicy
+            {
+                internal readonly IRetryPolicy Policy;
+                internal readonly Configuration Config;
+
+                internal CustomPolicy(IRetryPolicy policy, Configuration config)
+                {
+                    Policy = policy;
+                    Config = config;
+                }
+            }
+
+            /// <summary>
+            /// Builds the bridged policy, or <see cref="None"/> if the policy is implemented by the Rust driver.
+            /// </summary>
+            internal static BridgedCustomRetryPolicy BuildFrom(IRetryPolicy retryPolicy, Configuration config)
+            {
+                if (BuildRetryPolicyKind(retryPolicy) != BridgedRetryPolicyKind.Custom)
+                {
+                    return None;
+                }
+
+                unsafe
+                {
+                    return new BridgedCustomRetryPolicy
+                    {
+                        policy = new FFIMaybeGCHandle(GCHandle.Alloc(new CustomPolicy(retryPolicy, config))),
+                        decideRetry = (IntPtr)DecideRetryPtr,
+                    };
+                }
+            }
+
+            /// <summary>
+            /// Asks the policy for a decision. The statement is not available on the Rust side,
+            /// so the policy receives a statement carrying only the idempotence of the request.
+            /// Any exception thrown by the policy is logged and turned into a rethrow, as it must not
+            /// unwind into Rust.
+            /// </summary>
+            internal static BridgedRetryDecision Decide(IRetryPolicy policy, Configuration config, in BridgedRetryDecisionInput input)
+            {
+                var statement = new SimpleStatement().SetIdempotence(input.isIdempotent);
+                var consistency = (ConsistencyLevel)input.consistency;
+                var retryCount = (int)input.retryCount;
+
+                RetryDecision decision;
+                try
+                {
+                    decision = input.errorKind switch
+                    {
+                        BridgedRetryErrorKind.ReadTimeout => policy.OnReadTimeout(
+                            statement, consistency, input.required, input.received, input.dataPresent, retryCount),
+                        BridgedRetryErrorKind.WriteTimeout => policy.OnWriteTimeout(
+                            statement, consistency, input.writeType.ToManagedString(), input.required, input.received, retryCount),
+                        BridgedRetryErrorKind.Unavailable => policy.OnUnavailable(
+                            statement, consistency, input.required, input.received, retryCount),
+                        _ when policy is IExtendedRetryPolicy extended => extended.OnRequestError(
+                            statement, config, BuildRequestError(input.errorKind), retryCount),
+                        _ => RetryDecision.Rethrow(),
+                    };
+                }
+                catch (Exception ex)
+                {
+                    Logger.Error("Custom retry policy threw, rethrowing the error", ex);
+                    decision = RetryDecision.Rethrow();
+                }
+
+                return ToBridged(decision);
+            }
+
+            private static Exception BuildRequestError(BridgedRetryErrorKind errorKind)
+            {
+                return errorKind switch
+                {
+                    BridgedRetryErrorKind.Overloaded => new OverloadedException("Coordinator is overloaded"),
+                    BridgedRetryErrorKind.ServerError => new ServerErrorException("Coordinator returned a server error"),
+                    BridgedRetryErrorKind.TruncateError => new TruncateException("Coordinator failed to truncate"),
+                    BridgedRetryErrorKind.IsBootstrapping => new IsBootstrappingException("Coordinator is bootstrapping"),
+                    BridgedRetryErrorKind.ConnectionBroken => new DriverException("Connection to the coordinator was broken"),
+                    _ => new DriverException("Request attempt failed"),
+                };
+            }
+
+            private static BridgedRetryDecision ToBridged(RetryDecision decision)
+            {
+                var bridged = new BridgedRetryDecision
+                {
+                    decision = BridgedRetryDecisionCode.Rethrow,
+                    hasConsistency = false,
+                };
+                if (decision == null)
+                {
+                    return bridged;
+                }
+
+                switch (decision.DecisionType)
+                {
+                    case RetryDecision.RetryDecisionType.Retry:
+                        bridged.decision = decision.UseCurrentHost
+                            ? BridgedRetryDecisionCode.RetrySameHost
+                            : BridgedRetryDecisionCode.RetryNextHost;
+                        if (decision.RetryConsistencyLevel.HasValue)
+                        {
+                            bridged.consistency = (ushort)decision.RetryConsistencyLevel.Value;
+                            bridged.hasConsistency = true;
+                        }
+                        break;
+
+                    case RetryDecision.RetryDecisionType.Ignore:
+                        bridged.decision = BridgedRetryDecisionCode.Ignore;
+                        break;
+                }
+                return bridged;
+            }
+
+            [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+            unsafe private static void DecideRetry(IntPtr policyHandle, BridgedRetryDecisionInput* input, BridgedRetryDecision* decision)
+            {
+                try
+                {
+                    var custom = (CustomPolicy)GCHandle.FromIntPtr(policyHandle).Target;
+                    *decision = Decide(custom.Policy, custom.Config, in *input);
+                }
+                catch (Exception ex)
+                {
+                    // Only reached if the policy handle is invalid, as Decide catches policy exceptions.
+                    Logger.Error("Failed to call the custom retry policy, rethrowing the error", ex);
+                    *decision = ToBridged(RetryDecision.Rethrow());
+                }
+            }
+        }
+
+        /// <summary>
+        /// Maps the configured retry policy to one of the policies implemented by the Rust driver.
+        /// Logging and idempotence-aware wrappers are unwrapped, as the Rust driver logs retries
+        /// and only retries idempotent statements on its own.
+        /// Any other policy is called back by Rust as <see cref="BridgedRetryPolicyKind.Custom"/>.
+        /// </summary>
+        internal static BridgedRetryPolicyKind BuildRetryPolicyKind(IRetryPolicy retryPolicy)
+        {
+            while (true)
+            {
+                switch (retryPolicy)
+                {
+                    case null:
+                    case DefaultRetryPolicy:
+                        return BridgedRetryPolicyKind.Default;
+
+                    case FallthroughRetryPolicy:
+                        return BridgedRetryPolicyKind.Fallthrough;
+
+                    case DowngradingConsistencyRetryPolicy:
+                        return BridgedRetryPolicyKind.DowngradingConsistency;
+
+                    case LoggingRetryPolicy logging:
+                        retryPolicy = logging.ChildPolicy;
+                        break;
+
+                    case IdempotenceAwareRetryPolicy idempotenceAware:
+                        retryPolicy = idempotenceAware.ChildPolicy;
+                        break;
+
+                    default:
+                        return BridgedRetryPolicyKind.Custom;
+                }
+            }
+        }
+
+        /// <summary>
+        /// Execution profile options passed to Rust, for the default profile of the session
+        /// and for the profiles attached to statements and batches.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedExecutionProfileConfig
+        {
+            internal ushort consistency;
+            internal FFIBool hasConsistency;
+            internal ushort serialConsistency;
+            internal FFIBool hasSerialConsistency;
+
+            /// <summary>
+            /// Zero disables the timeout and negative values keep the Rust driver default.
+            /// </summary>
+            internal long requestTimeoutMillis;
+
+            internal BridgedLoadBalancingPolicy loadBalancingPolicy;
+            internal BridgedRetryPolicyKind retryPolicy;
+            internal BridgedCustomRetryPolicy customRetryPolicy;
+            internal BridgedSpeculativeExecutionConfig speculativeExecution;
+
+            /// <summary>
+            /// Builds the default profile of the session from the cluster policies.
+            /// Consistencies and the request timeout are left to the Rust driver defaults.
+            /// </summary>
+            internal static BridgedExecutionProfileConfig BuildDefault(Configuration clusterConfig)
+            {
+                return new BridgedExecutionProfileConfig
+                {
+                    requestTimeoutMillis = -1,
+                    loadBalancingPolicy = BridgedLoadBalancingPolicy.BuildFrom(clusterConfig.Policies.LoadBalancingPolicy),
+                    retryPolicy = BuildRetryPolicyKind(clusterConfig.Policies.RetryPolicy),
+                    customRetryPolicy = BridgedCustomRetryPolicy.BuildFrom(clusterConfig.Policies.RetryPolicy, clusterConfig),
+                    speculativeExecution = BridgedSpeculativeExecutionConfig.BuildFrom(clusterConfig.Policies.SpeculativeExecutionPolicy),
+                };
+            }
+
+            /// <summary>
+            /// Builds a profile from the options of a named execution profile, which already fall back
+            /// to the default profile and to the cluster configuration for the settings they leave unset.
+            /// </summary>
+            /// <exception cref="NotSupportedException">Thrown when a policy type is not supported.</exception>
+            internal static BridgedExecutionProfileConfig BuildFrom(IRequestOptions options, Configuration clusterConfig)
+            {
+                return new BridgedExecutionProfileConfig
+                {
+                    consistency = (ushort)options.ConsistencyLevel,
+                    hasConsistency = true,
+                    serialConsistency = (ushort)options.SerialConsistencyLevel,
+                    hasSerialConsistency = true,
+                    requestTimeoutMillis = Math.Max(options.ReadTimeoutMillis, 0),
+                    loadBalancingPolicy = BridgedLoadBalancingPolicy.BuildFrom(options.LoadBalancingPolicy),
+                    retryPolicy = BuildRetryPolicyKind(options.RetryPolicy),
+                    customRetryPolicy = BridgedCustomRetryPolicy.BuildFrom(options.RetryPolicy, clusterConfig),
+                    speculativeExecution = BridgedSpeculativeExecutionConfig.BuildFrom(options.SpeculativeExecutionPolicy),
+                };
+            }
+        }
+
+        /// <summary>
+        /// Address translator implemented in C#, called by Rust to translate the addresses advertised by nodes.
+        /// Rust takes ownership of the translator handle and calls <c>translate</c> on Tokio worker threads.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedAddressTranslator
+        {
+            internal FFIMaybeGCHandle translator;
+            internal IntPtr translate;
+
+            internal static BridgedAddressTranslator None => new BridgedAddressTranslator
+            {
+                translator = FFIMaybeGCHandle.Empty(),
+                translate = IntPtr.Zero,
+            };
+
+            [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+            private static extern void translated_address_set(IntPtr outTranslated, [MarshalAs(UnmanagedType.LPUTF8Str)] string address);
+
+            unsafe private static readonly delegate* unmanaged[Cdecl]<IntPtr, FFIString, IntPtr, FFIMaybeException> TranslatePtr = &Translate;
+
+            /// <summary>
+            /// Builds the bridged translator. The default translator is left out,
+            /// as the Rust driver connects to the advertised addresses on its own.
+            /// </summary>
+            internal static BridgedAddressTranslator BuildFrom(IAddressTranslator addressTranslator)
+            {
+                if (addressTranslator == null || addressTranslator is DefaultAddressTranslator)
+                {
+                    return None;
+                }
+
+                unsafe
+                {
+                    return new BridgedAddressTranslator
+                    {
+                        translator = new FFIMaybeGCHandle(GCHandle.Alloc(addressTranslator)),
+                        translate = (IntPtr)TranslatePtr,
+                    };
+                }
+            }
+
+            [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+            private static FFIMaybeException Translate(IntPtr translatorHandle, FFIString untranslatedAddress, IntPtr outTranslated)
+            {
+                try
+                {
+                    var translator = (IAddressTranslator)GCHandle.FromIntPtr(translatorHandle).Target;
+                    var untranslated = IPEndPoint.Parse(untranslatedAddress.ToManagedString());
+                    var translated = translator.Translate(untranslated);
+                    if (translated != null)
+                    {
+                        translated_address_set(outTranslated, translated.ToString());
+                    }
+                }
+                catch (Exception ex)
+                {
+                    return FFIMaybeException.FromException(ex);
+                }
+
+                return FFIMaybeException.Ok();
+            }
+        }
+
+        /// <summary>
+        /// Host filter passed to Rust. An <see cref="AddressListHostFilter"/> is applied by Rust directly,
+        /// while any other filter is called back on Tokio worker threads.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedHostFilter
+        {
+            /// <summary>
+            /// Comma-separated addresses of the only nodes to connect to, or null for no allow-list.
+            /// </summary>
+            [MarshalAs(UnmanagedType.LPUTF8Str)]
+            internal string allowed;
+
+            /// <summary>
+            /// Comma-separated addresses of nodes never to connect to, or null for no deny-list.
+            /// </summary>
+            [MarshalAs(UnmanagedType.LPUTF8Str)]
+            internal string denied;
+
+            internal FFIMaybeGCHandle filter;
+            internal IntPtr accept;
+
+            internal static BridgedHostFilter None => new BridgedHostFilter
+            {
+                allowed = null,
+                denied = null,
+                filter = FFIMaybeGCHandle.Empty(),
+                accept = IntPtr.Zero,
+            };
+
+            unsafe private static readonly delegate* unmanaged[Cdecl]<IntPtr, FFIString, FFIString, FFIString, FFIBool> AcceptPtr = &Accept;
+
+            internal static BridgedHostFilter BuildFrom(IHostFilter hostFilter)
+            {
+                switch (hostFilter)
+                {
+                    case null:
+                        return None;
+
+                    case AddressListHostFilter addressList:
+                        var bridged = None;
+                        bridged.allowed = addressList.Allowed == null ? null : string.Join(",", addressList.Allowed);
+                        bridged.denied = string.Join(",", addressList.Denied);
+                        return bridged;
+
+                    default:
+                        unsafe
+                        {
+                            return new BridgedHostFilter
+                            {
+                                allowed = null,
+                                denied = null,
+                                filter = new FFIMaybeGCHandle(GCHandle.Alloc(hostFilter)),
+                                accept = (IntPtr)AcceptPtr,
+                            };
+                        }
+                }
+            }
+
+            [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+            private static FFIBool Accept(IntPtr filterHandle, FFIString address, FFIString datacenter, FFIString rack)
+            {
+                try
+                {
+                    var filter = (IHostFilter)GCHandle.FromIntPtr(filterHandle).Target;
+                    return filter.Accept(IPEndPoint.Parse(address.ToManagedString()), datacenter.ToManagedString(), rack.ToManagedString());
+                }
+                catch (Exception ex)
+                {
+                    // Rust cannot carry the exception, so the node is rejected as documented on IHostFilter.
+                    Logger.Error($"Host filter failed for {address.ToManagedString()}, the node is rejected", ex);
+                    return false;
+                }
+            }
+        }
+
+        /// <summary>
+        /// TLS protocol versions allowed by <see cref="BridgedSslOptions"/>. No flags allow every supported version.
+        /// Any changes to this enum must be mirrored in the corresponding Rust flags.
+        /// </summary>
+        [Flags]
+        internal enum BridgedTlsProtocols : byte
+        {
+            Default = 0,
+            Tls12 = 0x01,
+            Tls13 = 0x02,
+        }
+
+        /// <summary>
+        /// TLS options passed to Rust. Certificates and the client key are PEM-encoded, given either inline
+        /// or as UTF-8 paths of PEM files read by Rust, and the slices point into buffers pinned by
+        /// <see cref="PinnedSslOptions"/>.
+        /// Slices are non-generic, as the enclosing session config is marshaled field by field.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedSslOptions
+        {
+            internal FFIBool enabled;
+            internal FFISliceRaw caCertificatesPem;
+            internal FFISliceRaw clientCertificatePem;
+            internal FFISliceRaw clientKeyPem;
+            internal FFISliceRaw caCertificatesPath;
+            internal FFISliceRaw clientCertificatePath;
+            internal FFISliceRaw clientKeyPath;
+            internal FFIBool verifyHostname;
+            internal BridgedTlsProtocols protocols;
+
+            internal static BridgedSslOptions Disabled => new BridgedSslOptions
+            {
+                enabled = false,
+                verifyHostname = true,
+            };
+        }
+
+        /// <summary>
+        /// Holds <see cref="BridgedSslOptions"/> together with its PEM and path buffers, pinned until disposed.
+        /// Rust reads the files and parses the buffers in <c>session_create</c> and does not keep them,
+        /// so they only need to outlive that call.
+        /// </summary>
+        internal sealed class PinnedSslOptions : IDisposable
+        {
+            private GCHandle _caCertificates;
+            private GCHandle _clientCertificate;
+            private GCHandle _clientKey;
+            private GCHandle _caCertificatesPath;
+            private GCHandle _clientCertificatePath;
+            private GCHandle _clientKeyPath;
+
+            internal BridgedSslOptions Options { get; }
+
+            /// <exception cref="NotSupportedException">Thrown when the protocol version is not supported.</exception>
+            internal PinnedSslOptions(SSLOptions sslOptions)
+            {
+                if (sslOptions == null)
+                {
+                    Options = BridgedSslOptions.Disabled;
+                    return;
+                }
+
+                if (!sslOptions.HasDefaultRemoteCertValidationCallback)
+                {
+                    Logger.Warning("SSLOptions.RemoteCertValidationCallback is not supported and will be ignored. " +
+                                   "Node certificates are verified against SSLOptions.CertificateAuthorities.");
+                }
+                if (sslOptions.CheckCertificateRevocation)
+                {
+                    Logger.Warning("SSLOptions.CheckCertificateRevocation is not supported and will be ignored.");
+                }
+
+                var protocols = BuildProtocols(sslOptions.SslProtocol);
+                var caCertificatesPem = ExportCertificates(sslOptions.CertificateAuthorities);
+                ExportClientIdentity(sslOptions.CertificateCollection, out var clientCertificatePem, out var clientKeyPem);
+
+                Options = new BridgedSslOptions
+                {
+                    enabled = true,
+                    caCertificatesPem = Pin(caCertificatesPem, out _caCertificates),
+                    clientCertificatePem = Pin(clientCertificatePem, out _clientCertificate),
+                    clientKeyPem = Pin(clientKeyPem, out _clientKey),
+                    caCertificatesPath = Pin(EncodePath(sslOptions.CertificateAuthoritiesPath), out _caCertificatesPath),
+                    clientCertificatePath = Pin(EncodePath(sslOptions.ClientCertificatePath), out _clientCertificatePath),
+                    clientKeyPath = Pin(EncodePath(sslOptions.ClientKeyPath), out _clientKeyPath),
+                    verifyHostname = sslOptions.HostNameVerification,
+                    protocols = protocols,
+                };
+            }
+
+            public void Dispose()
+            {
+                if (_clientKey.IsAllocated)
+                {
+                    // The key stays in managed memory until collected otherwise.
+                    Array.Clear((byte[])_clientKey.Target);
+                }
+                Free(ref _caCertificates);
+                Free(ref _clientCertificate);
+                Free(ref _clientKey);
+                Free(ref _caCertificatesPath);
+                Free(ref _clientCertificatePath);
+                Free(ref _clientKeyPath);
+            }
+
+            private static BridgedTlsProtocols BuildProtocols(SslProtocols sslProtocols)
+            {
+                var protocols = BridgedTlsProtocols.Default;
+                if (sslProtocols.HasFlag(SslProtocols.Tls12))
+                {
+                    protocols |= BridgedTlsProtocols.Tls12;
+                }
+                if (sslProtocols.HasFlag(SslProtocols.Tls13))
+                {
+                    protocols |= BridgedTlsProtocols.Tls13;
+                }
+                if ((sslProtocols & ~(SslProtocols.Tls12 | SslProtocols.Tls13)) != SslProtocols.None)
+                {
+                    throw new NotSupportedException(
+                        $"SSL protocol {sslProtocols} is not supported. Only TLS 1.2 and TLS 1.3 can be used.");
+                }
+                return protocols;
+            }
+
+            private static byte[] ExportCertificates(X509CertificateCollection certificates)
+            {
+                if (certificates == null || certificates.Count == 0)
+                {
+                    return null;
+                }
+                var pem = new StringBuilder();
+                foreach (X509Certificate certificate in certificates)
+                {
+                    pem.Append(PemEncoding.Write("CERTIFICATE", certificate.GetRawCertData())).Append('\n');
+                }
+                return Encoding.ASCII.GetBytes(pem.ToString());
+            }
+
+            /// <summary>
+            /// Exports the first certificate of the collection that has a private key, together with the key.
+            /// Certificates without a private key cannot be used for client authentication and are skipped.
+            /// </summary>
+            private static void ExportClientIdentity(X509CertificateCollection certificates, out byte[] certificatePem, out byte[] keyPem)
+            {
+                certificatePem = null;
+                keyPem = null;
+                if (certificates == null)
+                {
+                    return;
+                }
+
+                foreach (X509Certificate certificate in certificates)
+                {
+                    if (certificate is not X509Certificate2 { HasPrivateKey: true } withKey)
+                    {
+                        continue;
+                    }
+
+                    using AsymmetricAlgorithm key = (AsymmetricAlgorithm)withKey.GetRSAPrivateKey() ?? withKey.GetECDsaPrivateKey();
+                    if (key == null)
+                    {
+                        throw new NotSupportedException(
+                            $"The private key of client certificate {withKey.Subject} is neither RSA nor ECDSA.");
+                    }
+                    certificatePem = Encoding.ASCII.GetBytes(withKey.ExportCertificatePem());
+                    keyPem = Encoding.ASCII.GetBytes(key.ExportPkcs8PrivateKeyPem());
+                    return;
+                }
+
+                if (certificates.Count > 0)
+                {
+                    Logger.Warning("None of the certificates in SSLOptions.CertificateCollection has a private key. " +
+                                   "Client authentication will not be used.");
+                }
+            }
+
+            private static byte[] EncodePath(string path)
+            {
+                return string.IsNullOrEmpty(path) ? null : Encoding.UTF8.GetBytes(path);
+            }
+
+            private static FFISliceRaw Pin(byte[] bytes, out GCHandle handle)
+            {
+                if (bytes == null || bytes.Length == 0)
+                {
+                    handle = default;
+                    return new FFISliceRaw(IntPtr.Zero, 0);
+                }
+                handle = GCHandle.Alloc(bytes, GCHandleType.Pinned);
+                return new FFISliceRaw(handle.AddrOfPinnedObject(), (nuint)bytes.Length);
+            }
+
+            private static void Free(ref GCHandle handle)
+            {
+                if (handle.IsAllocated)
+                {
+                    handle.Free();
+                }
+            }
+        }
+
+        /// <summary>
+        /// Plain-text credentials passed to Rust. A null username disables authentication.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedCredentials
+        {
+            [MarshalAs(UnmanagedType.LPUTF8Str)]
+            internal string username;
+
+            [MarshalAs(UnmanagedType.LPUTF8Str)]
+            internal string password;
+
+            internal static BridgedCredentials BuildFrom(IAuthProvider authProvider)
+            {
+                switch (authProvider)
+                {
+                    case null:
+                    case NoneAuthProvider:
+                        return new BridgedCredentials();
+
+                    case PlainTextAuthProvider plainText:
+                        return new BridgedCredentials
+                        {
+                            username = plainText.Username,
+                            password = plainText.Password,
+                        };
+
+                    default:
+                        // Other providers are called through BridgedAuthenticator.
+                        return new BridgedCredentials();
+                }
+            }
+        }
+
+        /// <summary>
+        /// Auth provider implemented in C#, to which Rust forwards the SASL exchange of every connection,
+        /// e.g. for Kerberos or LDAP. Rust takes ownership of the provider handle and of the handle of each
+        /// authenticator created by <c>newAuthenticator</c>, and calls the callbacks on Tokio worker threads.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal struct BridgedAuthenticator
+        {
+            internal FFIMaybeGCHandle provider;
+            internal IntPtr newAuthenticator;
+            internal IntPtr initialResponse;
+            internal IntPtr evaluateChallenge;
+            internal IntPtr onSuccess;
+
+            internal static BridgedAuthenticator None => new BridgedAuthenticator
+            {
+                provider = FFIMaybeGCHandle.Empty(),
+                newAuthenticator = IntPtr.Zero,
+                initialResponse = IntPtr.Zero,
+                evaluateChallenge = IntPtr.Zero,
+                onSuccess = IntPtr.Zero,
+            };
+
+            [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+            private static extern void auth_response_set(IntPtr outResponse, FFISlice<byte> token);
+
+            unsafe private static readonly delegate* unmanaged[Cdecl]<IntPtr, FFIString, FFIMaybeGCHandle*, FFIMaybeException> NewAuthenticatorPtr = &NewAuthenticator;
+            unsafe private static readonly delegate* unmanaged[Cdecl]<IntPtr, FFIString, IntPtr, FFIMaybeException> InitialResponsePtr = &InitialResponse;
+            unsafe private static readonly delegate* unmanaged[Cdecl]<IntPtr, FFISliceRaw, FFIBool, IntPtr, FFIMaybeException> EvaluateChallengePtr = &EvaluateChallenge;
+
+            /// <summary>
+            /// Builds the bridged authenticator. No provider, <see cref="NoneAuthProvider"/> and
+            /// <see cref="PlainTextAuthProvider"/> are left out, the latter being passed as <see cref="BridgedCredentials"/>.
+            /// <para>
+            /// <see cref="IAuthenticator"/> has no hook for the final token of the server, so <c>onSuccess</c> is left unset.
+            /// The Rust driver does not tell which node a connection is made to, so authenticators are created with a null host.
+            /// </para>
+            /// </summary>
+            internal static BridgedAuthenticator BuildFrom(IAuthProvider authProvider)
+            {
+                if (authProvider == null || authProvider is NoneAuthProvider || authProvider is PlainTextAuthProvider)
+                {
+                    return None;
+                }
+
+                unsafe
+                {
+                    return new BridgedAuthenticator
+                    {
+                        provider = new FFIMaybeGCHandle(GCHandle.Alloc(authProvider)),
+                        newAuthenticator = (IntPtr)NewAuthenticatorPtr,
+                        initialResponse = (IntPtr)InitialResponsePtr,
+                        evaluateChallenge = (IntPtr)EvaluateChallengePtr,
+                        onSuccess = IntPtr.Zero,
+                    };
+                }
+            }
+
+            [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+            private static unsafe FFIMaybeException NewAuthenticator(IntPtr providerHandle, FFIString authenticatorName, FFIMaybeGCHandle* outAuthenticator)
+            {
+                try
+                {
+                    var provider = (IAuthProvider)GCHandle.FromIntPtr(providerHandle).Target;
+                    if (provider is IAuthProviderNamed named)
+                    {
+                        named.SetName(authenticatorName.ToManagedString());
+                    }
+                    var authenticator = provider.NewAuthenticator(null)
+                                        ?? throw new InvalidOperationException($"{provider.GetType().Name} returned no authenticator");
+                    *outAuthenticator = new FFIMaybeGCHandle(GCHandle.Alloc(authenticator));
+                }
+                catch (Exception ex)
+                {
+                    Logger.Error("Auth provider failed to create an authenticator", ex);
+                    return FFIMaybeException.FromException(ex);
+                }
+
+                return FFIMaybeException.Ok();
+            }
+
+            [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+            private static FFIMaybeException InitialResponse(IntPtr authenticatorHandle, FFIString authenticatorName, IntPtr outResponse)
+            {
+                try
+                {
+                    var authenticator = (IAuthenticator)GCHandle.FromIntPtr(authenticatorHandle).Target;
+                    SetResponse(outResponse, authenticator.InitialResponse());
+                }
+                catch (Exception ex)
+                {
+                    Logger.Error($"Authenticator failed to produce the initial response for {authenticatorName.ToManagedString()}", ex);
+                    return FFIMaybeException.FromException(ex);
+                }
+
+                return FFIMaybeException.Ok();
+            }
+
+            [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+            private static FFIMaybeException EvaluateChallenge(IntPtr authenticatorHandle, FFISliceRaw challenge, FFIBool challengeIsNull, IntPtr outResponse)
+            {
+                try
+                {
+                    var authenticator = (IAuthenticator)GCHandle.FromIntPtr(authenticatorHandle).Target;
+                    var challengeBytes = challengeIsNull ? null : challenge.As<byte>().ToSpan().ToArray();
+                    SetResponse(outResponse, authenticator.EvaluateChallenge(challengeBytes));
+                }
+                catch (Exception ex)
+                {
+                    Logger.Error("Authenticator failed to evaluate the challenge of the server", ex);
+                    return FFIMaybeException.FromException(ex);
+                }
+
+                return FFIMaybeException.Ok();
+            }
+
+            /// <summary>
+            /// Passes a token to Rust, which copies it. A null token is left unset, so that it is sent as null.
+            /// </summary>
+            private static unsafe void SetResponse(IntPtr outResponse, byte[] token)
+            {
+                if (token == null)
+                {
+                    return;
+                }
+                fixed (byte* ptr = token)
+                {
+                    auth_response_set(outResponse, new FFISlice<byte>((IntPtr)ptr, (nuint)token.Length));
+                }
+            }
+        }
+
+        /// <summary>
+        /// Converts <see cref="ProtocolOptions.MaxSchemaAgreementWaitSeconds"/> to milliseconds.
+        /// Returns 0, which keeps the Rust driver's default, if no options are given.
+        /// </summary>
+        internal static long BuildSchemaAgreementTimeoutMillis(ProtocolOptions protocolOptions)
+        {
+            var seconds = protocolOptions?.MaxSchemaAgreementWaitSeconds ?? ProtocolOptions.DefaultMaxSchemaAgreementWaitSeconds;
+            return Math.Max(seconds, 0) * 1000L;
+        }
+
+        /// <summary>
+        /// Configuration struct used to pass session creation parameters from C# to Rust.
+        /// Any changes to this struct must be mirrored in the corresponding Rust struct.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
         internal struct BridgedSessionConfig
         {
             [MarshalAs(UnmanagedType.LPUTF8Str)]
@@ -494,9 +1925,28 @@ namespace Cassandra
 
             internal BridgedTcpConfig tcp;
 
-            internal BridgedLoadBalancingPolicy loadBalancingPolicy;
+            internal BridgedConnectionConfig connection;
+
+            internal FFIBool disallowShardAwarePort;
+
+            internal BridgedCompression compression;
+
+            internal long schemaAgreementTimeoutMillis;
+
+            internal BridgedExecutionProfileConfig defaultProfile;
+
+            internal BridgedAddressTranslator addressTranslator;
+
+            internal BridgedHostFilter hostFilter;
+
+            internal BridgedSslOptions ssl;
+
+            internal BridgedCredentials credentials;
+
+            internal BridgedAuthenticator authenticator;
 
-            internal static BridgedSessionConfig BuildFrom(string uri, string keyspace, Configuration clusterConfig)
+            /// <param name="ssl">TLS options, whose buffers must stay pinned until the session is created.</param>
+            internal static BridgedSessionConfig BuildFrom(string uri, string keyspace, Configuration clusterConfig, PinnedSslOptions ssl)
             {
                 return new BridgedSessionConfig
                 {
@@ -504,7 +1954,16 @@ namespace Cassandra
                     Keyspace = keyspace ?? "",
                     connectTimeoutMillis = clusterConfig.SocketOptions?.ConnectTimeoutMillis ?? SocketOptions.DefaultConnectTimeoutMillis,
                     tcp = BridgedTcpConfig.BuildFrom(clusterConfig.SocketOptions),
-                    loadBalancingPolicy = BridgedLoadBalancingPolicy.BuildFrom(clusterConfig.Policies.LoadBalancingPolicy)
+                    connection = BridgedConnectionConfig.BuildFrom(clusterConfig.PoolingOptions),
+                    disallowShardAwarePort = clusterConfig.PoolingOptions?.GetDisableShardAwareness() ?? false,
+                    compression = BuildCompression(clusterConfig.ProtocolOptions),
+                    schemaAgreementTimeoutMillis = BuildSchemaAgreementTimeoutMillis(clusterConfig.ProtocolOptions),
+                    defaultProfile = BridgedExecutionProfileConfig.BuildDefault(clusterConfig),
+                    addressTranslator = BridgedAddressTranslator.BuildFrom(clusterConfig.AddressTranslator),
+                    hostFilter = BridgedHostFilter.BuildFrom(clusterConfig.HostFilter),
+                    ssl = ssl.Options,
+                    credentials = BridgedCredentials.BuildFrom(clusterConfig.AuthProvider),
+                    authenticator = BridgedAuthenticator.BuildFrom(clusterConfig.AuthProvider),
                 };
             }
         }
@@ -520,17 +1979,23 @@ namespace Cassandra
             internal readonly FFIBool HasConsistencyLevel;
             internal readonly FFIBool IsIdempotent;
             internal readonly int PageSize;
+            internal readonly int RequestTimeoutMillis;
+            internal readonly long TimestampMicros;
 
             internal PreparedStatementExecutionOptions(
                 bool hasConsistencyLevel,
                 ushort consistencyLevel,
                 bool isIdempotent,
-                int pageSize)
+                int pageSize,
+                int requestTimeoutMillis,
+                long timestampMicros)
             {
                 HasConsistencyLevel = hasConsistencyLevel;
                 ConsistencyLevel = consistencyLevel;
                 IsIdempotent = isIdempotent;
                 PageSize = pageSize;
+                RequestTimeoutMillis = requestTimeoutMillis;
+                TimestampMicros = timestampMicros;
             }
         }
 
@@ -545,17 +2010,23 @@ namespace Cassandra
             internal readonly FFIBool HasConsistencyLevel;
             internal readonly FFIBool IsIdempotent;
             internal readonly int PageSize;
+            internal readonly int RequestTimeoutMillis;
+            internal readonly long TimestampMicros;
 
             internal SimpleStatementExecutionOptions(
                 bool hasConsistencyLevel,
                 ushort consistencyLevel,
                 bool isIdempotent,
-                int pageSize)
+                int pageSize,
+                int requestTimeoutMillis,
+                long timestampMicros)
             {
                 HasConsistencyLevel = hasConsistencyLevel;
                 ConsistencyLevel = consistencyLevel;
                 IsIdempotent = isIdempotent;
                 PageSize = pageSize;
+                RequestTimeoutMillis = requestTimeoutMillis;
+                TimestampMicros = timestampMicros;
             }
         }
     }
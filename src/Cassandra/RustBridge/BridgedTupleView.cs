@@ -0,0 +1,170 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges a Rust-owned <c>tuple</c> value, read from a query result, to C#.
+    /// Fields are addressed by position, deserialized only when read, and may individually be null.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedTupleView : RustResource
+    {
+        private static readonly unsafe delegate* unmanaged[Cdecl]<IntPtr, void> FreePtr = &Free;
+
+        private BridgedTupleView(IntPtr tuplePtr) : base(CreateManuallyDestructible(tuplePtr))
+        {
+        }
+
+        private static unsafe ManuallyDestructible CreateManuallyDestructible(IntPtr tuplePtr)
+        {
+            return new ManuallyDestructible(tuplePtr, (IntPtr)FreePtr);
+        }
+
+        // Rust hands out tuple views as plain owned pointers, so the destructor is a C# trampoline to tuple_view_free.
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void Free(IntPtr tuplePtr)
+        {
+            tuple_view_free(tuplePtr);
+        }
+
+        /// <summary>
+        /// Opens the tuple cell at the given row and column of <paramref name="result"/>,
+        /// or returns null if the cell is null.
+        /// </summary>
+        internal static BridgedTupleView Open(BridgedQueryResult result, int row, int column)
+        {
+            IntPtr tuplePtr = IntPtr.Zero;
+            FFIBool isNull = false;
+            unsafe
+            {
+                result.RunWithIncrement(handle =>
+                    result_get_tuple(handle, (nuint)row, (nuint)column, out tuplePtr, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new BridgedTupleView(tuplePtr);
+        }
+
+        /// <summary>
+        /// Number of fields of the tuple type.
+        /// </summary>
+        internal int Count
+        {
+            get
+            {
+                nuint count = 0;
+                unsafe
+                {
+                    RunWithIncrement(handle => tuple_view_field_count(handle, out count, (IntPtr)Globals.ConstructorsPtr));
+                }
+                return (int)count;
+            }
+        }
+
+        /// <summary>
+        /// Type of the field at <paramref name="index"/>.
+        /// </summary>
+        internal ColumnTypeCode GetFieldType(int index)
+        {
+            byte typeCode = 0;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    tuple_view_field_type(handle, (nuint)index, out typeCode, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return (ColumnTypeCode)typeCode;
+        }
+
+        /// <summary>
+        /// Reads the <c>int</c> field at <paramref name="index"/>, or null if the field is null.
+        /// </summary>
+        internal int? GetInt32(int index)
+        {
+            int value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    tuple_view_get_int32(handle, (nuint)index, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the <c>bigint</c> field at <paramref name="index"/>, or null if the field is null.
+        /// </summary>
+        internal long? GetInt64(int index)
+        {
+            long value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    tuple_view_get_int64(handle, (nuint)index, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the <c>text</c> or <c>ascii</c> field at <paramref name="index"/>, or null if the field is null.
+        /// </summary>
+        internal string GetText(int index)
+        {
+            string value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = tuple_view_get_text(handle, (nuint)index, out var text, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    // The string borrows from the view, so it is copied while the handle is held.
+                    if (!exception.HasException && !isNull)
+                    {
+                        value = text.ToManagedString();
+                    }
+                    return exception;
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Reads the <c>uuid</c> or <c>timeuuid</c> field at <paramref name="index"/>, or null if the field is null.
+        /// </summary>
+        internal Guid? GetUuid(int index)
+        {
+            var bytes = new byte[16];
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    tuple_view_get_uuid(handle, (nuint)index, bytes, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : GuidFromFFIFormat(bytes);
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_tuple(IntPtr result, nuint row, nuint column, out IntPtr tuple, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern void tuple_view_free(IntPtr tuple);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIMaybeException tuple_view_field_count(IntPtr tuple, out nuint count, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException tuple_view_field_type(IntPtr tuple, nuint index, out byte typeCode, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException tuple_view_get_int32(IntPtr tuple, nuint index, out int value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException tuple_view_get_int64(IntPtr tuple, nuint index, out long value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException tuple_view_get_text(IntPtr tuple, nuint index, out FFIString value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException tuple_view_get_uuid(IntPtr tuple, nuint index, [Out] byte[] value, out FFIBool isNull, IntPtr constructors);
+    }
+}
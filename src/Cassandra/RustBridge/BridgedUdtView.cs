@@ -0,0 +1,235 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static Cassandra.RustBridge;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Bridges a Rust-owned user defined type value, read from a query result, to C#.
+    /// Fields are addressed by their position in the type definition, deserialized only when read,
+    /// and may individually be null. Fields missing from values written before the type was extended read as null.
+    /// Inherits destructor and handle management from RustResource.
+    /// </summary>
+    internal sealed class BridgedUdtView : RustResource
+    {
+        private static readonly unsafe delegate* unmanaged[Cdecl]<IntPtr, void> FreePtr = &Free;
+
+        private BridgedUdtView(IntPtr udtPtr) : base(CreateManuallyDestructible(udtPtr))
+        {
+        }
+
+        private static unsafe ManuallyDestructible CreateManuallyDestructible(IntPtr udtPtr)
+        {
+            return new ManuallyDestructible(udtPtr, (IntPtr)FreePtr);
+        }
+
+        // Rust hands out UDT views as plain owned pointers, so the destructor is a C# trampoline to udt_view_free.
+        [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+        private static void Free(IntPtr udtPtr)
+        {
+            udt_view_free(udtPtr);
+        }
+
+        /// <summary>
+        /// Opens the UDT cell at the given row and column of <paramref name="result"/>,
+        /// or returns null if the cell is null.
+        /// </summary>
+        internal static BridgedUdtView Open(BridgedQueryResult result, int row, int column)
+        {
+            IntPtr udtPtr = IntPtr.Zero;
+            FFIBool isNull = false;
+            unsafe
+            {
+                result.RunWithIncrement(handle =>
+                    result_get_udt(handle, (nuint)row, (nuint)column, out udtPtr, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new BridgedUdtView(udtPtr);
+        }
+
+        /// <summary>
+        /// Opens the UDT item at <paramref name="index"/> of <paramref name="collection"/>,
+        /// or returns null if the item is null.
+        /// The view is owned by the caller and must be disposed independently of the collection.
+        /// </summary>
+        internal static BridgedUdtView Open(BridgedCollection collection, int index)
+        {
+            IntPtr udtPtr = IntPtr.Zero;
+            FFIBool isNull = false;
+            unsafe
+            {
+                collection.RunWithIncrement(handle =>
+                    collection_get_udt(handle, (nuint)index, out udtPtr, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new BridgedUdtView(udtPtr);
+        }
+
+        /// <summary>
+        /// Opens the nested UDT field at <paramref name="index"/>, or returns null if the field is null.
+        /// The nested view is owned by the caller and must be disposed independently.
+        /// </summary>
+        internal BridgedUdtView OpenUdt(int index)
+        {
+            IntPtr udtPtr = IntPtr.Zero;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    udt_view_get_udt(handle, (nuint)index, out udtPtr, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : new BridgedUdtView(udtPtr);
+        }
+
+        /// <summary>
+        /// Number of fields of the user defined type.
+        /// </summary>
+        internal int Count
+        {
+            get
+            {
+                nuint count = 0;
+                unsafe
+                {
+                    RunWithIncrement(handle => udt_view_field_count(handle, out count, (IntPtr)Globals.ConstructorsPtr));
+                }
+                return (int)count;
+            }
+        }
+
+        /// <summary>
+        /// Name of the field at <paramref name="index"/>.
+        /// </summary>
+        internal string GetFieldName(int index)
+        {
+            string name = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = udt_view_field_name(handle, (nuint)index, out var fieldName, (IntPtr)Globals.ConstructorsPtr);
+                    // The name borrows from the view, so it is copied while the handle is held.
+                    if (!exception.HasException)
+                    {
+                        name = fieldName.ToManagedString();
+                    }
+                    return exception;
+                });
+            }
+            return name;
+        }
+
+        /// <summary>
+        /// Type of the field at <paramref name="index"/>.
+        /// </summary>
+        internal ColumnTypeCode GetFieldType(int index)
+        {
+            byte typeCode = 0;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    udt_view_field_type(handle, (nuint)index, out typeCode, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return (ColumnTypeCode)typeCode;
+        }
+
+        /// <summary>
+        /// Reads the <c>int</c> field at <paramref name="index"/>, or null if the field is null.
+        /// </summary>
+        internal int? GetInt32(int index)
+        {
+            int value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    udt_view_get_int32(handle, (nuint)index, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the <c>bigint</c> field at <paramref name="index"/>, or null if the field is null.
+        /// </summary>
+        internal long? GetInt64(int index)
+        {
+            long value = 0;
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    udt_view_get_int64(handle, (nuint)index, out value, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : value;
+        }
+
+        /// <summary>
+        /// Reads the <c>text</c> or <c>ascii</c> field at <paramref name="index"/>, or null if the field is null.
+        /// </summary>
+        internal string GetText(int index)
+        {
+            string value = null;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                {
+                    var exception = udt_view_get_text(handle, (nuint)index, out var text, out var isNull, (IntPtr)Globals.ConstructorsPtr);
+                    // The string borrows from the view, so it is copied while the handle is held.
+                    if (!exception.HasException && !isNull)
+                    {
+                        value = text.ToManagedString();
+                    }
+                    return exception;
+                });
+            }
+            return value;
+        }
+
+        /// <summary>
+        /// Reads the <c>uuid</c> or <c>timeuuid</c> field at <paramref name="index"/>, or null if the field is null.
+        /// </summary>
+        internal Guid? GetUuid(int index)
+        {
+            var bytes = new byte[16];
+            FFIBool isNull = false;
+            unsafe
+            {
+                RunWithIncrement(handle =>
+                    udt_view_get_uuid(handle, (nuint)index, bytes, out isNull, (IntPtr)Globals.ConstructorsPtr));
+            }
+            return isNull ? null : GuidFromFFIFormat(bytes);
+        }
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException result_get_udt(IntPtr result, nuint row, nuint column, out IntPtr udt, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException collection_get_udt(IntPtr collection, nuint index, out IntPtr udt, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException udt_view_get_udt(IntPtr udt, nuint index, out IntPtr nested, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern void udt_view_free(IntPtr udt);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIMaybeException udt_view_field_count(IntPtr udt, out nuint count, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException udt_view_field_name(IntPtr udt, nuint index, out FFIString name, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException udt_view_field_type(IntPtr udt, nuint index, out byte typeCode, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException udt_view_get_int32(IntPtr udt, nuint index, out int value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException udt_view_get_int64(IntPtr udt, nuint index, out long value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException udt_view_get_text(IntPtr udt, nuint index, out FFIString value, out FFIBool isNull, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        unsafe private static extern FFIMaybeException udt_view_get_uuid(IntPtr udt, nuint index, [Out] byte[] value, out FFIBool isNull, IntPtr constructors);
+    }
+}
@@ -1,5 +1,6 @@
 using System;
 using System.Diagnostics;
+using System.Net;
 using System.Runtime.InteropServices;
 using System.Runtime.CompilerServices;
 using System.Threading.Tasks;
@@ -213,6 +214,12 @@ namespace Cassandra
             internal readonly IntPtr ptr;
             internal readonly nuint len;
 
+            internal FFISliceRaw(IntPtr ptr, nuint len)
+            {
+                this.ptr = ptr;
+                this.len = len;
+            }
+
             // Reinterprets this non-generic slice as a typed FFISlice<T>.
             // This is safe because both structs have identical memory layout.
             internal FFISlice<T> As<T>() where T : unmanaged
@@ -648,18 +655,26 @@ namespace Cassandra
         internal static unsafe class Globals
         {
             // Exception constructors passed to Rust
-            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIString, FFIGCHandle> AlreadyExistsConstructorPtr = &AlreadyExistsException.AlreadyExistsExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIString, FFIBool, FFIGCHandle> AlreadyExistsConstructorPtr = &AlreadyExistsException.AlreadyExistsExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> AlreadyShutdownExceptionConstructorPtr = &AlreadyShutdownException.AlreadyShutdownExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> ArgumentExceptionConstructorPtr = &ArgumentExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIString, FFIGCHandle> ArgumentNullExceptionConstructorPtr = &ArgumentNullExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIString, FFIGCHandle> AuthenticationExceptionConstructorPtr = &AuthenticationException.AuthenticationExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> DeserializationExceptionConstructorPtr = &DeserializationException.DeserializationExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> DriverInternalErrorConstructorPtr = &DriverInternalError.DriverInternalErrorFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> FunctionFailureExceptionConstructorPtr = &FunctionFailureException.FunctionFailureExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> InvalidArgumentExceptionConstructorPtr = &InvalidArgumentException.InvalidArgumentExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> InvalidConfigurationInQueryExceptionConstructorPtr = &InvalidConfigurationInQueryException.InvalidConfigurationInQueryExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> InvalidQueryConstructorPtr = &InvalidQueryException.InvalidQueryExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> InvalidTypeExceptionConstructorPtr = &InvalidTypeException.InvalidTypeExceptionFromRust;
-            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> NoHostAvailableExceptionConstructorPtr = &NoHostAvailableException.NoHostAvailableExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> IsBootstrappingExceptionConstructorPtr = &IsBootstrappingException.IsBootstrappingExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFISliceRaw, FFIGCHandle> NoHostAvailableExceptionConstructorPtr = &NoHostAvailableException.NoHostAvailableExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<int, FFIGCHandle> OperationTimedOutExceptionConstructorPtr = &OperationTimedOutException.OperationTimedOutExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> OverloadedExceptionConstructorPtr = &OverloadedException.OverloadedExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFISliceRaw, FFIGCHandle> PreparedQueryNotFoundExceptionConstructorPtr = &PreparedQueryNotFoundException.PreparedQueryNotFoundExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<byte, FFIBool, FFIGCHandle> RateLimitReachedExceptionConstructorPtr = &RateLimitReachedException.RateLimitReachedExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<ushort, int, int, int, FFIBool, FFIGCHandle> ReadFailureExceptionConstructorPtr = &ReadFailureException.ReadFailureExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<ushort, int, int, FFIBool, FFIGCHandle> ReadTimeoutExceptionConstructorPtr = &ReadTimeoutException.ReadTimeoutExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> RequestInvalidExceptionConstructorPtr = &RequestInvalidException.RequestInvalidExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> RustExceptionConstructorPtr = &RustException.RustExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> SchemaAgreementRequiredHostAbsentExceptionConstructorPtr = &SchemaAgreementRequiredHostAbsentException.SchemaAgreementRequiredHostAbsentExceptionFromRust;
@@ -671,6 +686,9 @@ namespace Cassandra
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> TraceRetrievalExceptionConstructorPtr = &TraceRetrievalException.TraceRetrievalExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> TruncateExceptionConstructorPtr = &TruncateException.TruncateExceptionFromRust;
             unsafe readonly static delegate* unmanaged[Cdecl]<FFIString, FFIGCHandle> UnauthorizedExceptionConstructorPtr = &UnauthorizedException.UnauthorizedExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<ushort, int, int, FFIGCHandle> UnavailableExceptionConstructorPtr = &UnavailableException.UnavailableExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<ushort, int, int, int, FFIString, FFIGCHandle> WriteFailureExceptionConstructorPtr = &WriteFailureException.WriteFailureExceptionFromRust;
+            unsafe readonly static delegate* unmanaged[Cdecl]<ushort, int, int, FFIString, FFIGCHandle> WriteTimeoutExceptionConstructorPtr = &WriteTimeoutException.WriteTimeoutExceptionFromRust;
 
             /// <summary>
             /// Table of exception constructors passed to Rust via TCB.
@@ -684,15 +702,23 @@ namespace Cassandra
                 internal readonly IntPtr already_exists_constructor;
                 internal readonly IntPtr already_shutdown_exception_constructor;
                 internal readonly IntPtr argument_exception_constructor;
+                internal readonly IntPtr argument_null_exception_constructor;
+                internal readonly IntPtr authentication_exception_constructor;
                 internal readonly IntPtr deserialization_exception_constructor;
+                internal readonly IntPtr driver_internal_error_constructor;
                 internal readonly IntPtr function_failure_exception_constructor;
                 internal readonly IntPtr invalid_argument_exception_constructor;
                 internal readonly IntPtr invalid_configuration_in_query_constructor;
                 internal readonly IntPtr invalid_query_constructor;
                 internal readonly IntPtr invalid_type_exception_constructor;
+                internal readonly IntPtr is_bootstrapping_exception_constructor;
                 internal readonly IntPtr no_host_available_exception_constructor;
                 internal readonly IntPtr operation_timed_out_exception_constructor;
+                internal readonly IntPtr overloaded_exception_constructor;
                 internal readonly IntPtr prepared_query_not_found_exception_constructor;
+                internal readonly IntPtr rate_limit_reached_exception_constructor;
+                internal readonly IntPtr read_failure_exception_constructor;
+                internal readonly IntPtr read_timeout_exception_constructor;
                 internal readonly IntPtr request_invalid_exception_constructor;
                 internal readonly IntPtr rust_exception_constructor;
                 internal readonly IntPtr schema_agreement_required_host_absent_exception_constructor;
@@ -704,20 +730,31 @@ namespace Cassandra
                 internal readonly IntPtr trace_retrieval_exception_constructor;
                 internal readonly IntPtr truncate_exception_constructor;
                 internal readonly IntPtr unauthorized_exception_constructor;
+                internal readonly IntPtr unavailable_exception_constructor;
+                internal readonly IntPtr write_failure_exception_constructor;
+                internal readonly IntPtr write_timeout_exception_constructor;
 
                 internal Constructors(
                     IntPtr alreadyExistsException,
                     IntPtr alreadyShutdownException,
                     IntPtr argumentException,
+                    IntPtr argumentNullException,
+                    IntPtr authenticationException,
                     IntPtr deserializationException,
+                    IntPtr driverInternalError,
                     IntPtr functionFailureException,
                     IntPtr invalidArgumentException,
                     IntPtr invalidConfigurationInQueryException,
                     IntPtr invalidQueryException,
                     IntPtr invalidTypeException,
+                    IntPtr isBootstrappingException,
                     IntPtr noHostAvailableException,
                     IntPtr operationTimedOutException,
+                    IntPtr overloadedException,
                     IntPtr preparedQueryNotFoundException,
+                    IntPtr rateLimitReachedException,
+                    IntPtr readFailureException,
+                    IntPtr readTimeoutException,
                     IntPtr requestInvalidException,
                     IntPtr rustException,
                     IntPtr schemaAgreementRequiredHostAbsentException,
@@ -728,20 +765,31 @@ namespace Cassandra
                     IntPtr syntaxErrorException,
                     IntPtr traceRetrievalException,
                     IntPtr truncateException,
-                    IntPtr unauthorizedException)
+                    IntPtr unauthorizedException,
+                    IntPtr unavailableException,
+                    IntPtr writeFailureException,
+                    IntPtr writeTimeoutException)
                 {
                     already_exists_constructor = alreadyExistsException;
                     already_shutdown_exception_constructor = alreadyShutdownException;
                     argument_exception_constructor = argumentException;
+                    argument_null_exception_constructor = argumentNullException;
+                    authentication_exception_constructor = authenticationException;
                     deserialization_exception_constructor = deserializationException;
+                    driver_internal_error_constructor = driverInternalError;
                     function_failure_exception_constructor = functionFailureException;
                     invalid_argument_exception_constructor = invalidArgumentException;
                     invalid_configuration_in_query_constructor = invalidConfigurationInQueryException;
                     invalid_query_constructor = invalidQueryException;
                     invalid_type_exception_constructor = invalidTypeException;
+                    is_bootstrapping_exception_constructor = isBootstrappingException;
                     no_host_available_exception_constructor = noHostAvailableException;
                     operation_timed_out_exception_constructor = operationTimedOutException;
+                    overloaded_exception_constructor = overloadedException;
                     prepared_query_not_found_exception_constructor = preparedQueryNotFoundException;
+                    rate_limit_reached_exception_constructor = rateLimitReachedException;
+                    read_failure_exception_constructor = readFailureException;
+                    read_timeout_exception_constructor = readTimeoutException;
                     request_invalid_exception_constructor = requestInvalidException;
                     rust_exception_constructor = rustException;
                     schema_agreement_required_host_absent_exception_constructor = schemaAgreementRequiredHostAbsentException;
@@ -753,6 +801,9 @@ namespace Cassandra
                     trace_retrieval_exception_constructor = traceRetrievalException;
                     truncate_exception_constructor = truncateException;
                     unauthorized_exception_constructor = unauthorizedException;
+                    unavailable_exception_constructor = unavailableException;
+                    write_failure_exception_constructor = writeFailureException;
+                    write_timeout_exception_constructor = writeTimeoutException;
                 }
             }
 
@@ -789,6 +840,16 @@ namespace Cassandra
                 return new(handle);
             }
 
+            // Constructor for a System.ArgumentNullException meant for use by Rust.
+            [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
+            private static FFIGCHandle ArgumentNullExceptionFromRust(FFIString paramName, FFIString message)
+            {
+                var exception = new ArgumentNullException(paramName.ToManagedString(), message.ToManagedString());
+
+                GCHandle handle = GCHandle.Alloc(exception);
+                return new(handle);
+            }
+
             [UnmanagedCallersOnly(CallConvs = new Type[] { typeof(CallConvCdecl) })]
             private static void ForwardRustLog(byte level, FFIString message)
             {
@@ -838,15 +899,23 @@ namespace Cassandra
                     (IntPtr)AlreadyExistsConstructorPtr,
                     (IntPtr)AlreadyShutdownExceptionConstructorPtr,
                     (IntPtr)ArgumentExceptionConstructorPtr,
+                    (IntPtr)ArgumentNullExceptionConstructorPtr,
+                    (IntPtr)AuthenticationExceptionConstructorPtr,
                     (IntPtr)DeserializationExceptionConstructorPtr,
+                    (IntPtr)DriverInternalErrorConstructorPtr,
                     (IntPtr)FunctionFailureExceptionConstructorPtr,
                     (IntPtr)InvalidArgumentExceptionConstructorPtr,
                     (IntPtr)InvalidConfigurationInQueryExceptionConstructorPtr,
                     (IntPtr)InvalidQueryConstructorPtr,
                     (IntPtr)InvalidTypeExceptionConstructorPtr,
+                    (IntPtr)IsBootstrappingExceptionConstructorPtr,
                     (IntPtr)NoHostAvailableExceptionConstructorPtr,
                     (IntPtr)OperationTimedOutExceptionConstructorPtr,
+                    (IntPtr)OverloadedExceptionConstructorPtr,
                     (IntPtr)PreparedQueryNotFoundExceptionConstructorPtr,
+                    (IntPtr)RateLimitReachedExceptionConstructorPtr,
+                    (IntPtr)ReadFailureExceptionConstructorPtr,
+                    (IntPtr)ReadTimeoutExceptionConstructorPtr,
                     (IntPtr)RequestInvalidExceptionConstructorPtr,
                     (IntPtr)RustExceptionConstructorPtr,
                     (IntPtr)SchemaAgreementRequiredHostAbsentExceptionConstructorPtr,
@@ -857,18 +926,62 @@ namespace Cassandra
                     (IntPtr)SyntaxErrorExceptionConstructorPtr,
                     (IntPtr)TraceRetrievalExceptionConstructorPtr,
                     (IntPtr)TruncateExceptionConstructorPtr,
-                    (IntPtr)UnauthorizedExceptionConstructorPtr
+                    (IntPtr)UnauthorizedExceptionConstructorPtr,
+                    (IntPtr)UnavailableExceptionConstructorPtr,
+                    (IntPtr)WriteFailureExceptionConstructorPtr,
+                    (IntPtr)WriteTimeoutExceptionConstructorPtr
                 );
 
                 configure_rust_logging((IntPtr)RustLogCallbackPtr, GetRustMinLogLevel());
             }
         }
 
+        /// <summary>
+        /// Stable category of an error passed from Rust, independent of the exception type.
+        /// Must be kept in sync with <c>FFIErrorCode</c> in Rust.
+        /// </summary>
+        internal enum FFIErrorCode : int
+        {
+            None = 0,
+            Other = 1,
+            InternalError = 2,
+            InvalidArgument = 3,
+            Serialization = 4,
+            Deserialization = 5,
+            SyntaxError = 6,
+            InvalidQuery = 7,
+            Unauthorized = 8,
+            AlreadyExists = 9,
+            Unavailable = 10,
+            Overloaded = 11,
+            ServerTimeout = 12,
+            ServerFailure = 13,
+            ServerError = 14,
+            ClientTimeout = 15,
+            ConnectionBroken = 16,
+            NoHostAvailable = 17,
+            RequestInvalid = 18,
+        }
+
+        /// <summary>
+        /// Address of the node an error concerns. Read it with <see cref="FFIMaybeException.ErrorHost"/>.
+        /// All changes to this struct's fields must be mirrored in Rust code in the exact same order.
+        /// </summary>
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct FFIErrorHost
+        {
+            private readonly ushort port;
+            private readonly byte ipLength;
+            private fixed byte ip[16];
+        }
+
         /// <summary>
         /// Package used to pass optional exceptions from Rust to C# over FFI boundary.
         /// If the underlying FFIMaybeGCHandle is empty, no exception occurred.
         /// If it's non-empty, it points to a GCHandle referencing the Exception.
         /// This handle must be freed even when a different exception is thrown.
+        /// Alongside the exception, Rust passes its <see cref="FFIErrorCode"/> and the node it concerns,
+        /// so that callers can branch on the kind of error without inspecting the exception.
         /// All changes to this struct's fields must be mirrored in Rust code in the exact same order.
         /// </summary>
         // Note that there's no FFIException on the C# side, because lack of move semantics in C# makes
@@ -879,28 +992,66 @@ namespace Cassandra
             // Fields:
             // Maybe a GCHandle referencing the Exception.
             internal FFIMaybeGCHandle maybeException;
+            private FFIErrorCode code;
+            private FFIErrorHost host;
 
             // Functions:
-            private FFIMaybeException(FFIMaybeGCHandle maybeHandle)
+            private FFIMaybeException(FFIMaybeGCHandle maybeHandle, FFIErrorCode errorCode)
             {
                 maybeException = maybeHandle;
+                code = errorCode;
+                host = default;
             }
             // Creates an FFIMaybeException from the given Exception.
             internal static FFIMaybeException FromException(Exception ex)
             {
                 var handle = GCHandle.Alloc(ex);
-                return new(new FFIMaybeGCHandle(handle));
+                return new(new FFIMaybeGCHandle(handle), FFIErrorCode.Other);
             }
 
             // Creates an FFIMaybeException representing no exception.
             internal static FFIMaybeException Ok()
             {
-                return new(FFIMaybeGCHandle.Empty());
+                return new(FFIMaybeGCHandle.Empty(), FFIErrorCode.None);
             }
 
             internal readonly bool HasException => !maybeException.IsEmpty();
+
+            /// <summary>
+            /// Category of the exception, or <see cref="FFIErrorCode.None"/> if there is none.
+            /// It stays available after the exception handle is freed.
+            /// </summary>
+            internal readonly FFIErrorCode ErrorCode => ffi_exception_get_code(in this);
+
+            /// <summary>
+            /// Address of the node the exception concerns, or null if it is unknown.
+            /// </summary>
+            internal readonly IPEndPoint ErrorHost
+            {
+                get
+                {
+                    Span<byte> ip = stackalloc byte[16];
+                    unsafe
+                    {
+                        fixed (byte* ipPtr = ip)
+                        {
+                            if (!ffi_exception_get_host(in this, ipPtr, out var ipLength, out var port))
+                            {
+                                return null;
+                            }
+                            return new IPEndPoint(new IPAddress(ip.Slice(0, (int)ipLength)), port);
+                        }
+                    }
+                }
+            }
         }
 
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern FFIErrorCode ffi_exception_get_code(in FFIMaybeException exception);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern unsafe FFIBool ffi_exception_get_host(in FFIMaybeException exception, byte* ip, out nuint ipLength, out ushort port);
+
         /// <summary>
         /// Throws the exception contained in the FFIMaybeException if any.
         /// This mustn't be used in UnmanagedCallersOnly methods because throwing exceptions
@@ -1,6 +1,11 @@
 #nullable enable
 using System;
+using System.Buffers.Binary;
+using System.Collections;
 using System.Collections.Generic;
+using System.IO;
+using System.Net;
+using System.Numerics;
 using System.Runtime.CompilerServices;
 using System.Runtime.InteropServices;
 using Cassandra.Serialization;
@@ -35,7 +40,8 @@ namespace Cassandra
     /// Rust creates a <c>PreSerializedValues</c> on its stack and calls the C#
     /// <see cref="PopulateValuesCallback"/>, passing a raw pointer to the PSV.
     /// C# iterates the values and calls back into Rust via the exported
-    /// <c>psv_add_value</c> / <c>psv_add_null</c> / <c>psv_add_unset</c> functions,
+    /// <c>psv_add_value</c> / <c>psv_add_blob</c> / <c>psv_add_collection</c> / <c>psv_add_map</c> / <c>psv_add_tuple</c> / <c>psv_add_udt</c> / <c>psv_add_uuid</c> / <c>psv_add_inet</c> / <c>psv_add_decimal</c> / <c>psv_add_varint</c> /
+    /// <c>psv_add_timestamp</c> / <c>psv_add_date</c> / <c>psv_add_time</c> / <c>psv_add_duration</c> / <c>psv_add_float_vector</c> / <c>psv_add_null</c> / <c>psv_add_unset</c> functions,
     /// using <c>fixed</c> to pin each serialized byte[] for the duration of the call
     /// (no <see cref="GCHandle"/> needed).
     /// </para>
@@ -49,6 +55,48 @@ namespace Cassandra
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
         private static extern RustBridge.FFIMaybeException psv_add_value(IntPtr psv, FFISlice<byte> value, IntPtr constructors);
 
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_blob(IntPtr psv, FFISlice<byte> value, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_uuid(IntPtr psv, FFISlice<byte> value, FFIBool isTimeuuid, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_collection(IntPtr psv, byte collectionType, byte elementType, int count, FFISlice<byte> elements, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_tuple(IntPtr psv, FFISlice<byte> fieldTypes, FFISlice<byte> fields, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_udt(IntPtr psv, FFISlice<byte> fieldTypes, FFISlice<byte> fields, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_map(IntPtr psv, byte keyType, byte valueType, int count, FFISlice<byte> entries, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_inet(IntPtr psv, FFISlice<byte> value, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_decimal(IntPtr psv, int scale, FFISlice<byte> unscaled, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_varint(IntPtr psv, FFISlice<byte> value, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_timestamp(IntPtr psv, long millis, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_date(IntPtr psv, long days, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_time(IntPtr psv, long nanos, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_duration(IntPtr psv, int months, int days, long nanoseconds, IntPtr constructors);
+
+        [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
+        private static extern RustBridge.FFIMaybeException psv_add_float_vector(IntPtr psv, int dimension, FFISlice<float> elements, IntPtr constructors);
+
         [DllImport(NativeLibrary.CSharpWrapper, CallingConvention = CallingConvention.Cdecl)]
         private static extern RustBridge.FFIMaybeException psv_add_null(IntPtr psv, IntPtr constructors);
 
@@ -73,6 +121,8 @@ namespace Cassandra
             try
             {
                 var constructorsPtr = (IntPtr)Globals.ConstructorsPtr;
+                byte* uuidBuf = stackalloc byte[16];
+                byte* decimalBuf = stackalloc byte[MaxDecimalUnscaledLength];
 
                 foreach (var value in ctx.Values)
                 {
@@ -86,6 +136,123 @@ namespace Cassandra
                     {
                         result = psv_add_unset(psvPtr, constructorsPtr);
                     }
+                    else if (value is Guid || value is TimeUuid)
+                    {
+                        // Rust validates uuids itself, so they skip the serializer.
+                        var isTimeuuid = value is TimeUuid;
+                        var guid = isTimeuuid ? ((TimeUuid)value).ToGuid() : (Guid)value;
+                        GuidToFFIFormat(guid, new Span<byte>(uuidBuf, 16));
+                        var slice = new FFISlice<byte>((IntPtr)uuidBuf, 16);
+                        result = psv_add_uuid(psvPtr, slice, isTimeuuid, constructorsPtr);
+                    }
+                    else if (value is IPAddress address)
+                    {
+                        // An IPv6 address needs at most 16 bytes, so the uuid buffer is reused.
+                        address.TryWriteBytes(new Span<byte>(uuidBuf, 16), out var written);
+                        var slice = new FFISlice<byte>((IntPtr)uuidBuf, (nuint)written);
+                        result = psv_add_inet(psvPtr, slice, constructorsPtr);
+                    }
+                    else if (value is decimal dec)
+                    {
+                        var unscaledLength = DecimalToFFIFormat(dec, new Span<byte>(decimalBuf, MaxDecimalUnscaledLength), out var scale);
+                        var slice = new FFISlice<byte>((IntPtr)decimalBuf, (nuint)unscaledLength);
+                        result = psv_add_decimal(psvPtr, scale, slice, constructorsPtr);
+                    }
+                    else if (value is BigInteger varint)
+                    {
+                        var bytes = varint.ToByteArray(isUnsigned: false, isBigEndian: true);
+                        fixed (byte* ptr = bytes)
+                        {
+                            var slice = new FFISlice<byte>((IntPtr)ptr, (nuint)bytes.Length);
+                            result = psv_add_varint(psvPtr, slice, constructorsPtr);
+                        }
+                    }
+                    else if (value is DateTimeOffset timestamp)
+                    {
+                        result = psv_add_timestamp(psvPtr, TimestampToFFIFormat(timestamp), constructorsPtr);
+                    }
+                    else if (value is DateTime dateTime)
+                    {
+                        // Like the serializer, an unspecified kind is treated as UTC rather than local time.
+                        var offset = dateTime.Kind == DateTimeKind.Unspecified
+                            ? new DateTimeOffset(dateTime, TimeSpan.Zero)
+                            : new DateTimeOffset(dateTime);
+                        result = psv_add_timestamp(psvPtr, TimestampToFFIFormat(offset), constructorsPtr);
+                    }
+                    else if (value is LocalDate date)
+                    {
+                        result = psv_add_date(psvPtr, date.DaysSinceEpoch, constructorsPtr);
+                    }
+                    else if (value is LocalTime time)
+                    {
+                        result = psv_add_time(psvPtr, time.TotalNanoseconds, constructorsPtr);
+                    }
+                    else if (value is Duration duration)
+                    {
+                        result = psv_add_duration(psvPtr, duration.Months, duration.Days, duration.Nanoseconds, constructorsPtr);
+                    }
+                    else if (value is byte[] blob)
+                    {
+                        // Blobs are passed pinned in place, without a round trip through the serializer.
+                        fixed (byte* ptr = blob)
+                        {
+                            var slice = new FFISlice<byte>((IntPtr)ptr, (nuint)blob.Length);
+                            result = psv_add_blob(psvPtr, slice, constructorsPtr);
+                        }
+                    }
+                    else if (value is CqlVector<float> vector)
+                    {
+                        // Rust encodes the elements, and checks their count against the dimension of the bound column.
+                        var elements = vector.AsArray();
+                        fixed (float* ptr = elements)
+                        {
+                            var slice = new FFISlice<float>((IntPtr)ptr, (nuint)elements.Length);
+                            result = psv_add_float_vector(psvPtr, elements.Length, slice, constructorsPtr);
+                        }
+                    }
+                    else if (TryGetTupleFieldTypes(ctx.Serializer, value, out var fieldTypes))
+                    {
+                        var fields = SerializeFields(ctx.Serializer, (ITuple)value);
+                        fixed (byte* typesPtr = fieldTypes)
+                        fixed (byte* ptr = fields)
+                        {
+                            var typesSlice = new FFISlice<byte>((IntPtr)typesPtr, (nuint)fieldTypes.Length);
+                            var slice = new FFISlice<byte>((IntPtr)ptr, (nuint)fields.Length);
+                            result = psv_add_tuple(psvPtr, typesSlice, slice, constructorsPtr);
+                        }
+                    }
+                    else if (TryGetMapType(ctx.Serializer, value, out var keyType, out var valueType))
+                    {
+                        var entries = SerializeEntries(ctx.Serializer, (IDictionary)value, out var count);
+                        fixed (byte* ptr = entries)
+                        {
+                            var slice = new FFISlice<byte>((IntPtr)ptr, (nuint)entries.Length);
+                            result = psv_add_map(psvPtr, (byte)keyType, (byte)valueType, count, slice, constructorsPtr);
+                        }
+                    }
+                    else if (TryGetListOrSetType(ctx.Serializer, value, out var collectionType, out var elementType))
+                    {
+                        // Rust checks the elements against their type before assembling the collection.
+                        var elements = SerializeElements(ctx.Serializer, (IEnumerable)value, out var count);
+                        fixed (byte* ptr = elements)
+                        {
+                            var slice = new FFISlice<byte>((IntPtr)ptr, (nuint)elements.Length);
+                            result = psv_add_collection(psvPtr, (byte)collectionType, (byte)elementType, count, slice, constructorsPtr);
+                        }
+                    }
+                    else if (TryGetUdtFieldTypes(ctx.Serializer, value, out var udtFieldTypes))
+                    {
+                        // The UDT serializer writes the fields in the order of the type definition,
+                        // which Rust checks against the field types before binding the value.
+                        var fields = ctx.Serializer.Serialize(value);
+                        fixed (byte* typesPtr = udtFieldTypes)
+                        fixed (byte* ptr = fields)
+                        {
+                            var typesSlice = new FFISlice<byte>((IntPtr)typesPtr, (nuint)udtFieldTypes.Length);
+                            var slice = new FFISlice<byte>((IntPtr)ptr, (nuint)fields.Length);
+                            result = psv_add_udt(psvPtr, typesSlice, slice, constructorsPtr);
+                        }
+                    }
                     else
                     {
                         byte[] buf = ctx.Serializer.Serialize(value);
@@ -110,6 +277,264 @@ namespace Cassandra
             }
         }
 
+        /// <summary>
+        /// Upper bound on the length of the unscaled value of a <see cref="decimal"/>:
+        /// 96 bits of magnitude plus a sign bit.
+        /// </summary>
+        private const int MaxDecimalUnscaledLength = 13;
+
+        /// <summary>
+        /// Writes the unscaled value of <paramref name="value"/> to <paramref name="destination"/>
+        /// as big-endian two's-complement bytes, and returns how many bytes were written.
+        /// </summary>
+        internal static int DecimalToFFIFormat(decimal value, Span<byte> destination, out int scale)
+        {
+            Span<int> bits = stackalloc int[4];
+            decimal.GetBits(value, bits);
+            scale = (bits[3] >> 16) & 0xFF;
+            var magnitude = ((BigInteger)(uint)bits[2] << 64) | ((BigInteger)(uint)bits[1] << 32) | (uint)bits[0];
+            var unscaled = bits[3] < 0 ? -magnitude : magnitude;
+            if (!unscaled.TryWriteBytes(destination, out var written, isUnsigned: false, isBigEndian: true))
+            {
+                throw new InvalidOperationException("The unscaled value of a decimal does not fit in 13 bytes");
+            }
+            return written;
+        }
+
+        /// <summary>
+        /// Returns the milliseconds since the unix epoch of <paramref name="value"/>,
+        /// truncating sub-millisecond precision towards the epoch like the serializer does.
+        /// </summary>
+        internal static long TimestampToFFIFormat(DateTimeOffset value)
+        {
+            return (value - TypeSerializer.UnixStart).Ticks / TimeSpan.TicksPerMillisecond;
+        }
+
+        /// <summary>
+        /// Returns whether <paramref name="value"/> is bound as a <c>list</c> or <c>set</c> of a native type,
+        /// together with the type codes of the collection and its elements.
+        /// Lists and sets with nested or UDT elements are left to the serializer, and maps to <see cref="TryGetMapType"/>.
+        /// </summary>
+        internal static bool TryGetListOrSetType(ISerializer serializer, object value, out ColumnTypeCode collectionType, out ColumnTypeCode elementType)
+        {
+            collectionType = default;
+            elementType = default;
+            var type = value.GetType();
+            if (value is not IEnumerable || value is string || value is IDictionary || !(type.IsArray || type.IsGenericType))
+            {
+                return false;
+            }
+
+            IColumnInfo typeInfo;
+            try
+            {
+                collectionType = serializer.GetCqlType(type, out typeInfo);
+            }
+            catch (InvalidTypeException)
+            {
+                // e.g. List<object>, whose elements the serializer maps by their runtime type.
+                return false;
+            }
+
+            switch (typeInfo)
+            {
+                case ListColumnInfo list when collectionType == ColumnTypeCode.List && list.ValueTypeInfo == null:
+                    elementType = list.ValueTypeCode;
+                    return true;
+                case SetColumnInfo set when collectionType == ColumnTypeCode.Set && set.KeyTypeInfo == null:
+                    elementType = set.KeyTypeCode;
+                    return true;
+                default:
+                    return false;
+            }
+        }
+
+        /// <summary>
+        /// Returns whether <paramref name="value"/> is bound as a <c>tuple</c>, together with the type codes
+        /// of its fields. Fields may be of native types or nested collections, which are serialized as a whole.
+        /// Tuples with UDT or nested tuple fields are left to the serializer.
+        /// </summary>
+        internal static bool TryGetTupleFieldTypes(ISerializer serializer, object value, out byte[] fieldTypes)
+        {
+            fieldTypes = Array.Empty<byte>();
+            if (value is not ITuple)
+            {
+                return false;
+            }
+
+            IColumnInfo typeInfo;
+            try
+            {
+                if (serializer.GetCqlType(value.GetType(), out typeInfo) != ColumnTypeCode.Tuple)
+                {
+                    return false;
+                }
+            }
+            catch (InvalidTypeException)
+            {
+                return false;
+            }
+
+            if (typeInfo is not TupleColumnInfo tuple)
+            {
+                return false;
+            }
+            var types = new byte[tuple.Elements.Count];
+            for (var i = 0; i < types.Length; i++)
+            {
+                var element = tuple.Elements[i];
+                if (!IsNativeOrCollection(element.TypeCode, element.TypeInfo))
+                {
+                    return false;
+                }
+                types[i] = (byte)element.TypeCode;
+            }
+            fieldTypes = types;
+            return true;
+        }
+
+        /// <summary>
+        /// Serializes the fields of a tuple, each prefixed with its big-endian <c>[int]</c> length,
+        /// or -1 for a null field.
+        /// </summary>
+        internal static byte[] SerializeFields(ISerializer serializer, ITuple tuple)
+        {
+            using var buffer = new MemoryStream();
+            Span<byte> length = stackalloc byte[4];
+            for (var i = 0; i < tuple.Length; i++)
+            {
+                var field = tuple[i];
+                WriteItem(buffer, length, field == null ? null : serializer.Serialize(field));
+            }
+            return buffer.ToArray();
+        }
+
+        /// <summary>
+        /// Returns whether <paramref name="value"/> is bound as a user defined type, together with the type codes
+        /// of its fields in the order of the type definition. The definition comes from the cluster metadata,
+        /// through the <see cref="UdtMap"/> registered for the type of <paramref name="value"/>.
+        /// </summary>
+        internal static bool TryGetUdtFieldTypes(ISerializer serializer, object value, out byte[] fieldTypes)
+        {
+            fieldTypes = Array.Empty<byte>();
+            var type = value.GetType();
+            if (type.IsPrimitive || value is string)
+            {
+                return false;
+            }
+
+            IColumnInfo typeInfo;
+            try
+            {
+                if (serializer.GetCqlType(type, out typeInfo) != ColumnTypeCode.Udt)
+                {
+                    return false;
+                }
+            }
+            catch (InvalidTypeException)
+            {
+                return false;
+            }
+
+            if (typeInfo is not UdtColumnInfo udt)
+            {
+                return false;
+            }
+            var types = new byte[udt.Fields.Count];
+            for (var i = 0; i < types.Length; i++)
+            {
+                types[i] = (byte)udt.Fields[i].TypeCode;
+            }
+            fieldTypes = types;
+            return true;
+        }
+
+        /// <summary>
+        /// Returns whether <paramref name="value"/> is bound as a <c>map</c>, together with the type codes
+        /// of its keys and values. Keys and values may be of native types or nested collections, which are
+        /// serialized as a whole. Maps with UDT or tuple keys or values are left to the serializer.
+        /// </summary>
+        internal static bool TryGetMapType(ISerializer serializer, object value, out ColumnTypeCode keyType, out ColumnTypeCode valueType)
+        {
+            keyType = default;
+            valueType = default;
+            if (value is not IDictionary || !value.GetType().IsGenericType)
+            {
+                return false;
+            }
+
+            IColumnInfo typeInfo;
+            try
+            {
+                if (serializer.GetCqlType(value.GetType(), out typeInfo) != ColumnTypeCode.Map)
+                {
+                    return false;
+                }
+            }
+            catch (InvalidTypeException)
+            {
+                return false;
+            }
+
+            if (typeInfo is not MapColumnInfo map || !IsNativeOrCollection(map.KeyTypeCode, map.KeyTypeInfo) || !IsNativeOrCollection(map.ValueTypeCode, map.ValueTypeInfo))
+            {
+                return false;
+            }
+            keyType = map.KeyTypeCode;
+            valueType = map.ValueTypeCode;
+            return true;
+        }
+
+        private static bool IsNativeOrCollection(ColumnTypeCode typeCode, IColumnInfo? typeInfo)
+        {
+            return typeInfo == null || typeCode is ColumnTypeCode.List or ColumnTypeCode.Set or ColumnTypeCode.Map;
+        }
+
+        /// <summary>
+        /// Serializes the entries of a map as alternating keys and values, each prefixed with its
+        /// big-endian <c>[int]</c> length, or -1 for a null value.
+        /// </summary>
+        internal static byte[] SerializeEntries(ISerializer serializer, IDictionary map, out int count)
+        {
+            count = 0;
+            using var buffer = new MemoryStream();
+            Span<byte> length = stackalloc byte[4];
+            foreach (DictionaryEntry entry in map)
+            {
+                WriteItem(buffer, length, serializer.Serialize(entry.Key));
+                WriteItem(buffer, length, entry.Value == null ? null : serializer.Serialize(entry.Value));
+                count++;
+            }
+            return buffer.ToArray();
+        }
+
+        /// <summary>
+        /// Serializes the elements of a collection, each prefixed with its big-endian <c>[int]</c> length,
+        /// or -1 for a null element.
+        /// </summary>
+        internal static byte[] SerializeElements(ISerializer serializer, IEnumerable collection, out int count)
+        {
+            count = 0;
+            using var buffer = new MemoryStream();
+            Span<byte> length = stackalloc byte[4];
+            foreach (var element in collection)
+            {
+                WriteItem(buffer, length, element == null ? null : serializer.Serialize(element));
+                count++;
+            }
+            return buffer.ToArray();
+        }
+
+        private static void WriteItem(MemoryStream buffer, Span<byte> length, byte[]? bytes)
+        {
+            BinaryPrimitives.WriteInt32BigEndian(length, bytes?.Length ?? -1);
+            buffer.Write(length);
+            if (bytes != null)
+            {
+                buffer.Write(bytes, 0, bytes.Length);
+            }
+        }
+
         internal static PopulateValuesContext CreateContext(IReadOnlyList<object?> values, ISerializer serializer)
         {
             ArgumentNullException.ThrowIfNull(values);
@@ -33,6 +33,11 @@ namespace Cassandra
         private bool _checkCertificateRevocation;
         private X509CertificateCollection _certificateCollection = new X509CertificateCollection();
         private Func<IPAddress, string> _hostNameResolver = GetHostName;
+        private X509CertificateCollection _certificateAuthorities = new X509CertificateCollection();
+        private bool _hostNameVerification = true;
+        private string _certificateAuthoritiesPath;
+        private string _clientCertificatePath;
+        private string _clientKeyPath;
 
         /// <summary>
         /// Verifies Cassandra host SSL certificate used for authentication.
@@ -74,6 +79,51 @@ namespace Cassandra
             get { return _certificateCollection; }
         }
 
+        /// <summary>
+        /// Gets the certificates of the authorities trusted to sign node certificates.
+        /// If empty, the trust store of the platform is used.
+        /// </summary>
+        public X509CertificateCollection CertificateAuthorities
+        {
+            get { return _certificateAuthorities; }
+        }
+
+        /// <summary>
+        /// Determines whether node certificates must be valid for the address of the node.
+        /// The chain of trust is verified regardless. Defaults to <c>true</c>.
+        /// </summary>
+        public bool HostNameVerification
+        {
+            get { return _hostNameVerification; }
+        }
+
+        /// <summary>
+        /// Gets the path of a PEM file with the certificates of the authorities trusted to sign node certificates,
+        /// or <c>null</c>. The file is read when connecting.
+        /// </summary>
+        public string CertificateAuthoritiesPath
+        {
+            get { return _certificateAuthoritiesPath; }
+        }
+
+        /// <summary>
+        /// Gets the path of a PEM file with the client certificate chain, or <c>null</c>.
+        /// The file is read when connecting.
+        /// </summary>
+        public string ClientCertificatePath
+        {
+            get { return _clientCertificatePath; }
+        }
+
+        /// <summary>
+        /// Gets the path of a PEM file with the private key of the client certificate, or <c>null</c>.
+        /// The file is read when connecting.
+        /// </summary>
+        public string ClientKeyPath
+        {
+            get { return _clientKeyPath; }
+        }
+
         /// <summary>
         ///  Creates SSLOptions with default values.   
         /// </summary>
@@ -107,6 +157,51 @@ namespace Cassandra
             return this;
         }
 
+        /// <summary>
+        /// Sets the certificates of the authorities trusted to sign node certificates.
+        /// If empty, the trust store of the platform is used.
+        /// </summary>
+        public SSLOptions SetCertificateAuthorities(X509CertificateCollection certificates)
+        {
+            _certificateAuthorities = certificates ?? new X509CertificateCollection();
+            return this;
+        }
+
+        /// <summary>
+        /// Sets the path of a PEM file with the certificates of the authorities trusted to sign node certificates,
+        /// as an alternative to <see cref="SetCertificateAuthorities"/>, which must then be left empty.
+        /// The file is read when connecting, which fails with an <see cref="ArgumentException"/> if it cannot be read.
+        /// A <c>null</c> or empty path, with no <see cref="CertificateAuthorities"/>, uses the trust store of the platform.
+        /// </summary>
+        public SSLOptions SetCertificateAuthoritiesPath(string path)
+        {
+            _certificateAuthoritiesPath = path;
+            return this;
+        }
+
+        /// <summary>
+        /// Sets the paths of PEM files with the client certificate chain and its private key, used for mutual TLS
+        /// as an alternative to <see cref="SetCertificateCollection"/>, which must then hold no certificate with a
+        /// private key. Both paths must be set together.
+        /// The files are read when connecting, which fails with an <see cref="ArgumentException"/> if either cannot be read.
+        /// </summary>
+        public SSLOptions SetClientCertificatePaths(string certificatePath, string keyPath)
+        {
+            _clientCertificatePath = certificatePath;
+            _clientKeyPath = keyPath;
+            return this;
+        }
+
+        /// <summary>
+        /// Determines whether node certificates must be valid for the address of the node.
+        /// Disabling it still requires the certificate to be signed by a trusted authority.
+        /// </summary>
+        public SSLOptions SetHostNameVerification(bool flag)
+        {
+            _hostNameVerification = flag;
+            return this;
+        }
+
         /// <summary>
         /// Sets the method to be use to determine the host name from the host IP address
         /// </summary>
@@ -134,6 +229,18 @@ namespace Cassandra
             return this;
         }
 
+        /// <summary>
+        /// Whether no callback or the default one is used, which rejects any certificate with policy errors.
+        /// </summary>
+        internal bool HasDefaultRemoteCertValidationCallback
+        {
+            get
+            {
+                return _remoteCertValidationCallback == null ||
+                       _remoteCertValidationCallback == (RemoteCertificateValidationCallback)ValidateServerCertificate;
+            }
+        }
+
         private static bool ValidateServerCertificate(
             object sender,
             X509Certificate certificate,
@@ -15,6 +15,7 @@
 //
 
 using System;
+using System.Collections.Concurrent;
 using System.Collections.Generic;
 using System.Net;
 using System.Runtime.InteropServices;
@@ -33,12 +34,16 @@ namespace Cassandra
     {
         private readonly BridgedSession bridgedSession;
         private readonly ISerializerManager _serializerManager;
+        private readonly ConcurrentDictionary<string, BridgedExecutionProfile> _executionProfiles =
+            new ConcurrentDictionary<string, BridgedExecutionProfile>();
         private static readonly Logger Logger = new Logger(typeof(Session));
         private readonly ICluster _cluster;
         private int _disposed;
 
         public int BinaryProtocolVersion => 4;
 
+        internal BridgedSession BridgedSession => bridgedSession;
+
         /// <inheritdoc />
         public ICluster Cluster => _cluster;
 
@@ -261,6 +266,9 @@ namespace Cassandra
                         ushort consistencyLevel = s.ConsistencyLevel.HasValue ? (ushort)s.ConsistencyLevel.Value : (ushort)999;
                         bool isIdempotent = s.IsIdempotent ?? Configuration.QueryOptions.GetDefaultIdempotence();
                         int pageSize = s.PageSize <= 0 ? Configuration.QueryOptions.GetPageSize() : s.PageSize;
+                        // Non-positive values keep the session default.
+                        int requestTimeoutMillis = s.ReadTimeoutMillis;
+                        long timestampMicros = ToTimestampMicros(s.Timestamp);
                         if (pageSize == int.MaxValue)
                         {
                             throw new NotImplementedException(
@@ -276,7 +284,9 @@ namespace Cassandra
                                 hasConsistencyLevel,
                                 consistencyLevel,
                                 isIdempotent,
-                                pageSize
+                                pageSize,
+                                requestTimeoutMillis,
+                                timestampMicros
                             );
                         }
                         else
@@ -288,7 +298,9 @@ namespace Cassandra
                                 hasConsistencyLevel,
                                 consistencyLevel,
                                 isIdempotent,
-                                pageSize
+                                pageSize,
+                                requestTimeoutMillis,
+                                timestampMicros
                             );
                         }
 
@@ -314,6 +326,8 @@ namespace Cassandra
                         // When the idempotence property is null, the driver will use the default value from QueryOptions.GetDefaultIdempotence().
                         bool isIdempotent = bs.IsIdempotent ?? Configuration.QueryOptions.GetDefaultIdempotence();
                         int pageSize = bs.PageSize <= 0 ? Configuration.QueryOptions.GetPageSize() : bs.PageSize;
+                        int requestTimeoutMillis = bs.ReadTimeoutMillis;
+                        long timestampMicros = ToTimestampMicros(bs.Timestamp);
                         if (pageSize == int.MaxValue)
                         {
                             throw new NotImplementedException(
@@ -337,7 +351,9 @@ namespace Cassandra
                                 hasConsistencyLevel,
                                 consistencyLevel,
                                 isIdempotent,
-                                pageSize
+                                pageSize,
+                                requestTimeoutMillis,
+                                timestampMicros
                             );
                         }
                         else
@@ -349,7 +365,9 @@ namespace Cassandra
                                 hasConsistencyLevel,
                                 consistencyLevel,
                                 isIdempotent,
-                                pageSize
+                                pageSize,
+                                requestTimeoutMillis,
+                                timestampMicros
                             );
                         }
 
@@ -363,13 +381,83 @@ namespace Cassandra
                     }
 
                 case BatchStatement s:
-                    throw new NotImplementedException("Batches are not yet supported");
+                    {
+                        var serializer = _serializerManager.GetCurrentSerializer();
+                        using var bridgedBatch = BridgedBatch.Create(s.BatchType);
+                        foreach (var query in s.Queries)
+                        {
+                            switch (query)
+                            {
+                                case BoundStatement bs:
+                                    bridgedBatch.AddPrepared(
+                                        bs.PreparedStatement.bridgedPreparedStatement, bs.QueryValues ?? [], serializer);
+                                    break;
+                                case RegularStatement rs:
+                                    bridgedBatch.AddSimple(rs.QueryString, rs.QueryValues ?? [], serializer);
+                                    break;
+                                default:
+                                    throw new ArgumentException("Batches may only contain simple and bound statements");
+                            }
+                        }
+
+                        if (s.ConsistencyLevel.HasValue)
+                        {
+                            bridgedBatch.SetConsistencyLevel(s.ConsistencyLevel.Value);
+                        }
+                        var executionProfile = GetExecutionProfile(executionProfileName);
+                        if (executionProfile != null)
+                        {
+                            bridgedBatch.SetExecutionProfile(executionProfile);
+                        }
+                        bridgedBatch.SetIsIdempotent(s.IsIdempotent ?? Configuration.QueryOptions.GetDefaultIdempotence());
+
+                        // The batch is snapshotted by the native call, so it can be disposed right after it.
+                        return bridgedSession.ExecuteBatch(bridgedBatch).ContinueWith(t =>
+                        {
+                            // Use GetAwaiter().GetResult() to unwrap AggregateException
+                            // and throw the inner exception directly, avoiding double-wrapping.
+                            // Conditional batches return the [applied] row, and the existing values on conflict.
+                            using var result = new BridgedQueryResult(t.GetAwaiter().GetResult());
+                            var rs = new RowSet(result, _serializerManager);
+                            rs.Info.SetTriedHosts(new List<IPEndPoint> { result.GetCoordinatorAddress() });
+                            rs.Info.SetAchievedConsistency(result.GetAchievedConsistency());
+                            rs.Info.Warnings = result.GetWarnings();
+                            foreach (var warning in rs.Info.Warnings)
+                            {
+                                Logger.Warning("Server warning for batch: {0}", warning);
+                            }
+                            return rs;
+                        }, TaskContinuationOptions.ExecuteSynchronously);
+                    }
 
                 default:
                     throw new ArgumentException("Unsupported statement type");
             }
         }
 
+        /// <summary>
+        /// Fetches the server-side trace identified by <see cref="QueryTrace.TraceId"/> and fills <paramref name="trace"/> with it.
+        /// </summary>
+        internal async Task LoadQueryTraceAsync(QueryTrace trace)
+        {
+            var mdTrace = await bridgedSession.GetQueryTrace(trace.TraceId).ConfigureAwait(false);
+            using var bridgedTrace = new BridgedQueryTrace(mdTrace);
+            bridgedTrace.CopyTo(trace);
+        }
+
+        /// <summary>
+        /// Converts a client-side statement timestamp to microseconds since the Unix epoch,
+        /// or -1 to let the driver or server assign one. Timestamps before the epoch are not supported.
+        /// </summary>
+        private static long ToTimestampMicros(DateTimeOffset? timestamp)
+        {
+            if (!timestamp.HasValue || timestamp.Value < DateTimeOffset.UnixEpoch)
+            {
+                return -1;
+            }
+            return (timestamp.Value - DateTimeOffset.UnixEpoch).Ticks / 10;
+        }
+
         public IDriverMetrics GetMetrics()
         {
             throw new NotImplementedException("GetMetrics is not yet implemented"); // FIXME: bridge with Rust metrics.
@@ -444,7 +532,7 @@ namespace Cassandra
                 // Use GetAwaiter().GetResult() to unwrap AggregateException
                 // and throw the inner exception directly, avoiding double-wrapping.
                 RustBridge.ManuallyDestructible mdPreparedStatement = t.GetAwaiter().GetResult();
-                var ps = new PreparedStatement(mdPreparedStatement, cqlQuery, _serializerManager);
+                var ps = new PreparedStatement(mdPreparedStatement, cqlQuery, _serializerManager, this);
                 return ps;
             }, TaskContinuationOptions.ExecuteSynchronously);
         }
@@ -494,9 +582,27 @@ namespace Cassandra
             return new SimpleStatement(cqlQuery);
         }
 
+        /// <summary>
+        /// Gets the Rust profile built from the execution profile with the given name,
+        /// or null for the default profile, which is the one of the Rust session.
+        /// Profiles are built on first use and shared by every statement they are attached to.
+        /// </summary>
+        /// <exception cref="ArgumentException">Thrown when no execution profile with the given name was added.</exception>
+        internal BridgedExecutionProfile GetExecutionProfile(string executionProfileName)
+        {
+            if (executionProfileName == Configuration.DefaultExecutionProfileName)
+            {
+                return null;
+            }
+
+            var options = GetRequestOptions(executionProfileName);
+            return _executionProfiles.GetOrAdd(executionProfileName, _ => BridgedExecutionProfile.Build(options, Configuration));
+        }
+
         private IRequestOptions GetRequestOptions(string executionProfileName)
         {
-            // FIXME: bridge with Rust execution profiles.
+            // FIXME: bridge with Rust execution profiles for simple and bound statements,
+            // only batches and prepared statements can be given a profile so far.
             if (!Configuration.RequestOptions.TryGetValue(executionProfileName, out var profile))
             {
                 throw new ArgumentException("The provided execution profile name does not exist. It must be added through the Cluster Builder.");
@@ -505,6 +611,20 @@ namespace Cassandra
             return profile;
         }
 
+        /// <summary>
+        /// Gets a snapshot of the connection pools of the session, for the hosts known to the metadata.
+        /// </summary>
+        internal ISessionState GetState()
+        {
+            var pools = bridgedSession.GetPoolState();
+            var openConnections = new Dictionary<Host, int>();
+            foreach (var host in _cluster.Metadata.AllHosts())
+            {
+                openConnections[host] = pools.TryGetValue(host.HostId, out var connections) ? connections : 0;
+            }
+            return new SessionState(openConnections);
+        }
+
         /// <summary>
         /// Gets the ClusterState from the Rust session.
         /// </summary>
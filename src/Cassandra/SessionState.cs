@@ -0,0 +1,60 @@
+//
+//      Copyright (C) DataStax Inc.
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+//
+
+
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Cassandra
+{
+    /// <summary>
+    /// Snapshot of the connection pools of a session, taken from the Rust driver.
+    /// <para>
+    /// The Rust driver keeps every pool filled and reopens broken connections right away, so the number of
+    /// open connections is the size of the pool of each connected host. It does not track in-flight requests
+    /// per host, so <see cref="GetInFlightQueries"/> always returns 0.
+    /// </para>
+    /// </summary>
+    internal sealed class SessionState : ISessionState
+    {
+        private readonly IReadOnlyDictionary<Host, int> _openConnections;
+
+        internal SessionState(IReadOnlyDictionary<Host, int> openConnections)
+        {
+            _openConnections = openConnections;
+        }
+
+        public IReadOnlyCollection<Host> GetConnectedHosts()
+        {
+            return _openConnections.Where(pair => pair.Value > 0).Select(pair => pair.Key).ToList();
+        }
+
+        public int GetOpenConnections(Host host)
+        {
+            return _openConnections.TryGetValue(host, out var connections) ? connections : 0;
+        }
+
+        public int GetInFlightQueries(Host host)
+        {
+            return 0;
+        }
+
+        public override string ToString()
+        {
+            return "{" + string.Join(", ", _openConnections.Select(pair => $"\"{pair.Key.Address}\": {{ connections: {pair.Value} }}")) + "}";
+        }
+    }
+}
@@ -33,6 +33,7 @@ namespace Cassandra
         internal const bool DefaultKeepAlive = true;
         internal const int DefaultKeepAliveIntervalMillis = 2000;
         internal const bool DefaultTcpNoDelay = true;
+        internal const bool DefaultWriteCoalescing = true;
         private int _connectTimeoutMillis = DefaultConnectTimeoutMillis;
         private bool _keepAlive = DefaultKeepAlive;
         private int _keepAliveIntervalMillis = DefaultKeepAliveIntervalMillis;
@@ -41,6 +42,7 @@ namespace Cassandra
         private int? _sendBufferSize;
         private int? _soLinger;
         private bool _tcpNoDelay = DefaultTcpNoDelay;
+        private bool _writeCoalescing = DefaultWriteCoalescing;
         private int _readTimeoutMillis = DefaultReadTimeoutMillis;
 
         /// <summary>
@@ -90,6 +92,15 @@ namespace Cassandra
             get { return _tcpNoDelay; }
         }
 
+        /// <summary>
+        /// Gets a Boolean value that specifies whether requests written to a connection at about the same time
+        /// are coalesced into fewer, larger writes to the socket. The default is <c>true</c>.
+        /// </summary>
+        public bool WriteCoalescing
+        {
+            get { return _writeCoalescing; }
+        }
+
         /// <summary>
         /// Gets the size of the buffer used by the socket to receive
         /// </summary>
@@ -175,6 +186,17 @@ namespace Cassandra
             return this;
         }
 
+        /// <summary>
+        /// Sets whether requests written to a connection at about the same time are coalesced into fewer,
+        /// larger writes to the socket. Coalescing improves throughput when many small requests are sent,
+        /// at the cost of some latency jitter, as writes may wait for other requests. The default is <c>true</c>.
+        /// </summary>
+        public SocketOptions SetWriteCoalescing(bool writeCoalescing)
+        {
+            _writeCoalescing = writeCoalescing;
+            return this;
+        }
+
         /// <summary>
         /// Sets the size of the buffer used by the socket to receive
         /// </summary>